@@ -0,0 +1,105 @@
+//! Bearer-token authentication for the public HTTP API.
+use std::net::SocketAddr;
+use axum::extract::{ConnectInfo, FromRequestParts};
+use axum::http::header::AUTHORIZATION;
+use axum::http::request::Parts;
+
+use crate::authentication::check_rate_limit;
+use crate::authentication::models::{rate_limits, ApiKey, ApiScope, RateLimitKey, RateLimitResult};
+use crate::db::get_db;
+use crate::rest::ApiError;
+use crate::users::User;
+use crate::utils::RequestMeta;
+
+/// The API key a request was made with and the user who owns it.
+///
+/// Rejects requests without a valid `Authorization: Bearer wk_...` header, from
+/// addresses outside the key's allowlist, or over the API rate limit. Anything
+/// but a read is also refused while maintenance is running, unless the key's
+/// owner may work through it.
+#[derive(Debug, Clone)]
+pub struct ApiKeyAuth {
+    pub key: ApiKey,
+    pub user: User,
+    pub meta: RequestMeta,
+}
+
+impl ApiKeyAuth {
+    pub fn require(&self, scope: ApiScope) -> Result<(), ApiError> {
+        if self.key.has_scopes(&[scope]) {
+            Ok(())
+        } else {
+            Err(ApiError::forbidden(format!("This key lacks the '{}' scope", scope.as_str())))
+        }
+    }
+}
+
+#[axum::async_trait]
+impl<S> FromRequestParts<S> for ApiKeyAuth
+where
+    S: Send + Sync,
+{
+    type Rejection = ApiError;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        let db = get_db().await;
+        let db_error = |e: sqlx::Error| {
+            tracing::error!("Database error authenticating API key: {}", e);
+            ApiError::internal("Failed to authenticate")
+        };
+
+        let secret = parts
+            .headers
+            .get(AUTHORIZATION)
+            .and_then(|v| v.to_str().ok())
+            .and_then(|v| v.strip_prefix("Bearer "))
+            .map(str::trim)
+            .ok_or_else(|| ApiError::unauthorized("Missing bearer token"))?;
+
+        let peer = parts
+            .extensions
+            .get::<ConnectInfo<SocketAddr>>()
+            .map(|ConnectInfo(addr)| *addr);
+        let meta = RequestMeta::from_parts(&parts.headers, peer);
+
+        let mut key = ApiKey::find_by_secret(db, secret)
+            .await
+            .map_err(db_error)?
+            .filter(ApiKey::is_valid)
+            .ok_or_else(|| ApiError::unauthorized("Invalid or expired API key"))?;
+
+        if !key.allows_ip(meta.ip_address) {
+            tracing::warn!("API key {} used from disallowed address {:?}", key.key, meta.ip_address);
+            return Err(ApiError::forbidden("This key can't be used from your address"));
+        }
+
+        let user = sqlx::query_as::<_, User>("SELECT * FROM users WHERE id = $1 AND is_active = true")
+            .bind(key.user_id)
+            .fetch_optional(db)
+            .await
+            .map_err(db_error)?
+            .ok_or_else(|| ApiError::unauthorized("The account for this key is disabled"))?;
+
+        match check_rate_limit(db, &rate_limits::API, &RateLimitKey::ApiKey(key.key), 1.0).await {
+            Ok((RateLimitResult::Denied { retry_after_seconds }, _)) => {
+                return Err(ApiError::too_many_requests(retry_after_seconds));
+            }
+            Ok(_) => {}
+            // Same as server functions: don't fail requests because the limiter is down
+            Err(e) => tracing::error!("Rate limiter unavailable for API key {}: {}", key.key, e),
+        }
+
+        if !parts.method.is_safe() {
+            if let Some(window) = crate::log::writes_blocked_for(db, Some(user.role_id)).await.map_err(db_error)? {
+                return Err(ApiError::under_maintenance(
+                    format!("The site is read-only for maintenance until {}", window.ends_at.to_rfc3339()),
+                    window.ends_in().num_seconds().max(1),
+                ));
+            }
+        }
+
+        key.record_usage(db, meta.ip_address).await.map_err(db_error)?;
+
+        Ok(ApiKeyAuth { key, user, meta })
+    }
+}
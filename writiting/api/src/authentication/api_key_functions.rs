@@ -0,0 +1,138 @@
+#[cfg(feature = "server")]
+use chrono::{Duration, Utc};
+use dioxus::prelude::*;
+#[cfg(feature = "server")]
+use serde_json::json;
+use uuid::Uuid;
+#[cfg(feature = "server")]
+use validator::Validate;
+#[cfg(feature = "server")]
+use tracing::info;
+
+use crate::authentication::models::{ApiKey, CreateApiKeyRequest, CreatedApiKey};
+#[cfg(feature = "server")]
+use crate::authentication::models::SecurityAction;
+#[cfg(feature = "server")]
+use crate::authentication::models::api_key_model::{normalize_cidr, API_KEY_COLUMNS, MAX_API_KEYS_PER_USER};
+#[cfg(feature = "server")]
+use crate::authentication::record_security_event;
+#[cfg(feature = "server")]
+use crate::db::get_db;
+
+/// Creates a key for the signed-in user. The secret is returned once; only its hash is kept.
+#[server]
+pub async fn create_api_key(request: CreateApiKeyRequest) -> Result<CreatedApiKey, ServerFnError> {
+    let db = get_db().await;
+    crate::log::require_writable().await?;
+    let user = crate::session::current_user().await?;
+    let meta = crate::utils::request_meta().await;
+    let db_error = |e: sqlx::Error| -> ServerFnError {
+        tracing::error!("Database error creating API key: {}", e);
+        ServerFnError::ServerError("Failed to create API key".into())
+    };
+
+    request
+        .validate()
+        .map_err(|e| -> ServerFnError { ServerFnError::Request(e.to_string()) })?;
+
+    let mut allowed_ips = Vec::with_capacity(request.allowed_ips.len());
+    for block in request.allowed_ips.iter().map(|b| b.trim()).filter(|b| !b.is_empty()) {
+        let normalized = normalize_cidr(block).ok_or_else(|| -> ServerFnError {
+            ServerFnError::Request(format!("'{}' is not an IP address or CIDR block", block))
+        })?;
+        allowed_ips.push(normalized);
+    }
+
+    let active = sqlx::query_scalar::<_, i64>(
+        "SELECT COUNT(*) FROM api_keys WHERE user_id = $1 AND revoked_at IS NULL"
+    )
+    .bind(user.id)
+    .fetch_one(db)
+    .await
+    .map_err(db_error)?;
+
+    if active >= MAX_API_KEYS_PER_USER {
+        return Err(ServerFnError::Request(format!(
+            "You can have at most {} active API keys; revoke one first",
+            MAX_API_KEYS_PER_USER
+        )));
+    }
+
+    let mut scopes = request.scopes.clone();
+    scopes.sort();
+    scopes.dedup();
+
+    let secret = ApiKey::generate_secret();
+    let expires_at = request.expires_in_days.map(|days| Utc::now() + Duration::days(days));
+
+    let mut tx = db.begin().await.map_err(db_error)?;
+
+    let key = sqlx::query_as::<_, ApiKey>(&format!(
+        r#"
+        INSERT INTO api_keys (user_id, name, description, scopes, allowed_ips, key_hash, prefix, expires_at)
+        VALUES ($1, $2, $3, $4, NULLIF($5::cidr[], '{{}}'), $6, $7, $8)
+        RETURNING {}
+        "#,
+        API_KEY_COLUMNS
+    ))
+    .bind(user.id)
+    .bind(request.name.trim())
+    .bind(request.description.as_deref().map(str::trim).filter(|d| !d.is_empty()))
+    .bind(&scopes)
+    .bind(&allowed_ips)
+    .bind(ApiKey::hash_secret(&secret))
+    .bind(ApiKey::display_prefix(&secret))
+    .bind(expires_at)
+    .fetch_one(&mut *tx)
+    .await
+    .map_err(db_error)?;
+
+    record_security_event(
+        &mut *tx,
+        Some(user.id),
+        SecurityAction::ApiKeyCreated,
+        &meta,
+        json!({ "key": key.key, "name": key.name, "scopes": key.scopes }),
+    )
+    .await
+    .map_err(db_error)?;
+
+    tx.commit().await.map_err(db_error)?;
+
+    info!("API key {} created for user {}", key.key, user.id);
+    Ok(CreatedApiKey { key, secret })
+}
+
+/// The signed-in user's keys, newest first, including revoked and expired ones
+#[server]
+pub async fn list_api_keys() -> Result<Vec<ApiKey>, ServerFnError> {
+    let db = get_db().await;
+    let user = crate::session::current_user().await?;
+
+    ApiKey::list_for_user(db, user.id, true).await.map_err(|e| {
+        tracing::error!("Database error listing API keys: {}", e);
+        ServerFnError::ServerError("Failed to load API keys".into())
+    })
+}
+
+#[server]
+pub async fn revoke_api_key(key: Uuid) -> Result<(), ServerFnError> {
+    let db = get_db().await;
+    let user = crate::session::current_user().await?;
+    let meta = crate::utils::request_meta().await;
+    let db_error = |e: sqlx::Error| -> ServerFnError {
+        tracing::error!("Database error revoking API key: {}", e);
+        ServerFnError::ServerError("Failed to revoke API key".into())
+    };
+
+    if !ApiKey::revoke(db, user.id, key).await.map_err(db_error)? {
+        return Err(ServerFnError::Request("That key doesn't exist or is already revoked".into()));
+    }
+
+    record_security_event(db, Some(user.id), SecurityAction::ApiKeyRevoked, &meta, json!({ "key": key }))
+        .await
+        .map_err(db_error)?;
+
+    info!("API key {} revoked by user {}", key, user.id);
+    Ok(())
+}
@@ -0,0 +1,212 @@
+use dioxus::prelude::*;
+#[cfg(feature = "server")]
+use serde_json::json;
+#[cfg(feature = "server")]
+use tracing::info;
+
+use crate::authentication::models::{AuditLogBuilder, AuditLogFilter, AuditLogPage};
+#[cfg(feature = "server")]
+use crate::authentication::models::{AuditAction, AuditLog};
+#[cfg(feature = "server")]
+use crate::authentication::models::audit_log_model::AUDIT_LOG_COLUMNS;
+#[cfg(feature = "server")]
+use crate::db::get_db;
+
+#[cfg(feature = "server")]
+const AUDIT_PAGE_SIZE: i64 = 50;
+#[cfg(feature = "server")]
+const AUDIT_EXPORT_LIMIT: i64 = 10_000;
+
+// Every filter is optional; NULL parameters match everything
+#[cfg(feature = "server")]
+const AUDIT_FILTER: &str = r#"
+    FROM audit_logs a
+    LEFT JOIN users u ON u.id = a.user_id
+    WHERE ($1::text IS NULL OR lower(u.username) = lower($1) OR lower(u.email) = lower($1))
+      AND ($2::text IS NULL OR a.entity_type = $2)
+      AND ($3::text IS NULL OR a.entity_id = $3)
+      AND ($4::audit_action IS NULL OR a.action = $4)
+      AND ($5::date IS NULL OR a.created_at >= $5::date)
+      AND ($6::date IS NULL OR a.created_at < $6::date + 1)
+"#;
+
+/// Audit entry for a change made by the current request, with the signed-in
+/// user (if any) as the actor and the client's address and user agent filled in
+#[cfg(feature = "server")]
+pub async fn audit_entry(action: AuditAction, entity_type: &str, entity_id: impl ToString) -> AuditLogBuilder {
+    let meta = crate::utils::request_meta().await;
+    let builder = AuditLog::builder(action, entity_type, entity_id).request(&meta);
+
+    let builder = match crate::session::current_user().await {
+        Ok(user) => builder.user_id(user.id),
+        Err(_) => builder,
+    };
+
+    // Changes made while impersonating are credited to the account but name the admin
+    match crate::session::current_impersonator().await {
+        Some(admin_id) => builder.metadata(json!({ "impersonated_by": admin_id })),
+        None => builder,
+    }
+}
+
+/// Records an entry without failing the change it describes. Inside a
+/// transaction the insert runs in a savepoint, so a failed insert doesn't
+/// abort the transaction and turn its commit into a rollback.
+pub async fn record_audit<'c, A>(db: A, entry: AuditLogBuilder)
+where
+    A: sqlx::Acquire<'c, Database = sqlx::Postgres>,
+{
+    let result = async {
+        let mut savepoint = db.begin().await?;
+        entry.record(&mut *savepoint).await?;
+        savepoint.commit().await
+    }
+    .await;
+
+    if let Err(e) = result {
+        tracing::error!("Failed to write audit log: {}", e);
+    }
+}
+
+#[cfg(feature = "server")]
+fn blank_to_none(value: &Option<String>) -> Option<String> {
+    value.as_deref().map(str::trim).filter(|v| !v.is_empty()).map(str::to_string)
+}
+
+#[cfg(feature = "server")]
+async fn query_audit_logs(filter: &AuditLogFilter, limit: i64, offset: i64) -> Result<(Vec<AuditLog>, i64), sqlx::Error> {
+    let db = get_db().await;
+    let user = blank_to_none(&filter.user);
+    let entity_type = blank_to_none(&filter.entity_type);
+    let entity_id = blank_to_none(&filter.entity_id);
+
+    let total = sqlx::query_scalar::<_, i64>(&format!("SELECT COUNT(*) {}", AUDIT_FILTER))
+        .bind(&user)
+        .bind(&entity_type)
+        .bind(&entity_id)
+        .bind(filter.action)
+        .bind(filter.from)
+        .bind(filter.to)
+        .fetch_one(db)
+        .await?;
+
+    let entries = sqlx::query_as::<_, AuditLog>(&format!(
+        "SELECT {} {} ORDER BY a.created_at DESC, a.id DESC LIMIT $7 OFFSET $8",
+        AUDIT_LOG_COLUMNS, AUDIT_FILTER
+    ))
+    .bind(&user)
+    .bind(&entity_type)
+    .bind(&entity_id)
+    .bind(filter.action)
+    .bind(filter.from)
+    .bind(filter.to)
+    .bind(limit)
+    .bind(offset)
+    .fetch_all(db)
+    .await?;
+
+    Ok((entries, total))
+}
+
+#[server]
+pub async fn list_audit_logs(filter: AuditLogFilter) -> Result<AuditLogPage, ServerFnError> {
+    crate::session::require_permission("system:audit").await?;
+
+    let page = filter.page.max(1);
+    let (entries, total) = query_audit_logs(&filter, AUDIT_PAGE_SIZE, (page - 1) * AUDIT_PAGE_SIZE)
+        .await
+        .map_err(|e| -> ServerFnError {
+            tracing::error!("Database error listing audit logs: {}", e);
+            ServerFnError::ServerError("Failed to load audit log".into())
+        })?;
+
+    Ok(AuditLogPage { entries, total, page, per_page: AUDIT_PAGE_SIZE })
+}
+
+/// Matching entries as CSV, newest first, capped at 10,000 rows
+#[server]
+pub async fn export_audit_logs_csv(filter: AuditLogFilter) -> Result<String, ServerFnError> {
+    let admin = crate::session::require_permission("system:audit").await?;
+
+    let (entries, total) = query_audit_logs(&filter, AUDIT_EXPORT_LIMIT, 0).await.map_err(|e| -> ServerFnError {
+        tracing::error!("Database error exporting audit logs: {}", e);
+        ServerFnError::ServerError("Failed to export audit log".into())
+    })?;
+
+    let mut csv = String::from("id,created_at,user_id,username,action,entity_type,entity_id,ip_address,user_agent,changed_fields,old_values,new_values,metadata\n");
+    for entry in &entries {
+        let row = [
+            entry.id.to_string(),
+            entry.created_at.to_rfc3339(),
+            entry.user_id.map(|id| id.to_string()).unwrap_or_default(),
+            entry.username.clone().unwrap_or_default(),
+            entry.action.as_str().to_string(),
+            entry.entity_type.clone(),
+            entry.entity_id.clone(),
+            entry.ip_address.clone().unwrap_or_default(),
+            entry.user_agent.clone().unwrap_or_default(),
+            entry.changed_fields().join(" "),
+            entry.old_values.as_ref().map(|v| v.to_string()).unwrap_or_default(),
+            entry.new_values.as_ref().map(|v| v.to_string()).unwrap_or_default(),
+            entry.metadata.as_ref().map(|v| v.to_string()).unwrap_or_default(),
+        ];
+        csv.push_str(&row.iter().map(|field| csv_field(field)).collect::<Vec<_>>().join(","));
+        csv.push('\n');
+    }
+
+    record_audit(
+        get_db().await,
+        audit_entry(AuditAction::Export, "audit_log", "*")
+            .await
+            .metadata(json!({ "filter": filter, "rows": entries.len(), "matched": total })),
+    )
+    .await;
+
+    info!("Audit log exported by {} ({} rows)", admin.id, entries.len());
+    Ok(csv)
+}
+
+/// Quotes a CSV field when needed. Leading formula characters are neutralised
+/// so the export is safe to open in a spreadsheet.
+#[cfg(feature = "server")]
+fn csv_field(value: &str) -> String {
+    let value = if value.starts_with(['=', '+', '-', '@', '\t', '\r']) {
+        format!("'{}", value)
+    } else {
+        value.to_string()
+    };
+
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value
+    }
+}
+
+#[cfg(all(test, feature = "server"))]
+mod tests {
+    use super::csv_field;
+
+    #[test]
+    fn plain_fields_are_unchanged() {
+        assert_eq!(csv_field("post"), "post");
+        assert_eq!(csv_field(""), "");
+        assert_eq!(csv_field("a-b=c"), "a-b=c");
+    }
+
+    #[test]
+    fn quotes_separators_and_quotes() {
+        assert_eq!(csv_field("a,b"), "\"a,b\"");
+        assert_eq!(csv_field("say \"hi\""), "\"say \"\"hi\"\"\"");
+        assert_eq!(csv_field("two\nlines"), "\"two\nlines\"");
+    }
+
+    #[test]
+    fn neutralises_formulas() {
+        for formula in ["=1+1", "+1", "-1", "@SUM(A1)", "\t=1+1", "\r=1+1"] {
+            assert!(csv_field(formula).trim_start_matches('"').starts_with('\''), "{:?}", formula);
+        }
+        assert_eq!(csv_field("\tx"), "'\tx");
+        assert_eq!(csv_field("\rx"), "\"'\rx\"");
+    }
+}
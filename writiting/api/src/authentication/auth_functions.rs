@@ -0,0 +1,313 @@
+use dioxus::prelude::*;
+#[cfg(feature = "server")]
+use validator::Validate;
+#[cfg(feature = "server")]
+use tracing::info;
+
+use crate::authentication::models::{LoginOutcome, LoginRequest, RegisterRequest};
+#[cfg(feature = "server")]
+use crate::authentication::models::{rate_limits, AuthResponse, AuthToken, LockoutPolicy, LoginPolicy, SecurityAction};
+#[cfg(feature = "server")]
+use crate::authentication::send_verification_email;
+#[cfg(feature = "server")]
+use crate::authentication::two_factor_functions::{create_two_factor_challenge, two_factor_enabled};
+#[cfg(feature = "server")]
+use crate::db::get_db;
+#[cfg(feature = "server")]
+use crate::settings::RegistrationMode;
+use crate::users::User;
+
+/// Creates an account and sends the sign-up verification email. With an
+/// invitation code the account gets the invitation's role, if it has one.
+#[server]
+pub async fn register(request: RegisterRequest) -> Result<User, ServerFnError> {
+    use crate::authentication::invitation_functions::{claim_invitation, record_redemption};
+
+    crate::rate_limit!(rate_limits::REGISTER);
+    crate::log::require_writable().await?;
+    let invite_code = request
+        .invite_code
+        .as_deref()
+        .map(str::trim)
+        .filter(|code| !code.is_empty())
+        .map(str::to_string);
+    match (crate::settings::app_settings().await.registration_mode, &invite_code) {
+        (RegistrationMode::Closed, _) => {
+            return Err(ServerFnError::Request("Registration is closed".into()));
+        }
+        (RegistrationMode::InviteOnly, None) => {
+            return Err(ServerFnError::Request("Registration is by invitation only; enter your invitation code".into()));
+        }
+        _ => {}
+    }
+    let db = get_db().await;
+    let db_error = |e: sqlx::Error| -> ServerFnError {
+        tracing::error!("Database error registering user: {}", e);
+        ServerFnError::ServerError("Failed to create account".into())
+    };
+
+    request.validate().map_err(|e| -> ServerFnError {
+        tracing::warn!("Registration validation failed: {}", e);
+        ServerFnError::Request("Invalid registration details".into())
+    })?;
+
+    let mut tx = db.begin().await.map_err(db_error)?;
+
+    // Claiming locks the invitation, so two sign-ups can't both take its last use
+    let invitation = match &invite_code {
+        Some(code) => Some(claim_invitation(&mut tx, code, &request.email).await?),
+        None => None,
+    };
+
+    let role_id = match invitation.as_ref().and_then(|i| i.role_id) {
+        Some(role_id) => role_id,
+        None => sqlx::query_scalar::<_, i32>("SELECT id FROM roles WHERE is_default = true LIMIT 1")
+            .fetch_one(&mut *tx)
+            .await
+            .map_err(db_error)?,
+    };
+
+    let user = User::new(
+        request.email.trim().to_string(),
+        request.username.trim().to_string(),
+        request.password,
+        role_id,
+    )
+    .map_err(|_| -> ServerFnError { ServerFnError::Request("Invalid registration details".into()) })?;
+
+    let user = sqlx::query_as::<_, User>(
+        r#"
+        INSERT INTO users (id, email, username, password_hash, salt, role_id, invited_by)
+        VALUES ($1, $2, $3, $4, $5, $6, $7)
+        RETURNING *
+        "#
+    )
+    .bind(user.id)
+    .bind(&user.email)
+    .bind(&user.username)
+    .bind(&user.password_hash)
+    .bind(&user.salt)
+    .bind(user.role_id)
+    .bind(invitation.as_ref().and_then(|i| i.created_by))
+    .fetch_one(&mut *tx)
+    .await
+    .map_err(|e| match e {
+        sqlx::Error::Database(ref err) if err.is_unique_violation() => {
+            ServerFnError::Request("That email or username is already taken".into())
+        }
+        e => db_error(e),
+    })?;
+
+    sqlx::query("INSERT INTO profiles (user_id) VALUES ($1)")
+        .bind(user.id)
+        .execute(&mut *tx)
+        .await
+        .map_err(db_error)?;
+
+    sqlx::query("INSERT INTO user_preferences (user_id) VALUES ($1)")
+        .bind(user.id)
+        .execute(&mut *tx)
+        .await
+        .map_err(db_error)?;
+
+    if let Some(invitation) = &invitation {
+        record_redemption(&mut tx, invitation.id, user.id).await.map_err(db_error)?;
+    }
+
+    send_verification_email(&mut tx, &user, None).await.map_err(|e| -> ServerFnError {
+        tracing::error!("Failed to queue verification email: {}", e);
+        ServerFnError::ServerError("Failed to create account".into())
+    })?;
+
+    tx.commit().await.map_err(db_error)?;
+
+    match &invitation {
+        Some(invitation) => info!("Registered user {} with invitation {}", user.id, invitation.id),
+        None => info!("Registered user {}", user.id),
+    }
+    Ok(user)
+}
+
+#[server]
+pub async fn login(request: LoginRequest) -> Result<LoginOutcome, ServerFnError> {
+    use crate::authentication::lockout_functions::{
+        active_lockout, ip_blocked_until, record_login_failure, unknown_email_lockout,
+    };
+
+    crate::rate_limit!(rate_limits::LOGIN);
+    let db = get_db().await;
+    let meta = crate::utils::request_meta().await;
+    let policy = LockoutPolicy::default();
+    let invalid = || ServerFnError::Request("Invalid email or password".into());
+    let db_error = |e: sqlx::Error| -> ServerFnError {
+        tracing::error!("Database error during login: {}", e);
+        ServerFnError::ServerError("Failed to sign in".into())
+    };
+
+    if let Some(locked_until) = ip_blocked_until(db, &meta, &policy).await.map_err(db_error)? {
+        return Ok(LoginOutcome::Locked { locked_until });
+    }
+
+    let user = sqlx::query_as::<_, User>("SELECT * FROM users WHERE LOWER(email) = LOWER($1)")
+        .bind(request.email.trim())
+        .fetch_optional(db)
+        .await
+        .map_err(db_error)?;
+
+    // Unknown addresses lock out just like accounts, so the answer doesn't say which exist
+    let Some(user) = user else {
+        let email = request.email.trim();
+        if let Some(locked_until) = unknown_email_lockout(db, email).await.map_err(db_error)? {
+            return Ok(LoginOutcome::Locked { locked_until });
+        }
+        // A real account would have its password checked here, which takes a while
+        User::verify_dummy_password(&request.password);
+        let locked = record_login_failure(db, None, email, &meta, &policy).await.map_err(db_error)?;
+        return match locked {
+            Some(locked_until) => Ok(LoginOutcome::Locked { locked_until }),
+            None => Err(invalid()),
+        };
+    };
+
+    // Checked before the password so a locked account can't be probed
+    if let Some(locked_until) = active_lockout(db, user.id).await.map_err(db_error)? {
+        return Ok(LoginOutcome::Locked { locked_until });
+    }
+
+    if !user.verify_password(&request.password) {
+        let locked = record_login_failure(db, Some(user.id), &user.email, &meta, &policy)
+            .await
+            .map_err(db_error)?;
+        return match locked {
+            Some(locked_until) => Ok(LoginOutcome::Locked { locked_until }),
+            None => Err(invalid()),
+        };
+    }
+
+    if !user.is_active {
+        return Err(ServerFnError::Request("This account has been deactivated".into()));
+    }
+
+    if LoginPolicy::from_env().require_verified_email && !user.email_verified {
+        return Ok(LoginOutcome::EmailNotVerified);
+    }
+
+    if two_factor_enabled(db, user.id).await.map_err(db_error)? {
+        let challenge = create_two_factor_challenge(db, user.id).await.map_err(db_error)?;
+        return Ok(LoginOutcome::TwoFactorRequired { challenge });
+    }
+
+    Ok(LoginOutcome::Authenticated(Box::new(start_session(db, user).await?)))
+}
+
+/// Issues a session for a user who has passed every login check
+#[cfg(feature = "server")]
+pub(crate) async fn start_session(db: &sqlx::PgPool, user: User) -> Result<AuthResponse, ServerFnError> {
+    use crate::authentication::security_log_functions::{login_novelty, notify_new_login};
+    use crate::session::{create_session, set_session_cookie};
+
+    let meta = crate::utils::request_meta().await;
+    let session = create_session(db, user.id, &meta).await.map_err(|e| -> ServerFnError {
+        tracing::error!("Database error creating session: {}", e);
+        ServerFnError::ServerError("Failed to sign in".into())
+    })?;
+
+    sqlx::query("UPDATE users SET last_login_at = NOW() WHERE id = $1")
+        .bind(user.id)
+        .execute(db)
+        .await
+        .map_err(|e| -> ServerFnError {
+            tracing::error!("Database error recording login: {}", e);
+            ServerFnError::ServerError("Failed to sign in".into())
+        })?;
+
+    // Compare with earlier sign-ins before this one is recorded
+    let novelty = login_novelty(db, user.id, &meta).await.map_err(|e| -> ServerFnError {
+        tracing::error!("Database error checking login history: {}", e);
+        ServerFnError::ServerError("Failed to sign in".into())
+    })?;
+
+    // Also resets the failed-login count used for lockouts
+    crate::authentication::record_security_event(
+        db,
+        Some(user.id),
+        SecurityAction::LoginSuccess,
+        &meta,
+        serde_json::json!({ "new_ip": novelty.new_ip, "new_device": novelty.new_device }),
+    )
+    .await
+    .map_err(|e| -> ServerFnError {
+        tracing::error!("Database error recording login: {}", e);
+        ServerFnError::ServerError("Failed to sign in".into())
+    })?;
+
+    if novelty.should_notify() {
+        // The sign-in itself shouldn't fail because the alert couldn't be stored
+        if let Err(e) = notify_new_login(db, user.id, &meta, &novelty).await {
+            tracing::error!("Failed to notify user {} of new login: {}", user.id, e);
+        }
+    }
+
+    set_session_cookie(&session);
+    info!("User {} signed in", user.id);
+
+    Ok(AuthResponse {
+        user,
+        token: AuthToken {
+            token: session.token,
+            expires_at: session.expires_at,
+        },
+    })
+}
+
+/// Changes the signed-in user's password and signs out their other sessions
+#[server]
+pub async fn change_password(current_password: String, new_password: String) -> Result<(), ServerFnError> {
+    use crate::session::{current_session_token, current_user, revoke_user_sessions};
+
+    crate::log::require_writable().await?;
+    let db = get_db().await;
+    let mut user = current_user().await?;
+    let meta = crate::utils::request_meta().await;
+    let db_error = |e: sqlx::Error| -> ServerFnError {
+        tracing::error!("Database error changing password: {}", e);
+        ServerFnError::ServerError("Failed to change password".into())
+    };
+
+    if !user.verify_password(&current_password) {
+        return Err(ServerFnError::Request("Current password is incorrect".into()));
+    }
+    if new_password.len() < 8 {
+        return Err(ServerFnError::Request("Password must be at least 8 characters".into()));
+    }
+
+    user.set_password(&new_password);
+    let keep = current_session_token().await;
+
+    let mut tx = db.begin().await.map_err(db_error)?;
+
+    sqlx::query("UPDATE users SET password_hash = $2, salt = $3, updated_at = NOW() WHERE id = $1")
+        .bind(user.id)
+        .bind(&user.password_hash)
+        .bind(&user.salt)
+        .execute(&mut *tx)
+        .await
+        .map_err(db_error)?;
+
+    let revoked = revoke_user_sessions(&mut *tx, user.id, keep.as_deref()).await.map_err(db_error)?;
+
+    crate::authentication::record_security_event(
+        &mut *tx,
+        Some(user.id),
+        SecurityAction::PasswordChange,
+        &meta,
+        serde_json::json!({ "sessions_revoked": revoked }),
+    )
+    .await
+    .map_err(db_error)?;
+
+    tx.commit().await.map_err(db_error)?;
+
+    info!("User {} changed their password", user.id);
+    Ok(())
+}
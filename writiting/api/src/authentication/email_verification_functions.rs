@@ -0,0 +1,265 @@
+use chrono::{Duration, Utc};
+use dioxus::prelude::*;
+use sqlx::PgConnection;
+use uuid::Uuid;
+#[cfg(feature = "server")]
+use tracing::info;
+
+use crate::authentication::models::EmailVerificationToken;
+#[cfg(feature = "server")]
+use crate::authentication::models::rate_limits;
+use crate::authentication::models::email_verification_model::VERIFICATION_TOKEN_HOURS;
+#[cfg(feature = "server")]
+use crate::db::get_db;
+use crate::mail::{get_mailer, queue_email, EmailTemplate, QueueError};
+use crate::users::User;
+
+/// Minimum gap between two verification emails for the same user
+const RESEND_COOLDOWN_SECONDS: i64 = 60;
+/// Maximum verification emails per user per hour
+const RESEND_HOURLY_LIMIT: i64 = 5;
+
+/// Creates a verification token, replacing any outstanding token of the same kind
+/// (sign-up confirmation or change of address).
+pub async fn issue_verification_token(
+    db: &mut PgConnection,
+    user_id: Uuid,
+    new_email: Option<&str>,
+) -> Result<EmailVerificationToken, sqlx::Error> {
+    sqlx::query(
+        r#"
+        DELETE FROM email_verification_tokens
+        WHERE user_id = $1 AND (new_email IS NULL) = ($2::text IS NULL)
+        "#
+    )
+    .bind(user_id)
+    .bind(new_email)
+    .execute(&mut *db)
+    .await?;
+
+    sqlx::query_as::<_, EmailVerificationToken>(
+        r#"
+        INSERT INTO email_verification_tokens (user_id, new_email, expires_at)
+        VALUES ($1, $2, $3)
+        RETURNING token, user_id, new_email, expires_at, created_at
+        "#
+    )
+    .bind(user_id)
+    .bind(new_email)
+    .bind(Utc::now() + Duration::hours(VERIFICATION_TOKEN_HOURS))
+    .fetch_one(&mut *db)
+    .await
+}
+
+/// Issues a token and queues the verification email.
+/// With `new_email` set the link confirms a change of address and is sent to the new address.
+pub async fn send_verification_email(
+    db: &mut PgConnection,
+    user: &User,
+    new_email: Option<&str>,
+) -> Result<(), QueueError> {
+    let token = issue_verification_token(db, user.id, new_email).await?;
+    let mailer = get_mailer().await?;
+
+    let template = EmailTemplate::Verification {
+        username: user.username.clone(),
+        link: mailer.link(&format!("/auth/verify-email?token={}", token.token)),
+    };
+
+    queue_email(&mut *db, Some(user.id), new_email.unwrap_or(&user.email), &template).await?;
+    Ok(())
+}
+
+/// Whether the user has hit the resend cooldown or hourly limit
+pub async fn verification_throttled<'e, E>(db: E, user_id: Uuid) -> Result<bool, sqlx::Error>
+where
+    E: sqlx::PgExecutor<'e>,
+{
+    sqlx::query_scalar::<_, bool>(
+        r#"
+        SELECT COUNT(*) FILTER (WHERE created_at > NOW() - INTERVAL '1 hour') >= $2
+            OR COALESCE(MAX(created_at) > NOW() - make_interval(secs => $3), false)
+        FROM email_outbox
+        WHERE user_id = $1 AND kind = 'verification'
+        "#
+    )
+    .bind(user_id)
+    .bind(RESEND_HOURLY_LIMIT)
+    .bind(RESEND_COOLDOWN_SECONDS as f64)
+    .fetch_one(db)
+    .await
+}
+
+/// Consumes a verification token. Marks the user verified or swaps in the new address.
+#[server]
+pub async fn verify_email(token: Uuid) -> Result<User, ServerFnError> {
+    let db = get_db().await;
+    let db_error = |e: sqlx::Error| -> ServerFnError {
+        tracing::error!("Database error verifying email: {}", e);
+        ServerFnError::ServerError("Failed to verify email".into())
+    };
+
+    let mut tx = db.begin().await.map_err(db_error)?;
+
+    let record = sqlx::query_as::<_, EmailVerificationToken>(
+        r#"
+        SELECT token, user_id, new_email, expires_at, created_at
+        FROM email_verification_tokens
+        WHERE token = $1
+        FOR UPDATE
+        "#
+    )
+    .bind(token)
+    .fetch_optional(&mut *tx)
+    .await
+    .map_err(db_error)?
+    .filter(|record| record.is_valid())
+    .ok_or_else(|| -> ServerFnError { ServerFnError::Request("This verification link is invalid or has expired".into()) })?;
+
+    let user = sqlx::query_as::<_, User>(
+        r#"
+        UPDATE users
+        SET email = COALESCE($2, email), email_verified = true, updated_at = NOW()
+        WHERE id = $1
+        RETURNING *
+        "#
+    )
+    .bind(record.user_id)
+    .bind(&record.new_email)
+    .fetch_one(&mut *tx)
+    .await
+    .map_err(|e| match e {
+        sqlx::Error::Database(ref err) if err.is_unique_violation() => {
+            ServerFnError::Request("That email address is already in use".into())
+        }
+        e => db_error(e),
+    })?;
+
+    // Either kind of token proves ownership of the current address, so the
+    // sign-up token is no longer needed
+    sqlx::query(
+        "DELETE FROM email_verification_tokens WHERE user_id = $1 AND (token = $2 OR new_email IS NULL)"
+    )
+    .bind(record.user_id)
+    .bind(record.token)
+    .execute(&mut *tx)
+    .await
+    .map_err(db_error)?;
+
+    if record.is_email_change() {
+        let meta = crate::utils::request_meta().await;
+        crate::authentication::record_security_event(
+            &mut *tx,
+            Some(user.id),
+            crate::authentication::SecurityAction::EmailChange,
+            &meta,
+            serde_json::json!({ "new_email": user.email }),
+        )
+        .await
+        .map_err(db_error)?;
+    }
+
+    tx.commit().await.map_err(db_error)?;
+
+    info!(
+        "Verified email for user {}{}",
+        user.id,
+        if record.is_email_change() { " (address changed)" } else { "" }
+    );
+    Ok(user)
+}
+
+/// Sends a fresh sign-up verification link.
+/// Succeeds silently for unknown or already verified addresses.
+#[server]
+pub async fn resend_verification_email(email: String) -> Result<(), ServerFnError> {
+    crate::rate_limit!(rate_limits::EMAIL);
+    let db = get_db().await;
+    let db_error = |e: sqlx::Error| -> ServerFnError {
+        tracing::error!("Database error resending verification email: {}", e);
+        ServerFnError::ServerError("Failed to send verification email".into())
+    };
+
+    let user = sqlx::query_as::<_, User>(
+        r#"
+        SELECT * FROM users
+        WHERE LOWER(email) = LOWER($1) AND email_verified = false AND is_active = true
+        "#
+    )
+    .bind(email.trim())
+    .fetch_optional(db)
+    .await
+    .map_err(db_error)?;
+
+    let Some(user) = user else {
+        return Ok(());
+    };
+
+    // Answered like an unknown address so the reply doesn't reveal the account
+    if verification_throttled(db, user.id).await.map_err(db_error)? {
+        return Ok(());
+    }
+
+    let mut tx = db.begin().await.map_err(db_error)?;
+    send_verification_email(&mut tx, &user, None).await.map_err(|e| -> ServerFnError {
+        tracing::error!("Failed to queue verification email: {}", e);
+        ServerFnError::ServerError("Failed to send verification email".into())
+    })?;
+    tx.commit().await.map_err(db_error)?;
+
+    info!("Resent verification email for user {}", user.id);
+    Ok(())
+}
+
+/// Starts a change of address for the signed-in user.
+/// The address only changes once the link sent to it is opened.
+#[server]
+pub async fn request_email_change(new_email: String) -> Result<(), ServerFnError> {
+    use validator::ValidateEmail;
+    use crate::session::current_user;
+
+    let db = get_db().await;
+    crate::log::require_writable().await?;
+    let db_error = |e: sqlx::Error| -> ServerFnError {
+        tracing::error!("Database error requesting email change: {}", e);
+        ServerFnError::ServerError("Failed to request email change".into())
+    };
+
+    let user = current_user().await?;
+    let new_email = new_email.trim().to_string();
+
+    if !new_email.validate_email() {
+        return Err(ServerFnError::Request("Must be a valid email".into()));
+    }
+    if new_email.eq_ignore_ascii_case(&user.email) {
+        return Err(ServerFnError::Request("That is already your email address".into()));
+    }
+
+    let taken = sqlx::query_scalar::<_, bool>(
+        "SELECT EXISTS(SELECT 1 FROM users WHERE LOWER(email) = LOWER($1))"
+    )
+    .bind(&new_email)
+    .fetch_one(db)
+    .await
+    .map_err(db_error)?;
+
+    if taken {
+        return Err(ServerFnError::Request("That email address is already in use".into()));
+    }
+
+    if verification_throttled(db, user.id).await.map_err(db_error)? {
+        return Err(ServerFnError::Request(
+            "Please wait a few minutes before requesting another verification email".into(),
+        ));
+    }
+
+    let mut tx = db.begin().await.map_err(db_error)?;
+    send_verification_email(&mut tx, &user, Some(&new_email)).await.map_err(|e| -> ServerFnError {
+        tracing::error!("Failed to queue email change confirmation: {}", e);
+        ServerFnError::ServerError("Failed to request email change".into())
+    })?;
+    tx.commit().await.map_err(db_error)?;
+
+    info!("Email change requested for user {}", user.id);
+    Ok(())
+}
@@ -0,0 +1,193 @@
+use std::collections::HashMap;
+use dioxus::prelude::*;
+use sqlx::PgPool;
+use tokio::sync::{OnceCell, RwLock};
+use uuid::Uuid;
+#[cfg(feature = "server")]
+use validator::Validate;
+#[cfg(feature = "server")]
+use tracing::info;
+
+use crate::authentication::models::{FeatureFlag, FeatureFlagUpdate};
+use crate::authentication::models::feature_flag_model::FEATURE_FLAG_COLUMNS;
+#[cfg(feature = "server")]
+use crate::authentication::{audit_entities, audit_entry, record_audit, AuditAction};
+use crate::db::get_db;
+
+/// Flags as of the last refresh, keyed by name. Evaluating a flag happens on
+/// almost every page, so it reads from here rather than the database.
+static FLAG_CACHE: OnceCell<RwLock<HashMap<String, FeatureFlag>>> = OnceCell::const_new();
+
+async fn flag_cache() -> &'static RwLock<HashMap<String, FeatureFlag>> {
+    FLAG_CACHE
+        .get_or_init(|| async {
+            let flags = match load_feature_flags(get_db().await).await {
+                Ok(flags) => flags,
+                Err(e) => {
+                    // Start empty (every flag off); the next refresh fills it in
+                    tracing::error!("Failed to load feature flags: {}", e);
+                    Vec::new()
+                }
+            };
+            RwLock::new(flags.into_iter().map(|f| (f.name.clone(), f)).collect())
+        })
+        .await
+}
+
+pub async fn load_feature_flags(db: &PgPool) -> Result<Vec<FeatureFlag>, sqlx::Error> {
+    sqlx::query_as::<_, FeatureFlag>(&format!(
+        "SELECT {} FROM feature_flags ORDER BY name",
+        FEATURE_FLAG_COLUMNS
+    ))
+    .fetch_all(db)
+    .await
+}
+
+/// Reloads every flag into the cache, returning how many there are
+pub async fn refresh_feature_flags(db: &PgPool) -> Result<usize, sqlx::Error> {
+    let flags = load_feature_flags(db).await?;
+    let count = flags.len();
+
+    let mut cache = flag_cache().await.write().await;
+    *cache = flags.into_iter().map(|f| (f.name.clone(), f)).collect();
+    Ok(count)
+}
+
+/// Whether `name` is on for `user_id`. Unknown flags are off.
+pub async fn is_feature_enabled(name: &str, user_id: Option<Uuid>) -> bool {
+    flag_cache()
+        .await
+        .read()
+        .await
+        .get(name)
+        .is_some_and(|flag| flag.is_active_for_user(user_id))
+}
+
+/// Evaluates a flag for whoever is signed in, or for an anonymous visitor
+#[server]
+pub async fn check_feature_flag(name: String) -> Result<bool, ServerFnError> {
+    let user_id = crate::session::current_user().await.ok().map(|u| u.id);
+    Ok(is_feature_enabled(&name, user_id).await)
+}
+
+#[server]
+pub async fn list_feature_flags() -> Result<Vec<FeatureFlag>, ServerFnError> {
+    let db = get_db().await;
+    crate::session::require_permission("system:settings").await?;
+
+    load_feature_flags(db).await.map_err(|e| {
+        tracing::error!("Database error listing feature flags: {}", e);
+        ServerFnError::ServerError("Failed to load feature flags".into())
+    })
+}
+
+/// Creates or updates a flag. Changing the percentage, switching to or from a
+/// gradual rollout, or switching the flag on restarts the rollout clock.
+#[server]
+pub async fn save_feature_flag(update: FeatureFlagUpdate) -> Result<FeatureFlag, ServerFnError> {
+    let db = get_db().await;
+    crate::log::require_writable().await?;
+    let admin = crate::session::require_permission("system:settings").await?;
+    let db_error = |e: sqlx::Error| -> ServerFnError {
+        tracing::error!("Database error saving feature flag: {}", e);
+        ServerFnError::ServerError("Failed to save feature flag".into())
+    };
+
+    let mut update = update;
+    update.name = update.name.trim().to_string();
+    update.description = update.description.map(|d| d.trim().to_string()).filter(|d| !d.is_empty());
+    update.target_users.sort();
+    update.target_users.dedup();
+    update
+        .validate()
+        .map_err(|e| -> ServerFnError { ServerFnError::Request(e.to_string()) })?;
+
+    let before = sqlx::query_as::<_, FeatureFlag>(&format!(
+        "SELECT {} FROM feature_flags WHERE name = $1",
+        FEATURE_FLAG_COLUMNS
+    ))
+    .bind(&update.name)
+    .fetch_optional(db)
+    .await
+    .map_err(db_error)?;
+
+    let flag = sqlx::query_as::<_, FeatureFlag>(&format!(
+        r#"
+        INSERT INTO feature_flags (name, description, is_enabled, rollout_percentage, increment_per_day, target_users)
+        VALUES ($1, $2, $3, $4, $5, $6)
+        ON CONFLICT (name) DO UPDATE
+        SET description = EXCLUDED.description,
+            is_enabled = EXCLUDED.is_enabled,
+            rollout_percentage = EXCLUDED.rollout_percentage,
+            increment_per_day = EXCLUDED.increment_per_day,
+            target_users = EXCLUDED.target_users,
+            rollout_started_at = CASE
+                WHEN feature_flags.rollout_percentage <> EXCLUDED.rollout_percentage
+                  OR feature_flags.increment_per_day <> EXCLUDED.increment_per_day
+                  OR (NOT feature_flags.is_enabled AND EXCLUDED.is_enabled)
+                THEN NOW()
+                ELSE feature_flags.rollout_started_at
+            END,
+            updated_at = NOW()
+        RETURNING {}
+        "#,
+        FEATURE_FLAG_COLUMNS
+    ))
+    .bind(&update.name)
+    .bind(&update.description)
+    .bind(update.is_enabled)
+    .bind(update.rollout_percentage)
+    .bind(update.increment_per_day)
+    .bind(&update.target_users)
+    .fetch_one(db)
+    .await
+    .map_err(db_error)?;
+
+    let entry = match &before {
+        Some(before) => audit_entry(AuditAction::Update, audit_entities::FEATURE_FLAG, &flag.name)
+            .await
+            .before(&FeatureFlagUpdate::from(before)),
+        None => audit_entry(AuditAction::Create, audit_entities::FEATURE_FLAG, &flag.name).await,
+    };
+    record_audit(db, entry.after(&FeatureFlagUpdate::from(&flag))).await;
+
+    // Other servers pick the change up on their next periodic refresh
+    refresh_feature_flags(db).await.map_err(db_error)?;
+
+    info!("Feature flag {} saved by {}", flag.name, admin.id);
+    Ok(flag)
+}
+
+#[server]
+pub async fn delete_feature_flag(name: String) -> Result<(), ServerFnError> {
+    let db = get_db().await;
+    crate::log::require_writable().await?;
+    let admin = crate::session::require_permission("system:settings").await?;
+    let db_error = |e: sqlx::Error| -> ServerFnError {
+        tracing::error!("Database error deleting feature flag: {}", e);
+        ServerFnError::ServerError("Failed to delete feature flag".into())
+    };
+
+    let deleted = sqlx::query_as::<_, FeatureFlag>(&format!(
+        "DELETE FROM feature_flags WHERE name = $1 RETURNING {}",
+        FEATURE_FLAG_COLUMNS
+    ))
+    .bind(&name)
+    .fetch_optional(db)
+    .await
+    .map_err(db_error)?
+    .ok_or_else(|| -> ServerFnError { ServerFnError::Request("Feature flag not found".into()) })?;
+
+    record_audit(
+        db,
+        audit_entry(AuditAction::Delete, audit_entities::FEATURE_FLAG, &name)
+            .await
+            .before(&FeatureFlagUpdate::from(&deleted)),
+    )
+    .await;
+
+    refresh_feature_flags(db).await.map_err(db_error)?;
+
+    info!("Feature flag {} deleted by {}", name, admin.id);
+    Ok(())
+}
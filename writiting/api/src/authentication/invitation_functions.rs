@@ -0,0 +1,387 @@
+#[cfg(feature = "server")]
+use chrono::{Duration, Utc};
+use dioxus::prelude::*;
+#[cfg(feature = "server")]
+use serde_json::json;
+#[cfg(feature = "server")]
+use sqlx::{PgConnection, PgPool};
+use uuid::Uuid;
+#[cfg(feature = "server")]
+use validator::Validate;
+#[cfg(feature = "server")]
+use tracing::info;
+
+use crate::authentication::models::{
+    CreateInvitationRequest, Invitation, InvitationPage, InvitationPreview, InvitationRedemption,
+};
+#[cfg(feature = "server")]
+use crate::authentication::models::InvitationStatus;
+#[cfg(feature = "server")]
+use crate::authentication::models::invitation_model::{
+    DEFAULT_INVITATION_DAYS, INVITATION_COLUMNS, INVITATION_JOINS, MAX_ACTIVE_INVITATIONS_PER_USER,
+    MAX_USER_INVITATION_DAYS, MAX_USER_INVITATION_USES,
+};
+#[cfg(feature = "server")]
+use crate::authentication::models::rate_limits;
+#[cfg(feature = "server")]
+use crate::authentication::{audit_entities, audit_entry, record_audit, AuditAction};
+#[cfg(feature = "server")]
+use crate::db::get_db;
+#[cfg(feature = "server")]
+use crate::users::User;
+
+/// Lets a role see every invitation and issue ones with a role or no limits
+pub const INVITATION_ADMIN_PERMISSION: &str = "user:create";
+#[cfg(feature = "server")]
+const INVITATION_PAGE_SIZE: i64 = 25;
+
+#[cfg(feature = "server")]
+async fn find_invitation(db: &PgPool, id: Uuid) -> Result<Option<Invitation>, sqlx::Error> {
+    sqlx::query_as::<_, Invitation>(&format!(
+        "SELECT {} {} WHERE i.id = $1",
+        INVITATION_COLUMNS, INVITATION_JOINS
+    ))
+    .bind(id)
+    .fetch_optional(db)
+    .await
+}
+
+/// Loads an invitation the signed-in user issued, or any invitation for admins
+#[cfg(feature = "server")]
+async fn owned_invitation(db: &PgPool, user: &User, id: Uuid) -> Result<Invitation, ServerFnError> {
+    let db_error = |e: sqlx::Error| -> ServerFnError {
+        tracing::error!("Database error loading invitation: {}", e);
+        ServerFnError::ServerError("Failed to load invitation".into())
+    };
+
+    let invitation = find_invitation(db, id)
+        .await
+        .map_err(db_error)?
+        .ok_or_else(|| -> ServerFnError { ServerFnError::Request("Invitation not found".into()) })?;
+
+    if invitation.created_by != Some(user.id)
+        && !crate::session::role_has_permission(db, user.role_id, INVITATION_ADMIN_PERMISSION)
+            .await
+            .map_err(db_error)?
+    {
+        return Err(ServerFnError::Request("Invitation not found".into()));
+    }
+    Ok(invitation)
+}
+
+/// Locks the invitation behind `code` and counts a use, as part of sign-up.
+/// Fails with a message for the sign-up form if it can't be used by `email`.
+#[cfg(feature = "server")]
+pub(crate) async fn claim_invitation(
+    db: &mut PgConnection,
+    code: &str,
+    email: &str,
+) -> Result<Invitation, ServerFnError> {
+    let db_error = |e: sqlx::Error| -> ServerFnError {
+        tracing::error!("Database error claiming invitation: {}", e);
+        ServerFnError::ServerError("Failed to create account".into())
+    };
+
+    let invitation = sqlx::query_as::<_, Invitation>(&format!(
+        "SELECT {} {} WHERE i.code = $1 FOR UPDATE OF i",
+        INVITATION_COLUMNS, INVITATION_JOINS
+    ))
+    .bind(code.trim())
+    .fetch_optional(&mut *db)
+    .await
+    .map_err(db_error)?
+    .ok_or_else(|| -> ServerFnError { ServerFnError::Request("That invitation code isn't valid".into()) })?;
+
+    let status = invitation.status();
+    if status != InvitationStatus::Active {
+        return Err(ServerFnError::Request(status.refusal().into()));
+    }
+    if !invitation.allows_email(email) {
+        return Err(ServerFnError::Request("That invitation is for a different email address".into()));
+    }
+
+    sqlx::query("UPDATE invitations SET use_count = use_count + 1 WHERE id = $1")
+        .bind(invitation.id)
+        .execute(&mut *db)
+        .await
+        .map_err(db_error)?;
+
+    Ok(invitation)
+}
+
+/// Records that `user_id` signed up with `invitation_id`
+#[cfg(feature = "server")]
+pub(crate) async fn record_redemption(
+    db: &mut PgConnection,
+    invitation_id: Uuid,
+    user_id: Uuid,
+) -> Result<(), sqlx::Error> {
+    sqlx::query("INSERT INTO invitation_redemptions (invitation_id, user_id) VALUES ($1, $2)")
+        .bind(invitation_id)
+        .bind(user_id)
+        .execute(db)
+        .await?;
+    Ok(())
+}
+
+/// Issues an invitation from the signed-in user. Without `user:create` the
+/// invitation can't carry a role, is limited in uses, and expires within a month.
+#[server]
+pub async fn create_invitation(request: CreateInvitationRequest) -> Result<Invitation, ServerFnError> {
+    crate::rate_limit!(rate_limits::INVITE);
+    crate::log::require_writable().await?;
+    let db = get_db().await;
+    let user = crate::session::current_user().await?;
+    let db_error = |e: sqlx::Error| -> ServerFnError {
+        tracing::error!("Database error creating invitation: {}", e);
+        ServerFnError::ServerError("Failed to create invitation".into())
+    };
+
+    let mut request = request;
+    request.email = request.email.map(|e| e.trim().to_lowercase()).filter(|e| !e.is_empty());
+    request.note = request.note.map(|n| n.trim().to_string()).filter(|n| !n.is_empty());
+    request
+        .validate()
+        .map_err(|e| -> ServerFnError { ServerFnError::Request(e.to_string()) })?;
+
+    let is_admin = crate::session::role_has_permission(db, user.role_id, INVITATION_ADMIN_PERMISSION)
+        .await
+        .map_err(db_error)?;
+
+    let expires_in_days = if is_admin {
+        request.expires_in_days
+    } else {
+        if request.role_id.is_some() {
+            return Err(ServerFnError::Request("You can't choose a role for invitations".into()));
+        }
+        let max_uses = request.max_uses.unwrap_or(1);
+        if max_uses > MAX_USER_INVITATION_USES {
+            return Err(ServerFnError::Request(format!(
+                "Invitations can be used at most {} times",
+                MAX_USER_INVITATION_USES
+            )));
+        }
+        request.max_uses = Some(max_uses);
+        let days = request.expires_in_days.unwrap_or(DEFAULT_INVITATION_DAYS);
+        if days > MAX_USER_INVITATION_DAYS {
+            return Err(ServerFnError::Request(format!(
+                "Invitations must expire within {} days",
+                MAX_USER_INVITATION_DAYS
+            )));
+        }
+
+        let active = sqlx::query_scalar::<_, i64>(
+            r#"
+            SELECT COUNT(*) FROM invitations
+            WHERE created_by = $1
+              AND revoked_at IS NULL
+              AND (expires_at IS NULL OR expires_at > NOW())
+              AND (max_uses IS NULL OR use_count < max_uses)
+            "#,
+        )
+        .bind(user.id)
+        .fetch_one(db)
+        .await
+        .map_err(db_error)?;
+        if active >= MAX_ACTIVE_INVITATIONS_PER_USER {
+            return Err(ServerFnError::Request(format!(
+                "You can have at most {} open invitations; revoke one first",
+                MAX_ACTIVE_INVITATIONS_PER_USER
+            )));
+        }
+        Some(days)
+    };
+
+    if let Some(role_id) = request.role_id {
+        if !crate::users::users_functions::role_within(db, user.role_id, role_id)
+            .await
+            .map_err(db_error)?
+        {
+            return Err(ServerFnError::Request("You can't invite people into a role above your own".into()));
+        }
+    }
+
+    let expires_at = expires_in_days.map(|days| Utc::now() + Duration::days(days));
+
+    let id = sqlx::query_scalar::<_, Uuid>(
+        r#"
+        INSERT INTO invitations (code, created_by, email, role_id, max_uses, note, expires_at)
+        VALUES ($1, $2, $3, $4, $5, $6, $7)
+        RETURNING id
+        "#,
+    )
+    .bind(Invitation::generate_code())
+    .bind(user.id)
+    .bind(&request.email)
+    .bind(request.role_id)
+    .bind(request.max_uses)
+    .bind(&request.note)
+    .bind(expires_at)
+    .fetch_one(db)
+    .await
+    .map_err(|e| match e {
+        sqlx::Error::Database(ref err) if err.is_foreign_key_violation() => {
+            ServerFnError::Request("That role doesn't exist".into())
+        }
+        e => db_error(e),
+    })?;
+
+    let invitation = find_invitation(db, id)
+        .await
+        .map_err(db_error)?
+        .ok_or_else(|| -> ServerFnError { ServerFnError::ServerError("Failed to create invitation".into()) })?;
+
+    // The code itself stays out of the audit log
+    record_audit(
+        db,
+        audit_entry(AuditAction::Create, audit_entities::INVITATION, invitation.id)
+            .await
+            .after(&json!({
+                "email": invitation.email,
+                "role_id": invitation.role_id,
+                "max_uses": invitation.max_uses,
+                "expires_at": invitation.expires_at,
+            })),
+    )
+    .await;
+
+    info!("Invitation {} created by {}", invitation.id, user.id);
+    Ok(invitation)
+}
+
+/// Invitations the signed-in user issued, newest first
+#[server]
+pub async fn list_my_invitations() -> Result<Vec<Invitation>, ServerFnError> {
+    let db = get_db().await;
+    let user = crate::session::current_user().await?;
+
+    sqlx::query_as::<_, Invitation>(&format!(
+        "SELECT {} {} WHERE i.created_by = $1 ORDER BY i.created_at DESC",
+        INVITATION_COLUMNS, INVITATION_JOINS
+    ))
+    .bind(user.id)
+    .fetch_all(db)
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error listing invitations: {}", e);
+        ServerFnError::ServerError("Failed to load invitations".into())
+    })
+}
+
+/// Every invitation, newest first
+#[server]
+pub async fn list_invitations(page: i64) -> Result<InvitationPage, ServerFnError> {
+    let db = get_db().await;
+    crate::session::require_permission(INVITATION_ADMIN_PERMISSION).await?;
+    let db_error = |e: sqlx::Error| -> ServerFnError {
+        tracing::error!("Database error listing invitations: {}", e);
+        ServerFnError::ServerError("Failed to load invitations".into())
+    };
+
+    let page = page.max(1);
+    let total = sqlx::query_scalar::<_, i64>("SELECT COUNT(*) FROM invitations")
+        .fetch_one(db)
+        .await
+        .map_err(db_error)?;
+
+    let invitations = sqlx::query_as::<_, Invitation>(&format!(
+        "SELECT {} {} ORDER BY i.created_at DESC LIMIT $1 OFFSET $2",
+        INVITATION_COLUMNS, INVITATION_JOINS
+    ))
+    .bind(INVITATION_PAGE_SIZE)
+    .bind((page - 1) * INVITATION_PAGE_SIZE)
+    .fetch_all(db)
+    .await
+    .map_err(db_error)?;
+
+    Ok(InvitationPage { invitations, total, page, per_page: INVITATION_PAGE_SIZE })
+}
+
+/// Accounts created with an invitation, oldest first
+#[server]
+pub async fn list_invitation_redemptions(invitation_id: Uuid) -> Result<Vec<InvitationRedemption>, ServerFnError> {
+    let db = get_db().await;
+    let user = crate::session::current_user().await?;
+    owned_invitation(db, &user, invitation_id).await?;
+
+    sqlx::query_as::<_, InvitationRedemption>(
+        r#"
+        SELECT ir.user_id, u.username, ir.redeemed_at
+        FROM invitation_redemptions ir
+        JOIN users u ON u.id = ir.user_id
+        WHERE ir.invitation_id = $1
+        ORDER BY ir.redeemed_at
+        "#,
+    )
+    .bind(invitation_id)
+    .fetch_all(db)
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error listing invitation redemptions: {}", e);
+        ServerFnError::ServerError("Failed to load invitation".into())
+    })
+}
+
+/// Stops an invitation from being used again. Accounts already created stay.
+#[server]
+pub async fn revoke_invitation(invitation_id: Uuid) -> Result<(), ServerFnError> {
+    let db = get_db().await;
+    crate::log::require_writable().await?;
+    let user = crate::session::current_user().await?;
+    let invitation = owned_invitation(db, &user, invitation_id).await?;
+
+    if invitation.revoked_at.is_some() {
+        return Ok(());
+    }
+
+    sqlx::query("UPDATE invitations SET revoked_at = NOW() WHERE id = $1")
+        .bind(invitation_id)
+        .execute(db)
+        .await
+        .map_err(|e| -> ServerFnError {
+            tracing::error!("Database error revoking invitation: {}", e);
+            ServerFnError::ServerError("Failed to revoke invitation".into())
+        })?;
+
+    record_audit(
+        db,
+        audit_entry(AuditAction::Update, audit_entities::INVITATION, invitation_id)
+            .await
+            .metadata(json!({ "revoked": true })),
+    )
+    .await;
+
+    info!("Invitation {} revoked by {}", invitation_id, user.id);
+    Ok(())
+}
+
+/// Who sent an invitation and which address it's for, so the sign-up form
+/// can say so. Fails with the reason if the code can't be used.
+#[server]
+pub async fn check_invitation(code: String) -> Result<InvitationPreview, ServerFnError> {
+    crate::rate_limit!(rate_limits::INVITE_CHECK);
+    let db = get_db().await;
+
+    let invitation = sqlx::query_as::<_, Invitation>(&format!(
+        "SELECT {} {} WHERE i.code = $1",
+        INVITATION_COLUMNS, INVITATION_JOINS
+    ))
+    .bind(code.trim())
+    .fetch_optional(db)
+    .await
+    .map_err(|e| -> ServerFnError {
+        tracing::error!("Database error checking invitation: {}", e);
+        ServerFnError::ServerError("Failed to check invitation".into())
+    })?
+    .ok_or_else(|| -> ServerFnError { ServerFnError::Request("That invitation code isn't valid".into()) })?;
+
+    let status = invitation.status();
+    if status != InvitationStatus::Active {
+        return Err(ServerFnError::Request(status.refusal().into()));
+    }
+
+    Ok(InvitationPreview {
+        invited_by: invitation.created_by_username,
+        email: invitation.email,
+    })
+}
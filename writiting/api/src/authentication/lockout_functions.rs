@@ -0,0 +1,294 @@
+use chrono::{DateTime, Duration, Utc};
+use dioxus::prelude::*;
+use serde_json::json;
+use sqlx::PgPool;
+use uuid::Uuid;
+use tracing::warn;
+#[cfg(feature = "server")]
+use tracing::info;
+
+use crate::authentication::models::{LockedUser, LockoutPolicy, SecurityAction};
+use crate::authentication::models::account_lockout_model::LOCKOUT_REASON_FAILED_LOGINS;
+use crate::authentication::record_security_event;
+#[cfg(feature = "server")]
+use crate::db::get_db;
+use crate::utils::RequestMeta;
+
+/// End of the account's current lockout, if it has one
+pub async fn active_lockout(db: &PgPool, user_id: Uuid) -> Result<Option<DateTime<Utc>>, sqlx::Error> {
+    sqlx::query_scalar::<_, Option<DateTime<Utc>>>(
+        "SELECT MAX(locked_until) FROM account_lockouts WHERE user_id = $1 AND locked_until > NOW()"
+    )
+    .bind(user_id)
+    .fetch_one(db)
+    .await
+}
+
+/// End of the current lockout of an email address with no account behind it.
+/// These live only in the security log, and exist so that a locked account
+/// and an unknown address get the same answer at sign-in.
+pub async fn unknown_email_lockout(db: &PgPool, email: &str) -> Result<Option<DateTime<Utc>>, sqlx::Error> {
+    sqlx::query_scalar::<_, Option<DateTime<Utc>>>(
+        r#"
+        SELECT MAX((metadata->>'locked_until')::timestamptz) FROM security_logs
+        WHERE user_id IS NULL
+          AND action = 'account_locked'
+          AND LOWER(metadata->>'email') = LOWER($1)
+          AND (metadata->>'locked_until')::timestamptz > NOW()
+        "#
+    )
+    .bind(email)
+    .fetch_one(db)
+    .await
+}
+
+/// `record_login_failure` for an address with no account: same thresholds and
+/// lock durations, counted by email
+async fn lock_unknown_email(
+    db: &PgPool,
+    email: &str,
+    meta: &RequestMeta,
+    policy: &LockoutPolicy,
+) -> Result<Option<DateTime<Utc>>, sqlx::Error> {
+    let failures = sqlx::query_scalar::<_, i64>(
+        r#"
+        SELECT COUNT(*) FROM security_logs f
+        WHERE f.user_id IS NULL
+          AND f.action = 'login_failed'
+          AND LOWER(f.metadata->>'email') = LOWER($1)
+          AND f.created_at > GREATEST(
+              NOW() - make_interval(mins => $2::int),
+              COALESCE((SELECT MAX(created_at) FROM security_logs
+                        WHERE user_id IS NULL AND action = 'account_locked'
+                          AND LOWER(metadata->>'email') = LOWER($1)), '-infinity')
+          )
+        "#
+    )
+    .bind(email)
+    .bind(policy.account_window_minutes as i32)
+    .fetch_one(db)
+    .await?;
+
+    if failures < policy.max_account_failures {
+        return Ok(None);
+    }
+
+    let recent_lockouts = sqlx::query_scalar::<_, i64>(
+        r#"
+        SELECT COUNT(*) FROM security_logs
+        WHERE user_id IS NULL
+          AND action = 'account_locked'
+          AND LOWER(metadata->>'email') = LOWER($1)
+          AND created_at > NOW() - INTERVAL '24 hours'
+        "#
+    )
+    .bind(email)
+    .fetch_one(db)
+    .await?;
+
+    let locked_until = Utc::now() + policy.lock_duration(recent_lockouts);
+    record_security_event(
+        db,
+        None,
+        SecurityAction::AccountLocked,
+        meta,
+        json!({ "email": email, "failures": failures, "locked_until": locked_until }),
+    )
+    .await?;
+
+    warn!("Locked unknown email until {} after {} failed logins", locked_until, failures);
+    Ok(Some(locked_until))
+}
+
+/// When the address may try again, if it has failed too often recently
+pub async fn ip_blocked_until(
+    db: &PgPool,
+    meta: &RequestMeta,
+    policy: &LockoutPolicy,
+) -> Result<Option<DateTime<Utc>>, sqlx::Error> {
+    let Some(ip) = meta.ip_string() else {
+        return Ok(None);
+    };
+
+    let (failures, latest) = sqlx::query_as::<_, (i64, Option<DateTime<Utc>>)>(
+        r#"
+        SELECT COUNT(*), MAX(created_at) FROM security_logs
+        WHERE action = 'login_failed'
+          AND ip_address = $1::inet
+          AND created_at > NOW() - make_interval(mins => $2::int)
+        "#
+    )
+    .bind(ip)
+    .bind(policy.ip_window_minutes as i32)
+    .fetch_one(db)
+    .await?;
+
+    Ok(match latest {
+        Some(latest) if failures >= policy.max_ip_failures => {
+            Some(latest + Duration::minutes(policy.ip_window_minutes))
+        }
+        _ => None,
+    })
+}
+
+/// Records a failed login and locks the account (or, with no account, the
+/// email address) once it crosses the threshold.
+/// Returns the end of the new lockout, if one was placed.
+pub async fn record_login_failure(
+    db: &PgPool,
+    user_id: Option<Uuid>,
+    email: &str,
+    meta: &RequestMeta,
+    policy: &LockoutPolicy,
+) -> Result<Option<DateTime<Utc>>, sqlx::Error> {
+    record_security_event(db, user_id, SecurityAction::LoginFailed, meta, json!({ "email": email })).await?;
+
+    if meta.ip_address.is_some() {
+        let ip_failures = sqlx::query_scalar::<_, i64>(
+            r#"
+            SELECT COUNT(*) FROM security_logs
+            WHERE action = 'login_failed'
+              AND ip_address = $1::inet
+              AND created_at > NOW() - make_interval(mins => $2::int)
+            "#
+        )
+        .bind(meta.ip_string())
+        .bind(policy.ip_window_minutes as i32)
+        .fetch_one(db)
+        .await?;
+
+        // Flag the address once, when it first crosses the limit
+        if ip_failures == policy.max_ip_failures {
+            warn!("Too many failed logins from {:?}", meta.ip_address);
+            record_security_event(
+                db,
+                None,
+                SecurityAction::SuspiciousActivity,
+                meta,
+                json!({ "reason": "login_failures_from_ip", "failures": ip_failures }),
+            )
+            .await?;
+        }
+    }
+
+    let Some(user_id) = user_id else {
+        return lock_unknown_email(db, email, meta, policy).await;
+    };
+
+    // Count failures since the last successful login or lockout, within the window
+    let failures = sqlx::query_scalar::<_, i64>(
+        r#"
+        SELECT COUNT(*) FROM security_logs f
+        WHERE f.user_id = $1
+          AND f.action = 'login_failed'
+          AND f.created_at > GREATEST(
+              NOW() - make_interval(mins => $2::int),
+              COALESCE((SELECT MAX(created_at) FROM security_logs
+                        WHERE user_id = $1 AND action = 'login_success'), '-infinity'),
+              COALESCE((SELECT MAX(created_at) FROM account_lockouts
+                        WHERE user_id = $1), '-infinity')
+          )
+        "#
+    )
+    .bind(user_id)
+    .bind(policy.account_window_minutes as i32)
+    .fetch_one(db)
+    .await?;
+
+    if failures < policy.max_account_failures {
+        return Ok(None);
+    }
+
+    let recent_lockouts = sqlx::query_scalar::<_, i64>(
+        "SELECT COUNT(*) FROM account_lockouts WHERE user_id = $1 AND created_at > NOW() - INTERVAL '24 hours'"
+    )
+    .bind(user_id)
+    .fetch_one(db)
+    .await?;
+
+    let locked_until = Utc::now() + policy.lock_duration(recent_lockouts);
+
+    let mut tx = db.begin().await?;
+    sqlx::query("INSERT INTO account_lockouts (user_id, reason, locked_until) VALUES ($1, $2, $3)")
+        .bind(user_id)
+        .bind(LOCKOUT_REASON_FAILED_LOGINS)
+        .bind(locked_until)
+        .execute(&mut *tx)
+        .await?;
+    record_security_event(
+        &mut *tx,
+        Some(user_id),
+        SecurityAction::AccountLocked,
+        meta,
+        json!({ "failures": failures, "locked_until": locked_until }),
+    )
+    .await?;
+    tx.commit().await?;
+
+    warn!("Locked user {} until {} after {} failed logins", user_id, locked_until, failures);
+    Ok(Some(locked_until))
+}
+
+#[server]
+pub async fn list_locked_users() -> Result<Vec<LockedUser>, ServerFnError> {
+    let db = get_db().await;
+    crate::session::require_permission("user:update").await?;
+
+    sqlx::query_as::<_, LockedUser>(
+        r#"
+        SELECT DISTINCT ON (u.id)
+            u.id AS user_id, u.username, u.email, l.reason, l.locked_until
+        FROM account_lockouts l
+        JOIN users u ON u.id = l.user_id
+        WHERE l.locked_until > NOW()
+        ORDER BY u.id, l.locked_until DESC
+        "#
+    )
+    .fetch_all(db)
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error listing locked users: {}", e);
+        ServerFnError::ServerError("Failed to load locked users".into())
+    })
+}
+
+/// Ends every active lockout on an account
+#[server]
+pub async fn unlock_user(user_id: Uuid) -> Result<(), ServerFnError> {
+    let db = get_db().await;
+    let admin = crate::session::require_permission("user:update").await?;
+    let meta = crate::utils::request_meta().await;
+    let db_error = |e: sqlx::Error| -> ServerFnError {
+        tracing::error!("Database error unlocking user: {}", e);
+        ServerFnError::ServerError("Failed to unlock user".into())
+    };
+
+    let mut tx = db.begin().await.map_err(db_error)?;
+
+    let result = sqlx::query(
+        "UPDATE account_lockouts SET locked_until = NOW() WHERE user_id = $1 AND locked_until > NOW()"
+    )
+    .bind(user_id)
+    .execute(&mut *tx)
+    .await
+    .map_err(db_error)?;
+
+    if result.rows_affected() == 0 {
+        return Err(ServerFnError::Request("That account is not locked".into()));
+    }
+
+    record_security_event(
+        &mut *tx,
+        Some(user_id),
+        SecurityAction::AccountUnlocked,
+        &meta,
+        json!({ "unlocked_by": admin.id }),
+    )
+    .await
+    .map_err(db_error)?;
+
+    tx.commit().await.map_err(db_error)?;
+
+    info!("User {} unlocked by {}", user_id, admin.id);
+    Ok(())
+}
@@ -0,0 +1,40 @@
+pub mod models;
+#[cfg(feature = "server")]
+pub mod api_key_auth;
+pub mod api_key_functions;
+pub mod audit_functions;
+pub mod auth_functions;
+pub mod email_verification_functions;
+pub mod feature_flag_functions;
+pub mod invitation_functions;
+pub mod lockout_functions;
+pub mod password_reset_functions;
+pub mod rate_limit_functions;
+pub mod security_log_functions;
+pub mod two_factor_functions;
+
+pub use models::*;
+#[cfg(feature = "server")]
+pub use api_key_auth::ApiKeyAuth;
+pub use api_key_functions::{create_api_key, list_api_keys, revoke_api_key};
+pub use audit_functions::{export_audit_logs_csv, list_audit_logs, record_audit};
+#[cfg(feature = "server")]
+pub use audit_functions::audit_entry;
+pub use auth_functions::{change_password, login, register};
+pub use email_verification_functions::*;
+pub use feature_flag_functions::{
+    check_feature_flag, delete_feature_flag, is_feature_enabled, list_feature_flags,
+    refresh_feature_flags, save_feature_flag,
+};
+pub use invitation_functions::{
+    check_invitation, create_invitation, list_invitation_redemptions, list_invitations,
+    list_my_invitations, revoke_invitation, INVITATION_ADMIN_PERMISSION,
+};
+pub use lockout_functions::{list_locked_users, unlock_user};
+pub use password_reset_functions::*;
+pub use rate_limit_functions::{check_rate_limit, sweep_expired_rate_limits};
+pub use security_log_functions::{get_recent_security_activity, record_security_event, search_security_logs};
+pub use two_factor_functions::{
+    begin_two_factor_enrollment, confirm_two_factor_enrollment, disable_two_factor,
+    get_two_factor_status, regenerate_recovery_codes, verify_two_factor_login,
+};
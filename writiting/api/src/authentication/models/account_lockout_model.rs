@@ -0,0 +1,64 @@
+use uuid::Uuid;
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, FromRow)]
+pub struct AccountLockout {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub reason: String,
+    #[serde(with = "chrono::serde::ts_seconds")]
+    pub locked_until: DateTime<Utc>,
+    /// Admin who placed the lock, `None` for automatic lockouts
+    pub created_by: Option<Uuid>,
+    #[serde(with = "chrono::serde::ts_seconds")]
+    pub created_at: DateTime<Utc>,
+}
+
+/// A currently locked account, as listed for admins
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, FromRow)]
+pub struct LockedUser {
+    pub user_id: Uuid,
+    pub username: String,
+    pub email: String,
+    pub reason: String,
+    #[serde(with = "chrono::serde::ts_seconds")]
+    pub locked_until: DateTime<Utc>,
+}
+
+/// Thresholds for brute-force protection on login
+#[derive(Debug, Clone)]
+pub struct LockoutPolicy {
+    /// Failed passwords on one account before it is locked
+    pub max_account_failures: i64,
+    pub account_window_minutes: i64,
+    /// Failed logins from one address (across all accounts) before it is refused
+    pub max_ip_failures: i64,
+    pub ip_window_minutes: i64,
+    pub base_lock_minutes: i64,
+    pub max_lock_hours: i64,
+}
+
+impl Default for LockoutPolicy {
+    fn default() -> Self {
+        Self {
+            max_account_failures: 5,
+            account_window_minutes: 60,
+            max_ip_failures: 20,
+            ip_window_minutes: 15,
+            base_lock_minutes: 5,
+            max_lock_hours: 24,
+        }
+    }
+}
+
+impl LockoutPolicy {
+    /// Doubles with each lockout in the past day: 5, 10, 20... minutes, capped at `max_lock_hours`
+    pub fn lock_duration(&self, recent_lockouts: i64) -> Duration {
+        let minutes = self.base_lock_minutes << recent_lockouts.clamp(0, 12);
+        Duration::minutes(minutes.min(self.max_lock_hours * 60))
+    }
+}
+
+pub const LOCKOUT_REASON_FAILED_LOGINS: &str = "too_many_failed_logins";
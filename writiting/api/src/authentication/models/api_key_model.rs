@@ -1,70 +1,129 @@
+use std::net::IpAddr;
 use uuid::Uuid;
 use chrono::{DateTime, Utc};
 use serde::{Serialize, Deserialize};
+use sha2::{Digest, Sha256};
 use sqlx::FromRow;
 use validator::Validate;
 
-#[derive(Debug, Clone, Serialize, Deserialize, FromRow, Validate)]
+use super::TokenGenerator;
+
+/// Secrets look like `wk_` followed by 40 alphanumerics
+pub const API_KEY_SECRET_PREFIX: &str = "wk_";
+const API_KEY_SECRET_LENGTH: usize = 40;
+/// Characters of the secret kept in the clear so users can tell keys apart
+const API_KEY_DISPLAY_LENGTH: usize = 11;
+pub const MAX_API_KEYS_PER_USER: i64 = 20;
+
+// allowed_ips is CIDR[] and last_used_ip INET in the database, text on the model
+pub const API_KEY_COLUMNS: &str = "key, user_id, name, description, scopes, \
+    allowed_ips::text[] AS allowed_ips, prefix, expires_at, last_used_at, \
+    host(last_used_ip) AS last_used_ip, revoked_at, created_at";
+
+/// Mirrors the `api_scope` enum in the database.
+/// Each scope includes the ones before it: `admin` can write, `write` can read.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize, sqlx::Type)]
+#[sqlx(type_name = "api_scope", rename_all = "lowercase")]
+#[serde(rename_all = "lowercase")]
+pub enum ApiScope {
+    Read,
+    Write,
+    Admin,
+}
+
+impl ApiScope {
+    pub const ALL: [ApiScope; 3] = [ApiScope::Read, ApiScope::Write, ApiScope::Admin];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ApiScope::Read => "read",
+            ApiScope::Write => "write",
+            ApiScope::Admin => "admin",
+        }
+    }
+
+    pub fn includes(&self, other: ApiScope) -> bool {
+        *self >= other
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, FromRow)]
 pub struct ApiKey {
     pub key: Uuid,
     pub user_id: Uuid,
-    #[validate(length(min = 1, max = 100))]
     pub name: String,
-    pub scopes: Vec<String>,
-    #[serde(with = "chrono::serde::ts_seconds_option", skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    pub scopes: Vec<ApiScope>,
+    /// CIDR blocks the key may be used from; `None` allows any address
+    pub allowed_ips: Option<Vec<String>>,
+    /// Start of the secret, e.g. `wk_a1b2c3d4`
+    pub prefix: String,
+    #[serde(with = "chrono::serde::ts_seconds_option", skip_serializing_if = "Option::is_none", default)]
     pub expires_at: Option<DateTime<Utc>>,
-    #[serde(with = "chrono::serde::ts_seconds_option", skip_serializing_if = "Option::is_none")]
+    #[serde(with = "chrono::serde::ts_seconds_option", skip_serializing_if = "Option::is_none", default)]
     pub last_used_at: Option<DateTime<Utc>>,
+    pub last_used_ip: Option<String>,
+    #[serde(with = "chrono::serde::ts_seconds_option", skip_serializing_if = "Option::is_none", default)]
+    pub revoked_at: Option<DateTime<Utc>>,
     #[serde(with = "chrono::serde::ts_seconds")]
     pub created_at: DateTime<Utc>,
 }
 
 impl ApiKey {
-    /// Creates a new API key with default values
-    pub fn new(
-        user_id: Uuid,
-        name: String,
-        scopes: Vec<String>,
-        expires_in_days: Option<i64>,
-    ) -> Result<Self, validator::ValidationErrors> {
-        let mut key = Self {
-            key: Uuid::new_v4(),
-            user_id,
-            name,
-            scopes,
-            expires_at: expires_in_days.map(|days| Utc::now() + chrono::Duration::days(days)),
-            last_used_at: None,
-            created_at: Utc::now(),
-        };
+    /// New random secret; shown to the user once and never stored
+    pub fn generate_secret() -> String {
+        format!(
+            "{}{}",
+            API_KEY_SECRET_PREFIX,
+            TokenGenerator::generate_alphanumeric_token(API_KEY_SECRET_LENGTH)
+        )
+    }
 
-        key.validate()?;
-        Ok(key)
+    /// Hex SHA-256 of a secret. Secrets are random, so a slow hash adds nothing here.
+    pub fn hash_secret(secret: &str) -> String {
+        Sha256::digest(secret.as_bytes())
+            .iter()
+            .map(|b| format!("{:02x}", b))
+            .collect()
+    }
+
+    pub fn display_prefix(secret: &str) -> String {
+        secret.chars().take(API_KEY_DISPLAY_LENGTH).collect()
     }
 
     /// Checks if the API key is currently valid
     pub fn is_valid(&self) -> bool {
-        match self.expires_at {
-            Some(expiry) => Utc::now() < expiry,
-            None => true, // No expiration set
-        }
+        self.revoked_at.is_none() && self.expires_at.is_none_or(|expiry| Utc::now() < expiry)
     }
 
-    /// Verifies if the key has all required scopes
-    pub fn has_scopes(&self, required: &[&str]) -> bool {
-        required.iter().all(|scope| self.scopes.contains(&scope.to_string()))
+    /// Verifies the key holds every required scope (directly or through a higher one)
+    pub fn has_scopes(&self, required: &[ApiScope]) -> bool {
+        required
+            .iter()
+            .all(|needed| self.scopes.iter().any(|held| held.includes(*needed)))
     }
 
-    /// Updates the last used timestamp (requires mutable DB reference)
-    pub async fn record_usage(&mut self, pool: &sqlx::PgPool) -> Result<(), sqlx::Error> {
+    /// Whether a request from `ip` is allowed by the key's allowlist
+    pub fn allows_ip(&self, ip: Option<IpAddr>) -> bool {
+        match (&self.allowed_ips, ip) {
+            (None, _) => true,
+            (Some(blocks), _) if blocks.is_empty() => true,
+            (Some(_), None) => false,
+            (Some(blocks), Some(ip)) => blocks.iter().any(|block| cidr_contains(block, ip)),
+        }
+    }
+
+    /// Updates the last used timestamp and address
+    pub async fn record_usage(&mut self, pool: &sqlx::PgPool, ip: Option<IpAddr>) -> Result<(), sqlx::Error> {
         self.last_used_at = Some(Utc::now());
-        
-        sqlx::query!(
-            r#"UPDATE api_keys SET last_used_at = $1 WHERE key = $2"#,
-            self.last_used_at,
-            self.key
-        )
-        .execute(pool)
-        .await?;
+        self.last_used_ip = ip.map(|ip| ip.to_string());
+
+        sqlx::query("UPDATE api_keys SET last_used_at = $1, last_used_ip = $2::inet WHERE key = $3")
+            .bind(self.last_used_at)
+            .bind(&self.last_used_ip)
+            .bind(self.key)
+            .execute(pool)
+            .await?;
 
         Ok(())
     }
@@ -74,11 +133,22 @@ impl ApiKey {
         pool: &sqlx::PgPool,
         key: Uuid,
     ) -> Result<Option<Self>, sqlx::Error> {
-        sqlx::query_as!(
-            Self,
-            r#"SELECT * FROM api_keys WHERE key = $1"#,
-            key
-        )
+        sqlx::query_as::<_, Self>(&format!("SELECT {} FROM api_keys WHERE key = $1", API_KEY_COLUMNS))
+            .bind(key)
+            .fetch_optional(pool)
+            .await
+    }
+
+    /// Repository pattern - Fetch the unrevoked key a secret belongs to
+    pub async fn find_by_secret(
+        pool: &sqlx::PgPool,
+        secret: &str,
+    ) -> Result<Option<Self>, sqlx::Error> {
+        sqlx::query_as::<_, Self>(&format!(
+            "SELECT {} FROM api_keys WHERE key_hash = $1 AND revoked_at IS NULL",
+            API_KEY_COLUMNS
+        ))
+        .bind(Self::hash_secret(secret))
         .fetch_optional(pool)
         .await
     }
@@ -87,29 +157,34 @@ impl ApiKey {
     pub async fn list_for_user(
         pool: &sqlx::PgPool,
         user_id: Uuid,
-        include_expired: bool,
+        include_inactive: bool,
     ) -> Result<Vec<Self>, sqlx::Error> {
-        let query = if include_expired {
-            "SELECT * FROM api_keys WHERE user_id = $1"
+        let filter = if include_inactive {
+            ""
         } else {
-            "SELECT * FROM api_keys WHERE user_id = $1 AND (expires_at IS NULL OR expires_at > NOW())"
+            "AND revoked_at IS NULL AND (expires_at IS NULL OR expires_at > NOW())"
         };
 
-        sqlx::query_as::<_, Self>(query)
-            .bind(user_id)
-            .fetch_all(pool)
-            .await
+        sqlx::query_as::<_, Self>(&format!(
+            "SELECT {} FROM api_keys WHERE user_id = $1 {} ORDER BY created_at DESC",
+            API_KEY_COLUMNS, filter
+        ))
+        .bind(user_id)
+        .fetch_all(pool)
+        .await
     }
 
-    /// Repository pattern - Revoke a key
+    /// Repository pattern - Revoke one of a user's keys
     pub async fn revoke(
         pool: &sqlx::PgPool,
+        user_id: Uuid,
         key: Uuid,
     ) -> Result<bool, sqlx::Error> {
-        let result = sqlx::query!(
-            r#"DELETE FROM api_keys WHERE key = $1"#,
-            key
+        let result = sqlx::query(
+            "UPDATE api_keys SET revoked_at = NOW() WHERE key = $1 AND user_id = $2 AND revoked_at IS NULL"
         )
+        .bind(key)
+        .bind(user_id)
         .execute(pool)
         .await?;
 
@@ -117,24 +192,115 @@ impl ApiKey {
     }
 }
 
-// DTO for safe serialization (excludes sensitive fields)
-#[derive(Debug, Serialize)]
-pub struct ApiKeyResponse {
-    pub key: Uuid,
+/// Parses `addr/bits` (or a bare address) into its network address and prefix
+/// length. Host bits are cleared, so `10.0.0.1/24` gives `10.0.0.0` and 24.
+pub fn parse_cidr(block: &str) -> Option<(IpAddr, u8)> {
+    let (addr, bits) = match block.split_once('/') {
+        Some((addr, bits)) => (addr.parse::<IpAddr>().ok()?, bits.parse::<u8>().ok()?),
+        None => {
+            let addr = block.parse::<IpAddr>().ok()?;
+            (addr, if addr.is_ipv4() { 32 } else { 128 })
+        }
+    };
+
+    let network = match addr {
+        IpAddr::V4(addr) if bits <= 32 => {
+            let mask = u32::MAX.checked_shl(32 - bits as u32).unwrap_or(0);
+            IpAddr::V4((u32::from(addr) & mask).into())
+        }
+        IpAddr::V6(addr) if bits <= 128 => {
+            let mask = u128::MAX.checked_shl(128 - bits as u32).unwrap_or(0);
+            IpAddr::V6((u128::from(addr) & mask).into())
+        }
+        _ => return None,
+    };
+    Some((network, bits))
+}
+
+/// `block` as Postgres `cidr` accepts it: `network/bits` with the host bits cleared
+pub fn normalize_cidr(block: &str) -> Option<String> {
+    parse_cidr(block).map(|(network, bits)| format!("{}/{}", network, bits))
+}
+
+/// Whether `ip` falls inside `block`, an address or CIDR block
+pub fn cidr_contains(block: &str, ip: IpAddr) -> bool {
+    let Some((network, bits)) = parse_cidr(block) else {
+        return false;
+    };
+    // Inside the block when masking `ip` to the same prefix gives its network
+    parse_cidr(&format!("{}/{}", ip, bits)) == Some((network, bits))
+}
+
+/// Form input for a new key
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Validate)]
+pub struct CreateApiKeyRequest {
+    #[validate(length(min = 1, max = 100))]
     pub name: String,
-    pub scopes: Vec<String>,
-    pub expires_at: Option<DateTime<Utc>>,
-    pub created_at: DateTime<Utc>,
+    #[validate(length(max = 500))]
+    pub description: Option<String>,
+    #[validate(length(min = 1, message = "Choose at least one scope"))]
+    pub scopes: Vec<ApiScope>,
+    /// Addresses or CIDR blocks; empty allows any address
+    pub allowed_ips: Vec<String>,
+    #[validate(range(min = 1, max = 365))]
+    pub expires_in_days: Option<i64>,
 }
 
-impl From<ApiKey> for ApiKeyResponse {
-    fn from(api_key: ApiKey) -> Self {
-        Self {
-            key: api_key.key,
-            name: api_key.name,
-            scopes: api_key.scopes,
-            expires_at: api_key.expires_at,
-            created_at: api_key.created_at,
-        }
+/// A newly created key together with its secret, which is only ever returned here
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CreatedApiKey {
+    pub key: ApiKey,
+    pub secret: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ip(addr: &str) -> IpAddr {
+        addr.parse().unwrap()
+    }
+
+    #[test]
+    fn parse_cidr_reads_v4_and_v6_blocks() {
+        assert_eq!(parse_cidr("10.0.0.0/8"), Some((ip("10.0.0.0"), 8)));
+        assert_eq!(parse_cidr("2001:db8::/32"), Some((ip("2001:db8::"), 32)));
+    }
+
+    #[test]
+    fn parse_cidr_treats_bare_addresses_as_single_hosts() {
+        assert_eq!(parse_cidr("192.168.1.7"), Some((ip("192.168.1.7"), 32)));
+        assert_eq!(parse_cidr("::1"), Some((ip("::1"), 128)));
+    }
+
+    #[test]
+    fn parse_cidr_clears_host_bits() {
+        assert_eq!(parse_cidr("10.0.0.1/24"), Some((ip("10.0.0.0"), 24)));
+        assert_eq!(parse_cidr("2001:db8::1/64"), Some((ip("2001:db8::"), 64)));
+        assert_eq!(parse_cidr("203.0.113.9/0"), Some((ip("0.0.0.0"), 0)));
+        assert_eq!(normalize_cidr("10.0.0.1/24").as_deref(), Some("10.0.0.0/24"));
+    }
+
+    #[test]
+    fn parse_cidr_rejects_prefixes_out_of_range() {
+        assert_eq!(parse_cidr("10.0.0.0/33"), None);
+        assert_eq!(parse_cidr("2001:db8::/129"), None);
+        assert_eq!(parse_cidr("10.0.0.0/-1"), None);
+        assert_eq!(parse_cidr("10.0.0.0/"), None);
+    }
+
+    #[test]
+    fn parse_cidr_rejects_garbage() {
+        assert_eq!(parse_cidr(""), None);
+        assert_eq!(parse_cidr("localhost"), None);
+        assert_eq!(parse_cidr("10.0.0/8"), None);
+    }
+
+    #[test]
+    fn cidr_contains_matches_within_the_block_only() {
+        assert!(cidr_contains("10.0.0.1/24", ip("10.0.0.200")));
+        assert!(!cidr_contains("10.0.0.0/24", ip("10.0.1.1")));
+        assert!(cidr_contains("2001:db8::/32", ip("2001:db8:1::5")));
+        assert!(!cidr_contains("10.0.0.0/8", ip("::ffff:10.0.0.1")));
     }
-}
\ No newline at end of file
+}
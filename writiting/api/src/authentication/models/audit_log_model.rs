@@ -1,18 +1,64 @@
 use uuid::Uuid;
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, NaiveDate, Utc};
 use serde::{Serialize, Deserialize};
 use sqlx::FromRow;
 use serde_json::Value;
 use std::net::IpAddr;
 
-#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+use crate::utils::RequestMeta;
+
+/// Mirrors the `audit_action` enum in the database
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type)]
+#[sqlx(type_name = "audit_action", rename_all = "lowercase")]
+#[serde(rename_all = "lowercase")]
+pub enum AuditAction {
+    Create,
+    Update,
+    Delete,
+    View,
+    Export,
+    Import,
+}
+
+impl AuditAction {
+    pub const ALL: [AuditAction; 6] = [
+        AuditAction::Create,
+        AuditAction::Update,
+        AuditAction::Delete,
+        AuditAction::View,
+        AuditAction::Export,
+        AuditAction::Import,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AuditAction::Create => "create",
+            AuditAction::Update => "update",
+            AuditAction::Delete => "delete",
+            AuditAction::View => "view",
+            AuditAction::Export => "export",
+            AuditAction::Import => "import",
+        }
+    }
+}
+
+// ip_address is INET in the database and text on the model
+pub const AUDIT_LOG_COLUMNS: &str = "a.id, a.user_id, u.username, a.action, a.entity_type, a.entity_id, \
+    a.old_values, a.new_values, host(a.ip_address) AS ip_address, a.user_agent, a.metadata, a.created_at";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, FromRow)]
 pub struct AuditLog {
     pub id: i64,
+    /// Who made the change; `None` for system jobs or deleted accounts
     pub user_id: Option<Uuid>,
-    pub action: String,
-    pub entity_type: Option<String>,
-    pub entity_id: Option<String>, // Flexible ID (UUID or other)
-    pub ip_address: Option<IpAddr>,
+    /// Actor's username at read time, joined in by listings
+    pub username: Option<String>,
+    pub action: AuditAction,
+    pub entity_type: String,
+    pub entity_id: String, // Flexible ID (UUID or other)
+    pub old_values: Option<Value>,
+    pub new_values: Option<Value>,
+    pub ip_address: Option<String>,
     pub user_agent: Option<String>,
     pub metadata: Option<Value>,
     #[serde(with = "chrono::serde::ts_seconds")]
@@ -21,47 +67,56 @@ pub struct AuditLog {
 
 impl AuditLog {
     /// Creates a new audit log entry builder
-    pub fn builder(action: impl Into<String>) -> AuditLogBuilder {
-        AuditLogBuilder::new(action.into())
+    pub fn builder(action: AuditAction, entity_type: &str, entity_id: impl ToString) -> AuditLogBuilder {
+        AuditLogBuilder::new(action, entity_type, entity_id.to_string())
     }
 
     /// Checks if this log entry relates to a specific entity
     pub fn relates_to(&self, entity_type: &str, entity_id: &str) -> bool {
-        self.entity_type.as_deref() == Some(entity_type) && 
-        self.entity_id.as_deref() == Some(entity_id)
+        self.entity_type == entity_type && self.entity_id == entity_id
     }
 
-    /// Checks if the action matches (with optional wildcard)
-    pub fn is_action(&self, action: &str) -> bool {
-        // Support wildcard matching like "user.*"
-        if action.ends_with(".*") {
-            let prefix = &action[..action.len() - 2];
-            self.action.starts_with(prefix)
-        } else {
-            self.action == action
-        }
+    /// Top-level fields whose value differs between the before and after snapshots
+    pub fn changed_fields(&self) -> Vec<String> {
+        let empty = serde_json::Map::new();
+        let old = self.old_values.as_ref().and_then(Value::as_object).unwrap_or(&empty);
+        let new = self.new_values.as_ref().and_then(Value::as_object).unwrap_or(&empty);
+
+        let mut fields: Vec<String> = old
+            .keys()
+            .chain(new.keys())
+            .filter(|k| old.get(*k) != new.get(*k))
+            .cloned()
+            .collect();
+        fields.sort();
+        fields.dedup();
+        fields
     }
 }
 
 /// Builder pattern for audit logs
 #[derive(Debug)]
-pub struct AuditLogBuilder { 
+pub struct AuditLogBuilder {
     user_id: Option<Uuid>,
-    action: String,
-    entity_type: Option<String>,
-    entity_id: Option<String>,
+    action: AuditAction,
+    entity_type: String,
+    entity_id: String,
+    old_values: Option<Value>,
+    new_values: Option<Value>,
     ip_address: Option<IpAddr>,
     user_agent: Option<String>,
     metadata: Option<Value>,
 }
 
 impl AuditLogBuilder {
-    pub fn new(action: String) -> Self {
+    pub fn new(action: AuditAction, entity_type: &str, entity_id: String) -> Self {
         Self {
             user_id: None,
             action,
-            entity_type: None,
-            entity_id: None,
+            entity_type: entity_type.to_string(),
+            entity_id,
+            old_values: None,
+            new_values: None,
             ip_address: None,
             user_agent: None,
             metadata: None,
@@ -73,12 +128,6 @@ impl AuditLogBuilder {
         self
     }
 
-    pub fn entity<T: ToString>(mut self, entity_type: &str, entity_id: T) -> Self {
-        self.entity_type = Some(entity_type.to_string());
-        self.entity_id = Some(entity_id.to_string());
-        self
-    }
-
     pub fn ip(mut self, ip: IpAddr) -> Self {
         self.ip_address = Some(ip);
         self
@@ -89,45 +138,101 @@ impl AuditLogBuilder {
         self
     }
 
+    /// Client address and user agent of the request that made the change
+    pub fn request(mut self, meta: &RequestMeta) -> Self {
+        self.ip_address = meta.ip_address;
+        self.user_agent = meta.user_agent.clone();
+        self
+    }
+
+    /// State before the change; omitted for creates
+    pub fn before<T: Serialize>(mut self, value: &T) -> Self {
+        self.old_values = serde_json::to_value(value).ok();
+        self
+    }
+
+    /// State after the change; omitted for deletes
+    pub fn after<T: Serialize>(mut self, value: &T) -> Self {
+        self.new_values = serde_json::to_value(value).ok();
+        self
+    }
+
+    /// Extra context; objects given more than once are merged
     pub fn metadata(mut self, metadata: Value) -> Self {
-        self.metadata = Some(metadata);
+        self.metadata = match (self.metadata.take(), metadata) {
+            (Some(Value::Object(mut existing)), Value::Object(extra)) => {
+                existing.extend(extra);
+                Some(Value::Object(existing))
+            }
+            (_, metadata) => Some(metadata),
+        };
         self
     }
 
-    /// Consumes the builder and returns an unpersisted AuditLog
-    pub fn build(self) -> AuditLog {
-        AuditLog {
-            id: 0, // Will be set by database
-            user_id: self.user_id,
-            action: self.action,
-            entity_type: self.entity_type,
-            entity_id: self.entity_id,
-            ip_address: self.ip_address,
-            user_agent: self.user_agent,
-            metadata: self.metadata,
-            created_at: Utc::now(),
-        }
+    /// Writes the entry
+    pub async fn record<'e, E>(self, db: E) -> Result<(), sqlx::Error>
+    where
+        E: sqlx::PgExecutor<'e>,
+    {
+        sqlx::query(
+            r#"
+            INSERT INTO audit_logs
+                (user_id, action, entity_type, entity_id, old_values, new_values, ip_address, user_agent, metadata)
+            VALUES ($1, $2, $3, $4, $5, $6, $7::inet, $8, $9)
+            "#
+        )
+        .bind(self.user_id)
+        .bind(self.action)
+        .bind(&self.entity_type)
+        .bind(&self.entity_id)
+        .bind(&self.old_values)
+        .bind(&self.new_values)
+        .bind(self.ip_address.map(|ip| ip.to_string()))
+        .bind(&self.user_agent)
+        .bind(&self.metadata)
+        .execute(db)
+        .await?;
+
+        Ok(())
     }
 }
 
-/// Example action types
-pub mod audit_actions {
-    pub const USER_LOGIN: &str = "user.login";
-    pub const USER_LOGOUT: &str = "user.logout";
-    pub const USER_PASSWORD_CHANGE: &str = "user.password_change";
-    
-    pub fn entity_action(entity: &str, action: &str) -> String {
-        format!("{}.{}", entity, action)
-    }
+/// Entity names used in `audit_logs.entity_type`
+pub mod audit_entities {
+    pub const POST: &str = "post";
+    pub const USER: &str = "user";
+    pub const PROFILE: &str = "profile";
+    pub const ROLE: &str = "role";
+    pub const SETTING: &str = "setting";
+    pub const API_KEY: &str = "api_key";
+    pub const FEATURE_FLAG: &str = "feature_flag";
+    pub const MAINTENANCE_WINDOW: &str = "maintenance_window";
+    pub const COMMENT: &str = "comment";
+    pub const CONTENT_REPORT: &str = "content_report";
+    pub const INVITATION: &str = "invitation";
+    pub const MEDIA: &str = "media";
+    pub const PREFERENCES: &str = "preferences";
 }
 
-/// Safe DTO for API responses
-#[derive(Debug, Serialize)]
-pub struct AuditLogResponse {
-    pub id: i64,
-    pub user_id: Option<Uuid>,
-    pub action: String,
+/// Filters for the admin audit viewer; every field is optional
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct AuditLogFilter {
+    /// Matches the actor's username or email, case-insensitively
+    pub user: Option<String>,
     pub entity_type: Option<String>,
     pub entity_id: Option<String>,
-    pub created_at: DateTime<Utc>,
-}
\ No newline at end of file
+    pub action: Option<AuditAction>,
+    /// Inclusive start date (UTC)
+    pub from: Option<NaiveDate>,
+    /// Inclusive end date (UTC)
+    pub to: Option<NaiveDate>,
+    pub page: i64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AuditLogPage {
+    pub entries: Vec<AuditLog>,
+    pub total: i64,
+    pub page: i64,
+    pub per_page: i64,
+}
@@ -1,16 +1,55 @@
-#[derive(Debug, Serialize, Deserialize)]
+use std::env;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+use crate::users::User;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct AuthToken {
     pub token: String,
+    #[serde(with = "chrono::serde::ts_seconds")]
     pub expires_at: DateTime<Utc>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct AuthResponse {
     pub user: User,
     pub token: AuthToken,
 }
 
-#[derive(Debug, Validate, Deserialize)]
+/// Result of a login attempt that got past the password check
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum LoginOutcome {
+    Authenticated(Box<AuthResponse>),
+    /// The login policy requires a verified email before a session is issued
+    EmailNotVerified,
+    /// Password accepted; a TOTP or recovery code must be submitted with this challenge token
+    TwoFactorRequired { challenge: String },
+    /// Too many failed attempts for this account or address
+    Locked {
+        #[serde(with = "chrono::serde::ts_seconds")]
+        locked_until: DateTime<Utc>,
+    },
+}
+
+/// Rules applied after credentials are accepted
+#[derive(Debug, Clone)]
+pub struct LoginPolicy {
+    pub require_verified_email: bool,
+}
+
+impl LoginPolicy {
+    pub fn from_env() -> Self {
+        Self {
+            require_verified_email: env::var("REQUIRE_EMAIL_VERIFICATION")
+                .map(|v| v == "true" || v == "1")
+                .unwrap_or(false),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Validate, Serialize, Deserialize)]
 pub struct LoginRequest {
     #[validate(email)]
     pub email: String,
@@ -19,7 +58,7 @@ pub struct LoginRequest {
     pub password: String,
 }
 
-#[derive(Debug, Validate, Deserialize)]
+#[derive(Debug, Clone, Validate, Serialize, Deserialize)]
 pub struct RegisterRequest {
     #[validate(email)]
     pub email: String,
@@ -32,4 +71,8 @@ pub struct RegisterRequest {
     
     #[validate(must_match(other = "password", message = "Passwords must match"))]
     pub password_confirmation: String,
-}
\ No newline at end of file
+
+    /// Required while registration is invite-only
+    #[serde(default)]
+    pub invite_code: Option<String>,
+}
@@ -1,9 +1,20 @@
+use uuid::Uuid;
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+
+/// How long a verification link stays valid
+pub const VERIFICATION_TOKEN_HOURS: i64 = 48;
+
 #[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
 pub struct EmailVerificationToken {
     pub token: Uuid,
     pub user_id: Uuid,
+    /// Set when the token confirms a change of address rather than the sign-up email
+    pub new_email: Option<String>,
     #[serde(with = "chrono::serde::ts_seconds")]
     pub expires_at: DateTime<Utc>,
+    #[serde(with = "chrono::serde::ts_seconds")]
+    pub created_at: DateTime<Utc>,
 }
 
 impl EmailVerificationToken {
@@ -11,11 +22,24 @@ impl EmailVerificationToken {
         Self {
             token: Uuid::new_v4(),
             user_id,
+            new_email: None,
             expires_at,
+            created_at: Utc::now(),
+        }
+    }
+
+    pub fn for_new_email(user_id: Uuid, new_email: String) -> Self {
+        Self {
+            new_email: Some(new_email),
+            ..Self::new(user_id, Utc::now() + Duration::hours(VERIFICATION_TOKEN_HOURS))
         }
     }
 
     pub fn is_valid(&self) -> bool {
         Utc::now() < self.expires_at
     }
-}
\ No newline at end of file
+
+    pub fn is_email_change(&self) -> bool {
+        self.new_email.is_some()
+    }
+}
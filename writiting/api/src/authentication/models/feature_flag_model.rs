@@ -1,17 +1,30 @@
 use uuid::Uuid;
 use chrono::{DateTime, Utc};
 use serde::{Serialize, Deserialize};
+use sha2::{Digest, Sha256};
 use sqlx::FromRow;
 use validator::Validate;
 
-#[derive(Debug, Clone, Serialize, Deserialize, FromRow, Validate)]
+use crate::utils::validate_flag_name;
+
+pub const FEATURE_FLAG_COLUMNS: &str = "name, description, is_enabled, rollout_percentage, increment_per_day, \
+    target_users, rollout_started_at, created_at, updated_at";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, FromRow, Validate)]
 pub struct FeatureFlag {
-    #[validate(length(min = 1, max = 100))]
+    #[validate(length(min = 1, max = 100), custom(function = "validate_flag_name"))]
     pub name: String,
+    pub description: Option<String>,
     pub is_enabled: bool,
     #[validate(range(min = 0, max = 100))]
     pub rollout_percentage: i32,
+    /// Percentage points added each day for gradual rollouts, 0 for a fixed percentage
+    #[validate(range(min = 0, max = 100))]
+    pub increment_per_day: i32,
     pub target_users: Vec<Uuid>,
+    /// Start of the current rollout; reset whenever the percentage or strategy changes
+    #[serde(with = "chrono::serde::ts_seconds")]
+    pub rollout_started_at: DateTime<Utc>,
     #[serde(with = "chrono::serde::ts_seconds")]
     pub created_at: DateTime<Utc>,
     #[serde(with = "chrono::serde::ts_seconds")]
@@ -26,6 +39,11 @@ impl FeatureFlag {
 
     /// Evaluates if the feature is active for a specific user
     pub fn is_active_for_user(&self, user_id: Option<Uuid>) -> bool {
+        self.is_active_at(user_id, Utc::now())
+    }
+
+    /// Evaluates the flag as it stands at `now`, which matters for gradual rollouts
+    pub fn is_active_at(&self, user_id: Option<Uuid>, now: DateTime<Utc>) -> bool {
         if !self.is_enabled {
             return false;
         }
@@ -37,23 +55,63 @@ impl FeatureFlag {
             }
         }
 
-        // Check percentage rollout
-        if self.rollout_percentage >= 100 {
+        let percentage = self.percentage_at(now);
+        if percentage >= 100 {
             true
-        } else if self.rollout_percentage <= 0 {
+        } else if percentage <= 0 {
             false
         } else {
-            // Stable rollout based on user ID or random fallback
-            user_id.map_or_else(
-                || rand::random::<i32>() % 100 < self.rollout_percentage,
-                |uid| (uid.as_u128() % 100) < self.rollout_percentage as u128,
-            )
+            // Anonymous visitors have nothing stable to bucket on, so they only
+            // see a feature once it reaches everyone
+            user_id.is_some_and(|uid| self.bucket(uid) < percentage as u64)
+        }
+    }
+
+    /// Share of users the flag covers at `now`, after any gradual increase
+    pub fn percentage_at(&self, now: DateTime<Utc>) -> i32 {
+        if self.increment_per_day <= 0 {
+            return self.rollout_percentage;
         }
+
+        let days = (now - self.rollout_started_at).num_days().max(0);
+        let grown = self.rollout_percentage as i64 + days * self.increment_per_day as i64;
+        grown.min(100) as i32
+    }
+
+    /// Stable bucket in 0..100 for a user. Hashing the flag name in means each
+    /// flag picks a different slice of users rather than always the same ones.
+    pub fn bucket(&self, user_id: Uuid) -> u64 {
+        let digest = Sha256::new()
+            .chain_update(self.name.as_bytes())
+            .chain_update(b":")
+            .chain_update(user_id.as_bytes())
+            .finalize();
+        let mut prefix = [0u8; 8];
+        prefix.copy_from_slice(&digest[..8]);
+        u64::from_be_bytes(prefix) % 100
     }
 
     /// Checks if the flag has any targeting rules
     pub fn has_targeting(&self) -> bool {
-        self.rollout_percentage != 100 || !self.target_users.is_empty()
+        self.rollout_percentage != 100 || self.increment_per_day > 0 || !self.target_users.is_empty()
+    }
+
+    /// The rollout strategy the stored fields describe
+    pub fn strategy(&self) -> RolloutStrategy {
+        if !self.is_enabled {
+            RolloutStrategy::Disabled
+        } else if self.increment_per_day > 0 {
+            RolloutStrategy::Gradual {
+                initial_percentage: self.rollout_percentage,
+                increment_per_day: self.increment_per_day,
+            }
+        } else if !self.target_users.is_empty() && self.rollout_percentage == 0 {
+            RolloutStrategy::Targeted(self.target_users.clone())
+        } else if self.rollout_percentage >= 100 {
+            RolloutStrategy::Enabled
+        } else {
+            RolloutStrategy::Percentage(self.rollout_percentage)
+        }
     }
 }
 
@@ -61,8 +119,10 @@ impl FeatureFlag {
 #[derive(Debug)]
 pub struct FeatureFlagBuilder {
     name: String,
+    description: Option<String>,
     is_enabled: bool,
     rollout_percentage: i32,
+    increment_per_day: i32,
     target_users: Vec<Uuid>,
 }
 
@@ -70,12 +130,19 @@ impl FeatureFlagBuilder {
     pub fn new(name: String) -> Self {
         Self {
             name,
+            description: None,
             is_enabled: false,
             rollout_percentage: 0,
+            increment_per_day: 0,
             target_users: Vec::new(),
         }
     }
 
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
     pub fn enabled(mut self) -> Self {
         self.is_enabled = true;
         self
@@ -101,13 +168,36 @@ impl FeatureFlagBuilder {
         self
     }
 
+    /// Sets the enabled state, percentage and targets to match `strategy`
+    pub fn strategy(mut self, strategy: RolloutStrategy) -> Self {
+        self.is_enabled = !matches!(strategy, RolloutStrategy::Disabled);
+        self.increment_per_day = 0;
+        match strategy {
+            RolloutStrategy::Disabled => {}
+            RolloutStrategy::Enabled => self.rollout_percentage = 100,
+            RolloutStrategy::Percentage(percentage) => self.rollout_percentage = percentage.clamp(0, 100),
+            RolloutStrategy::Targeted(users) => {
+                self.rollout_percentage = 0;
+                self.target_users = users;
+            }
+            RolloutStrategy::Gradual { initial_percentage, increment_per_day } => {
+                self.rollout_percentage = initial_percentage.clamp(0, 100);
+                self.increment_per_day = increment_per_day.clamp(0, 100);
+            }
+        }
+        self
+    }
+
     pub fn build(self) -> FeatureFlag {
         let now = Utc::now();
         FeatureFlag {
             name: self.name,
+            description: self.description,
             is_enabled: self.is_enabled,
             rollout_percentage: self.rollout_percentage,
+            increment_per_day: self.increment_per_day,
             target_users: self.target_users,
+            rollout_started_at: now,
             created_at: now,
             updated_at: now,
         }
@@ -120,6 +210,8 @@ pub struct FeatureFlagResponse {
     pub name: String,
     pub is_enabled: bool,
     pub rollout_percentage: i32,
+    /// Percentage in effect right now, after any gradual increase
+    pub current_percentage: i32,
     pub has_targeting: bool,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
@@ -128,10 +220,11 @@ pub struct FeatureFlagResponse {
 impl From<FeatureFlag> for FeatureFlagResponse {
     fn from(flag: FeatureFlag) -> Self {
         Self {
+            current_percentage: flag.percentage_at(Utc::now()),
+            has_targeting: flag.has_targeting(),
             name: flag.name,
             is_enabled: flag.is_enabled,
             rollout_percentage: flag.rollout_percentage,
-            has_targeting: flag.has_targeting(),
             created_at: flag.created_at,
             updated_at: flag.updated_at,
         }
@@ -139,7 +232,7 @@ impl From<FeatureFlag> for FeatureFlagResponse {
 }
 
 /// Rollout strategy enums
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum RolloutStrategy {
     Disabled,
     Enabled,
@@ -149,4 +242,100 @@ pub enum RolloutStrategy {
         initial_percentage: i32,
         increment_per_day: i32,
     },
-}
\ No newline at end of file
+}
+
+/// An admin's edit to a flag; creates it if the name is new
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, Validate)]
+pub struct FeatureFlagUpdate {
+    #[validate(length(min = 1, max = 100), custom(function = "validate_flag_name"))]
+    pub name: String,
+    pub description: Option<String>,
+    pub is_enabled: bool,
+    #[validate(range(min = 0, max = 100))]
+    pub rollout_percentage: i32,
+    #[validate(range(min = 0, max = 100))]
+    pub increment_per_day: i32,
+    pub target_users: Vec<Uuid>,
+}
+
+impl From<&FeatureFlag> for FeatureFlagUpdate {
+    fn from(flag: &FeatureFlag) -> Self {
+        Self {
+            name: flag.name.clone(),
+            description: flag.description.clone(),
+            is_enabled: flag.is_enabled,
+            rollout_percentage: flag.rollout_percentage,
+            increment_per_day: flag.increment_per_day,
+            target_users: flag.target_users.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    fn user(n: u128) -> Uuid {
+        Uuid::from_u128(n)
+    }
+
+    #[test]
+    fn bucket_is_stable_and_below_100() {
+        let flag = FeatureFlag::builder("new_editor").build();
+        for n in 0..500 {
+            let bucket = flag.bucket(user(n));
+            assert!(bucket < 100);
+            assert_eq!(bucket, flag.bucket(user(n)));
+        }
+    }
+
+    #[test]
+    fn bucket_depends_on_the_flag_name() {
+        let a = FeatureFlag::builder("new_editor").build();
+        let b = FeatureFlag::builder("dark_mode").build();
+        assert!((0..100).any(|n| a.bucket(user(n)) != b.bucket(user(n))));
+    }
+
+    #[test]
+    fn percentage_rollout_covers_about_that_share_of_users() {
+        let flag = FeatureFlag::builder("new_editor").enabled().rollout_percentage(30).build();
+        let active = (0..2000).filter(|n| flag.is_active_for_user(Some(user(*n)))).count();
+        assert!((500..700).contains(&active), "{} of 2000 users", active);
+    }
+
+    #[test]
+    fn percentage_at_grows_daily_and_stops_at_100() {
+        let flag = FeatureFlag::builder("new_editor")
+            .strategy(RolloutStrategy::Gradual { initial_percentage: 10, increment_per_day: 20 })
+            .build();
+        let start = flag.rollout_started_at;
+        assert_eq!(flag.percentage_at(start), 10);
+        assert_eq!(flag.percentage_at(start + Duration::hours(23)), 10);
+        assert_eq!(flag.percentage_at(start + Duration::days(2)), 50);
+        assert_eq!(flag.percentage_at(start + Duration::days(30)), 100);
+        assert_eq!(flag.percentage_at(start - Duration::days(3)), 10);
+    }
+
+    #[test]
+    fn percentage_at_is_fixed_without_an_increment() {
+        let flag = FeatureFlag::builder("new_editor").rollout_percentage(40).build();
+        assert_eq!(flag.percentage_at(flag.rollout_started_at + Duration::days(10)), 40);
+    }
+
+    #[test]
+    fn targets_and_anonymous_visitors() {
+        let target = user(7);
+        let flag = FeatureFlag::builder("new_editor")
+            .strategy(RolloutStrategy::Targeted(vec![target]))
+            .build();
+        assert!(flag.is_active_for_user(Some(target)));
+        assert!(!flag.is_active_for_user(Some(user(8))));
+        assert!(!flag.is_active_for_user(None));
+
+        let everyone = FeatureFlag::builder("new_editor").strategy(RolloutStrategy::Enabled).build();
+        assert!(everyone.is_active_for_user(None));
+        let off = FeatureFlag::builder("new_editor").disabled().rollout_percentage(100).build();
+        assert!(!off.is_active_for_user(Some(target)));
+    }
+}
@@ -0,0 +1,142 @@
+use uuid::Uuid;
+use chrono::{DateTime, Utc};
+use serde::{Serialize, Deserialize};
+use sqlx::FromRow;
+use validator::Validate;
+
+use super::TokenGenerator;
+
+const INVITATION_CODE_LENGTH: usize = 12;
+/// Open invitations a user without `user:create` may have at once
+pub const MAX_ACTIVE_INVITATIONS_PER_USER: i64 = 10;
+/// Limits for invitations issued by users without `user:create`
+pub const MAX_USER_INVITATION_USES: i32 = 5;
+pub const MAX_USER_INVITATION_DAYS: i64 = 30;
+pub const DEFAULT_INVITATION_DAYS: i64 = 7;
+
+// Creator and role names are joined in for the listings
+pub const INVITATION_COLUMNS: &str = "i.id, i.code, i.created_by, creator.username AS created_by_username, \
+    i.email, i.role_id, r.name AS role_name, i.max_uses, i.use_count, i.note, i.expires_at, i.revoked_at, i.created_at";
+
+pub const INVITATION_JOINS: &str = "FROM invitations i \
+    LEFT JOIN users creator ON creator.id = i.created_by \
+    LEFT JOIN roles r ON r.id = i.role_id";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, FromRow)]
+pub struct Invitation {
+    pub id: Uuid,
+    /// What the invitee enters, or follows as `/auth/register?invite=<code>`
+    pub code: String,
+    pub created_by: Option<Uuid>,
+    pub created_by_username: Option<String>,
+    /// Only this address may sign up with it
+    pub email: Option<String>,
+    /// Role given on sign-up; `None` means the default role
+    pub role_id: Option<i32>,
+    pub role_name: Option<String>,
+    /// `None` for unlimited
+    pub max_uses: Option<i32>,
+    pub use_count: i32,
+    pub note: Option<String>,
+    #[serde(with = "chrono::serde::ts_seconds_option", skip_serializing_if = "Option::is_none", default)]
+    pub expires_at: Option<DateTime<Utc>>,
+    #[serde(with = "chrono::serde::ts_seconds_option", skip_serializing_if = "Option::is_none", default)]
+    pub revoked_at: Option<DateTime<Utc>>,
+    #[serde(with = "chrono::serde::ts_seconds")]
+    pub created_at: DateTime<Utc>,
+}
+
+impl Invitation {
+    pub fn generate_code() -> String {
+        TokenGenerator::generate_alphanumeric_token(INVITATION_CODE_LENGTH)
+    }
+
+    pub fn status(&self) -> InvitationStatus {
+        if self.revoked_at.is_some() {
+            InvitationStatus::Revoked
+        } else if self.expires_at.is_some_and(|expiry| expiry <= Utc::now()) {
+            InvitationStatus::Expired
+        } else if self.max_uses.is_some_and(|max| self.use_count >= max) {
+            InvitationStatus::UsedUp
+        } else {
+            InvitationStatus::Active
+        }
+    }
+
+    /// Whether `email` may sign up with this invitation
+    pub fn allows_email(&self, email: &str) -> bool {
+        self.email
+            .as_deref()
+            .is_none_or(|pinned| pinned.eq_ignore_ascii_case(email.trim()))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum InvitationStatus {
+    Active,
+    UsedUp,
+    Expired,
+    Revoked,
+}
+
+impl InvitationStatus {
+    pub fn label(&self) -> &'static str {
+        match self {
+            InvitationStatus::Active => "Active",
+            InvitationStatus::UsedUp => "Used",
+            InvitationStatus::Expired => "Expired",
+            InvitationStatus::Revoked => "Revoked",
+        }
+    }
+
+    /// Why sign-up was refused, for anything but `Active`
+    pub fn refusal(&self) -> &'static str {
+        match self {
+            InvitationStatus::Active => "",
+            InvitationStatus::UsedUp => "That invitation has already been used",
+            InvitationStatus::Expired => "That invitation has expired",
+            InvitationStatus::Revoked => "That invitation has been withdrawn",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, Validate)]
+pub struct CreateInvitationRequest {
+    #[validate(email(message = "Must be a valid email"))]
+    pub email: Option<String>,
+    /// Only honoured for users with `user:create`
+    pub role_id: Option<i32>,
+    /// `None` for unlimited, which also needs `user:create`
+    #[validate(range(min = 1, max = 1000))]
+    pub max_uses: Option<i32>,
+    /// `None` for the default of a week, or no expiry with `user:create`
+    #[validate(range(min = 1, max = 365))]
+    pub expires_in_days: Option<i64>,
+    #[validate(length(max = 200, message = "Keep the note under 200 characters"))]
+    pub note: Option<String>,
+}
+
+/// What the sign-up form shows for a code before the account is created
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct InvitationPreview {
+    pub invited_by: Option<String>,
+    pub email: Option<String>,
+}
+
+/// An account created with an invitation
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, FromRow)]
+pub struct InvitationRedemption {
+    pub user_id: Uuid,
+    pub username: String,
+    #[serde(with = "chrono::serde::ts_seconds")]
+    pub redeemed_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct InvitationPage {
+    pub invitations: Vec<Invitation>,
+    pub total: i64,
+    pub page: i64,
+    pub per_page: i64,
+}
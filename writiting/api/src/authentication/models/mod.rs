@@ -1,11 +1,33 @@
-mod api_key_model;
-mod audit_log_model;
-mod auth_model;
-mod email_verification_model;
-mod feature_flag_model;
-mod password_reset_model;
-mod rate_limit_model;
-mod secret_rotation_model;
-mod token_creation_model;
-mod token_generation_model;
-mod token_response_model;
\ No newline at end of file
+pub mod account_lockout_model;
+pub mod api_key_model;
+pub mod audit_log_model;
+pub mod auth_model;
+pub mod email_verification_model;
+pub mod feature_flag_model;
+pub mod invitation_model;
+pub mod password_reset_model;
+pub mod rate_limit_model;
+pub mod security_log_model;
+// Needs the `secrecy` crate, which isn't a dependency yet
+// pub mod secret_rotation_model;
+pub mod token_creation_model;
+pub mod token_generation_model;
+pub mod token_response_model;
+pub mod two_factor_model;
+
+pub use account_lockout_model::{AccountLockout, LockedUser, LockoutPolicy};
+pub use api_key_model::{ApiKey, ApiScope, CreateApiKeyRequest, CreatedApiKey};
+pub use audit_log_model::{audit_entities, AuditAction, AuditLog, AuditLogBuilder, AuditLogFilter, AuditLogPage};
+pub use auth_model::{AuthResponse, AuthToken, LoginOutcome, LoginPolicy, LoginRequest, RegisterRequest};
+pub use email_verification_model::EmailVerificationToken;
+pub use feature_flag_model::{FeatureFlag, FeatureFlagBuilder, FeatureFlagResponse, FeatureFlagUpdate, RolloutStrategy};
+pub use invitation_model::{
+    CreateInvitationRequest, Invitation, InvitationPage, InvitationPreview, InvitationRedemption, InvitationStatus,
+};
+pub use password_reset_model::PasswordResetToken;
+pub use rate_limit_model::{rate_limits, RateLimit, RateLimitHeaders, RateLimitKey, RateLimitResult, RateLimitRule};
+pub use security_log_model::{SecurityAction, SecurityLog, SecurityLogFilter, SecurityLogPage};
+pub use token_creation_model::{EmailVerificationRequest, PasswordResetRequest, TokenValidationRequest};
+pub use token_generation_model::TokenGenerator;
+pub use token_response_model::TokenResponse;
+pub use two_factor_model::{RecoveryCode, TotpEnrollment, TwoFactorChallenge, TwoFactorStatus, UserTotp};
@@ -2,6 +2,9 @@ use uuid::Uuid;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
+/// How long a password reset link stays valid
+pub const PASSWORD_RESET_TOKEN_MINUTES: i64 = 60;
+
 #[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
 pub struct PasswordResetToken {
     pub token: Uuid,
@@ -9,6 +12,11 @@ pub struct PasswordResetToken {
     #[serde(with = "chrono::serde::ts_seconds")]
     pub expires_at: DateTime<Utc>,
     pub used: bool,
+    /// Where the reset was requested from
+    pub ip_address: Option<String>,
+    pub user_agent: Option<String>,
+    #[serde(with = "chrono::serde::ts_seconds")]
+    pub created_at: DateTime<Utc>,
 }
 
 impl PasswordResetToken {
@@ -18,6 +26,9 @@ impl PasswordResetToken {
             user_id,
             expires_at,
             used: false,
+            ip_address: None,
+            user_agent: None,
+            created_at: Utc::now(),
         }
     }
 
@@ -28,4 +39,4 @@ impl PasswordResetToken {
     pub fn mark_as_used(&mut self) {
         self.used = true;
     }
-}
\ No newline at end of file
+}
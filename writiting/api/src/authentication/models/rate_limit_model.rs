@@ -8,8 +8,12 @@ use validator::Validate;
 pub struct RateLimit {
     #[validate(length(min = 1, max = 255))]
     pub bucket: String,
-    #[validate(range(min = 0))]
+    #[validate(range(min = 0.0))]
     pub tokens: f64,
+    #[validate(range(min = 0.0))]
+    pub capacity: f64,
+    /// Tokens added per second
+    pub refill_rate: f64,
     #[serde(with = "chrono::serde::ts_seconds")]
     pub last_refill: DateTime<Utc>,
     #[serde(with = "chrono::serde::ts_seconds")]
@@ -29,6 +33,8 @@ impl RateLimit {
         Self {
             bucket: format!("{}:{}", scope, identifier),
             tokens: capacity,
+            capacity,
+            refill_rate,
             last_refill: now,
             expires_at: now + expires_in,
         }
@@ -65,38 +71,20 @@ impl RateLimit {
     /// Refills tokens based on time elapsed
     fn refill_tokens(&mut self) {
         let now = Utc::now();
-        let elapsed = (now - self.last_refill).to_std().unwrap().as_secs_f64();
-        self.tokens = (self.tokens + elapsed * self.refill_rate()).min(self.capacity());
+        let elapsed = (now - self.last_refill).to_std().unwrap_or_default().as_secs_f64();
+        self.tokens = (self.tokens + elapsed * self.refill_rate).min(self.capacity);
         self.last_refill = now;
     }
 
     /// Calculates time until next refill to full capacity
     pub fn reset_in(&self) -> chrono::Duration {
-        let missing = self.capacity() - self.tokens;
-        chrono::Duration::seconds((missing / self.refill_rate()).ceil() as i64)
-    }
-
-    /// Extracts the refill rate from bucket name (tokens/second)
-    fn refill_rate(&self) -> f64 {
-        // Parse from bucket name format "scope:identifier:rate"
-        self.bucket.split(':')
-            .nth(2)
-            .and_then(|s| s.parse().ok())
-            .unwrap_or(1.0) // default 1 token/sec
-    }
-
-    /// Extracts the capacity from bucket name
-    fn capacity(&self) -> f64 {
-        // Parse from bucket name format "scope:identifier:rate:capacity"
-        self.bucket.split(':')
-            .nth(3)
-            .and_then(|s| s.parse().ok())
-            .unwrap_or(10.0) // default 10 tokens
+        let missing = self.capacity - self.tokens;
+        chrono::Duration::seconds((missing / self.refill_rate).ceil() as i64)
     }
 }
 
 /// Result of a rate limit check
-#[derive(Debug, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub enum RateLimitResult {
     Allowed {
         remaining: f64,
@@ -120,14 +108,75 @@ impl From<&RateLimit> for RateLimitHeaders {
         Self {
             remaining: rl.tokens.floor() as i64,
             reset: rl.reset_in().num_seconds(),
-            limit: rl.capacity().floor() as i64,
+            limit: rl.capacity.floor() as i64,
+        }
+    }
+}
+
+impl RateLimitHeaders {
+    /// `(name, value)` pairs for the `RateLimit-*` response headers (names lower-cased for `http`)
+    pub fn to_pairs(&self) -> [(&'static str, String); 3] {
+        [
+            ("ratelimit-limit", self.limit.to_string()),
+            ("ratelimit-remaining", self.remaining.max(0).to_string()),
+            ("ratelimit-reset", self.reset.max(0).to_string()),
+        ]
+    }
+}
+
+/// Who a bucket is charged to
+#[derive(Debug, Clone, PartialEq)]
+pub enum RateLimitKey {
+    Ip(IpAddr),
+    User(uuid::Uuid),
+    ApiKey(uuid::Uuid),
+}
+
+impl RateLimitKey {
+    pub fn identifier(&self) -> String {
+        match self {
+            RateLimitKey::Ip(ip) => format!("ip:{}", ip),
+            RateLimitKey::User(id) => format!("user:{}", id),
+            RateLimitKey::ApiKey(key) => format!("key:{}", key),
         }
     }
 }
 
+/// Bucket settings for one kind of request
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RateLimitRule {
+    pub scope: &'static str,
+    pub capacity: f64,
+    /// Tokens added per second
+    pub refill_rate: f64,
+}
+
+impl RateLimitRule {
+    pub fn bucket(&self, key: &RateLimitKey) -> String {
+        format!("{}:{}", self.scope, key.identifier())
+    }
+}
+
 /// Predefined rate limit configurations
 pub mod rate_limits {
-    pub const LOGIN: &str = "login:1:10"; // 1 token/sec, burst 10
-    pub const API: &str = "api:10:100";   // 10 tokens/sec, burst 100
-    pub const EMAIL: &str = "email:0.2:5"; // 1 email every 5 sec, burst 5
-}
\ No newline at end of file
+    use super::RateLimitRule;
+
+    /// Burst 10, then one attempt every 6 seconds
+    pub const LOGIN: RateLimitRule = RateLimitRule { scope: "login", capacity: 10.0, refill_rate: 1.0 / 6.0 };
+    /// Second login step; wrong codes also count toward the account lockout
+    pub const TWO_FACTOR: RateLimitRule = RateLimitRule { scope: "two_factor", capacity: 5.0, refill_rate: 1.0 / 12.0 };
+    pub const REGISTER: RateLimitRule = RateLimitRule { scope: "register", capacity: 5.0, refill_rate: 1.0 / 600.0 };
+    /// Covers reset and verification emails
+    pub const EMAIL: RateLimitRule = RateLimitRule { scope: "email", capacity: 5.0, refill_rate: 1.0 / 60.0 };
+    pub const AI_PROMPT: RateLimitRule = RateLimitRule { scope: "ai", capacity: 20.0, refill_rate: 1.0 / 15.0 };
+    pub const COMMENT: RateLimitRule = RateLimitRule { scope: "comment", capacity: 10.0, refill_rate: 1.0 / 10.0 };
+    pub const REPORT: RateLimitRule = RateLimitRule { scope: "report", capacity: 5.0, refill_rate: 1.0 / 60.0 };
+    pub const INVITE: RateLimitRule = RateLimitRule { scope: "invite", capacity: 10.0, refill_rate: 1.0 / 360.0 };
+    pub const INVITE_CHECK: RateLimitRule = RateLimitRule { scope: "invite_check", capacity: 10.0, refill_rate: 1.0 / 30.0 };
+    pub const UPLOAD: RateLimitRule = RateLimitRule { scope: "upload", capacity: 20.0, refill_rate: 1.0 / 30.0 };
+    /// Searches and the suggestions shown while typing
+    pub const SEARCH: RateLimitRule = RateLimitRule { scope: "search", capacity: 60.0, refill_rate: 1.0 };
+    /// The editor re-renders its preview as the writer types
+    pub const PREVIEW: RateLimitRule = RateLimitRule { scope: "preview", capacity: 60.0, refill_rate: 2.0 };
+    pub const API: RateLimitRule = RateLimitRule { scope: "api", capacity: 100.0, refill_rate: 10.0 };
+}
@@ -0,0 +1,169 @@
+use uuid::Uuid;
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use sqlx::FromRow;
+
+/// Mirrors the `security_action` enum in the database
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type)]
+#[sqlx(type_name = "security_action", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum SecurityAction {
+    LoginSuccess,
+    LoginFailed,
+    Logout,
+    PasswordChange,
+    PasswordResetRequest,
+    PasswordResetComplete,
+    EmailChange,
+    ApiKeyCreated,
+    ApiKeyRevoked,
+    SuspiciousActivity,
+    TwoFactorEnabled,
+    TwoFactorDisabled,
+    TwoFactorChallengePassed,
+    TwoFactorChallengeFailed,
+    RecoveryCodeUsed,
+    RecoveryCodesRegenerated,
+    AccountLocked,
+    AccountUnlocked,
+    ImpersonationStarted,
+    ImpersonationEnded,
+    AccountDeactivated,
+    AccountReactivated,
+}
+
+impl SecurityAction {
+    pub const ALL: [SecurityAction; 22] = [
+        SecurityAction::LoginSuccess,
+        SecurityAction::LoginFailed,
+        SecurityAction::Logout,
+        SecurityAction::PasswordChange,
+        SecurityAction::PasswordResetRequest,
+        SecurityAction::PasswordResetComplete,
+        SecurityAction::EmailChange,
+        SecurityAction::ApiKeyCreated,
+        SecurityAction::ApiKeyRevoked,
+        SecurityAction::SuspiciousActivity,
+        SecurityAction::TwoFactorEnabled,
+        SecurityAction::TwoFactorDisabled,
+        SecurityAction::TwoFactorChallengePassed,
+        SecurityAction::TwoFactorChallengeFailed,
+        SecurityAction::RecoveryCodeUsed,
+        SecurityAction::RecoveryCodesRegenerated,
+        SecurityAction::AccountLocked,
+        SecurityAction::AccountUnlocked,
+        SecurityAction::ImpersonationStarted,
+        SecurityAction::ImpersonationEnded,
+        SecurityAction::AccountDeactivated,
+        SecurityAction::AccountReactivated,
+    ];
+
+    /// Database name, e.g. `login_success`
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SecurityAction::LoginSuccess => "login_success",
+            SecurityAction::LoginFailed => "login_failed",
+            SecurityAction::Logout => "logout",
+            SecurityAction::PasswordChange => "password_change",
+            SecurityAction::PasswordResetRequest => "password_reset_request",
+            SecurityAction::PasswordResetComplete => "password_reset_complete",
+            SecurityAction::EmailChange => "email_change",
+            SecurityAction::ApiKeyCreated => "api_key_created",
+            SecurityAction::ApiKeyRevoked => "api_key_revoked",
+            SecurityAction::SuspiciousActivity => "suspicious_activity",
+            SecurityAction::TwoFactorEnabled => "two_factor_enabled",
+            SecurityAction::TwoFactorDisabled => "two_factor_disabled",
+            SecurityAction::TwoFactorChallengePassed => "two_factor_challenge_passed",
+            SecurityAction::TwoFactorChallengeFailed => "two_factor_challenge_failed",
+            SecurityAction::RecoveryCodeUsed => "recovery_code_used",
+            SecurityAction::RecoveryCodesRegenerated => "recovery_codes_regenerated",
+            SecurityAction::AccountLocked => "account_locked",
+            SecurityAction::AccountUnlocked => "account_unlocked",
+            SecurityAction::ImpersonationStarted => "impersonation_started",
+            SecurityAction::ImpersonationEnded => "impersonation_ended",
+            SecurityAction::AccountDeactivated => "account_deactivated",
+            SecurityAction::AccountReactivated => "account_reactivated",
+        }
+    }
+
+    /// Wording for the activity list shown to users
+    pub fn description(&self) -> &'static str {
+        match self {
+            SecurityAction::LoginSuccess => "Signed in",
+            SecurityAction::LoginFailed => "Failed sign-in attempt",
+            SecurityAction::Logout => "Signed out",
+            SecurityAction::PasswordChange => "Password changed",
+            SecurityAction::PasswordResetRequest => "Password reset requested",
+            SecurityAction::PasswordResetComplete => "Password reset",
+            SecurityAction::EmailChange => "Email address changed",
+            SecurityAction::ApiKeyCreated => "API key created",
+            SecurityAction::ApiKeyRevoked => "API key revoked",
+            SecurityAction::SuspiciousActivity => "Suspicious activity",
+            SecurityAction::TwoFactorEnabled => "Two-factor authentication enabled",
+            SecurityAction::TwoFactorDisabled => "Two-factor authentication disabled",
+            SecurityAction::TwoFactorChallengePassed => "Two-factor code accepted",
+            SecurityAction::TwoFactorChallengeFailed => "Wrong two-factor code",
+            SecurityAction::RecoveryCodeUsed => "Recovery code used",
+            SecurityAction::RecoveryCodesRegenerated => "Recovery codes regenerated",
+            SecurityAction::AccountLocked => "Account locked",
+            SecurityAction::AccountUnlocked => "Account unlocked",
+            SecurityAction::ImpersonationStarted => "Support staff signed in as you",
+            SecurityAction::ImpersonationEnded => "Support staff signed out",
+            SecurityAction::AccountDeactivated => "Account deactivated",
+            SecurityAction::AccountReactivated => "Account reactivated",
+        }
+    }
+
+    /// Events a user should look at twice if they don't recognise them
+    pub fn is_warning(&self) -> bool {
+        matches!(
+            self,
+            SecurityAction::LoginFailed
+                | SecurityAction::SuspiciousActivity
+                | SecurityAction::TwoFactorChallengeFailed
+                | SecurityAction::AccountLocked
+                | SecurityAction::ImpersonationStarted
+        )
+    }
+}
+
+// ip_address is INET in the database and text on the model
+pub const SECURITY_LOG_COLUMNS: &str = "s.id, s.user_id, u.username, s.action, host(s.ip_address) AS ip_address, \
+    s.user_agent, s.metadata, s.created_at";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, FromRow)]
+pub struct SecurityLog {
+    pub id: Uuid,
+    pub user_id: Option<Uuid>,
+    /// Joined in by listings that show other users' events
+    #[sqlx(default)]
+    pub username: Option<String>,
+    pub action: SecurityAction,
+    pub ip_address: String,
+    pub user_agent: Option<String>,
+    pub metadata: Option<Value>,
+    #[serde(with = "chrono::serde::ts_seconds")]
+    pub created_at: DateTime<Utc>,
+}
+
+/// Filters for the admin security log search; every field is optional
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SecurityLogFilter {
+    /// Matches the username or email, case-insensitively
+    pub user: Option<String>,
+    pub action: Option<SecurityAction>,
+    /// An address or CIDR block, e.g. `203.0.113.0/24`
+    pub ip: Option<String>,
+    pub from: Option<NaiveDate>,
+    pub to: Option<NaiveDate>,
+    pub page: i64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SecurityLogPage {
+    pub entries: Vec<SecurityLog>,
+    pub total: i64,
+    pub page: i64,
+    pub per_page: i64,
+}
@@ -1,11 +1,11 @@
-use rand::distributions::Alphanumeric;
-use rand::{thread_rng, Rng};
+use rand::distr::Alphanumeric;
+use rand::{rng, Rng};
 
 pub struct TokenGenerator;
 
 impl TokenGenerator {
     pub fn generate_alphanumeric_token(length: usize) -> String {
-        thread_rng()
+        rng()
             .sample_iter(&Alphanumeric)
             .take(length)
             .map(char::from)
@@ -13,9 +13,9 @@ impl TokenGenerator {
     }
 
     pub fn generate_hex_token(length: usize) -> String {
-        let mut rng = thread_rng();
+        let mut rng = rng();
         (0..length)
-            .map(|_| format!("{:x}", rng.gen::<u8>()))
+            .map(|_| format!("{:02x}", rng.random::<u8>()))
             .collect()
     }
-}
\ No newline at end of file
+}
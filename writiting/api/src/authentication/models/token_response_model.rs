@@ -1,3 +1,9 @@
+use uuid::Uuid;
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+use super::{EmailVerificationToken, PasswordResetToken};
+
 #[derive(Debug, Serialize)]
 pub struct TokenResponse {
     pub token: Uuid,
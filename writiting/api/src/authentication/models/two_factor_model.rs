@@ -0,0 +1,152 @@
+use uuid::Uuid;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use argon2::{Argon2, PasswordHash, PasswordHasher, PasswordVerifier, password_hash::SaltString};
+use argon2::password_hash::rand_core::OsRng;
+use totp_rs::{Algorithm, Secret, TOTP};
+
+use super::TokenGenerator;
+
+pub const TOTP_ISSUER: &str = "Writing Tool";
+const TOTP_DIGITS: usize = 6;
+const TOTP_STEP_SECONDS: u64 = 30;
+/// Codes from one step either side of now are accepted to allow for clock drift
+const TOTP_SKEW_STEPS: i64 = 1;
+pub const RECOVERY_CODE_COUNT: usize = 10;
+/// How long the second login step stays open
+pub const CHALLENGE_MINUTES: i64 = 5;
+pub const CHALLENGE_MAX_ATTEMPTS: i32 = 5;
+
+#[derive(Debug, Clone, FromRow)]
+pub struct UserTotp {
+    pub user_id: Uuid,
+    /// Base32 encoded shared secret
+    pub secret: String,
+    pub enabled_at: Option<DateTime<Utc>>,
+    pub last_used_step: Option<i64>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl UserTotp {
+    pub fn is_enabled(&self) -> bool {
+        self.enabled_at.is_some()
+    }
+
+    /// Checks `code` against the secret, in constant time, and returns the matching
+    /// time step. Steps at or before `last_used_step` are rejected so a code can't be replayed.
+    pub fn verify(&self, account: &str, code: &str) -> Option<i64> {
+        let totp = build_totp(&self.secret, account)?;
+        let code = code.trim().replace(' ', "");
+        let now = Utc::now().timestamp() / TOTP_STEP_SECONDS as i64;
+
+        (-TOTP_SKEW_STEPS..=TOTP_SKEW_STEPS)
+            .map(|offset| now + offset)
+            .filter(|step| self.last_used_step.is_none_or(|last| *step > last))
+            .find(|step| totp.check(&code, *step as u64 * TOTP_STEP_SECONDS))
+    }
+}
+
+/// Builds the RFC 6238 generator (SHA-1, 6 digits, 30 second step) used by authenticator apps
+pub fn build_totp(secret: &str, account: &str) -> Option<TOTP> {
+    let bytes = Secret::Encoded(secret.to_string()).to_bytes().ok()?;
+    TOTP::new(
+        Algorithm::SHA1,
+        TOTP_DIGITS,
+        0,
+        TOTP_STEP_SECONDS,
+        bytes,
+        Some(TOTP_ISSUER.to_string()),
+        account.to_string(),
+    )
+    .ok()
+}
+
+/// New random base32 secret
+pub fn generate_totp_secret() -> String {
+    Secret::generate_secret().to_encoded().to_string()
+}
+
+/// What the user needs to add the account to an authenticator app
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TotpEnrollment {
+    pub secret: String,
+    pub otpauth_uri: String,
+    /// PNG of the otpauth URI, base64 encoded
+    pub qr_code_png: String,
+}
+
+impl TotpEnrollment {
+    pub fn new(secret: &str, account: &str) -> Option<Self> {
+        let totp = build_totp(secret, account)?;
+        Some(Self {
+            secret: secret.to_string(),
+            otpauth_uri: totp.get_url(),
+            qr_code_png: totp.get_qr_base64().ok()?,
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TwoFactorStatus {
+    pub enabled: bool,
+    pub recovery_codes_remaining: i64,
+}
+
+#[derive(Debug, Clone, FromRow)]
+pub struct TwoFactorChallenge {
+    pub token: String,
+    pub user_id: Uuid,
+    pub attempts: i32,
+    pub expires_at: DateTime<Utc>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl TwoFactorChallenge {
+    pub fn is_valid(&self) -> bool {
+        Utc::now() < self.expires_at && self.attempts < CHALLENGE_MAX_ATTEMPTS
+    }
+}
+
+#[derive(Debug, Clone, FromRow)]
+pub struct RecoveryCode {
+    pub id: i32,
+    pub user_id: Uuid,
+    pub code_hash: String,
+    pub used_at: Option<DateTime<Utc>>,
+}
+
+impl RecoveryCode {
+    /// Generates a fresh set of codes formatted as `xxxxx-xxxxx`
+    pub fn generate_set() -> Vec<String> {
+        (0..RECOVERY_CODE_COUNT)
+            .map(|_| {
+                let raw = TokenGenerator::generate_hex_token(5);
+                format!("{}-{}", &raw[..5], &raw[5..])
+            })
+            .collect()
+    }
+
+    pub fn hash(code: &str) -> String {
+        let salt = SaltString::generate(&mut OsRng);
+        Argon2::default()
+            .hash_password(normalize_recovery_code(code).as_bytes(), &salt)
+            .unwrap()
+            .to_string()
+    }
+
+    pub fn matches(&self, code: &str) -> bool {
+        PasswordHash::new(&self.code_hash)
+            .map(|hash| {
+                Argon2::default()
+                    .verify_password(normalize_recovery_code(code).as_bytes(), &hash)
+                    .is_ok()
+            })
+            .unwrap_or(false)
+    }
+}
+
+fn normalize_recovery_code(code: &str) -> String {
+    code.trim().to_lowercase().replace(' ', "")
+}
@@ -0,0 +1,182 @@
+use chrono::{Duration, Utc};
+use dioxus::prelude::*;
+use serde_json::json;
+use sqlx::PgPool;
+use uuid::Uuid;
+use tracing::info;
+
+use crate::authentication::models::{PasswordResetToken, SecurityAction};
+#[cfg(feature = "server")]
+use crate::authentication::models::rate_limits;
+use crate::authentication::models::password_reset_model::PASSWORD_RESET_TOKEN_MINUTES;
+use crate::authentication::record_security_event;
+#[cfg(feature = "server")]
+use crate::db::get_db;
+use crate::mail::{get_mailer, queue_email, EmailTemplate, QueueError};
+#[cfg(feature = "server")]
+use crate::session::revoke_user_sessions;
+use crate::users::User;
+use crate::utils::RequestMeta;
+
+/// Minimum gap between two reset emails for the same account
+const RESET_COOLDOWN_SECONDS: i64 = 60;
+
+const RESET_TOKEN_COLUMNS: &str =
+    "token, user_id, expires_at, used, ip_address::text AS ip_address, user_agent, created_at";
+
+/// Issues a reset token and queues the email. Does nothing for unknown or inactive
+/// addresses, or while the previous request is still cooling down.
+pub async fn send_password_reset(db: &PgPool, email: &str, meta: &RequestMeta) -> Result<(), QueueError> {
+    let user = sqlx::query_as::<_, User>(
+        "SELECT * FROM users WHERE LOWER(email) = LOWER($1) AND is_active = true"
+    )
+    .bind(email.trim())
+    .fetch_optional(db)
+    .await?;
+
+    let Some(user) = user else {
+        return Ok(());
+    };
+
+    let recently_sent = sqlx::query_scalar::<_, bool>(
+        r#"
+        SELECT EXISTS(
+            SELECT 1 FROM password_reset_tokens
+            WHERE user_id = $1 AND created_at > NOW() - make_interval(secs => $2)
+        )
+        "#
+    )
+    .bind(user.id)
+    .bind(RESET_COOLDOWN_SECONDS as f64)
+    .fetch_one(db)
+    .await?;
+
+    if recently_sent {
+        return Ok(());
+    }
+
+    let mut tx = db.begin().await?;
+
+    // Only the newest link works
+    sqlx::query("UPDATE password_reset_tokens SET used = true WHERE user_id = $1 AND used = false")
+        .bind(user.id)
+        .execute(&mut *tx)
+        .await?;
+
+    let token = sqlx::query_as::<_, PasswordResetToken>(&format!(
+        r#"
+        INSERT INTO password_reset_tokens (user_id, expires_at, ip_address, user_agent)
+        VALUES ($1, $2, $3::inet, $4)
+        RETURNING {}
+        "#,
+        RESET_TOKEN_COLUMNS
+    ))
+    .bind(user.id)
+    .bind(Utc::now() + Duration::minutes(PASSWORD_RESET_TOKEN_MINUTES))
+    .bind(meta.ip_string())
+    .bind(&meta.user_agent)
+    .fetch_one(&mut *tx)
+    .await?;
+
+    let mailer = get_mailer().await?;
+    let template = EmailTemplate::PasswordReset {
+        username: user.username.clone(),
+        link: mailer.link(&format!("/auth/new-password?token={}", token.token)),
+        expires_in_minutes: PASSWORD_RESET_TOKEN_MINUTES,
+    };
+    queue_email(&mut *tx, Some(user.id), &user.email, &template).await?;
+
+    record_security_event(&mut *tx, Some(user.id), SecurityAction::PasswordResetRequest, meta, json!({})).await?;
+
+    tx.commit().await?;
+
+    info!("Password reset requested for user {}", user.id);
+    Ok(())
+}
+
+/// Emails a reset link if the address belongs to an account.
+/// The response is the same whether or not it does.
+#[server]
+pub async fn request_password_reset(email: String) -> Result<(), ServerFnError> {
+    crate::rate_limit!(rate_limits::EMAIL);
+    let db = get_db().await;
+    let meta = crate::utils::request_meta().await;
+
+    if let Err(e) = send_password_reset(db, &email, &meta).await {
+        // Failing loudly here would tell the caller the account exists
+        tracing::error!("Failed to send password reset: {}", e);
+    }
+
+    Ok(())
+}
+
+/// Sets a new password from a reset link and signs the account out everywhere
+#[server]
+pub async fn complete_password_reset(token: Uuid, new_password: String) -> Result<(), ServerFnError> {
+    let db = get_db().await;
+    crate::log::require_writable().await?;
+    let meta = crate::utils::request_meta().await;
+    let db_error = |e: sqlx::Error| -> ServerFnError {
+        tracing::error!("Database error completing password reset: {}", e);
+        ServerFnError::ServerError("Failed to reset password".into())
+    };
+
+    if new_password.len() < 8 {
+        return Err(ServerFnError::Request("Password must be at least 8 characters".into()));
+    }
+
+    let mut tx = db.begin().await.map_err(db_error)?;
+
+    let mut reset = sqlx::query_as::<_, PasswordResetToken>(&format!(
+        "SELECT {} FROM password_reset_tokens WHERE token = $1 FOR UPDATE",
+        RESET_TOKEN_COLUMNS
+    ))
+    .bind(token)
+    .fetch_optional(&mut *tx)
+    .await
+    .map_err(db_error)?
+    .filter(|reset| reset.is_valid())
+    .ok_or_else(|| -> ServerFnError { ServerFnError::Request("This reset link is invalid or has expired".into()) })?;
+
+    let mut user = sqlx::query_as::<_, User>("SELECT * FROM users WHERE id = $1 AND is_active = true")
+        .bind(reset.user_id)
+        .fetch_optional(&mut *tx)
+        .await
+        .map_err(db_error)?
+        .ok_or_else(|| -> ServerFnError { ServerFnError::Request("This reset link is invalid or has expired".into()) })?;
+
+    user.set_password(&new_password);
+
+    sqlx::query("UPDATE users SET password_hash = $2, salt = $3, updated_at = NOW() WHERE id = $1")
+        .bind(user.id)
+        .bind(&user.password_hash)
+        .bind(&user.salt)
+        .execute(&mut *tx)
+        .await
+        .map_err(db_error)?;
+
+    reset.mark_as_used();
+    sqlx::query("UPDATE password_reset_tokens SET used = $2 WHERE token = $1")
+        .bind(reset.token)
+        .bind(reset.used)
+        .execute(&mut *tx)
+        .await
+        .map_err(db_error)?;
+
+    let revoked = revoke_user_sessions(&mut *tx, user.id, None).await.map_err(db_error)?;
+
+    record_security_event(
+        &mut *tx,
+        Some(user.id),
+        SecurityAction::PasswordResetComplete,
+        &meta,
+        json!({ "sessions_revoked": revoked }),
+    )
+    .await
+    .map_err(db_error)?;
+
+    tx.commit().await.map_err(db_error)?;
+
+    info!("Password reset completed for user {}", user.id);
+    Ok(())
+}
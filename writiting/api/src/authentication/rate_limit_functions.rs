@@ -0,0 +1,158 @@
+#[cfg(feature = "server")]
+use std::net::{IpAddr, Ipv4Addr};
+use sqlx::{FromRow, PgPool};
+
+use crate::authentication::models::{RateLimit, RateLimitHeaders, RateLimitKey, RateLimitResult, RateLimitRule};
+
+#[derive(FromRow)]
+struct CheckedBucket {
+    #[sqlx(flatten)]
+    limit: RateLimit,
+    allowed: bool,
+}
+
+/// Takes `cost` tokens from the bucket for `key` if it has them.
+/// The refill and the decrement happen in one locked UPDATE so concurrent
+/// requests can't both spend the last token.
+pub async fn check_rate_limit(
+    db: &PgPool,
+    rule: &RateLimitRule,
+    key: &RateLimitKey,
+    cost: f64,
+) -> Result<(RateLimitResult, RateLimitHeaders), sqlx::Error> {
+    let bucket = rule.bucket(key);
+
+    // Creates the bucket full; keeps its settings in step with the rule
+    sqlx::query(
+        r#"
+        INSERT INTO rate_limits (bucket, tokens, capacity, refill_rate, last_refill, expires_at)
+        VALUES ($1, $2, $2, $3, NOW(), NOW())
+        ON CONFLICT (bucket) DO UPDATE
+        SET capacity = EXCLUDED.capacity, refill_rate = EXCLUDED.refill_rate
+        "#
+    )
+    .bind(&bucket)
+    .bind(rule.capacity)
+    .bind(rule.refill_rate)
+    .execute(db)
+    .await?;
+
+    let CheckedBucket { limit, allowed } = sqlx::query_as::<_, CheckedBucket>(
+        r#"
+        UPDATE rate_limits rl
+        SET tokens = CASE WHEN r.available >= $2 THEN r.available - $2 ELSE r.available END,
+            last_refill = NOW(),
+            expires_at = NOW() + make_interval(secs => rl.capacity / rl.refill_rate)
+        FROM (
+            SELECT bucket,
+                   LEAST(capacity, tokens + EXTRACT(EPOCH FROM NOW() - last_refill) * refill_rate) AS available
+            FROM rate_limits
+            WHERE bucket = $1
+            FOR UPDATE
+        ) r
+        WHERE rl.bucket = r.bucket
+        RETURNING rl.bucket, rl.tokens, rl.capacity, rl.refill_rate, rl.last_refill, rl.expires_at,
+                  r.available >= $2 AS allowed
+        "#
+    )
+    .bind(&bucket)
+    .bind(cost)
+    .fetch_one(db)
+    .await?;
+
+    let headers = RateLimitHeaders::from(&limit);
+    let result = if allowed {
+        RateLimitResult::Allowed {
+            remaining: limit.tokens,
+            reset_seconds: limit.reset_in().num_seconds(),
+        }
+    } else {
+        RateLimitResult::Denied {
+            retry_after_seconds: ((cost - limit.tokens) / limit.refill_rate).ceil().max(1.0) as i64,
+        }
+    };
+
+    Ok((result, headers))
+}
+
+/// Removes buckets that have refilled completely; they'd be recreated full anyway
+pub async fn sweep_expired_rate_limits(db: &PgPool) -> Result<u64, sqlx::Error> {
+    let result = sqlx::query("DELETE FROM rate_limits WHERE expires_at < NOW()")
+        .execute(db)
+        .await?;
+    Ok(result.rows_affected())
+}
+
+/// Signed-in user for the current request, otherwise the client address
+#[cfg(feature = "server")]
+pub async fn current_rate_limit_key() -> RateLimitKey {
+    if let Some(token) = crate::session::current_session_token().await {
+        if let Ok(Some(user)) = crate::session::find_session_user(crate::db::get_db().await, &token).await {
+            return RateLimitKey::User(user.id);
+        }
+    }
+
+    let ip = crate::utils::request_meta()
+        .await
+        .ip_address
+        .unwrap_or(IpAddr::V4(Ipv4Addr::UNSPECIFIED));
+    RateLimitKey::Ip(ip)
+}
+
+/// Charges the current caller against `rule`, setting `RateLimit-*` headers on the
+/// response, and `Retry-After` with a 429 status when the bucket is empty.
+#[cfg(feature = "server")]
+pub async fn enforce_rate_limit(rule: &RateLimitRule, cost: f64) -> Result<(), dioxus::prelude::ServerFnError> {
+    use axum::http::{HeaderName, HeaderValue, StatusCode};
+    use dioxus::prelude::{server_context, ServerFnError};
+
+    let key = current_rate_limit_key().await;
+    let (result, headers) = match check_rate_limit(crate::db::get_db().await, rule, &key, cost).await {
+        Ok(checked) => checked,
+        Err(e) => {
+            // Don't lock everyone out because the limiter itself is unavailable
+            tracing::error!("Rate limiter unavailable for {}: {}", rule.scope, e);
+            return Ok(());
+        }
+    };
+
+    let context = server_context();
+    let mut response = context.response_parts_mut();
+    for (name, value) in headers.to_pairs() {
+        if let Ok(value) = HeaderValue::from_str(&value) {
+            response.headers.insert(HeaderName::from_static(name), value);
+        }
+    }
+
+    match result {
+        RateLimitResult::Allowed { .. } => Ok(()),
+        RateLimitResult::Denied { retry_after_seconds } => {
+            response.status = StatusCode::TOO_MANY_REQUESTS;
+            response.headers.insert(
+                axum::http::header::RETRY_AFTER,
+                HeaderValue::from(retry_after_seconds),
+            );
+            tracing::warn!("Rate limit hit for {} by {}", rule.scope, key.identifier());
+            Err(ServerFnError::ServerError(format!(
+                "Too many requests. Try again in {} seconds",
+                retry_after_seconds
+            )))
+        }
+    }
+}
+
+/// Guards a server function with a token bucket from `rate_limits`.
+///
+/// ```ignore
+/// rate_limit!(rate_limits::LOGIN);
+/// rate_limit!(rate_limits::AI_PROMPT, 2);
+/// ```
+#[macro_export]
+macro_rules! rate_limit {
+    ($rule:expr) => {
+        $crate::rate_limit!($rule, 1)
+    };
+    ($rule:expr, $cost:expr) => {
+        $crate::authentication::rate_limit_functions::enforce_rate_limit(&$rule, $cost as f64).await?
+    };
+}
@@ -0,0 +1,196 @@
+use dioxus::prelude::*;
+use serde_json::{json, Value};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::authentication::models::{SecurityAction, SecurityLog, SecurityLogFilter, SecurityLogPage};
+#[cfg(feature = "server")]
+use crate::authentication::models::security_log_model::SECURITY_LOG_COLUMNS;
+#[cfg(feature = "server")]
+use crate::db::get_db;
+use crate::features::{create_notification, NotificationCreate};
+use crate::utils::RequestMeta;
+
+#[cfg(feature = "server")]
+const RECENT_ACTIVITY_LIMIT: i64 = 20;
+#[cfg(feature = "server")]
+const SECURITY_LOG_PAGE_SIZE: i64 = 50;
+
+/// Appends an entry to `security_logs`.
+/// Requests without a known client address are logged as `0.0.0.0`.
+pub async fn record_security_event<'e, E>(
+    db: E,
+    user_id: Option<Uuid>,
+    action: SecurityAction,
+    meta: &RequestMeta,
+    metadata: Value,
+) -> Result<(), sqlx::Error>
+where
+    E: sqlx::PgExecutor<'e>,
+{
+    sqlx::query(
+        r#"
+        INSERT INTO security_logs (user_id, action, ip_address, user_agent, metadata)
+        VALUES ($1, $2, COALESCE($3::inet, '0.0.0.0'::inet), $4, $5)
+        "#
+    )
+    .bind(user_id)
+    .bind(action)
+    .bind(meta.ip_string())
+    .bind(&meta.user_agent)
+    .bind(metadata)
+    .execute(db)
+    .await?;
+
+    Ok(())
+}
+
+/// How a sign-in compares with the account's earlier successful ones
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LoginNovelty {
+    pub first_login: bool,
+    pub new_ip: bool,
+    pub new_device: bool,
+}
+
+impl LoginNovelty {
+    /// Worth telling the user about: something changed on an account that has signed in before
+    pub fn should_notify(&self) -> bool {
+        !self.first_login && (self.new_ip || self.new_device)
+    }
+}
+
+/// Checks a sign-in against earlier `login_success` events. The user agent
+/// string stands in for the device.
+pub async fn login_novelty(db: &PgPool, user_id: Uuid, meta: &RequestMeta) -> Result<LoginNovelty, sqlx::Error> {
+    let (previous, same_ip, same_device) = sqlx::query_as::<_, (i64, bool, bool)>(
+        r#"
+        SELECT COUNT(*),
+               COALESCE(bool_or(ip_address = $2::inet), false),
+               COALESCE(bool_or(user_agent IS NOT DISTINCT FROM $3), false)
+        FROM security_logs
+        WHERE user_id = $1 AND action = 'login_success'
+        "#
+    )
+    .bind(user_id)
+    .bind(meta.ip_string())
+    .bind(&meta.user_agent)
+    .fetch_one(db)
+    .await?;
+
+    Ok(LoginNovelty {
+        first_login: previous == 0,
+        new_ip: meta.ip_address.is_some() && !same_ip,
+        new_device: !same_device,
+    })
+}
+
+/// Leaves a `new_login` notification describing where the sign-in came from
+pub async fn notify_new_login(
+    db: &PgPool,
+    user_id: Uuid,
+    meta: &RequestMeta,
+    novelty: &LoginNovelty,
+) -> Result<(), sqlx::Error> {
+    create_notification(
+        db,
+        &NotificationCreate {
+            user_id,
+            notification_type: "new_login".to_string(),
+            content: json!({
+                "ip_address": meta.ip_string(),
+                "user_agent": meta.user_agent,
+                "new_ip": novelty.new_ip,
+                "new_device": novelty.new_device,
+            }),
+        },
+    )
+    .await?;
+
+    Ok(())
+}
+
+/// The signed-in user's newest security events
+#[server]
+pub async fn get_recent_security_activity() -> Result<Vec<SecurityLog>, ServerFnError> {
+    let db = get_db().await;
+    let user = crate::session::current_user().await?;
+
+    sqlx::query_as::<_, SecurityLog>(&format!(
+        r#"
+        SELECT {} FROM security_logs s
+        LEFT JOIN users u ON u.id = s.user_id
+        WHERE s.user_id = $1
+        ORDER BY s.created_at DESC
+        LIMIT $2
+        "#,
+        SECURITY_LOG_COLUMNS
+    ))
+    .bind(user.id)
+    .bind(RECENT_ACTIVITY_LIMIT)
+    .fetch_all(db)
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error loading security activity: {}", e);
+        ServerFnError::ServerError("Failed to load security activity".into())
+    })
+}
+
+/// Site-wide search over `security_logs` for admins
+#[server]
+pub async fn search_security_logs(filter: SecurityLogFilter) -> Result<SecurityLogPage, ServerFnError> {
+    let db = get_db().await;
+    crate::session::require_permission("system:audit").await?;
+    let db_error = |e: sqlx::Error| -> ServerFnError {
+        tracing::error!("Database error searching security logs: {}", e);
+        ServerFnError::ServerError("Failed to search security log".into())
+    };
+
+    let trimmed = |v: &Option<String>| v.as_deref().map(str::trim).filter(|v| !v.is_empty()).map(str::to_string);
+    let user = trimmed(&filter.user);
+    // Normalised so a block like 10.0.0.1/24 reaches Postgres as 10.0.0.0/24
+    let ip = match trimmed(&filter.ip) {
+        Some(ip) => Some(crate::authentication::models::api_key_model::normalize_cidr(&ip).ok_or_else(
+            || -> ServerFnError { ServerFnError::Request(format!("'{}' is not an IP address or CIDR block", ip)) },
+        )?),
+        None => None,
+    };
+    let page = filter.page.max(1);
+
+    let where_clause = r#"
+        FROM security_logs s
+        LEFT JOIN users u ON u.id = s.user_id
+        WHERE ($1::text IS NULL OR lower(u.username) = lower($1) OR lower(u.email) = lower($1))
+          AND ($2::security_action IS NULL OR s.action = $2)
+          AND ($3::cidr IS NULL OR s.ip_address <<= $3::cidr)
+          AND ($4::date IS NULL OR s.created_at >= $4::date)
+          AND ($5::date IS NULL OR s.created_at < $5::date + 1)
+    "#;
+
+    let total = sqlx::query_scalar::<_, i64>(&format!("SELECT COUNT(*) {}", where_clause))
+        .bind(&user)
+        .bind(filter.action)
+        .bind(&ip)
+        .bind(filter.from)
+        .bind(filter.to)
+        .fetch_one(db)
+        .await
+        .map_err(db_error)?;
+
+    let entries = sqlx::query_as::<_, SecurityLog>(&format!(
+        "SELECT {} {} ORDER BY s.created_at DESC LIMIT $6 OFFSET $7",
+        SECURITY_LOG_COLUMNS, where_clause
+    ))
+    .bind(&user)
+    .bind(filter.action)
+    .bind(&ip)
+    .bind(filter.from)
+    .bind(filter.to)
+    .bind(SECURITY_LOG_PAGE_SIZE)
+    .bind((page - 1) * SECURITY_LOG_PAGE_SIZE)
+    .fetch_all(db)
+    .await
+    .map_err(db_error)?;
+
+    Ok(SecurityLogPage { entries, total, page, per_page: SECURITY_LOG_PAGE_SIZE })
+}
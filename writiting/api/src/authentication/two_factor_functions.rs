@@ -0,0 +1,420 @@
+use chrono::{Duration, Utc};
+#[cfg(feature = "server")]
+use chrono::DateTime;
+use dioxus::prelude::*;
+#[cfg(feature = "server")]
+use serde_json::json;
+use sqlx::PgConnection;
+use uuid::Uuid;
+#[cfg(feature = "server")]
+use tracing::info;
+
+use crate::authentication::models::{
+    AuthResponse, RecoveryCode, SecurityAction, TokenGenerator, TotpEnrollment, TwoFactorStatus, UserTotp,
+};
+#[cfg(feature = "server")]
+use crate::authentication::models::{rate_limits, LockoutPolicy, TwoFactorChallenge};
+use crate::authentication::models::two_factor_model::CHALLENGE_MINUTES;
+#[cfg(feature = "server")]
+use crate::authentication::models::two_factor_model::generate_totp_secret;
+#[cfg(feature = "server")]
+use crate::authentication::record_security_event;
+#[cfg(feature = "server")]
+use crate::db::get_db;
+use crate::users::User;
+
+const CHALLENGE_TOKEN_LENGTH: usize = 48;
+
+pub async fn find_user_totp<'e, E>(db: E, user_id: Uuid) -> Result<Option<UserTotp>, sqlx::Error>
+where
+    E: sqlx::PgExecutor<'e>,
+{
+    sqlx::query_as::<_, UserTotp>("SELECT * FROM user_totp WHERE user_id = $1")
+        .bind(user_id)
+        .fetch_optional(db)
+        .await
+}
+
+/// `find_user_totp` that holds the row until the transaction ends, so two
+/// requests can't both accept the same code before `last_used_step` moves on
+pub async fn lock_user_totp(db: &mut PgConnection, user_id: Uuid) -> Result<Option<UserTotp>, sqlx::Error> {
+    sqlx::query_as::<_, UserTotp>("SELECT * FROM user_totp WHERE user_id = $1 FOR UPDATE")
+        .bind(user_id)
+        .fetch_optional(db)
+        .await
+}
+
+pub async fn two_factor_enabled<'e, E>(db: E, user_id: Uuid) -> Result<bool, sqlx::Error>
+where
+    E: sqlx::PgExecutor<'e>,
+{
+    sqlx::query_scalar::<_, bool>(
+        "SELECT EXISTS(SELECT 1 FROM user_totp WHERE user_id = $1 AND enabled_at IS NOT NULL)"
+    )
+    .bind(user_id)
+    .fetch_one(db)
+    .await
+}
+
+/// Opens the second login step for a user whose password was accepted
+pub async fn create_two_factor_challenge<'e, E>(db: E, user_id: Uuid) -> Result<String, sqlx::Error>
+where
+    E: sqlx::PgExecutor<'e>,
+{
+    sqlx::query_scalar::<_, String>(
+        r#"
+        INSERT INTO two_factor_challenges (token, user_id, expires_at)
+        VALUES ($1, $2, $3)
+        RETURNING token
+        "#
+    )
+    .bind(TokenGenerator::generate_alphanumeric_token(CHALLENGE_TOKEN_LENGTH))
+    .bind(user_id)
+    .bind(Utc::now() + Duration::minutes(CHALLENGE_MINUTES))
+    .fetch_one(db)
+    .await
+}
+
+/// Replaces all recovery codes and returns the new plaintext set
+pub async fn replace_recovery_codes(db: &mut PgConnection, user_id: Uuid) -> Result<Vec<String>, sqlx::Error> {
+    let codes = RecoveryCode::generate_set();
+
+    sqlx::query("DELETE FROM totp_recovery_codes WHERE user_id = $1")
+        .bind(user_id)
+        .execute(&mut *db)
+        .await?;
+
+    for code in &codes {
+        sqlx::query("INSERT INTO totp_recovery_codes (user_id, code_hash) VALUES ($1, $2)")
+            .bind(user_id)
+            .bind(RecoveryCode::hash(code))
+            .execute(&mut *db)
+            .await?;
+    }
+
+    Ok(codes)
+}
+
+/// Accepts a TOTP code, or failing that an unused recovery code, consuming whichever matched.
+/// Returns the security action to record, or `None` if nothing matched.
+pub async fn consume_second_factor(
+    db: &mut PgConnection,
+    user: &User,
+    code: &str,
+) -> Result<Option<SecurityAction>, sqlx::Error> {
+    let Some(totp) = lock_user_totp(&mut *db, user.id).await?.filter(|t| t.is_enabled()) else {
+        return Ok(None);
+    };
+
+    if let Some(step) = totp.verify(&user.email, code) {
+        sqlx::query("UPDATE user_totp SET last_used_step = $2, updated_at = NOW() WHERE user_id = $1")
+            .bind(user.id)
+            .bind(step)
+            .execute(&mut *db)
+            .await?;
+        return Ok(Some(SecurityAction::TwoFactorChallengePassed));
+    }
+
+    let recovery_codes = sqlx::query_as::<_, RecoveryCode>(
+        r#"
+        SELECT id, user_id, code_hash, used_at FROM totp_recovery_codes
+        WHERE user_id = $1 AND used_at IS NULL
+        FOR UPDATE
+        "#
+    )
+    .bind(user.id)
+    .fetch_all(&mut *db)
+    .await?;
+
+    if let Some(matched) = recovery_codes.iter().find(|c| c.matches(code)) {
+        sqlx::query("UPDATE totp_recovery_codes SET used_at = NOW() WHERE id = $1")
+            .bind(matched.id)
+            .execute(&mut *db)
+            .await?;
+        return Ok(Some(SecurityAction::RecoveryCodeUsed));
+    }
+
+    Ok(None)
+}
+
+/// Sensitive changes ask for the password again even within a valid session
+#[cfg(feature = "server")]
+fn reauthenticate(user: &User, password: &str) -> Result<(), ServerFnError> {
+    if user.verify_password(password) {
+        Ok(())
+    } else {
+        Err(ServerFnError::Request("Incorrect password".into()))
+    }
+}
+
+/// Completes a login that returned `LoginOutcome::TwoFactorRequired`.
+/// Wrong codes count toward the same lockout as wrong passwords, so starting
+/// a new challenge doesn't buy more guesses.
+#[server]
+pub async fn verify_two_factor_login(challenge: String, code: String) -> Result<AuthResponse, ServerFnError> {
+    use crate::authentication::auth_functions::start_session;
+    use crate::authentication::lockout_functions::{active_lockout, ip_blocked_until, record_login_failure};
+
+    crate::rate_limit!(rate_limits::TWO_FACTOR);
+    let db = get_db().await;
+    let meta = crate::utils::request_meta().await;
+    let policy = LockoutPolicy::default();
+    let db_error = |e: sqlx::Error| -> ServerFnError {
+        tracing::error!("Database error verifying two-factor code: {}", e);
+        ServerFnError::ServerError("Failed to verify code".into())
+    };
+    let expired = || -> ServerFnError { ServerFnError::Request("Your sign-in attempt has expired. Please sign in again".into()) };
+    let locked = |locked_until: DateTime<Utc>| -> ServerFnError {
+        ServerFnError::Request(format!(
+            "Too many failed attempts. Try again after {}",
+            locked_until.format("%H:%M UTC")
+        ))
+    };
+
+    if let Some(locked_until) = ip_blocked_until(db, &meta, &policy).await.map_err(db_error)? {
+        return Err(locked(locked_until));
+    }
+
+    sqlx::query("DELETE FROM two_factor_challenges WHERE expires_at <= NOW()")
+        .execute(db)
+        .await
+        .map_err(db_error)?;
+
+    let mut tx = db.begin().await.map_err(db_error)?;
+
+    let pending = sqlx::query_as::<_, TwoFactorChallenge>(
+        "SELECT * FROM two_factor_challenges WHERE token = $1 FOR UPDATE"
+    )
+    .bind(&challenge)
+    .fetch_optional(&mut *tx)
+    .await
+    .map_err(db_error)?
+    .filter(|c| c.is_valid())
+    .ok_or_else(expired)?;
+
+    let user = sqlx::query_as::<_, User>("SELECT * FROM users WHERE id = $1 AND is_active = true")
+        .bind(pending.user_id)
+        .fetch_optional(&mut *tx)
+        .await
+        .map_err(db_error)?
+        .ok_or_else(expired)?;
+
+    if let Some(locked_until) = active_lockout(db, user.id).await.map_err(db_error)? {
+        return Err(locked(locked_until));
+    }
+
+    let Some(action) = consume_second_factor(&mut tx, &user, &code).await.map_err(db_error)? else {
+        sqlx::query("UPDATE two_factor_challenges SET attempts = attempts + 1 WHERE token = $1")
+            .bind(&pending.token)
+            .execute(&mut *tx)
+            .await
+            .map_err(db_error)?;
+        record_security_event(
+            &mut *tx,
+            Some(user.id),
+            SecurityAction::TwoFactorChallengeFailed,
+            &meta,
+            json!({ "attempt": pending.attempts + 1 }),
+        )
+        .await
+        .map_err(db_error)?;
+        tx.commit().await.map_err(db_error)?;
+
+        if let Some(locked_until) = record_login_failure(db, Some(user.id), &user.email, &meta, &policy)
+            .await
+            .map_err(db_error)?
+        {
+            // The lockout outlasts every open challenge, so drop them
+            sqlx::query("DELETE FROM two_factor_challenges WHERE user_id = $1")
+                .bind(user.id)
+                .execute(db)
+                .await
+                .map_err(db_error)?;
+            return Err(locked(locked_until));
+        }
+        return Err(ServerFnError::Request("Invalid authentication code".into()));
+    };
+
+    sqlx::query("DELETE FROM two_factor_challenges WHERE token = $1")
+        .bind(&pending.token)
+        .execute(&mut *tx)
+        .await
+        .map_err(db_error)?;
+    record_security_event(&mut *tx, Some(user.id), action, &meta, json!({}))
+        .await
+        .map_err(db_error)?;
+    tx.commit().await.map_err(db_error)?;
+
+    start_session(db, user).await
+}
+
+#[server]
+pub async fn get_two_factor_status() -> Result<TwoFactorStatus, ServerFnError> {
+    let db = get_db().await;
+    let user = crate::session::current_user().await?;
+
+    let (enabled, recovery_codes_remaining) = sqlx::query_as::<_, (bool, i64)>(
+        r#"
+        SELECT
+            EXISTS(SELECT 1 FROM user_totp WHERE user_id = $1 AND enabled_at IS NOT NULL),
+            (SELECT COUNT(*) FROM totp_recovery_codes WHERE user_id = $1 AND used_at IS NULL)
+        "#
+    )
+    .bind(user.id)
+    .fetch_one(db)
+    .await
+    .map_err(|e| -> ServerFnError {
+        tracing::error!("Database error fetching two-factor status: {}", e);
+        ServerFnError::ServerError("Failed to load two-factor status".into())
+    })?;
+
+    Ok(TwoFactorStatus { enabled, recovery_codes_remaining })
+}
+
+/// Generates a new secret for the signed-in user. Two-factor stays off until confirmed.
+#[server]
+pub async fn begin_two_factor_enrollment() -> Result<TotpEnrollment, ServerFnError> {
+    let db = get_db().await;
+    crate::log::require_writable().await?;
+    let user = crate::session::current_user().await?;
+    let db_error = |e: sqlx::Error| -> ServerFnError {
+        tracing::error!("Database error starting two-factor enrollment: {}", e);
+        ServerFnError::ServerError("Failed to start two-factor setup".into())
+    };
+
+    if two_factor_enabled(db, user.id).await.map_err(db_error)? {
+        return Err(ServerFnError::Request("Two-factor authentication is already enabled".into()));
+    }
+
+    let secret = generate_totp_secret();
+    sqlx::query(
+        r#"
+        INSERT INTO user_totp (user_id, secret)
+        VALUES ($1, $2)
+        ON CONFLICT (user_id) DO UPDATE
+        SET secret = EXCLUDED.secret, enabled_at = NULL, last_used_step = NULL, updated_at = NOW()
+        "#
+    )
+    .bind(user.id)
+    .bind(&secret)
+    .execute(db)
+    .await
+    .map_err(db_error)?;
+
+    TotpEnrollment::new(&secret, &user.email)
+        .ok_or_else(|| ServerFnError::ServerError("Failed to start two-factor setup".into()))
+}
+
+/// Turns two-factor on once the user proves their app produces valid codes.
+/// Returns the recovery codes, which are only ever shown this once.
+#[server]
+pub async fn confirm_two_factor_enrollment(code: String) -> Result<Vec<String>, ServerFnError> {
+    crate::rate_limit!(rate_limits::TWO_FACTOR);
+    let db = get_db().await;
+    crate::log::require_writable().await?;
+    let meta = crate::utils::request_meta().await;
+    let user = crate::session::current_user().await?;
+    let db_error = |e: sqlx::Error| -> ServerFnError {
+        tracing::error!("Database error confirming two-factor enrollment: {}", e);
+        ServerFnError::ServerError("Failed to enable two-factor authentication".into())
+    };
+
+    let mut tx = db.begin().await.map_err(db_error)?;
+
+    let pending = lock_user_totp(&mut tx, user.id)
+        .await
+        .map_err(db_error)?
+        .filter(|t| !t.is_enabled())
+        .ok_or_else(|| -> ServerFnError { ServerFnError::Request("Start two-factor setup first".into()) })?;
+
+    let step = pending
+        .verify(&user.email, &code)
+        .ok_or_else(|| -> ServerFnError { ServerFnError::Request("Invalid authentication code".into()) })?;
+
+    sqlx::query(
+        "UPDATE user_totp SET enabled_at = NOW(), last_used_step = $2, updated_at = NOW() WHERE user_id = $1"
+    )
+    .bind(user.id)
+    .bind(step)
+    .execute(&mut *tx)
+    .await
+    .map_err(db_error)?;
+
+    let codes = replace_recovery_codes(&mut tx, user.id).await.map_err(db_error)?;
+
+    record_security_event(&mut *tx, Some(user.id), SecurityAction::TwoFactorEnabled, &meta, json!({}))
+        .await
+        .map_err(db_error)?;
+
+    tx.commit().await.map_err(db_error)?;
+
+    info!("Two-factor authentication enabled for user {}", user.id);
+    Ok(codes)
+}
+
+#[server]
+pub async fn disable_two_factor(password: String) -> Result<(), ServerFnError> {
+    let db = get_db().await;
+    crate::log::require_writable().await?;
+    let meta = crate::utils::request_meta().await;
+    let user = crate::session::current_user().await?;
+    let db_error = |e: sqlx::Error| -> ServerFnError {
+        tracing::error!("Database error disabling two-factor: {}", e);
+        ServerFnError::ServerError("Failed to disable two-factor authentication".into())
+    };
+
+    reauthenticate(&user, &password)?;
+
+    let mut tx = db.begin().await.map_err(db_error)?;
+
+    sqlx::query("DELETE FROM user_totp WHERE user_id = $1")
+        .bind(user.id)
+        .execute(&mut *tx)
+        .await
+        .map_err(db_error)?;
+    sqlx::query("DELETE FROM totp_recovery_codes WHERE user_id = $1")
+        .bind(user.id)
+        .execute(&mut *tx)
+        .await
+        .map_err(db_error)?;
+
+    record_security_event(&mut *tx, Some(user.id), SecurityAction::TwoFactorDisabled, &meta, json!({}))
+        .await
+        .map_err(db_error)?;
+
+    tx.commit().await.map_err(db_error)?;
+
+    info!("Two-factor authentication disabled for user {}", user.id);
+    Ok(())
+}
+
+#[server]
+pub async fn regenerate_recovery_codes(password: String) -> Result<Vec<String>, ServerFnError> {
+    let db = get_db().await;
+    crate::log::require_writable().await?;
+    let meta = crate::utils::request_meta().await;
+    let user = crate::session::current_user().await?;
+    let db_error = |e: sqlx::Error| -> ServerFnError {
+        tracing::error!("Database error regenerating recovery codes: {}", e);
+        ServerFnError::ServerError("Failed to regenerate recovery codes".into())
+    };
+
+    reauthenticate(&user, &password)?;
+
+    let mut tx = db.begin().await.map_err(db_error)?;
+
+    if !two_factor_enabled(&mut *tx, user.id).await.map_err(db_error)? {
+        return Err(ServerFnError::Request("Two-factor authentication is not enabled".into()));
+    }
+
+    let codes = replace_recovery_codes(&mut tx, user.id).await.map_err(db_error)?;
+
+    record_security_event(&mut *tx, Some(user.id), SecurityAction::RecoveryCodesRegenerated, &meta, json!({}))
+        .await
+        .map_err(db_error)?;
+
+    tx.commit().await.map_err(db_error)?;
+
+    info!("Recovery codes regenerated for user {}", user.id);
+    Ok(codes)
+}
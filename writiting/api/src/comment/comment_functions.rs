@@ -0,0 +1,74 @@
+use dioxus::prelude::*;
+use crate::comment::Comment;
+#[cfg(feature = "server")]
+use crate::authentication::models::rate_limits;
+#[cfg(feature = "server")]
+use crate::mentions::{sync_mentions, MentionTarget};
+use crate::ServerFnError;
+#[cfg(feature = "server")]
+use crate::db::connection_pool::get_db;
+#[cfg(feature = "server")]
+use tracing::info;
+
+#[server]
+pub async fn create_comment(
+    post_id: i32,
+    parent_id: Option<i32>,
+    content: String,
+) -> Result<Comment, ServerFnError> {
+    crate::rate_limit!(rate_limits::COMMENT);
+    crate::log::require_writable().await?;
+    let db = get_db().await;
+    let user = crate::session::current_user().await?;
+
+    let content = content.trim().to_string();
+    if content.is_empty() || content.chars().count() > 1000 {
+        return Err(ServerFnError::Request("Comment must be 1-1000 characters".into()));
+    }
+
+    let comment = sqlx::query_as::<_, Comment>(
+        r#"
+        INSERT INTO post_comments (post_id, user_id, parent_id, content)
+        VALUES ($1, $2, $3, $4)
+        RETURNING *
+        "#
+    )
+    .bind(post_id)
+    .bind(user.id)
+    .bind(parent_id)
+    .bind(&content)
+    .fetch_one(db)
+    .await
+    .map_err(|e| -> ServerFnError {
+        tracing::error!("Database error creating comment: {}", e);
+        ServerFnError::ServerError("Failed to create comment".into())
+    })?;
+
+    // Mentions are best-effort: a failure here should not lose the comment
+    if let Err(e) = sync_mentions(db, MentionTarget::Comment(comment.id), Some(user.id), &comment.content).await {
+        tracing::error!("Failed to record mentions for comment {}: {}", comment.id, e);
+    }
+
+    info!("Created comment {} on post {}", comment.id, post_id);
+    Ok(comment)
+}
+
+#[server]
+pub async fn get_comments_for_post(post_id: i32) -> Result<Vec<Comment>, ServerFnError> {
+    let db = get_db().await;
+
+    sqlx::query_as::<_, Comment>(
+        r#"
+        SELECT * FROM post_comments
+        WHERE post_id = $1 AND is_deleted = false
+        ORDER BY created_at
+        "#
+    )
+    .bind(post_id)
+    .fetch_all(db)
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error fetching comments: {}", e);
+        ServerFnError::ServerError("Failed to fetch comments".into())
+    })
+}
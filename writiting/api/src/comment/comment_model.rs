@@ -1,8 +1,15 @@
+use uuid::Uuid;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+use crate::users::User;
+
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize, sqlx::FromRow, Validate)]
 pub struct Comment {
     pub id: i32,
     pub post_id: i32,
     pub user_id: Uuid,
+    pub parent_id: Option<i32>,
     
     #[validate(length(min = 1, max = 1000))]
     pub content: String,
@@ -14,5 +21,6 @@ pub struct Comment {
     pub updated_at: DateTime<Utc>,
     
     #[serde(skip_serializing)]
+    #[sqlx(skip)]
     pub user: Option<User>,
-}
\ No newline at end of file
+}
@@ -0,0 +1,5 @@
+pub mod comment_model;
+pub mod comment_functions;
+
+pub use comment_model::Comment;
+pub use comment_functions::{create_comment, get_comments_for_post};
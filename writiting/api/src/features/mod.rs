@@ -0,0 +1,9 @@
+pub mod notification_model;
+pub mod notification_functions;
+pub mod user_preferences_model;
+pub mod user_preferences_functions;
+
+pub use notification_model::{Notification, NotificationCreate};
+pub use notification_functions::*;
+pub use user_preferences_model::{Language, Theme, UpdatePreferences, UserPreferences};
+pub use user_preferences_functions::*;
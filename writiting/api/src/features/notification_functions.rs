@@ -0,0 +1,64 @@
+use dioxus::prelude::*;
+use crate::features::{Notification, NotificationCreate};
+use crate::ServerFnError;
+#[cfg(feature = "server")]
+use crate::db::connection_pool::get_db;
+use uuid::Uuid;
+
+/// Inserts a notification row. Used by other server functions, not exposed to clients.
+pub async fn create_notification<'e, E>(db: E, notification: &NotificationCreate) -> Result<Uuid, sqlx::Error>
+where
+    E: sqlx::PgExecutor<'e>,
+{
+    sqlx::query_scalar::<_, Uuid>(
+        r#"
+        INSERT INTO notifications (user_id, type, content)
+        VALUES ($1, $2::notification_type, $3)
+        RETURNING id
+        "#
+    )
+    .bind(notification.user_id)
+    .bind(&notification.notification_type)
+    .bind(&notification.content)
+    .fetch_one(db)
+    .await
+}
+
+#[server]
+pub async fn get_notifications(user_id: Uuid, unread_only: bool) -> Result<Vec<Notification>, ServerFnError> {
+    let db = get_db().await;
+
+    sqlx::query_as::<_, Notification>(
+        r#"
+        SELECT id, user_id, type::text AS type, content, read, created_at
+        FROM notifications
+        WHERE user_id = $1 AND ($2 = false OR read = false)
+        ORDER BY created_at DESC
+        LIMIT 100
+        "#
+    )
+    .bind(user_id)
+    .bind(unread_only)
+    .fetch_all(db)
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error fetching notifications: {}", e);
+        ServerFnError::ServerError("Failed to fetch notifications".into())
+    })
+}
+
+#[server]
+pub async fn mark_notification_read(id: Uuid) -> Result<(), ServerFnError> {
+    let db = get_db().await;
+
+    sqlx::query("UPDATE notifications SET read = true WHERE id = $1")
+        .bind(id)
+        .execute(db)
+        .await
+        .map_err(|e| -> ServerFnError {
+            tracing::error!("Database error updating notification: {}", e);
+            ServerFnError::ServerError("Failed to update notification".into())
+        })?;
+
+    Ok(())
+}
@@ -1,9 +1,13 @@
+use uuid::Uuid;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
 #[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
 pub struct Notification {
     pub id: Uuid,
     pub user_id: Uuid,
+    #[sqlx(rename = "type")]
     pub notification_type: String,
     pub content: Value, // JSONB content
     pub read: bool,
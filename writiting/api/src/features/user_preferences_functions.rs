@@ -0,0 +1,86 @@
+use dioxus::prelude::*;
+use crate::features::{UpdatePreferences, UserPreferences};
+use crate::features::user_preferences_model::PREFERENCES_COLUMNS;
+use crate::ServerFnError;
+#[cfg(feature = "server")]
+use crate::db::connection_pool::get_db;
+use sqlx::PgPool;
+use uuid::Uuid;
+#[cfg(feature = "server")]
+use crate::authentication::{audit_entities, audit_entry, record_audit, AuditAction};
+
+pub async fn find_preferences(db: &PgPool, user_id: Uuid) -> Result<Option<UserPreferences>, sqlx::Error> {
+    sqlx::query_as::<_, UserPreferences>(&format!(
+        "SELECT {} FROM user_preferences WHERE user_id = $1",
+        PREFERENCES_COLUMNS
+    ))
+    .bind(user_id)
+    .fetch_optional(db)
+    .await
+}
+
+/// The signed-in user's preferences, or the defaults if they never saved any
+#[server]
+pub async fn get_preferences() -> Result<UserPreferences, ServerFnError> {
+    let db = get_db().await;
+    let user = crate::session::current_user().await?;
+
+    let preferences = find_preferences(db, user.id).await.map_err(|e| -> ServerFnError {
+        tracing::error!("Database error fetching preferences: {}", e);
+        ServerFnError::ServerError("Failed to load preferences".into())
+    })?;
+
+    Ok(preferences.unwrap_or_else(|| UserPreferences::new(user.id)))
+}
+
+/// Saves the fields set in `update` and returns the full preferences.
+/// Named so its generated type doesn't clash with the `UpdatePreferences` DTO.
+#[server(SavePreferences)]
+pub async fn update_preferences(update: UpdatePreferences) -> Result<UserPreferences, ServerFnError> {
+    let db = get_db().await;
+    crate::log::require_writable().await?;
+    let user = crate::session::current_user().await?;
+
+    let db_error = |e: sqlx::Error| -> ServerFnError {
+        tracing::error!("Database error saving preferences: {}", e);
+        ServerFnError::ServerError("Failed to save preferences".into())
+    };
+
+    let before = find_preferences(db, user.id).await.map_err(db_error)?;
+    let wanted = before
+        .clone()
+        .unwrap_or_else(|| UserPreferences::new(user.id))
+        .apply(&update);
+
+    let saved = sqlx::query_as::<_, UserPreferences>(&format!(
+        r#"
+        INSERT INTO user_preferences (user_id, language, theme, email_notifications)
+        VALUES ($1, $2, $3, $4)
+        ON CONFLICT (user_id) DO UPDATE SET
+            language = EXCLUDED.language,
+            theme = EXCLUDED.theme,
+            email_notifications = EXCLUDED.email_notifications,
+            updated_at = NOW()
+        RETURNING {}
+        "#,
+        PREFERENCES_COLUMNS
+    ))
+    .bind(user.id)
+    .bind(wanted.language)
+    .bind(wanted.theme)
+    .bind(wanted.email_notifications)
+    .fetch_one(db)
+    .await
+    .map_err(db_error)?;
+
+    let action = if before.is_some() { AuditAction::Update } else { AuditAction::Create };
+    let mut entry = audit_entry(action, audit_entities::PREFERENCES, user.id)
+        .await
+        .after(&saved);
+    if let Some(before) = &before {
+        entry = entry.before(before);
+    }
+    record_audit(db, entry).await;
+
+    Ok(saved)
+}
@@ -4,22 +4,24 @@ use serde::{Serialize, Deserialize};
 use sqlx::FromRow;
 use validator::Validate;
 
-#[derive(Debug, Clone, Serialize, Deserialize, FromRow, Validate)]
+/// The enums are stored as checked VARCHARs, so they're read back as text
+pub const PREFERENCES_COLUMNS: &str =
+    "user_id, language::text AS language, theme::text AS theme, email_notifications, created_at, updated_at";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, FromRow, Validate)]
 pub struct UserPreferences {
     #[validate(required)]
     pub user_id: Option<Uuid>,
-    
-    #[validate(length(min = 2, max = 10))]
-    pub language: String,
-    
-    #[validate(length(min = 3, max = 20))]
-    pub theme: String,
-    
+
+    pub language: Language,
+
+    pub theme: Theme,
+
     pub email_notifications: bool,
-    
+
     #[serde(with = "chrono::serde::ts_seconds")]
     pub created_at: DateTime<Utc>,
-    
+
     #[serde(with = "chrono::serde::ts_seconds")]
     pub updated_at: DateTime<Utc>,
 }
@@ -27,14 +29,7 @@ pub struct UserPreferences {
 impl UserPreferences {
     /// Creates default preferences for a user
     pub fn new(user_id: Uuid) -> Self {
-        Self {
-            user_id: Some(user_id),
-            language: "en".to_string(),
-            theme: "light".to_string(),
-            email_notifications: true,
-            created_at: Utc::now(),
-            updated_at: Utc::now(),
-        }
+        Self::builder(user_id).build()
     }
 
     /// Builder pattern for preferences
@@ -42,26 +37,29 @@ impl UserPreferences {
         UserPreferencesBuilder::new(user_id)
     }
 
-    /// Checks if dark mode is enabled
+    /// Checks if dark mode is enabled. A `System` theme depends on the
+    /// device, so it only counts here once resolved with [`Theme::is_dark`].
     pub fn is_dark_mode(&self) -> bool {
-        self.theme.to_lowercase() == "dark"
+        self.theme == Theme::Dark
     }
 
-    /// Validates the language code format
-    pub fn validate_language(&self) -> Result<(), validator::ValidationError> {
-        // Basic ISO 639-1 validation
-        if self.language.len() != 2 || !self.language.chars().all(|c| c.is_ascii_lowercase()) {
-            return Err(validator::ValidationError::new("invalid_language_code"));
+    /// These preferences with `update` applied; fields it leaves out are kept
+    pub fn apply(&self, update: &UpdatePreferences) -> Self {
+        Self {
+            language: update.language.unwrap_or(self.language),
+            theme: update.theme.unwrap_or(self.theme),
+            email_notifications: update.email_notifications.unwrap_or(self.email_notifications),
+            updated_at: Utc::now(),
+            ..self.clone()
         }
-        Ok(())
     }
 }
 
 /// Builder for user preferences
 pub struct UserPreferencesBuilder {
     user_id: Uuid,
-    language: Option<String>,
-    theme: Option<String>,
+    language: Option<Language>,
+    theme: Option<Theme>,
     email_notifications: Option<bool>,
 }
 
@@ -75,13 +73,13 @@ impl UserPreferencesBuilder {
         }
     }
 
-    pub fn language(mut self, language: impl Into<String>) -> Self {
-        self.language = Some(language.into());
+    pub fn language(mut self, language: Language) -> Self {
+        self.language = Some(language);
         self
     }
 
-    pub fn theme(mut self, theme: impl Into<String>) -> Self {
-        self.theme = Some(theme.into());
+    pub fn theme(mut self, theme: Theme) -> Self {
+        self.theme = Some(theme);
         self
     }
 
@@ -93,8 +91,8 @@ impl UserPreferencesBuilder {
     pub fn build(self) -> UserPreferences {
         UserPreferences {
             user_id: Some(self.user_id),
-            language: self.language.unwrap_or_else(|| "en".to_string()),
-            theme: self.theme.unwrap_or_else(|| "light".to_string()),
+            language: self.language.unwrap_or_default(),
+            theme: self.theme.unwrap_or_default(),
             email_notifications: self.email_notifications.unwrap_or(true),
             created_at: Utc::now(),
             updated_at: Utc::now(),
@@ -102,43 +100,126 @@ impl UserPreferencesBuilder {
     }
 }
 
-/// Supported languages
-#[derive(Debug, Serialize, Deserialize)]
+/// Supported languages; mirrors the check on `user_preferences.language`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize, sqlx::Type)]
+#[sqlx(type_name = "text")]
 pub enum Language {
+    #[default]
     #[serde(rename = "en")]
+    #[sqlx(rename = "en")]
     English,
     #[serde(rename = "es")]
+    #[sqlx(rename = "es")]
     Spanish,
+    #[serde(rename = "fr")]
+    #[sqlx(rename = "fr")]
+    French,
+    #[serde(rename = "de")]
+    #[sqlx(rename = "de")]
+    German,
+    #[serde(rename = "ja")]
+    #[sqlx(rename = "ja")]
+    Japanese,
+    #[serde(rename = "zh")]
+    #[sqlx(rename = "zh")]
+    Chinese,
 }
 
-/// Supported themes
-#[derive(Debug, Serialize, Deserialize)]
+impl Language {
+    pub const ALL: [Language; 6] = [
+        Language::English,
+        Language::Spanish,
+        Language::French,
+        Language::German,
+        Language::Japanese,
+        Language::Chinese,
+    ];
+
+    /// ISO 639-1 code, as stored
+    pub fn code(&self) -> &'static str {
+        match self {
+            Language::English => "en",
+            Language::Spanish => "es",
+            Language::French => "fr",
+            Language::German => "de",
+            Language::Japanese => "ja",
+            Language::Chinese => "zh",
+        }
+    }
+
+    pub fn from_code(code: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|language| language.code() == code)
+    }
+
+    /// The language's name for itself, for pickers
+    pub fn native_name(&self) -> &'static str {
+        match self {
+            Language::English => "English",
+            Language::Spanish => "Español",
+            Language::French => "Français",
+            Language::German => "Deutsch",
+            Language::Japanese => "日本語",
+            Language::Chinese => "中文",
+        }
+    }
+}
+
+/// Supported themes; mirrors the check on `user_preferences.theme`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize, sqlx::Type)]
+#[sqlx(type_name = "text", rename_all = "lowercase")]
+#[serde(rename_all = "lowercase")]
 pub enum Theme {
-    #[serde(rename = "light")]
+    #[default]
     Light,
-    #[serde(rename = "dark")]
     Dark,
-    #[serde(rename = "system")]
+    /// Follows the device's light/dark setting
     System,
 }
 
+impl Theme {
+    pub const ALL: [Theme; 3] = [Theme::Light, Theme::Dark, Theme::System];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Theme::Light => "light",
+            Theme::Dark => "dark",
+            Theme::System => "system",
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Theme::Light => "Light",
+            Theme::Dark => "Dark",
+            Theme::System => "System",
+        }
+    }
+
+    /// Whether to draw dark, given whether the device currently prefers dark
+    pub fn is_dark(&self, system_prefers_dark: bool) -> bool {
+        match self {
+            Theme::Light => false,
+            Theme::Dark => true,
+            Theme::System => system_prefers_dark,
+        }
+    }
+}
+
 /// DTO for updating preferences
-#[derive(Debug, Deserialize, Validate)]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
 pub struct UpdatePreferences {
-    #[validate(length(min = 2, max = 10))]
-    pub language: Option<String>,
-    
-    #[validate(length(min = 3, max = 20))]
-    pub theme: Option<String>,
-    
+    pub language: Option<Language>,
+
+    pub theme: Option<Theme>,
+
     pub email_notifications: Option<bool>,
 }
 
 /// API response DTO
 #[derive(Debug, Serialize)]
 pub struct PreferencesResponse {
-    pub language: String,
-    pub theme: String,
+    pub language: Language,
+    pub theme: Theme,
     pub email_notifications: bool,
     pub updated_at: DateTime<Utc>,
 }
@@ -152,4 +233,4 @@ impl From<UserPreferences> for PreferencesResponse {
             updated_at: prefs.updated_at,
         }
     }
-}
\ No newline at end of file
+}
@@ -9,7 +9,7 @@ pub async fn echo(input: String) -> Result<String, ServerFnError> {
 
 
 mod ollama;
-pub use ollama::send_prompt_to_ollama;
+pub use ollama::{ask_ai, send_prompt_to_ollama};
 
 pub mod posts;
 pub use posts::{
@@ -20,7 +20,15 @@ pub use posts::{
 pub mod db;
 pub use db::{get_db, init_db};
 
-// pub mod authentication;
+pub mod mail;
+
+#[cfg(feature = "server")]
+pub mod server;
+
+#[cfg(feature = "server")]
+pub mod rest;
+
+pub mod authentication;
 
 
 pub mod users;
@@ -31,4 +39,18 @@ pub mod session;
 
 pub mod comment;
 
+pub mod features;
+
+pub mod log;
+
+pub mod mentions;
+
+pub mod moderation;
+
+pub mod settings;
+
+pub mod media;
+
+pub mod search;
+
 pub mod utils;
@@ -0,0 +1,53 @@
+#[cfg(feature = "server")]
+use chrono::{Duration, Utc};
+use dioxus::prelude::*;
+
+#[cfg(feature = "server")]
+use crate::db::get_db;
+use crate::log::DatabaseHealthOverview;
+#[cfg(feature = "server")]
+use crate::log::{DatabaseHealthMonitor, DatabaseHealthSample, SlowQuery, MAINTENANCE_PERMISSION};
+
+#[cfg(feature = "server")]
+const MAX_HISTORY_HOURS: i64 = 24 * 7;
+#[cfg(feature = "server")]
+const SLOW_QUERY_LIMIT: i64 = 20;
+
+/// Health samples from the last `hours` hours and the slowest queries seen in that time
+#[server]
+pub async fn get_database_health(hours: i64) -> Result<DatabaseHealthOverview, ServerFnError> {
+    let db = get_db().await;
+    crate::session::require_permission(MAINTENANCE_PERMISSION).await?;
+    let db_error = |e: sqlx::Error| -> ServerFnError {
+        tracing::error!("Database error loading database health: {}", e);
+        ServerFnError::ServerError("Failed to load database health".into())
+    };
+
+    let since = Utc::now() - Duration::hours(hours.clamp(1, MAX_HISTORY_HOURS));
+    let history = DatabaseHealthSample::since(db, since).await.map_err(db_error)?;
+    let slow_queries = SlowQuery::slowest_since(db, since, SLOW_QUERY_LIMIT)
+        .await
+        .map_err(db_error)?;
+
+    Ok(DatabaseHealthOverview {
+        latest: history.last().cloned(),
+        history,
+        slow_queries,
+    })
+}
+
+/// Runs a health check now rather than waiting for the monitor
+#[server]
+pub async fn run_database_health_check() -> Result<(), ServerFnError> {
+    let db = get_db().await;
+    crate::session::require_permission(MAINTENANCE_PERMISSION).await?;
+
+    DatabaseHealthMonitor::new(db.clone())
+        .check()
+        .await
+        .map(|_| ())
+        .map_err(|e| {
+            tracing::error!("Database health check failed: {}", e);
+            ServerFnError::ServerError("Health check failed".into())
+        })
+}
@@ -1,9 +1,10 @@
 use chrono::{DateTime, Utc};
 use serde::{Serialize, Deserialize};
-use sqlx::FromRow;
+use sqlx::{FromRow, PgPool};
 use std::fmt;
+use std::time::Instant;
 
-#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, FromRow)]
 pub struct DatabaseConnectionStats {
     pub id: i64,
     pub application_name: String,
@@ -15,19 +16,17 @@ pub struct DatabaseConnectionStats {
 
 impl DatabaseConnectionStats {
     /// Creates a new stats snapshot from current database state
-    pub async fn capture(pool: &sqlx::PgPool) -> Result<Vec<Self>, sqlx::Error> {
-        sqlx::query_as!(
-            Self,
+    pub async fn capture(pool: &PgPool) -> Result<Vec<Self>, sqlx::Error> {
+        sqlx::query_as::<_, Self>(
             r#"
-            INSERT INTO database_connections
-            SELECT 
-                nextval('database_connections_id_seq'),
-                application_name,
+            INSERT INTO database_connections (application_name, connection_count, max_connections)
+            SELECT
+                COALESCE(NULLIF(application_name, ''), 'unknown'),
                 count(*),
-                (SELECT setting::int FROM pg_settings WHERE name='max_connections'),
-                NOW()
-            FROM pg_stat_activity 
-            GROUP BY application_name
+                (SELECT setting::int FROM pg_settings WHERE name = 'max_connections')
+            FROM pg_stat_activity
+            WHERE backend_type = 'client backend'
+            GROUP BY 1
             RETURNING *
             "#
         )
@@ -68,43 +67,294 @@ impl fmt::Display for DatabaseConnectionStats {
     }
 }
 
+/// This server's connection pool, as sqlx sees it
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct PoolMetrics {
+    pub size: u32,
+    pub idle: u32,
+    pub max: u32,
+    /// How long it took to get a connection, i.e. the wait a request would see
+    pub acquire_ms: f64,
+    /// Round trip for `SELECT 1` on that connection
+    pub ping_ms: f64,
+}
+
+impl PoolMetrics {
+    pub async fn capture(pool: &PgPool) -> Result<Self, sqlx::Error> {
+        // Read before acquiring, which would otherwise count itself as active
+        let size = pool.size();
+        let idle = pool.num_idle() as u32;
+
+        let started = Instant::now();
+        let mut conn = pool.acquire().await?;
+        let acquire_ms = started.elapsed().as_secs_f64() * 1000.0;
+
+        let started = Instant::now();
+        sqlx::query("SELECT 1").execute(&mut *conn).await?;
+        let ping_ms = started.elapsed().as_secs_f64() * 1000.0;
+
+        Ok(Self {
+            size,
+            idle,
+            max: pool.options().get_max_connections(),
+            acquire_ms,
+            ping_ms,
+        })
+    }
+
+    pub fn active(&self) -> u32 {
+        self.size.saturating_sub(self.idle)
+    }
+
+    /// Share of the pool's maximum that is checked out
+    pub fn utilization_pct(&self) -> f64 {
+        if self.max > 0 {
+            (self.active() as f64 / self.max as f64) * 100.0
+        } else {
+            0.0
+        }
+    }
+}
+
+/// A statement whose executions averaged over the slow-query threshold, from
+/// `pg_stat_statements`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, FromRow)]
+pub struct SlowQuery {
+    pub queryid: i64,
+    pub calls: i64,
+    pub total_ms: f64,
+    pub query: String,
+    /// When a check last found it slow
+    #[serde(with = "chrono::serde::ts_seconds")]
+    pub created_at: DateTime<Utc>,
+}
+
+impl SlowQuery {
+    /// Statements on this database whose executions since the previous check
+    /// averaged over `threshold_ms`, and saves the running totals for the next
+    /// check. Empty when `pg_stat_statements` isn't installed or loaded.
+    pub async fn capture(pool: &PgPool, threshold_ms: u64) -> Result<Vec<Self>, sqlx::Error> {
+        let captured = sqlx::query_as::<_, Self>(
+            r#"
+            WITH current AS (
+                SELECT queryid, SUM(calls)::bigint AS calls, SUM(total_exec_time) AS total_ms, MIN(query) AS query
+                FROM pg_stat_statements
+                WHERE dbid = (SELECT oid FROM pg_database WHERE datname = current_database())
+                  AND queryid IS NOT NULL
+                GROUP BY queryid
+            ),
+            saved AS (
+                INSERT INTO database_statement_counters (queryid, calls, total_ms)
+                SELECT queryid, calls, total_ms FROM current
+                ON CONFLICT (queryid) DO UPDATE SET calls = EXCLUDED.calls, total_ms = EXCLUDED.total_ms
+            ),
+            since_last AS (
+                -- Totals that went down mean the statistics were reset, so count from zero
+                SELECT c.queryid,
+                       CASE WHEN p.calls IS NULL OR c.calls < p.calls THEN c.calls ELSE c.calls - p.calls END AS calls,
+                       CASE WHEN p.calls IS NULL OR c.calls < p.calls THEN c.total_ms ELSE c.total_ms - p.total_ms END AS total_ms,
+                       LEFT(c.query, 2000) AS query
+                FROM current c
+                LEFT JOIN database_statement_counters p ON p.queryid = c.queryid
+            )
+            SELECT queryid, calls, total_ms, query, NOW() AS created_at
+            FROM since_last
+            WHERE calls > 0 AND total_ms / calls > $1
+            ORDER BY total_ms / calls DESC
+            "#
+        )
+        .bind(threshold_ms as f64)
+        .fetch_all(pool)
+        .await;
+
+        match captured {
+            // 42P01: the view doesn't exist; 55000: the library isn't preloaded
+            Err(sqlx::Error::Database(e)) if matches!(e.code().as_deref(), Some("42P01" | "55000")) => {
+                tracing::debug!("Slow queries unavailable, pg_stat_statements isn't set up: {}", e);
+                Ok(Vec::new())
+            }
+            result => result,
+        }
+    }
+
+    /// Average time per execution
+    pub fn mean_ms(&self) -> f64 {
+        if self.calls > 0 { self.total_ms / self.calls as f64 } else { 0.0 }
+    }
+
+    pub async fn record(&self, pool: &PgPool) -> Result<(), sqlx::Error> {
+        sqlx::query(
+            r#"
+            INSERT INTO database_slow_queries (queryid, calls, total_ms, query, created_at)
+            VALUES ($1, $2, $3, $4, $5)
+            "#
+        )
+        .bind(self.queryid)
+        .bind(self.calls)
+        .bind(self.total_ms)
+        .bind(&self.query)
+        .bind(self.created_at)
+        .execute(pool)
+        .await?;
+        Ok(())
+    }
+
+    /// Statements found slow since `since`, with their executions in that time
+    /// added up, slowest on average first
+    pub async fn slowest_since(pool: &PgPool, since: DateTime<Utc>, limit: i64) -> Result<Vec<Self>, sqlx::Error> {
+        sqlx::query_as::<_, Self>(
+            r#"
+            SELECT queryid,
+                   SUM(calls)::bigint AS calls,
+                   SUM(total_ms) AS total_ms,
+                   MIN(query) AS query,
+                   MAX(created_at) AS created_at
+            FROM database_slow_queries
+            WHERE created_at > $1
+            GROUP BY queryid
+            ORDER BY SUM(total_ms) / SUM(calls) DESC
+            LIMIT $2
+            "#
+        )
+        .bind(since)
+        .bind(limit)
+        .fetch_all(pool)
+        .await
+    }
+}
+
+/// Mirrors the `database_health_level` enum in the database
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize, sqlx::Type)]
+#[sqlx(type_name = "database_health_level", rename_all = "lowercase")]
+#[serde(rename_all = "lowercase")]
+pub enum HealthLevel {
+    Normal,
+    Warning,
+    Critical,
+}
+
 /// Health status derived from connection stats
-#[derive(Debug, Serialize, PartialEq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum DatabaseHealthStatus {
     Normal,
     Warning(String),
     Critical(String),
 }
 
+impl DatabaseHealthStatus {
+    pub fn level(&self) -> HealthLevel {
+        match self {
+            DatabaseHealthStatus::Normal => HealthLevel::Normal,
+            DatabaseHealthStatus::Warning(_) => HealthLevel::Warning,
+            DatabaseHealthStatus::Critical(_) => HealthLevel::Critical,
+        }
+    }
+
+    pub fn message(&self) -> Option<&str> {
+        match self {
+            DatabaseHealthStatus::Normal => None,
+            DatabaseHealthStatus::Warning(msg) | DatabaseHealthStatus::Critical(msg) => Some(msg),
+        }
+    }
+}
+
+/// A stored health check, as shown in the admin dashboard history
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, FromRow)]
+pub struct DatabaseHealthSample {
+    pub id: i64,
+    pub level: HealthLevel,
+    pub message: Option<String>,
+    pub server_connections: i32,
+    pub max_connections: i32,
+    pub pool_size: i32,
+    pub pool_idle: i32,
+    pub pool_max: i32,
+    pub acquire_ms: f64,
+    pub ping_ms: f64,
+    pub slow_queries: i32,
+    #[serde(with = "chrono::serde::ts_seconds")]
+    pub created_at: DateTime<Utc>,
+}
+
+impl DatabaseHealthSample {
+    /// Samples taken since `since`, oldest first
+    pub async fn since(pool: &PgPool, since: DateTime<Utc>) -> Result<Vec<Self>, sqlx::Error> {
+        sqlx::query_as::<_, Self>(
+            "SELECT * FROM database_health_samples WHERE created_at > $1 ORDER BY created_at"
+        )
+        .bind(since)
+        .fetch_all(pool)
+        .await
+    }
+
+    /// Server-wide connection utilization percentage
+    pub fn utilization_pct(&self) -> f64 {
+        if self.max_connections > 0 {
+            (self.server_connections as f64 / self.max_connections as f64) * 100.0
+        } else {
+            0.0
+        }
+    }
+}
+
+/// Everything the admin health panel shows
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DatabaseHealthOverview {
+    pub latest: Option<DatabaseHealthSample>,
+    /// Oldest first, for the history graph
+    pub history: Vec<DatabaseHealthSample>,
+    pub slow_queries: Vec<SlowQuery>,
+}
+
 /// Analysis of database health
 pub struct DatabaseHealthReport {
     pub stats: Vec<DatabaseConnectionStats>,
+    pub pool: PoolMetrics,
+    pub slow_queries: Vec<SlowQuery>,
     pub status: DatabaseHealthStatus,
     pub timestamp: DateTime<Utc>,
 }
 
 impl DatabaseHealthReport {
-    pub async fn generate(pool: &sqlx::PgPool) -> Result<Self, sqlx::Error> {
+    pub async fn generate(pool: &PgPool, config: &DatabaseHealthConfig) -> Result<Self, sqlx::Error> {
+        let metrics = PoolMetrics::capture(pool).await?;
         let stats = DatabaseConnectionStats::capture(pool).await?;
-        let status = Self::analyze_health(&stats);
+        let slow_queries = SlowQuery::capture(pool, config.slow_query_ms).await?;
+        let status = Self::analyze_health(&stats, &metrics, &slow_queries, config);
         Ok(Self {
             stats,
+            pool: metrics,
+            slow_queries,
             status,
             timestamp: Utc::now(),
         })
     }
 
-    fn analyze_health(stats: &[DatabaseConnectionStats]) -> DatabaseHealthStatus {
+    /// Connections across every application on the server
+    pub fn server_connections(&self) -> i32 {
+        self.stats.iter().map(|s| s.connection_count).sum()
+    }
+
+    pub fn max_connections(&self) -> i32 {
+        self.stats.first().map_or(0, |s| s.max_connections)
+    }
+
+    fn analyze_health(
+        stats: &[DatabaseConnectionStats],
+        pool: &PoolMetrics,
+        slow_queries: &[SlowQuery],
+        config: &DatabaseHealthConfig,
+    ) -> DatabaseHealthStatus {
         let mut messages = Vec::new();
 
         for stat in stats {
-            if stat.is_over_critical(None) {
+            if stat.is_over_critical(Some(config.critical_threshold)) {
                 messages.push(format!(
                     "CRITICAL: {} at {:.1}% utilization",
                     stat.application_name,
                     stat.utilization_pct()
                 ));
-            } else if stat.is_over_warning(None) {
+            } else if stat.is_over_warning(Some(config.warning_threshold)) {
                 messages.push(format!(
                     "WARNING: {} at {:.1}% utilization",
                     stat.application_name,
@@ -113,6 +363,26 @@ impl DatabaseHealthReport {
             }
         }
 
+        if pool.utilization_pct() > config.critical_threshold {
+            messages.push(format!("CRITICAL: pool at {}/{} connections", pool.active(), pool.max));
+        } else if pool.utilization_pct() > config.warning_threshold {
+            messages.push(format!("WARNING: pool at {}/{} connections", pool.active(), pool.max));
+        }
+
+        if pool.acquire_ms > config.acquire_critical_ms {
+            messages.push(format!("CRITICAL: waited {:.0} ms for a connection", pool.acquire_ms));
+        } else if pool.acquire_ms > config.acquire_warning_ms {
+            messages.push(format!("WARNING: waited {:.0} ms for a connection", pool.acquire_ms));
+        }
+
+        if !slow_queries.is_empty() {
+            messages.push(format!(
+                "WARNING: {} statements averaging over {} ms",
+                slow_queries.len(),
+                config.slow_query_ms
+            ));
+        }
+
         match messages.len() {
             0 => DatabaseHealthStatus::Normal,
             _ if messages.iter().any(|m| m.contains("CRITICAL")) => {
@@ -121,6 +391,36 @@ impl DatabaseHealthReport {
             _ => DatabaseHealthStatus::Warning(messages.join("\n")),
         }
     }
+
+    /// Stores the report as a sample, along with any slow queries it found
+    pub async fn persist(&self, pool: &PgPool) -> Result<DatabaseHealthSample, sqlx::Error> {
+        for query in &self.slow_queries {
+            query.record(pool).await?;
+        }
+
+        sqlx::query_as::<_, DatabaseHealthSample>(
+            r#"
+            INSERT INTO database_health_samples
+                (level, message, server_connections, max_connections, pool_size, pool_idle, pool_max,
+                 acquire_ms, ping_ms, slow_queries, created_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11)
+            RETURNING *
+            "#
+        )
+        .bind(self.status.level())
+        .bind(self.status.message())
+        .bind(self.server_connections())
+        .bind(self.max_connections())
+        .bind(self.pool.size as i32)
+        .bind(self.pool.idle as i32)
+        .bind(self.pool.max as i32)
+        .bind(self.pool.acquire_ms)
+        .bind(self.pool.ping_ms)
+        .bind(self.slow_queries.len() as i32)
+        .bind(self.timestamp)
+        .fetch_one(pool)
+        .await
+    }
 }
 
 /// Configuration for connection monitoring
@@ -129,6 +429,12 @@ pub struct DatabaseHealthConfig {
     pub warning_threshold: f64,
     pub critical_threshold: f64,
     pub check_interval_seconds: u64,
+    pub acquire_warning_ms: f64,
+    pub acquire_critical_ms: f64,
+    /// Statements averaging longer than this between checks are recorded as slow
+    pub slow_query_ms: u64,
+    /// Samples and slow queries older than this are deleted
+    pub retention_days: i32,
 }
 
 impl Default for DatabaseHealthConfig {
@@ -137,18 +443,22 @@ impl Default for DatabaseHealthConfig {
             warning_threshold: 75.0,
             critical_threshold: 90.0,
             check_interval_seconds: 300, // 5 minutes
+            acquire_warning_ms: 250.0,
+            acquire_critical_ms: 1000.0,
+            slow_query_ms: 1000,
+            retention_days: 14,
         }
     }
 }
 
 /// Background monitor service
 pub struct DatabaseHealthMonitor {
-    pool: sqlx::PgPool,
+    pool: PgPool,
     config: DatabaseHealthConfig,
 }
 
 impl DatabaseHealthMonitor {
-    pub fn new(pool: sqlx::PgPool) -> Self {
+    pub fn new(pool: PgPool) -> Self {
         Self {
             pool,
             config: DatabaseHealthConfig::default(),
@@ -167,37 +477,68 @@ impl DatabaseHealthMonitor {
 
         loop {
             interval.tick().await;
-            
-            match DatabaseHealthReport::generate(&self.pool).await {
+
+            match self.check().await {
                 Ok(report) => self.handle_report(report).await,
-                Err(e) => log::error!("Failed to generate health report: {}", e),
+                Err(e) => tracing::error!("Failed to generate health report: {}", e),
+            }
+
+            if let Err(e) = prune_health_history(&self.pool, self.config.retention_days).await {
+                tracing::error!("Failed to prune database health history: {}", e);
             }
         }
     }
 
+    /// Runs one check and stores the result
+    pub async fn check(&self) -> Result<DatabaseHealthReport, sqlx::Error> {
+        let report = DatabaseHealthReport::generate(&self.pool, &self.config).await?;
+        report.persist(&self.pool).await?;
+        Ok(report)
+    }
+
     async fn handle_report(&self, report: DatabaseHealthReport) {
         match report.status {
             DatabaseHealthStatus::Critical(msg) => {
-                log::error!("Database health critical:\n{}", msg);
+                tracing::error!("Database health critical:\n{}", msg);
                 // TODO: Trigger alerts
             }
             DatabaseHealthStatus::Warning(msg) => {
-                log::warn!("Database health warning:\n{}", msg);
+                tracing::warn!("Database health warning:\n{}", msg);
             }
             DatabaseHealthStatus::Normal => {
-                log::debug!("Database health normal");
+                tracing::debug!("Database health normal");
             }
         }
 
         // Log all stats at appropriate level
         for stat in report.stats {
             if stat.is_over_critical(Some(self.config.critical_threshold)) {
-                log::error!("{}", stat);
+                tracing::error!("{}", stat);
             } else if stat.is_over_warning(Some(self.config.warning_threshold)) {
-                log::warn!("{}", stat);
+                tracing::warn!("{}", stat);
             } else {
-                log::debug!("{}", stat);
+                tracing::debug!("{}", stat);
             }
         }
+
+        for query in report.slow_queries {
+            tracing::warn!("Slow query ({} calls, {:.0} ms on average): {}", query.calls, query.mean_ms(), query.query);
+        }
+    }
+}
+
+/// Deletes health samples, connection stats and slow queries older than `retention_days`
+pub async fn prune_health_history(pool: &PgPool, retention_days: i32) -> Result<u64, sqlx::Error> {
+    let mut removed = 0;
+    for table in ["database_health_samples", "database_connections", "database_slow_queries"] {
+        removed += sqlx::query(&format!(
+            "DELETE FROM {} WHERE created_at < NOW() - make_interval(days => $1)",
+            table
+        ))
+        .bind(retention_days)
+        .execute(pool)
+        .await?
+        .rows_affected();
     }
-}
\ No newline at end of file
+    Ok(removed)
+}
@@ -0,0 +1,213 @@
+#[cfg(feature = "server")]
+use chrono::{Duration, Utc};
+use dioxus::prelude::*;
+use sqlx::PgPool;
+use uuid::Uuid;
+#[cfg(feature = "server")]
+use tracing::info;
+
+#[cfg(feature = "server")]
+use crate::db::get_db;
+#[cfg(feature = "server")]
+use crate::log::maintenance_window_model::MAINTENANCE_WINDOW_COLUMNS;
+use crate::log::{MaintenanceService, MaintenanceWindow, ScheduleMaintenanceRequest, SystemStatus};
+#[cfg(feature = "server")]
+use crate::authentication::{audit_entities, audit_entry, record_audit, AuditAction};
+
+/// Permission that lets a user keep working while maintenance is running
+pub const MAINTENANCE_PERMISSION: &str = "system:maintenance";
+
+// Leeway for "start now" windows whose start time passed while the form was open
+#[cfg(feature = "server")]
+const SCHEDULE_GRACE_SECONDS: i64 = 300;
+
+/// The running window, if there is one and `role_id` may not write through it
+pub async fn writes_blocked_for(db: &PgPool, role_id: Option<i32>) -> Result<Option<MaintenanceWindow>, sqlx::Error> {
+    let status = MaintenanceService::new(db.clone()).get_status().await?;
+    let Some(window) = status.current_window else {
+        return Ok(None);
+    };
+
+    if let Some(role_id) = role_id {
+        if crate::session::role_has_permission(db, role_id, MAINTENANCE_PERMISSION).await? {
+            return Ok(None);
+        }
+    }
+    Ok(Some(window))
+}
+
+/// Rejects the current request with a 503 while maintenance is running,
+/// unless the signed-in user is allowed to work through it. Reads don't call this.
+#[cfg(feature = "server")]
+pub async fn require_writable() -> Result<(), ServerFnError> {
+    use axum::http::{header::RETRY_AFTER, HeaderValue, StatusCode};
+
+    let role_id = crate::session::current_user().await.ok().map(|u| u.role_id);
+    let window = match writes_blocked_for(get_db().await, role_id).await {
+        Ok(Some(window)) => window,
+        Ok(None) => return Ok(()),
+        Err(e) => {
+            // If the database is down the write will fail on its own
+            tracing::error!("Failed to check maintenance status: {}", e);
+            return Ok(());
+        }
+    };
+
+    let context = server_context();
+    let mut response = context.response_parts_mut();
+    response.status = StatusCode::SERVICE_UNAVAILABLE;
+    response
+        .headers
+        .insert(RETRY_AFTER, HeaderValue::from(window.ends_in().num_seconds().max(1)));
+
+    Err(ServerFnError::ServerError(format!(
+        "The site is read-only for maintenance until {}",
+        window.ends_at.format("%H:%M UTC")
+    )))
+}
+
+/// Whether maintenance is running or coming up. Anyone may ask.
+#[server]
+pub async fn get_system_status() -> Result<SystemStatus, ServerFnError> {
+    let db = get_db().await;
+
+    MaintenanceService::new(db.clone())
+        .get_status()
+        .await
+        .map_err(|e| {
+            tracing::error!("Database error loading system status: {}", e);
+            ServerFnError::ServerError("Failed to load system status".into())
+        })
+}
+
+/// Running and upcoming windows, soonest first
+#[server]
+pub async fn list_maintenance_windows() -> Result<Vec<MaintenanceWindow>, ServerFnError> {
+    let db = get_db().await;
+    crate::session::require_permission(MAINTENANCE_PERMISSION).await?;
+
+    MaintenanceService::new(db.clone())
+        .upcoming()
+        .await
+        .map_err(|e| {
+            tracing::error!("Database error listing maintenance windows: {}", e);
+            ServerFnError::ServerError("Failed to load maintenance windows".into())
+        })
+}
+
+#[server]
+pub async fn schedule_maintenance_window(request: ScheduleMaintenanceRequest) -> Result<MaintenanceWindow, ServerFnError> {
+    let db = get_db().await;
+    let admin = crate::session::require_permission(MAINTENANCE_PERMISSION).await?;
+    let db_error = |e: sqlx::Error| -> ServerFnError {
+        tracing::error!("Database error scheduling maintenance: {}", e);
+        ServerFnError::ServerError("Failed to schedule maintenance".into())
+    };
+
+    if request.starts_at < Utc::now() - Duration::seconds(SCHEDULE_GRACE_SECONDS) {
+        return Err(ServerFnError::Request("Maintenance can't start in the past".into()));
+    }
+
+    let window = MaintenanceWindow::builder()
+        .starts_at(request.starts_at)
+        .ends_at(request.ends_at)
+        .description(request.description.trim())
+        .created_by(admin.id)
+        .build()
+        .map_err(|e| -> ServerFnError { ServerFnError::Request(e.to_string()) })?;
+
+    let mut tx = db.begin().await.map_err(db_error)?;
+
+    // Serialise scheduling so two admins can't book overlapping windows at once
+    sqlx::query("LOCK TABLE maintenance_windows IN SHARE ROW EXCLUSIVE MODE")
+        .execute(&mut *tx)
+        .await
+        .map_err(db_error)?;
+
+    let scheduled = sqlx::query_as::<_, MaintenanceWindow>(&format!(
+        "SELECT {} FROM maintenance_windows WHERE ends_at > NOW()",
+        MAINTENANCE_WINDOW_COLUMNS
+    ))
+    .fetch_all(&mut *tx)
+    .await
+    .map_err(db_error)?;
+
+    if let Some(clash) = scheduled.iter().find(|other| window.overlaps(other)) {
+        return Err(ServerFnError::Request(format!(
+            "That overlaps the window from {} to {}",
+            clash.starts_at.format("%Y-%m-%d %H:%M UTC"),
+            clash.ends_at.format("%Y-%m-%d %H:%M UTC")
+        )));
+    }
+
+    let window = sqlx::query_as::<_, MaintenanceWindow>(&format!(
+        r#"
+        INSERT INTO maintenance_windows (id, starts_at, ends_at, description, created_by)
+        VALUES ($1, $2, $3, $4, $5)
+        RETURNING {}
+        "#,
+        MAINTENANCE_WINDOW_COLUMNS
+    ))
+    .bind(window.id)
+    .bind(window.starts_at)
+    .bind(window.ends_at)
+    .bind(&window.description)
+    .bind(window.created_by)
+    .fetch_one(&mut *tx)
+    .await
+    .map_err(db_error)?;
+
+    record_audit(
+        &mut *tx,
+        audit_entry(AuditAction::Create, audit_entities::MAINTENANCE_WINDOW, window.id)
+            .await
+            .after(&window),
+    )
+    .await;
+
+    tx.commit().await.map_err(db_error)?;
+
+    info!("Maintenance from {} to {} scheduled by {}", window.starts_at, window.ends_at, admin.id);
+    Ok(window)
+}
+
+/// Removes a window that hasn't started, or ends a running one now
+#[server]
+pub async fn cancel_maintenance_window(id: Uuid) -> Result<(), ServerFnError> {
+    let db = get_db().await;
+    let admin = crate::session::require_permission(MAINTENANCE_PERMISSION).await?;
+    let db_error = |e: sqlx::Error| -> ServerFnError {
+        tracing::error!("Database error cancelling maintenance: {}", e);
+        ServerFnError::ServerError("Failed to cancel maintenance".into())
+    };
+
+    let window = sqlx::query_as::<_, MaintenanceWindow>(&format!(
+        "SELECT {} FROM maintenance_windows WHERE id = $1 AND ends_at > NOW()",
+        MAINTENANCE_WINDOW_COLUMNS
+    ))
+    .bind(id)
+    .fetch_optional(db)
+    .await
+    .map_err(db_error)?
+    .ok_or_else(|| -> ServerFnError { ServerFnError::Request("That maintenance window has already ended".into()) })?;
+
+    let (action, query) = if window.is_active {
+        (AuditAction::Update, "UPDATE maintenance_windows SET ends_at = NOW() WHERE id = $1")
+    } else {
+        (AuditAction::Delete, "DELETE FROM maintenance_windows WHERE id = $1")
+    };
+
+    sqlx::query(query).bind(id).execute(db).await.map_err(db_error)?;
+
+    record_audit(
+        db,
+        audit_entry(action, audit_entities::MAINTENANCE_WINDOW, id)
+            .await
+            .before(&window)
+            .metadata(serde_json::json!({ "ended_early": window.is_active })),
+    )
+    .await;
+
+    info!("Maintenance window {} cancelled by {}", id, admin.id);
+    Ok(())
+}
@@ -1,25 +1,38 @@
 use uuid::Uuid;
 use chrono::{DateTime, Utc};
 use serde::{Serialize, Deserialize};
-use sqlx::FromRow;
-use validator::Validate;
+use sqlx::{FromRow, PgPool};
+use validator::{Validate, ValidationError};
 
-#[derive(Debug, Clone, Serialize, Deserialize, FromRow, Validate)]
+// is_active is worked out by the query; NOW() can't go in a generated column
+pub const MAINTENANCE_WINDOW_COLUMNS: &str = "id, starts_at, ends_at, description, \
+    NOW() BETWEEN starts_at AND ends_at AS is_active, created_by, created_at";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, FromRow, Validate)]
+#[validate(schema(function = "validate_window_order"))]
 pub struct MaintenanceWindow {
     pub id: Uuid,
-    #[validate(range(min = "now", message = "Must be in the future"))]
+    #[serde(with = "chrono::serde::ts_seconds")]
     pub starts_at: DateTime<Utc>,
-    #[validate(range(min = "starts_at", message = "Must be after start time"))]
+    #[serde(with = "chrono::serde::ts_seconds")]
     pub ends_at: DateTime<Utc>,
     #[validate(length(min = 10, max = 1000))]
     pub description: String,
-    #[serde(skip)] // Generated by DB
+    /// Whether the window was running when it was loaded
     pub is_active: bool,
     pub created_by: Option<Uuid>,
     #[serde(with = "chrono::serde::ts_seconds")]
     pub created_at: DateTime<Utc>,
 }
 
+fn validate_window_order(window: &MaintenanceWindow) -> Result<(), ValidationError> {
+    if window.ends_at > window.starts_at {
+        Ok(())
+    } else {
+        Err(ValidationError::new("Must end after it starts"))
+    }
+}
+
 impl MaintenanceWindow {
     /// Creates a new maintenance window builder
     pub fn builder() -> MaintenanceWindowBuilder {
@@ -46,6 +59,11 @@ impl MaintenanceWindow {
     pub fn starts_in(&self) -> chrono::Duration {
         self.starts_at - Utc::now()
     }
+
+    /// Time remaining until maintenance ends
+    pub fn ends_in(&self) -> chrono::Duration {
+        self.ends_at - Utc::now()
+    }
 }
 
 /// Builder pattern for maintenance windows
@@ -101,44 +119,46 @@ impl MaintenanceWindowBuilder {
     }
 }
 
-/// Session extension for maintenance mode
-#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
-pub struct Session {
-    // ... your existing session fields ...
-    pub is_maintenance: bool,
-}
-
 /// System maintenance status
-#[derive(Debug, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct SystemStatus {
     pub is_maintenance: bool,
     pub current_window: Option<MaintenanceWindow>,
     pub next_window: Option<MaintenanceWindow>,
 }
 
+impl SystemStatus {
+    /// Seconds until writes are accepted again, if maintenance is running
+    pub fn retry_after_seconds(&self) -> Option<i64> {
+        self.current_window
+            .as_ref()
+            .map(|w| w.ends_in().num_seconds().max(1))
+    }
+}
+
 /// Maintenance service
 pub struct MaintenanceService {
-    pool: sqlx::PgPool,
+    pool: PgPool,
 }
 
 impl MaintenanceService {
-    pub fn new(pool: sqlx::PgPool) -> Self {
+    pub fn new(pool: PgPool) -> Self {
         Self { pool }
     }
 
     /// Get current system maintenance status
     pub async fn get_status(&self) -> Result<SystemStatus, sqlx::Error> {
-        let current = sqlx::query_as!(
-            MaintenanceWindow,
-            r#"SELECT * FROM maintenance_windows WHERE NOW() BETWEEN starts_at AND ends_at"#
-        )
+        let current = sqlx::query_as::<_, MaintenanceWindow>(&format!(
+            "SELECT {} FROM maintenance_windows WHERE NOW() BETWEEN starts_at AND ends_at ORDER BY ends_at DESC LIMIT 1",
+            MAINTENANCE_WINDOW_COLUMNS
+        ))
         .fetch_optional(&self.pool)
         .await?;
 
-        let next = sqlx::query_as!(
-            MaintenanceWindow,
-            r#"SELECT * FROM maintenance_windows WHERE starts_at > NOW() ORDER BY starts_at LIMIT 1"#
-        )
+        let next = sqlx::query_as::<_, MaintenanceWindow>(&format!(
+            "SELECT {} FROM maintenance_windows WHERE starts_at > NOW() ORDER BY starts_at LIMIT 1",
+            MAINTENANCE_WINDOW_COLUMNS
+        ))
         .fetch_optional(&self.pool)
         .await?;
 
@@ -151,32 +171,32 @@ impl MaintenanceService {
 
     /// Check if maintenance mode is active
     pub async fn is_active(&self) -> Result<bool, sqlx::Error> {
-        Ok(
-            sqlx::query_scalar!(
-                r#"SELECT EXISTS(SELECT 1 FROM maintenance_windows WHERE NOW() BETWEEN starts_at AND ends_at)"#
-            )
-            .fetch_one(&self.pool)
-            .await?
+        sqlx::query_scalar::<_, bool>(
+            "SELECT EXISTS(SELECT 1 FROM maintenance_windows WHERE NOW() BETWEEN starts_at AND ends_at)"
         )
+        .fetch_one(&self.pool)
+        .await
     }
 
-    /// Mark sessions created during maintenance
-    pub async fn mark_maintenance_sessions(&self) -> Result<u64, sqlx::Error> {
-        sqlx::query!(
-            r#"
-            UPDATE sessions 
-            SET is_maintenance = true
-            WHERE created_at BETWEEN 
-                (SELECT starts_at FROM maintenance_windows WHERE NOW() BETWEEN starts_at AND ends_at)
-                AND NOW()
-            "#
-        )
-        .execute(&self.pool)
+    /// Windows that are running or still to come, soonest first
+    pub async fn upcoming(&self) -> Result<Vec<MaintenanceWindow>, sqlx::Error> {
+        sqlx::query_as::<_, MaintenanceWindow>(&format!(
+            "SELECT {} FROM maintenance_windows WHERE ends_at > NOW() ORDER BY starts_at",
+            MAINTENANCE_WINDOW_COLUMNS
+        ))
+        .fetch_all(&self.pool)
         .await
-        .map(|r| r.rows_affected())
     }
 }
 
+/// An admin's request to schedule a window
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ScheduleMaintenanceRequest {
+    pub starts_at: DateTime<Utc>,
+    pub ends_at: DateTime<Utc>,
+    pub description: String,
+}
+
 /// API response DTO
 #[derive(Debug, Serialize)]
 pub struct MaintenanceWindowResponse {
@@ -193,12 +213,12 @@ impl From<MaintenanceWindow> for MaintenanceWindowResponse {
     fn from(window: MaintenanceWindow) -> Self {
         Self {
             id: window.id,
-            starts_at: window.starts_at,
-            ends_at: window.ends_at,
-            description: window.description,
             is_active: window.is_active_now(),
             duration_minutes: window.duration().num_minutes(),
             starts_in_minutes: window.starts_in().num_minutes(),
+            starts_at: window.starts_at,
+            ends_at: window.ends_at,
+            description: window.description,
         }
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,21 @@
+pub mod database_health_functions;
+pub mod database_health_model;
+pub mod maintenance_functions;
+pub mod maintenance_window_model;
+
+pub use database_health_functions::{get_database_health, run_database_health_check};
+pub use database_health_model::{
+    prune_health_history, DatabaseConnectionStats, DatabaseHealthConfig, DatabaseHealthMonitor,
+    DatabaseHealthOverview, DatabaseHealthReport, DatabaseHealthSample, DatabaseHealthStatus, HealthLevel,
+    PoolMetrics, SlowQuery,
+};
+pub use maintenance_functions::{
+    cancel_maintenance_window, get_system_status, list_maintenance_windows, schedule_maintenance_window,
+    writes_blocked_for, MAINTENANCE_PERMISSION,
+};
+#[cfg(feature = "server")]
+pub use maintenance_functions::require_writable;
+pub use maintenance_window_model::{
+    MaintenanceService, MaintenanceWindow, MaintenanceWindowBuilder, MaintenanceWindowResponse,
+    ScheduleMaintenanceRequest, SystemStatus,
+};
@@ -0,0 +1,42 @@
+use std::path::{Path, PathBuf};
+use async_trait::async_trait;
+use chrono::Utc;
+use lettre::Message;
+use uuid::Uuid;
+
+use crate::mail::{MailError, MailTransport};
+
+/// Writes each message into a maildir (`tmp/`, `new/`, `cur/`) instead of sending it.
+/// Used for local development and tests; any mail client can open the directory.
+pub struct FileTransport {
+    dir: PathBuf,
+}
+
+impl FileTransport {
+    pub fn new(dir: impl AsRef<Path>) -> Self {
+        Self { dir: dir.as_ref().to_path_buf() }
+    }
+
+    /// Directory new messages are delivered to
+    pub fn new_dir(&self) -> PathBuf {
+        self.dir.join("new")
+    }
+}
+
+#[async_trait]
+impl MailTransport for FileTransport {
+    async fn send(&self, message: Message) -> Result<(), MailError> {
+        for sub in ["tmp", "new", "cur"] {
+            tokio::fs::create_dir_all(self.dir.join(sub)).await?;
+        }
+
+        // Maildir delivery: write to tmp/ then rename into new/ so readers never see partial files
+        let name = format!("{}.{}.writiting.eml", Utc::now().timestamp_millis(), Uuid::new_v4().simple());
+        let tmp_path = self.dir.join("tmp").join(&name);
+        tokio::fs::write(&tmp_path, message.formatted()).await?;
+        tokio::fs::rename(&tmp_path, self.new_dir().join(&name)).await?;
+
+        tracing::info!("Wrote email to {}", self.new_dir().join(&name).display());
+        Ok(())
+    }
+}
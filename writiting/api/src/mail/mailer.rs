@@ -0,0 +1,148 @@
+use std::env;
+use std::fmt;
+use std::sync::Arc;
+use async_trait::async_trait;
+use lettre::message::{Mailbox, MultiPart};
+use lettre::Message;
+use tokio::sync::OnceCell;
+
+use crate::mail::{FileTransport, SmtpTransport};
+
+/// A rendered email ready to hand to a transport
+#[derive(Debug, Clone)]
+pub struct EmailMessage {
+    pub to: String,
+    pub subject: String,
+    pub html_body: String,
+    pub text_body: String,
+}
+
+impl EmailMessage {
+    /// Builds a multipart/alternative message with text and HTML parts
+    pub fn to_lettre(&self, from: &Mailbox) -> Result<Message, MailError> {
+        let to: Mailbox = self.to
+            .parse()
+            .map_err(|_| MailError::InvalidAddress(self.to.clone()))?;
+
+        Message::builder()
+            .from(from.clone())
+            .to(to)
+            .subject(&self.subject)
+            .multipart(MultiPart::alternative_plain_html(
+                self.text_body.clone(),
+                self.html_body.clone(),
+            ))
+            .map_err(|e| MailError::Build(e.to_string()))
+    }
+}
+
+#[derive(Debug)]
+pub enum MailError {
+    InvalidAddress(String),
+    Build(String),
+    Transport(String),
+    Io(std::io::Error),
+}
+
+impl fmt::Display for MailError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MailError::InvalidAddress(addr) => write!(f, "Invalid email address: {}", addr),
+            MailError::Build(e) => write!(f, "Failed to build email: {}", e),
+            MailError::Transport(e) => write!(f, "Mail transport error: {}", e),
+            MailError::Io(e) => write!(f, "Mail I/O error: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for MailError {}
+
+impl From<std::io::Error> for MailError {
+    fn from(e: std::io::Error) -> Self {
+        MailError::Io(e)
+    }
+}
+
+/// Delivery backend (SMTP in production, maildir for development and tests)
+#[async_trait]
+pub trait MailTransport: Send + Sync {
+    async fn send(&self, message: Message) -> Result<(), MailError>;
+}
+
+/// Configuration read from the environment
+#[derive(Debug, Clone)]
+pub struct MailerConfig {
+    pub transport: String,
+    pub smtp_url: Option<String>,
+    pub mail_dir: String,
+    pub from: String,
+    pub base_url: String,
+}
+
+impl MailerConfig {
+    pub fn from_env() -> Self {
+        Self {
+            transport: env::var("MAIL_TRANSPORT").unwrap_or_else(|_| "file".to_string()),
+            smtp_url: env::var("SMTP_URL").ok(),
+            mail_dir: env::var("MAIL_DIR").unwrap_or_else(|_| "./maildir".to_string()),
+            from: env::var("MAIL_FROM").unwrap_or_else(|_| "Writing Tool <no-reply@localhost>".to_string()),
+            base_url: env::var("APP_BASE_URL").unwrap_or_else(|_| "http://localhost:8080".to_string()),
+        }
+    }
+}
+
+/// Sends email through the configured transport
+#[derive(Clone)]
+pub struct Mailer {
+    transport: Arc<dyn MailTransport>,
+    from: Mailbox,
+    base_url: String,
+}
+
+impl Mailer {
+    pub fn new(transport: Arc<dyn MailTransport>, from: Mailbox, base_url: impl Into<String>) -> Self {
+        Self {
+            transport,
+            from,
+            base_url: base_url.into(),
+        }
+    }
+
+    pub fn from_config(config: &MailerConfig) -> Result<Self, MailError> {
+        let transport: Arc<dyn MailTransport> = match config.transport.as_str() {
+            "smtp" => {
+                let url = config.smtp_url.as_deref().ok_or_else(|| {
+                    MailError::Transport("SMTP_URL must be set when MAIL_TRANSPORT=smtp".into())
+                })?;
+                Arc::new(SmtpTransport::from_url(url)?)
+            }
+            "file" | "maildir" => Arc::new(FileTransport::new(&config.mail_dir)),
+            other => return Err(MailError::Transport(format!("Unknown MAIL_TRANSPORT: {}", other))),
+        };
+
+        let from = config.from
+            .parse()
+            .map_err(|_| MailError::InvalidAddress(config.from.clone()))?;
+
+        Ok(Self::new(transport, from, config.base_url.trim_end_matches('/')))
+    }
+
+    pub async fn send(&self, message: &EmailMessage) -> Result<(), MailError> {
+        self.transport.send(message.to_lettre(&self.from)?).await
+    }
+
+    /// Absolute link into the app for use in email bodies
+    pub fn link(&self, path: &str) -> String {
+        format!("{}/{}", self.base_url, path.trim_start_matches('/'))
+    }
+}
+
+static MAILER: OnceCell<Mailer> = OnceCell::const_new();
+
+/// The mailer configured from the environment. A bad configuration is
+/// reported to the caller and tried again on the next call.
+pub async fn get_mailer() -> Result<&'static Mailer, MailError> {
+    MAILER.get_or_try_init(|| async {
+        Mailer::from_config(&MailerConfig::from_env())
+    }).await
+}
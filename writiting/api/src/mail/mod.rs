@@ -0,0 +1,13 @@
+pub mod mailer;
+pub mod smtp_transport;
+pub mod file_transport;
+pub mod templates;
+pub mod outbox_model;
+pub mod outbox_functions;
+
+pub use mailer::{get_mailer, EmailMessage, MailError, MailTransport, Mailer, MailerConfig};
+pub use smtp_transport::SmtpTransport;
+pub use file_transport::FileTransport;
+pub use templates::{escape_html, DigestItem, EmailTemplate, RenderedEmail};
+pub use outbox_model::{OutboxConfig, OutboxEmail};
+pub use outbox_functions::{queue_email, queue_notification_digests, OutboxWorker, QueueError};
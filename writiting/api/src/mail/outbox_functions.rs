@@ -0,0 +1,288 @@
+use std::fmt;
+use chrono::Utc;
+use sqlx::PgPool;
+use uuid::Uuid;
+use tracing::info;
+
+use crate::mail::{DigestItem, EmailTemplate, MailError, Mailer, OutboxConfig, OutboxEmail};
+
+/// Why a templated email couldn't be queued
+#[derive(Debug)]
+pub enum QueueError {
+    Database(sqlx::Error),
+    /// The mailer couldn't be set up from the environment
+    Mailer(MailError),
+}
+
+impl fmt::Display for QueueError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            QueueError::Database(e) => write!(f, "database error: {}", e),
+            QueueError::Mailer(e) => write!(f, "mailer unavailable: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for QueueError {}
+
+impl From<sqlx::Error> for QueueError {
+    fn from(e: sqlx::Error) -> Self {
+        QueueError::Database(e)
+    }
+}
+
+impl From<MailError> for QueueError {
+    fn from(e: MailError) -> Self {
+        QueueError::Mailer(e)
+    }
+}
+
+/// Queues an email for delivery by the outbox worker.
+/// Non-transactional mail is skipped (returns `None`) when the user has turned email notifications off.
+pub async fn queue_email<'e, E>(
+    db: E,
+    user_id: Option<Uuid>,
+    to: &str,
+    template: &EmailTemplate,
+) -> Result<Option<Uuid>, sqlx::Error>
+where
+    E: sqlx::PgExecutor<'e>,
+{
+    let rendered = template.render();
+
+    // The preference check happens inside the INSERT so a single executor is enough
+    sqlx::query_scalar::<_, Uuid>(
+        r#"
+        INSERT INTO email_outbox (user_id, kind, to_address, subject, html_body, text_body)
+        SELECT $1, $2, $3, $4, $5, $6
+        WHERE $7 OR NOT EXISTS (
+            SELECT 1 FROM user_preferences
+            WHERE user_id = $1 AND email_notifications = false
+        )
+        RETURNING id
+        "#
+    )
+    .bind(user_id)
+    .bind(template.kind())
+    .bind(to)
+    .bind(&rendered.subject)
+    .bind(&rendered.html_body)
+    .bind(&rendered.text_body)
+    .bind(template.is_transactional())
+    .fetch_optional(db)
+    .await
+}
+
+/// Queues a digest of unread notifications for every user who opted in and is due one
+pub async fn queue_notification_digests(
+    db: &PgPool,
+    mailer: &Mailer,
+    interval_hours: i64,
+) -> Result<u64, sqlx::Error> {
+    let due_users = sqlx::query_as::<_, (Uuid, String, String)>(
+        r#"
+        SELECT u.id, u.email, u.username
+        FROM users u
+        JOIN user_preferences p ON p.user_id = u.id
+        WHERE u.is_active = true
+          AND u.email_verified = true
+          AND p.email_notifications = true
+          AND (p.last_digest_sent_at IS NULL
+               OR p.last_digest_sent_at < NOW() - make_interval(hours => $1::int))
+          AND EXISTS (
+              SELECT 1 FROM notifications n
+              WHERE n.user_id = u.id AND n.read = false
+                AND n.created_at > COALESCE(p.last_digest_sent_at, '-infinity')
+          )
+        "#
+    )
+    .bind(interval_hours as i32)
+    .fetch_all(db)
+    .await?;
+
+    let mut queued = 0;
+    for (user_id, email, username) in due_users {
+        let rows = sqlx::query_as::<_, (String, serde_json::Value)>(
+            r#"
+            SELECT n.type::text, n.content
+            FROM notifications n
+            JOIN user_preferences p ON p.user_id = n.user_id
+            WHERE n.user_id = $1 AND n.read = false
+              AND n.created_at > COALESCE(p.last_digest_sent_at, '-infinity')
+            ORDER BY n.created_at DESC
+            LIMIT 20
+            "#
+        )
+        .bind(user_id)
+        .fetch_all(db)
+        .await?;
+
+        let items: Vec<DigestItem> = rows
+            .into_iter()
+            .map(|(kind, content)| digest_item(mailer, &kind, &content))
+            .collect();
+
+        let template = EmailTemplate::NotificationDigest {
+            username,
+            items,
+            settings_link: mailer.link("/profile"),
+        };
+
+        let mut tx = db.begin().await?;
+        queue_email(&mut *tx, Some(user_id), &email, &template).await?;
+        sqlx::query("UPDATE user_preferences SET last_digest_sent_at = NOW() WHERE user_id = $1")
+            .bind(user_id)
+            .execute(&mut *tx)
+            .await?;
+        tx.commit().await?;
+        queued += 1;
+    }
+
+    Ok(queued)
+}
+
+fn digest_item(mailer: &Mailer, kind: &str, content: &serde_json::Value) -> DigestItem {
+    let post_id = content.get("post_id").and_then(|v| v.as_i64());
+    let summary = match kind {
+        "post_mention" => "You were mentioned in a post",
+        "comment_mention" => "You were mentioned in a comment",
+        "comment_reply" => "Someone replied to your comment",
+        "post_reaction" => "Someone reacted to your post",
+        "comment_reaction" => "Someone reacted to your comment",
+        "new_follower" => "You have a new follower",
+        _ => "You have a new notification",
+    };
+
+    DigestItem {
+        summary: summary.to_string(),
+        link: match post_id {
+            Some(id) => mailer.link(&format!("/blog/{}", id)),
+            None => mailer.link("/"),
+        },
+    }
+}
+
+/// Background worker that drains `email_outbox` with retries
+pub struct OutboxWorker {
+    pool: PgPool,
+    mailer: Mailer,
+    config: OutboxConfig,
+}
+
+impl OutboxWorker {
+    pub fn new(pool: PgPool, mailer: Mailer) -> Self {
+        Self {
+            pool,
+            mailer,
+            config: OutboxConfig::default(),
+        }
+    }
+
+    pub fn with_config(mut self, config: OutboxConfig) -> Self {
+        self.config = config;
+        self
+    }
+
+    pub async fn run(&self) -> ! {
+        let mut interval = tokio::time::interval(
+            std::time::Duration::from_secs(self.config.poll_interval_seconds)
+        );
+        let mut last_digest_run = Utc::now() - chrono::Duration::hours(self.config.digest_interval_hours);
+
+        loop {
+            interval.tick().await;
+
+            if let Err(e) = self.process_batch().await {
+                tracing::error!("Failed to process email outbox: {}", e);
+            }
+
+            // Digests only need checking roughly once an hour
+            if Utc::now() - last_digest_run >= chrono::Duration::hours(1) {
+                last_digest_run = Utc::now();
+                match queue_notification_digests(&self.pool, &self.mailer, self.config.digest_interval_hours).await {
+                    Ok(0) => {}
+                    Ok(n) => info!("Queued {} notification digest(s)", n),
+                    Err(e) => tracing::error!("Failed to queue notification digests: {}", e),
+                }
+            }
+        }
+    }
+
+    /// Sends up to `batch_size` due emails. Returns how many were delivered.
+    ///
+    /// Emails are claimed and committed before anything is sent, and each result
+    /// is saved on its own, so a database error part way through can't cause an
+    /// email that already went out to be sent again.
+    pub async fn process_batch(&self) -> Result<usize, sqlx::Error> {
+        // SKIP LOCKED lets several server instances share the queue
+        let batch = sqlx::query_as::<_, OutboxEmail>(
+            r#"
+            UPDATE email_outbox
+            SET status = 'sending', next_attempt_at = NOW() + make_interval(secs => $2)
+            WHERE id IN (
+                SELECT id FROM email_outbox
+                WHERE status IN ('pending', 'sending') AND next_attempt_at <= NOW()
+                ORDER BY next_attempt_at
+                LIMIT $1
+                FOR UPDATE SKIP LOCKED
+            )
+            RETURNING *
+            "#
+        )
+        .bind(self.config.batch_size)
+        .bind(self.config.claim_timeout_seconds as f64)
+        .fetch_all(&self.pool)
+        .await?;
+
+        let mut delivered = 0;
+        for email in batch {
+            let result = self.mailer.send(&email.to_message()).await;
+            match self.record_attempt(&email, result).await {
+                Ok(true) => delivered += 1,
+                Ok(false) => {}
+                // Left as 'sending', so it is retried once the claim expires
+                Err(e) => tracing::error!("Failed to record delivery of email {}: {}", email.id, e),
+            }
+        }
+
+        Ok(delivered)
+    }
+
+    /// Saves the outcome of one send, scheduling a retry if it failed.
+    /// Returns whether the email was delivered.
+    async fn record_attempt(&self, email: &OutboxEmail, result: Result<(), MailError>) -> Result<bool, sqlx::Error> {
+        let attempts = email.attempts + 1;
+        match result {
+            Ok(()) => {
+                sqlx::query(
+                    "UPDATE email_outbox SET status = 'sent', sent_at = NOW(), attempts = $2 WHERE id = $1"
+                )
+                .bind(email.id)
+                .bind(attempts)
+                .execute(&self.pool)
+                .await?;
+                Ok(true)
+            }
+            Err(e) => {
+                let status = if attempts >= self.config.max_attempts { "failed" } else { "pending" };
+                tracing::warn!("Email {} attempt {} failed: {}", email.id, attempts, e);
+
+                sqlx::query(
+                    r#"
+                    UPDATE email_outbox
+                    SET status = $2, attempts = $3, last_error = $4, next_attempt_at = $5
+                    WHERE id = $1
+                    "#
+                )
+                .bind(email.id)
+                .bind(status)
+                .bind(attempts)
+                .bind(e.to_string())
+                .bind(Utc::now() + OutboxEmail::retry_delay(attempts))
+                .execute(&self.pool)
+                .await?;
+                Ok(false)
+            }
+        }
+    }
+}
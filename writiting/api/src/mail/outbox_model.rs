@@ -0,0 +1,68 @@
+use uuid::Uuid;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+
+use crate::mail::EmailMessage;
+
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct OutboxEmail {
+    pub id: Uuid,
+    pub user_id: Option<Uuid>,
+    pub kind: String,
+    pub to_address: String,
+    pub subject: String,
+    #[serde(skip_serializing)]
+    pub html_body: String,
+    #[serde(skip_serializing)]
+    pub text_body: String,
+    pub status: String,
+    pub attempts: i32,
+    pub last_error: Option<String>,
+    #[serde(with = "chrono::serde::ts_seconds")]
+    pub next_attempt_at: DateTime<Utc>,
+    #[serde(with = "chrono::serde::ts_seconds_option")]
+    pub sent_at: Option<DateTime<Utc>>,
+    #[serde(with = "chrono::serde::ts_seconds")]
+    pub created_at: DateTime<Utc>,
+}
+
+impl OutboxEmail {
+    pub fn to_message(&self) -> EmailMessage {
+        EmailMessage {
+            to: self.to_address.clone(),
+            subject: self.subject.clone(),
+            html_body: self.html_body.clone(),
+            text_body: self.text_body.clone(),
+        }
+    }
+
+    /// Exponential back-off before the next attempt: 1, 2, 4, 8... minutes, capped at 6 hours
+    pub fn retry_delay(attempts: i32) -> chrono::Duration {
+        let minutes = 1i64 << attempts.clamp(0, 9);
+        chrono::Duration::minutes(minutes.min(360))
+    }
+}
+
+/// Configuration for the outbox worker
+#[derive(Debug, Clone)]
+pub struct OutboxConfig {
+    pub poll_interval_seconds: u64,
+    pub batch_size: i64,
+    pub max_attempts: i32,
+    /// How long a claimed email may stay 'sending' before another worker retries it
+    pub claim_timeout_seconds: u64,
+    pub digest_interval_hours: i64,
+}
+
+impl Default for OutboxConfig {
+    fn default() -> Self {
+        Self {
+            poll_interval_seconds: 15,
+            batch_size: 20,
+            max_attempts: 8,
+            claim_timeout_seconds: 600,
+            digest_interval_hours: 24,
+        }
+    }
+}
@@ -0,0 +1,140 @@
+use serde::{Deserialize, Serialize};
+
+/// One line in a notification digest
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DigestItem {
+    pub summary: String,
+    pub link: String,
+}
+
+/// Templated emails sent by the application
+#[derive(Debug, Clone)]
+pub enum EmailTemplate {
+    Verification {
+        username: String,
+        link: String,
+    },
+    PasswordReset {
+        username: String,
+        link: String,
+        expires_in_minutes: i64,
+    },
+    NotificationDigest {
+        username: String,
+        items: Vec<DigestItem>,
+        settings_link: String,
+    },
+}
+
+/// Subject and bodies produced from a template
+#[derive(Debug, Clone)]
+pub struct RenderedEmail {
+    pub subject: String,
+    pub html_body: String,
+    pub text_body: String,
+}
+
+impl EmailTemplate {
+    /// Value stored in `email_outbox.kind`
+    pub fn kind(&self) -> &'static str {
+        match self {
+            EmailTemplate::Verification { .. } => "verification",
+            EmailTemplate::PasswordReset { .. } => "password_reset",
+            EmailTemplate::NotificationDigest { .. } => "notification_digest",
+        }
+    }
+
+    /// Transactional mail is sent regardless of the user's email preference
+    pub fn is_transactional(&self) -> bool {
+        !matches!(self, EmailTemplate::NotificationDigest { .. })
+    }
+
+    pub fn render(&self) -> RenderedEmail {
+        match self {
+            EmailTemplate::Verification { username, link } => RenderedEmail {
+                subject: "Verify your email address".to_string(),
+                text_body: format!(
+                    "Hi {},\n\nPlease confirm your email address by opening the link below:\n\n{}\n\nIf you did not create an account you can ignore this email.\n",
+                    username, link
+                ),
+                html_body: layout(&format!(
+                    "<p>Hi {},</p><p>Please confirm your email address.</p>{}<p>If you did not create an account you can ignore this email.</p>",
+                    escape_html(username),
+                    button(link, "Verify email")
+                )),
+            },
+            EmailTemplate::PasswordReset { username, link, expires_in_minutes } => RenderedEmail {
+                subject: "Reset your password".to_string(),
+                text_body: format!(
+                    "Hi {},\n\nSomeone asked to reset the password for your account. Open the link below to choose a new one:\n\n{}\n\nThe link expires in {} minutes. If this wasn't you, no action is needed.\n",
+                    username, link, expires_in_minutes
+                ),
+                html_body: layout(&format!(
+                    "<p>Hi {},</p><p>Someone asked to reset the password for your account.</p>{}<p>The link expires in {} minutes. If this wasn't you, no action is needed.</p>",
+                    escape_html(username),
+                    button(link, "Choose a new password"),
+                    expires_in_minutes
+                )),
+            },
+            EmailTemplate::NotificationDigest { username, items, settings_link } => {
+                let text_items: String = items
+                    .iter()
+                    .map(|item| format!("- {}\n  {}\n", item.summary, item.link))
+                    .collect();
+                let html_items: String = items
+                    .iter()
+                    .map(|item| format!(
+                        "<li><a href=\"{}\">{}</a></li>",
+                        escape_html(&item.link),
+                        escape_html(&item.summary)
+                    ))
+                    .collect();
+
+                RenderedEmail {
+                    subject: format!("You have {} new notification(s)", items.len()),
+                    text_body: format!(
+                        "Hi {},\n\nHere's what you missed:\n\n{}\nManage email preferences: {}\n",
+                        username, text_items, settings_link
+                    ),
+                    html_body: layout(&format!(
+                        "<p>Hi {},</p><p>Here's what you missed:</p><ul>{}</ul><p style=\"font-size:12px;color:#6b7280\"><a href=\"{}\">Manage email preferences</a></p>",
+                        escape_html(username),
+                        html_items,
+                        escape_html(settings_link)
+                    )),
+                }
+            }
+        }
+    }
+}
+
+fn layout(content: &str) -> String {
+    format!(
+        "<!DOCTYPE html><html><body style=\"font-family:sans-serif;color:#1f2937;max-width:560px;margin:0 auto;padding:24px\">{}<p style=\"color:#6b7280;font-size:12px\">Writing Tool</p></body></html>",
+        content
+    )
+}
+
+fn button(link: &str, label: &str) -> String {
+    format!(
+        "<p><a href=\"{}\" style=\"display:inline-block;padding:10px 16px;background:#4f46e5;color:#fff;border-radius:6px;text-decoration:none\">{}</a></p>",
+        escape_html(link),
+        escape_html(label)
+    )
+}
+
+/// Escapes text for inclusion in HTML bodies and attributes
+pub fn escape_html(input: &str) -> String {
+    let mut out = String::with_capacity(input.len());
+    for c in input.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&#39;"),
+            _ => out.push(c),
+        }
+    }
+    out
+}
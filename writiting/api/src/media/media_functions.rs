@@ -0,0 +1,344 @@
+use dioxus::prelude::*;
+use server_fn::codec::Cbor;
+#[cfg(feature = "server")]
+use serde_json::json;
+#[cfg(feature = "server")]
+use sqlx::PgPool;
+use uuid::Uuid;
+#[cfg(feature = "server")]
+use tracing::info;
+
+#[cfg(feature = "server")]
+use crate::db::get_db;
+use crate::media::{MediaItem, MediaQuota, MediaUpload, MediaUsage};
+#[cfg(feature = "server")]
+use crate::media::media_model::{extension_for, format_bytes, storage_key, thumbnail_key, MEDIA_COLUMNS};
+#[cfg(feature = "server")]
+use crate::media::processing::{process_image, sniff_content_type, ProcessingError};
+#[cfg(feature = "server")]
+use crate::media::get_storage;
+#[cfg(feature = "server")]
+use crate::authentication::models::rate_limits;
+#[cfg(feature = "server")]
+use crate::authentication::{audit_entities, audit_entry, record_audit, AuditAction};
+#[cfg(feature = "server")]
+use crate::settings::app_settings;
+
+/// Lets a role remove anyone's uploads
+pub const MEDIA_ADMIN_PERMISSION: &str = "content:moderate";
+#[cfg(feature = "server")]
+const MEDIA_LIST_LIMIT: i64 = 100;
+
+/// Why an upload wasn't stored
+#[cfg(feature = "server")]
+pub(crate) enum UploadError {
+    /// Something about the file; the message is for the uploader
+    Rejected(String),
+    /// Storage or database failure, already logged
+    Failed,
+}
+
+#[cfg(feature = "server")]
+impl From<ProcessingError> for UploadError {
+    fn from(e: ProcessingError) -> Self {
+        UploadError::Rejected(e.to_string())
+    }
+}
+
+#[cfg(feature = "server")]
+fn hash_bytes(bytes: &[u8]) -> String {
+    use sha2::{Digest, Sha256};
+    Sha256::digest(bytes).iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+/// Base name of whatever path the client sent, e.g. `C:\Photos\me.jpg` -> `me.jpg`
+#[cfg(feature = "server")]
+fn clean_file_name(name: &str) -> Option<String> {
+    let base = name.rsplit(['/', '\\']).next().unwrap_or_default().trim();
+    (!base.is_empty()).then(|| base.chars().take(255).collect())
+}
+
+pub async fn used_storage<'e, E>(db: E, user_id: Uuid) -> Result<i64, sqlx::Error>
+where
+    E: sqlx::PgExecutor<'e>,
+{
+    sqlx::query_scalar::<_, i64>("SELECT COALESCE(SUM(byte_size), 0)::BIGINT FROM media WHERE user_id = $1")
+        .bind(user_id)
+        .fetch_one(db)
+        .await
+}
+
+/// Validates, strips and stores an upload for `user_id`, shared by the server
+/// function and the multipart `POST /media` route.
+///
+/// Files are keyed by the SHA-256 of the uploaded bytes: a user sending the same
+/// file twice gets their existing item back, and a file someone else already
+/// uploaded isn't written to storage again. Avatar uploads also become the
+/// user's profile picture.
+#[cfg(feature = "server")]
+pub(crate) async fn save_upload(db: &PgPool, user_id: Uuid, upload: MediaUpload) -> Result<MediaItem, UploadError> {
+    let settings = app_settings().await;
+    if upload.bytes.is_empty() {
+        return Err(UploadError::Rejected("That file is empty".into()));
+    }
+    if upload.bytes.len() > settings.max_upload_bytes() {
+        return Err(UploadError::Rejected(format!("Files can be at most {} MB", settings.max_upload_mb)));
+    }
+    let content_type = sniff_content_type(&upload.bytes).ok_or(ProcessingError::UnsupportedType)?;
+    if !settings.allows_upload_type(content_type) {
+        return Err(UploadError::Rejected(format!("{} uploads aren't allowed on this site", content_type)));
+    }
+
+    let db_error = |e: sqlx::Error| {
+        tracing::error!("Database error saving upload: {}", e);
+        UploadError::Failed
+    };
+    let storage_error = |e: crate::media::StorageError| {
+        tracing::error!("Failed to store upload: {}", e);
+        UploadError::Failed
+    };
+
+    let hash = hash_bytes(&upload.bytes);
+    let existing = sqlx::query_as::<_, MediaItem>(&format!(
+        "SELECT {} FROM media WHERE user_id = $1 AND hash = $2",
+        MEDIA_COLUMNS
+    ))
+    .bind(user_id)
+    .bind(&hash)
+    .fetch_optional(db)
+    .await
+    .map_err(db_error)?;
+
+    let item = match existing {
+        Some(item) => item,
+        None => {
+            // Every upload is processed and counted against the uploader's quota,
+            // whoever else may have sent the same file
+            let bytes = upload.bytes;
+            // Decoding is CPU-bound, so keep it off the async workers
+            let processed = tokio::task::spawn_blocking(move || process_image(&bytes))
+                .await
+                .map_err(|e| {
+                    tracing::error!("Image processing task failed: {}", e);
+                    UploadError::Failed
+                })??;
+            let content_type = processed.content_type.to_string();
+            let byte_size = processed.bytes.len() as i64;
+
+            // Holding the uploader's row until the insert commits makes their
+            // concurrent uploads take turns, so together they can't overrun the quota
+            let mut tx = db.begin().await.map_err(db_error)?;
+            sqlx::query("SELECT 1 FROM users WHERE id = $1 FOR UPDATE")
+                .bind(user_id)
+                .execute(&mut *tx)
+                .await
+                .map_err(db_error)?;
+
+            let used = used_storage(&mut *tx, user_id).await.map_err(db_error)?;
+            if used + byte_size > settings.user_quota_bytes() {
+                return Err(UploadError::Rejected(format!(
+                    "That would take you over your {} MB of storage; you're using {}",
+                    settings.user_quota_mb,
+                    format_bytes(used)
+                )));
+            }
+
+            // Only the stored file is shared between accounts
+            let storage = get_storage().await.map_err(storage_error)?;
+            let key = storage_key(&hash, extension_for(&content_type));
+            if !storage.exists(&key).await.map_err(storage_error)? {
+                let thumbnail_type = if content_type == "image/jpeg" { "image/jpeg" } else { "image/png" };
+                storage.put(&key, processed.bytes, &content_type).await.map_err(storage_error)?;
+                storage
+                    .put(&thumbnail_key(&hash, &content_type), processed.thumbnail, thumbnail_type)
+                    .await
+                    .map_err(storage_error)?;
+            }
+
+            // The no-op update makes RETURNING yield the row even if a concurrent
+            // upload of the same file inserted it first
+            let item = sqlx::query_as::<_, MediaItem>(&format!(
+                r#"
+                INSERT INTO media (user_id, hash, content_type, byte_size, width, height, original_name, usage)
+                VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+                ON CONFLICT (user_id, hash) DO UPDATE SET usage = media.usage
+                RETURNING {}
+                "#,
+                MEDIA_COLUMNS
+            ))
+            .bind(user_id)
+            .bind(&hash)
+            .bind(&content_type)
+            .bind(byte_size)
+            .bind(processed.width as i32)
+            .bind(processed.height as i32)
+            .bind(clean_file_name(&upload.file_name))
+            .bind(upload.usage)
+            .fetch_one(&mut *tx)
+            .await
+            .map_err(db_error)?;
+
+            tx.commit().await.map_err(db_error)?;
+            item
+        }
+    };
+
+    if upload.usage == MediaUsage::Avatar {
+        sqlx::query(
+            r#"
+            INSERT INTO profiles (user_id, avatar_url) VALUES ($1, $2)
+            ON CONFLICT (user_id) DO UPDATE SET avatar_url = EXCLUDED.avatar_url, updated_at = NOW()
+            "#
+        )
+        .bind(user_id)
+        .bind(item.thumbnail_url())
+        .execute(db)
+        .await
+        .map_err(db_error)?;
+    }
+
+    Ok(item)
+}
+
+/// Uploads an image for a post or as the signed-in user's avatar.
+///
+/// Sent as CBOR rather than multipart: server functions only support multipart
+/// from the browser, and the desktop app needs this too. Browsers and scripts
+/// can also `POST /media` as `multipart/form-data`.
+#[server(input = Cbor)]
+pub async fn upload_media(upload: MediaUpload) -> Result<MediaItem, ServerFnError> {
+    let db = get_db().await;
+    crate::log::require_writable().await?;
+    let user = crate::session::current_user().await?;
+    crate::rate_limit!(rate_limits::UPLOAD);
+
+    let usage = upload.usage;
+    let item = save_upload(db, user.id, upload).await.map_err(|e| -> ServerFnError {
+        match e {
+            UploadError::Rejected(message) => ServerFnError::Request(message),
+            UploadError::Failed => ServerFnError::ServerError("Failed to save upload".into()),
+        }
+    })?;
+
+    record_audit(
+        db,
+        audit_entry(AuditAction::Create, audit_entities::MEDIA, item.id)
+            .await
+            .after(&item)
+            .metadata(json!({ "usage": usage.as_str() })),
+    )
+    .await;
+
+    info!("User {} uploaded {} ({} bytes)", user.id, item.storage_key(), item.byte_size);
+    Ok(item)
+}
+
+/// The signed-in user's uploads, newest first
+#[server]
+pub async fn list_my_media(usage: Option<MediaUsage>) -> Result<Vec<MediaItem>, ServerFnError> {
+    let db = get_db().await;
+    let user = crate::session::current_user().await?;
+
+    sqlx::query_as::<_, MediaItem>(&format!(
+        "SELECT {} FROM media WHERE user_id = $1 AND ($2::media_usage IS NULL OR usage = $2) \
+         ORDER BY created_at DESC LIMIT $3",
+        MEDIA_COLUMNS
+    ))
+    .bind(user.id)
+    .bind(usage)
+    .bind(MEDIA_LIST_LIMIT)
+    .fetch_all(db)
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error listing media: {}", e);
+        ServerFnError::ServerError("Failed to load uploads".into())
+    })
+}
+
+#[server]
+pub async fn get_media_quota() -> Result<MediaQuota, ServerFnError> {
+    let db = get_db().await;
+    let user = crate::session::current_user().await?;
+
+    let used_bytes = used_storage(db, user.id).await.map_err(|e| -> ServerFnError {
+        tracing::error!("Database error reading storage use: {}", e);
+        ServerFnError::ServerError("Failed to load storage use".into())
+    })?;
+
+    Ok(MediaQuota {
+        used_bytes,
+        limit_bytes: app_settings().await.user_quota_bytes(),
+    })
+}
+
+/// Removes an upload. The file itself is deleted once no one else has uploaded
+/// the same content; an avatar pointing at it is cleared.
+#[server]
+pub async fn delete_media(id: Uuid) -> Result<(), ServerFnError> {
+    let db = get_db().await;
+    crate::log::require_writable().await?;
+    let user = crate::session::current_user().await?;
+
+    let db_error = |e: sqlx::Error| -> ServerFnError {
+        tracing::error!("Database error deleting media: {}", e);
+        ServerFnError::ServerError("Failed to delete upload".into())
+    };
+
+    let item = sqlx::query_as::<_, MediaItem>(&format!("SELECT {} FROM media WHERE id = $1", MEDIA_COLUMNS))
+        .bind(id)
+        .fetch_optional(db)
+        .await
+        .map_err(db_error)?
+        .ok_or_else(|| -> ServerFnError { ServerFnError::Request("Upload not found".into()) })?;
+
+    if item.user_id != user.id
+        && !crate::session::role_has_permission(db, user.role_id, MEDIA_ADMIN_PERMISSION)
+            .await
+            .map_err(db_error)?
+    {
+        return Err(ServerFnError::Request("You can only delete your own uploads".into()));
+    }
+
+    let mut tx = db.begin().await.map_err(db_error)?;
+    sqlx::query("DELETE FROM media WHERE id = $1")
+        .bind(id)
+        .execute(&mut *tx)
+        .await
+        .map_err(db_error)?;
+    sqlx::query("UPDATE profiles SET avatar_url = NULL, updated_at = NOW() WHERE user_id = $1 AND avatar_url IN ($2, $3)")
+        .bind(item.user_id)
+        .bind(item.url())
+        .bind(item.thumbnail_url())
+        .execute(&mut *tx)
+        .await
+        .map_err(db_error)?;
+    let shared = sqlx::query_scalar::<_, bool>("SELECT EXISTS(SELECT 1 FROM media WHERE hash = $1)")
+        .bind(&item.hash)
+        .fetch_one(&mut *tx)
+        .await
+        .map_err(db_error)?;
+    record_audit(
+        &mut *tx,
+        audit_entry(AuditAction::Delete, audit_entities::MEDIA, item.id)
+            .await
+            .before(&item),
+    )
+    .await;
+    tx.commit().await.map_err(db_error)?;
+
+    // Posts that embedded it lose the image, which is the owner's call to make
+    if !shared {
+        match get_storage().await {
+            Ok(storage) => {
+                for key in [item.storage_key(), item.thumbnail_key()] {
+                    if let Err(e) = storage.delete(&key).await {
+                        tracing::error!("Failed to delete stored file {}: {}", key, e);
+                    }
+                }
+            }
+            Err(e) => tracing::error!("Failed to delete stored files of media {}: {}", item.id, e),
+        }
+    }
+
+    Ok(())
+}
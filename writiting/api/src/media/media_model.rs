@@ -0,0 +1,156 @@
+use uuid::Uuid;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+
+/// Longest side of the generated thumbnail, in pixels
+pub const THUMBNAIL_SIZE: u32 = 320;
+/// Images with a longer side than this are refused before decoding
+pub const MAX_IMAGE_DIMENSION: u32 = 8000;
+
+pub const MEDIA_COLUMNS: &str = "id, user_id, hash, content_type, byte_size, width, height, original_name, usage, created_at";
+
+/// What an upload is for; mirrors the `media_usage` enum in the database
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type)]
+#[sqlx(type_name = "media_usage", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum MediaUsage {
+    Avatar,
+    Post,
+}
+
+impl MediaUsage {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            MediaUsage::Avatar => "avatar",
+            MediaUsage::Post => "post",
+        }
+    }
+}
+
+/// A file sent from the editor or profile page
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MediaUpload {
+    pub file_name: String,
+    pub bytes: Vec<u8>,
+    pub usage: MediaUsage,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, FromRow)]
+pub struct MediaItem {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    /// SHA-256 of the uploaded bytes; names the stored file
+    pub hash: String,
+    pub content_type: String,
+    /// Size of the stored file
+    pub byte_size: i64,
+    pub width: i32,
+    pub height: i32,
+    pub original_name: Option<String>,
+    pub usage: MediaUsage,
+    #[serde(with = "chrono::serde::ts_seconds")]
+    pub created_at: DateTime<Utc>,
+}
+
+impl MediaItem {
+    pub fn extension(&self) -> &'static str {
+        extension_for(&self.content_type)
+    }
+
+    pub fn storage_key(&self) -> String {
+        storage_key(&self.hash, self.extension())
+    }
+
+    pub fn thumbnail_key(&self) -> String {
+        thumbnail_key(&self.hash, &self.content_type)
+    }
+
+    /// Where the app serves the file, e.g. `/media/ab/ab12…ef.png`
+    pub fn url(&self) -> String {
+        format!("/media/{}", self.storage_key())
+    }
+
+    pub fn thumbnail_url(&self) -> String {
+        format!("/media/{}", self.thumbnail_key())
+    }
+
+    /// Markdown that embeds the image in a post
+    pub fn markdown(&self) -> String {
+        let alt = self
+            .original_name
+            .as_deref()
+            .map(|name| name.rsplit_once('.').map_or(name, |(stem, _)| stem))
+            .unwrap_or("image")
+            .replace(['[', ']'], "");
+        format!("![{}]({})", alt, self.url())
+    }
+}
+
+pub fn extension_for(content_type: &str) -> &'static str {
+    match content_type {
+        "image/png" => "png",
+        "image/jpeg" => "jpg",
+        "image/gif" => "gif",
+        "image/webp" => "webp",
+        _ => "bin",
+    }
+}
+
+pub fn content_type_for(extension: &str) -> &'static str {
+    match extension {
+        "png" => "image/png",
+        "jpg" | "jpeg" => "image/jpeg",
+        "gif" => "image/gif",
+        "webp" => "image/webp",
+        _ => "application/octet-stream",
+    }
+}
+
+/// Files are spread over 256 directories by the first byte of their hash
+pub fn storage_key(hash: &str, extension: &str) -> String {
+    format!("{}/{}.{}", &hash[..2], hash, extension)
+}
+
+/// Photos get JPEG thumbnails; everything else PNG, to keep transparency
+pub fn thumbnail_key(hash: &str, content_type: &str) -> String {
+    let extension = if content_type == "image/jpeg" { "jpg" } else { "png" };
+    format!("{}/{}_thumb.{}", &hash[..2], hash, extension)
+}
+
+/// How much of their storage quota a user has used
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct MediaQuota {
+    pub used_bytes: i64,
+    pub limit_bytes: i64,
+}
+
+impl MediaQuota {
+    pub fn remaining_bytes(&self) -> i64 {
+        (self.limit_bytes - self.used_bytes).max(0)
+    }
+
+    pub fn percent_used(&self) -> i64 {
+        if self.limit_bytes <= 0 {
+            100
+        } else {
+            (self.used_bytes * 100 / self.limit_bytes).min(100)
+        }
+    }
+}
+
+/// `1536` -> `1.5 KB`
+pub fn format_bytes(bytes: i64) -> String {
+    const UNITS: [&str; 4] = ["B", "KB", "MB", "GB"];
+    let mut value = bytes as f64;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    if unit == 0 {
+        format!("{} B", bytes)
+    } else {
+        format!("{:.1} {}", value, UNITS[unit])
+    }
+}
@@ -0,0 +1,23 @@
+pub mod media_model;
+pub mod media_functions;
+#[cfg(feature = "server")]
+pub mod storage;
+#[cfg(feature = "server")]
+pub mod local_storage;
+#[cfg(feature = "server")]
+pub mod s3_storage;
+#[cfg(feature = "server")]
+pub mod processing;
+#[cfg(feature = "server")]
+mod routes;
+
+pub use media_model::{format_bytes, MediaItem, MediaQuota, MediaUpload, MediaUsage};
+pub use media_functions::*;
+#[cfg(feature = "server")]
+pub use storage::{get_storage, StorageBackend, StorageConfig, StorageError};
+#[cfg(feature = "server")]
+pub use local_storage::LocalStorage;
+#[cfg(feature = "server")]
+pub use s3_storage::S3Storage;
+#[cfg(feature = "server")]
+pub use routes::router;
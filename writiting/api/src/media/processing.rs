@@ -0,0 +1,106 @@
+use std::fmt;
+use std::io::Cursor;
+use image::codecs::jpeg::JpegEncoder;
+use image::metadata::Orientation;
+use image::{DynamicImage, ImageDecoder, ImageFormat, ImageReader};
+
+use crate::media::media_model::{MAX_IMAGE_DIMENSION, THUMBNAIL_SIZE};
+
+const JPEG_QUALITY: u8 = 88;
+
+#[derive(Debug)]
+pub enum ProcessingError {
+    /// Not an image, or a format we don't accept
+    UnsupportedType,
+    TooLarge { width: u32, height: u32 },
+    Invalid(String),
+}
+
+impl fmt::Display for ProcessingError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ProcessingError::UnsupportedType => write!(f, "Only PNG, JPEG, GIF and WebP images can be uploaded"),
+            ProcessingError::TooLarge { width, height } => write!(
+                f,
+                "Images can be at most {0}x{0} pixels; that one is {1}x{2}",
+                MAX_IMAGE_DIMENSION, width, height
+            ),
+            ProcessingError::Invalid(e) => write!(f, "That image couldn't be read: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for ProcessingError {}
+
+impl From<image::ImageError> for ProcessingError {
+    fn from(e: image::ImageError) -> Self {
+        ProcessingError::Invalid(e.to_string())
+    }
+}
+
+/// An upload ready to store: metadata stripped, plus its thumbnail
+pub struct ProcessedImage {
+    pub content_type: &'static str,
+    pub bytes: Vec<u8>,
+    pub width: u32,
+    pub height: u32,
+    pub thumbnail: Vec<u8>,
+}
+
+/// MIME type of an image from its first bytes, ignoring whatever name or type the client claimed
+pub fn sniff_content_type(bytes: &[u8]) -> Option<&'static str> {
+    match image::guess_format(bytes).ok()? {
+        ImageFormat::Png => Some("image/png"),
+        ImageFormat::Jpeg => Some("image/jpeg"),
+        ImageFormat::Gif => Some("image/gif"),
+        ImageFormat::WebP => Some("image/webp"),
+        _ => None,
+    }
+}
+
+fn encode(image: &DynamicImage, format: ImageFormat) -> Result<Vec<u8>, ProcessingError> {
+    let mut buffer = Cursor::new(Vec::new());
+    match format {
+        ImageFormat::Jpeg => {
+            JpegEncoder::new_with_quality(&mut buffer, JPEG_QUALITY).encode_image(&image.to_rgb8())?;
+        }
+        // The WebP encoder only takes 8-bit RGB(A)
+        ImageFormat::WebP => DynamicImage::ImageRgba8(image.to_rgba8()).write_to(&mut buffer, format)?,
+        _ => image.write_to(&mut buffer, format)?,
+    }
+    Ok(buffer.into_inner())
+}
+
+/// Decodes `bytes` and re-encodes it. Only pixels are written back, so EXIF
+/// (camera, GPS position) and other metadata are dropped; the EXIF rotation is
+/// applied first so photos stay the right way up. GIFs are kept as uploaded,
+/// since they carry no EXIF and re-encoding would lose their animation.
+pub fn process_image(bytes: &[u8]) -> Result<ProcessedImage, ProcessingError> {
+    let content_type = sniff_content_type(bytes).ok_or(ProcessingError::UnsupportedType)?;
+    let format = ImageFormat::from_mime_type(content_type).ok_or(ProcessingError::UnsupportedType)?;
+
+    let mut decoder = ImageReader::with_format(Cursor::new(bytes), format).into_decoder()?;
+    let (width, height) = decoder.dimensions();
+    if width > MAX_IMAGE_DIMENSION || height > MAX_IMAGE_DIMENSION {
+        return Err(ProcessingError::TooLarge { width, height });
+    }
+    let orientation = decoder.orientation().unwrap_or(Orientation::NoTransforms);
+    let mut image = DynamicImage::from_decoder(decoder)?;
+    image.apply_orientation(orientation);
+
+    let stored = if format == ImageFormat::Gif {
+        bytes.to_vec()
+    } else {
+        encode(&image, format)?
+    };
+    let thumbnail_format = if format == ImageFormat::Jpeg { ImageFormat::Jpeg } else { ImageFormat::Png };
+    let thumbnail = encode(&image.thumbnail(THUMBNAIL_SIZE, THUMBNAIL_SIZE), thumbnail_format)?;
+
+    Ok(ProcessedImage {
+        content_type,
+        bytes: stored,
+        width: image.width(),
+        height: image.height(),
+        thumbnail,
+    })
+}
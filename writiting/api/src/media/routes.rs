@@ -0,0 +1,182 @@
+//! `/media` routes: multipart uploads and serving stored files.
+use axum::extract::multipart::Field;
+use std::net::SocketAddr;
+use axum::extract::{ConnectInfo, DefaultBodyLimit, Multipart, Path};
+use axum::http::header::{CACHE_CONTROL, CONTENT_LENGTH, CONTENT_TYPE, X_CONTENT_TYPE_OPTIONS};
+use axum::http::{HeaderMap, StatusCode};
+use axum::response::{IntoResponse, Response};
+use axum::routing::{get, post};
+use axum::{Json, Router};
+use serde_json::json;
+
+use crate::authentication::check_rate_limit;
+use crate::authentication::models::{rate_limits, RateLimitKey, RateLimitResult};
+use crate::authentication::{audit_entities, record_audit, AuditAction, AuditLog};
+use crate::db::get_db;
+use crate::media::media_functions::{save_upload, UploadError};
+use crate::media::media_model::content_type_for;
+use crate::media::storage::validate_key;
+use crate::media::{get_storage, MediaItem, MediaUpload, MediaUsage};
+use crate::rest::ApiError;
+use crate::session::{find_session_user, session_token_from_headers};
+use crate::utils::RequestMeta;
+
+/// Room for the multipart boundaries, headers and the `usage` field
+const FORM_OVERHEAD_BYTES: usize = 64 * 1024;
+/// Longest `usage` value worth reading
+const MAX_USAGE_BYTES: usize = 16;
+/// Outer bound for the route; `upload` holds each request to the configured
+/// `uploads.max_size_mb` as it reads
+const MAX_REQUEST_BYTES: usize = 1024 * 1024 * 1024 + FORM_OVERHEAD_BYTES;
+
+pub fn router() -> Router {
+    Router::new()
+        .route("/media", post(upload).layer(DefaultBodyLimit::max(MAX_REQUEST_BYTES)))
+        .route("/media/*key", get(serve))
+}
+
+fn too_large(max_upload_mb: i64) -> ApiError {
+    ApiError::new(
+        StatusCode::PAYLOAD_TOO_LARGE,
+        "too_large",
+        format!("Files can be at most {} MB", max_upload_mb),
+    )
+}
+
+/// Reads a form field chunk by chunk, giving up as soon as it passes `limit`
+/// rather than buffering the whole thing first
+async fn read_field(mut field: Field<'_>, limit: usize) -> Result<Option<Vec<u8>>, ApiError> {
+    let mut bytes = Vec::new();
+    while let Some(chunk) = field.chunk().await.map_err(|e| ApiError::bad_request(e.to_string()))? {
+        if bytes.len() + chunk.len() > limit {
+            return Ok(None);
+        }
+        bytes.extend_from_slice(&chunk);
+    }
+    Ok(Some(bytes))
+}
+
+fn db_error(e: sqlx::Error) -> ApiError {
+    tracing::error!("Database error in media upload: {}", e);
+    ApiError::internal("Failed to save upload")
+}
+
+/// `multipart/form-data` with the image in `file` and optionally `usage`
+/// (`post` or `avatar`), from a signed-in session
+async fn upload(
+    peer: Option<ConnectInfo<SocketAddr>>,
+    headers: HeaderMap,
+    mut form: Multipart,
+) -> Result<Json<MediaItem>, ApiError> {
+    let db = get_db().await;
+    let token = session_token_from_headers(&headers).ok_or_else(|| ApiError::unauthorized("Sign in to upload files"))?;
+    let user = find_session_user(db, &token)
+        .await
+        .map_err(db_error)?
+        .ok_or_else(|| ApiError::unauthorized("Sign in to upload files"))?;
+
+    match check_rate_limit(db, &rate_limits::UPLOAD, &RateLimitKey::User(user.id), 1.0).await {
+        Ok((RateLimitResult::Denied { retry_after_seconds }, _)) => {
+            return Err(ApiError::too_many_requests(retry_after_seconds));
+        }
+        Ok(_) => {}
+        Err(e) => tracing::error!("Rate limiter unavailable for uploads: {}", e),
+    }
+    if let Some(window) = crate::log::writes_blocked_for(db, Some(user.role_id)).await.map_err(db_error)? {
+        return Err(ApiError::under_maintenance(
+            format!("The site is read-only for maintenance until {}", window.ends_at.to_rfc3339()),
+            window.ends_in().num_seconds().max(1),
+        ));
+    }
+
+    let settings = crate::settings::app_settings().await;
+    let max_bytes = settings.max_upload_bytes();
+    let declared = headers
+        .get(CONTENT_LENGTH)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.parse::<usize>().ok());
+    if declared.is_some_and(|len| len > max_bytes + FORM_OVERHEAD_BYTES) {
+        return Err(too_large(settings.max_upload_mb));
+    }
+
+    // Other fields are refused rather than skipped, so every byte read is counted
+    let mut usage = MediaUsage::Post;
+    let mut file = None;
+    while let Some(field) = form.next_field().await.map_err(|e| ApiError::bad_request(e.to_string()))? {
+        match field.name() {
+            Some("usage") => {
+                let value = read_field(field, MAX_USAGE_BYTES)
+                    .await?
+                    .and_then(|bytes| String::from_utf8(bytes).ok())
+                    .ok_or_else(|| ApiError::bad_request("usage must be post or avatar"))?;
+                usage = serde_json::from_value(json!(value))
+                    .map_err(|_| ApiError::bad_request("usage must be post or avatar"))?;
+            }
+            Some("file") if file.is_none() => {
+                let file_name = field.file_name().unwrap_or("upload").to_string();
+                let bytes = read_field(field, max_bytes)
+                    .await?
+                    .ok_or_else(|| too_large(settings.max_upload_mb))?;
+                file = Some((file_name, bytes));
+            }
+            Some("file") => return Err(ApiError::bad_request("Send one file per upload")),
+            _ => return Err(ApiError::bad_request("Only `file` and `usage` fields are accepted")),
+        }
+    }
+    let (file_name, bytes) = file.ok_or_else(|| ApiError::bad_request("Send the image in a `file` field"))?;
+
+    let item = save_upload(db, user.id, MediaUpload { file_name, bytes, usage })
+        .await
+        .map_err(|e| match e {
+            UploadError::Rejected(message) => ApiError::bad_request(message),
+            UploadError::Failed => ApiError::internal("Failed to save upload"),
+        })?;
+
+    // Outside a server function `audit_entry` has no request to read, so the
+    // address and user agent come from this one
+    let meta = RequestMeta::from_parts(&headers, peer.map(|ConnectInfo(addr)| addr));
+    record_audit(
+        db,
+        AuditLog::builder(AuditAction::Create, audit_entities::MEDIA, item.id)
+            .request(&meta)
+            .user_id(user.id)
+            .after(&item)
+            .metadata(json!({ "usage": usage.as_str(), "via": "multipart" })),
+    )
+    .await;
+
+    Ok(Json(item))
+}
+
+/// Stored files never change (they're named by content hash), so they can be cached forever
+async fn serve(Path(key): Path<String>) -> Response {
+    if validate_key(&key).is_err() {
+        return StatusCode::NOT_FOUND.into_response();
+    }
+    let extension = key.rsplit_once('.').map(|(_, ext)| ext).unwrap_or_default();
+
+    let storage = match get_storage().await {
+        Ok(storage) => storage,
+        Err(e) => {
+            tracing::error!("Media storage unavailable: {}", e);
+            return StatusCode::INTERNAL_SERVER_ERROR.into_response();
+        }
+    };
+
+    match storage.get(&key).await {
+        Ok(Some(bytes)) => (
+            [
+                (CONTENT_TYPE, content_type_for(extension)),
+                (CACHE_CONTROL, "public, max-age=31536000, immutable"),
+                (X_CONTENT_TYPE_OPTIONS, "nosniff"),
+            ],
+            bytes,
+        )
+            .into_response(),
+        Ok(None) => StatusCode::NOT_FOUND.into_response(),
+        Err(e) => {
+            tracing::error!("Failed to read stored file {}: {}", key, e);
+            StatusCode::INTERNAL_SERVER_ERROR.into_response()
+        }
+    }
+}
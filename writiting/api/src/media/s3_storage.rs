@@ -0,0 +1,153 @@
+use async_trait::async_trait;
+use chrono::Utc;
+use hmac::{Hmac, Mac};
+use reqwest::{Method, RequestBuilder, StatusCode};
+use sha2::{Digest, Sha256};
+
+use crate::media::storage::validate_key;
+use crate::media::{StorageBackend, StorageError};
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// Keeps files in a bucket on any S3-compatible service (AWS, MinIO, R2, ...).
+///
+/// Requests use path-style addressing (`<endpoint>/<bucket>/<key>`) and are
+/// signed with AWS Signature Version 4, so a local MinIO works as a stand-in:
+/// `S3_ENDPOINT=http://localhost:9000 S3_BUCKET=writiting`.
+pub struct S3Storage {
+    client: reqwest::Client,
+    endpoint: String,
+    bucket: String,
+    region: String,
+    access_key: String,
+    secret_key: String,
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+fn hmac(key: &[u8], data: &str) -> Vec<u8> {
+    let mut mac = HmacSha256::new_from_slice(key).expect("HMAC accepts keys of any length");
+    mac.update(data.as_bytes());
+    mac.finalize().into_bytes().to_vec()
+}
+
+impl S3Storage {
+    pub fn new(endpoint: String, bucket: String, region: String, access_key: String, secret_key: String) -> Self {
+        Self {
+            client: reqwest::Client::new(),
+            endpoint: endpoint.trim_end_matches('/').to_string(),
+            bucket,
+            region,
+            access_key,
+            secret_key,
+        }
+    }
+
+    /// A request for `key` carrying the SigV4 `Authorization` header
+    fn signed(&self, method: Method, key: &str, body: &[u8]) -> Result<RequestBuilder, StorageError> {
+        // Keys are validated to need no percent-encoding, so the path is used as-is
+        validate_key(key)?;
+        let path = format!("/{}/{}", self.bucket, key);
+        let url = reqwest::Url::parse(&format!("{}{}", self.endpoint, path))
+            .map_err(|e| StorageError::Backend(format!("Invalid S3_ENDPOINT: {}", e)))?;
+        let host = match (url.host_str(), url.port()) {
+            (Some(host), Some(port)) => format!("{}:{}", host, port),
+            (Some(host), None) => host.to_string(),
+            (None, _) => return Err(StorageError::Backend("S3_ENDPOINT has no host".into())),
+        };
+
+        let now = Utc::now();
+        let amz_date = now.format("%Y%m%dT%H%M%SZ").to_string();
+        let date = now.format("%Y%m%d").to_string();
+        let payload_hash = hex(&Sha256::digest(body));
+
+        let signed_headers = "host;x-amz-content-sha256;x-amz-date";
+        let canonical_request = format!(
+            "{}\n{}\n\nhost:{}\nx-amz-content-sha256:{}\nx-amz-date:{}\n\n{}\n{}",
+            method, path, host, payload_hash, amz_date, signed_headers, payload_hash
+        );
+        let scope = format!("{}/{}/s3/aws4_request", date, self.region);
+        let string_to_sign = format!(
+            "AWS4-HMAC-SHA256\n{}\n{}\n{}",
+            amz_date,
+            scope,
+            hex(&Sha256::digest(canonical_request.as_bytes()))
+        );
+
+        let date_key = hmac(format!("AWS4{}", self.secret_key).as_bytes(), &date);
+        let region_key = hmac(&date_key, &self.region);
+        let service_key = hmac(&region_key, "s3");
+        let signing_key = hmac(&service_key, "aws4_request");
+        let signature = hex(&hmac(&signing_key, &string_to_sign));
+
+        Ok(self
+            .client
+            .request(method, url)
+            .header("x-amz-date", amz_date)
+            .header("x-amz-content-sha256", payload_hash)
+            .header(
+                "authorization",
+                format!(
+                    "AWS4-HMAC-SHA256 Credential={}/{}, SignedHeaders={}, Signature={}",
+                    self.access_key, scope, signed_headers, signature
+                ),
+            ))
+    }
+
+    async fn send(&self, request: RequestBuilder) -> Result<reqwest::Response, StorageError> {
+        request.send().await.map_err(|e| StorageError::Backend(e.to_string()))
+    }
+}
+
+fn unexpected(response: &reqwest::Response, action: &str, key: &str) -> StorageError {
+    StorageError::Backend(format!("S3 {} of {} failed with {}", action, key, response.status()))
+}
+
+#[async_trait]
+impl StorageBackend for S3Storage {
+    async fn put(&self, key: &str, bytes: Vec<u8>, content_type: &str) -> Result<(), StorageError> {
+        let request = self
+            .signed(Method::PUT, key, &bytes)?
+            .header("content-type", content_type)
+            .body(bytes);
+        let response = self.send(request).await?;
+        if response.status().is_success() {
+            Ok(())
+        } else {
+            Err(unexpected(&response, "upload", key))
+        }
+    }
+
+    async fn get(&self, key: &str) -> Result<Option<Vec<u8>>, StorageError> {
+        let response = self.send(self.signed(Method::GET, key, b"")?).await?;
+        match response.status() {
+            StatusCode::NOT_FOUND => Ok(None),
+            status if status.is_success() => response
+                .bytes()
+                .await
+                .map(|bytes| Some(bytes.to_vec()))
+                .map_err(|e| StorageError::Backend(e.to_string())),
+            _ => Err(unexpected(&response, "download", key)),
+        }
+    }
+
+    async fn exists(&self, key: &str) -> Result<bool, StorageError> {
+        let response = self.send(self.signed(Method::HEAD, key, b"")?).await?;
+        match response.status() {
+            StatusCode::NOT_FOUND => Ok(false),
+            status if status.is_success() => Ok(true),
+            _ => Err(unexpected(&response, "lookup", key)),
+        }
+    }
+
+    async fn delete(&self, key: &str) -> Result<(), StorageError> {
+        let response = self.send(self.signed(Method::DELETE, key, b"")?).await?;
+        if response.status().is_success() || response.status() == StatusCode::NOT_FOUND {
+            Ok(())
+        } else {
+            Err(unexpected(&response, "delete", key))
+        }
+    }
+}
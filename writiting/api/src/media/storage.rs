@@ -0,0 +1,118 @@
+use std::env;
+use std::fmt;
+use std::sync::Arc;
+use async_trait::async_trait;
+use tokio::sync::OnceCell;
+
+use crate::media::{LocalStorage, S3Storage};
+
+#[derive(Debug)]
+pub enum StorageError {
+    InvalidKey(String),
+    Backend(String),
+    Io(std::io::Error),
+}
+
+impl fmt::Display for StorageError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StorageError::InvalidKey(key) => write!(f, "Invalid storage key: {}", key),
+            StorageError::Backend(e) => write!(f, "Storage backend error: {}", e),
+            StorageError::Io(e) => write!(f, "Storage I/O error: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for StorageError {}
+
+impl From<std::io::Error> for StorageError {
+    fn from(e: std::io::Error) -> Self {
+        StorageError::Io(e)
+    }
+}
+
+/// Where uploaded files live (a local directory, or an S3-compatible bucket).
+/// Keys are relative paths like `ab/ab12…ef.png`; see [`validate_key`].
+#[async_trait]
+pub trait StorageBackend: Send + Sync {
+    async fn put(&self, key: &str, bytes: Vec<u8>, content_type: &str) -> Result<(), StorageError>;
+    /// `None` if nothing is stored under `key`
+    async fn get(&self, key: &str) -> Result<Option<Vec<u8>>, StorageError>;
+    async fn exists(&self, key: &str) -> Result<bool, StorageError>;
+    /// Deleting a key that isn't there is not an error
+    async fn delete(&self, key: &str) -> Result<(), StorageError>;
+}
+
+/// Keys are `/`-separated segments of letters, digits, `.`, `_` and `-`, with no
+/// empty or `..` segments, so they are safe as file paths and need no URL encoding
+pub fn validate_key(key: &str) -> Result<(), StorageError> {
+    let valid = !key.is_empty()
+        && key.len() <= 200
+        && key.split('/').all(|segment| {
+            !segment.is_empty()
+                && segment != "."
+                && segment != ".."
+                && segment.chars().all(|c| c.is_ascii_alphanumeric() || matches!(c, '.' | '_' | '-'))
+        });
+
+    if valid {
+        Ok(())
+    } else {
+        Err(StorageError::InvalidKey(key.to_string()))
+    }
+}
+
+/// Configuration read from the environment
+#[derive(Debug, Clone)]
+pub struct StorageConfig {
+    pub backend: String,
+    pub media_dir: String,
+    pub s3_endpoint: Option<String>,
+    pub s3_bucket: Option<String>,
+    pub s3_region: String,
+    pub s3_access_key: Option<String>,
+    pub s3_secret_key: Option<String>,
+}
+
+impl StorageConfig {
+    pub fn from_env() -> Self {
+        Self {
+            backend: env::var("MEDIA_STORAGE").unwrap_or_else(|_| "local".to_string()),
+            media_dir: env::var("MEDIA_DIR").unwrap_or_else(|_| "./media".to_string()),
+            s3_endpoint: env::var("S3_ENDPOINT").ok(),
+            s3_bucket: env::var("S3_BUCKET").ok(),
+            s3_region: env::var("S3_REGION").unwrap_or_else(|_| "us-east-1".to_string()),
+            s3_access_key: env::var("S3_ACCESS_KEY_ID").ok(),
+            s3_secret_key: env::var("S3_SECRET_ACCESS_KEY").ok(),
+        }
+    }
+
+    pub fn build(&self) -> Result<Arc<dyn StorageBackend>, StorageError> {
+        match self.backend.as_str() {
+            "local" => Ok(Arc::new(LocalStorage::new(&self.media_dir))),
+            "s3" => {
+                let required = |value: &Option<String>, name: &str| {
+                    value.clone().ok_or_else(|| {
+                        StorageError::Backend(format!("{} must be set when MEDIA_STORAGE=s3", name))
+                    })
+                };
+                Ok(Arc::new(S3Storage::new(
+                    required(&self.s3_endpoint, "S3_ENDPOINT")?,
+                    required(&self.s3_bucket, "S3_BUCKET")?,
+                    self.s3_region.clone(),
+                    required(&self.s3_access_key, "S3_ACCESS_KEY_ID")?,
+                    required(&self.s3_secret_key, "S3_SECRET_ACCESS_KEY")?,
+                )))
+            }
+            other => Err(StorageError::Backend(format!("Unknown MEDIA_STORAGE: {}", other))),
+        }
+    }
+}
+
+static STORAGE: OnceCell<Arc<dyn StorageBackend>> = OnceCell::const_new();
+
+/// The configured backend, built on first use. A bad configuration is
+/// returned as an error (and tried again next time) rather than panicking.
+pub async fn get_storage() -> Result<&'static Arc<dyn StorageBackend>, StorageError> {
+    STORAGE.get_or_try_init(|| async { StorageConfig::from_env().build() }).await
+}
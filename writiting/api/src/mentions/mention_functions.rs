@@ -0,0 +1,179 @@
+use dioxus::prelude::*;
+use crate::mentions::{extract_mentions, MentionTarget};
+use crate::features::{create_notification, NotificationCreate};
+use crate::ServerFnError;
+#[cfg(feature = "server")]
+use crate::db::connection_pool::get_db;
+use sqlx::PgPool;
+use uuid::Uuid;
+use tracing::info;
+
+/// Maximum number of usernames returned for editor autocomplete
+#[cfg(feature = "server")]
+const SUGGESTION_LIMIT: i64 = 8;
+
+/// Resolves usernames against `users.username`, ignoring case.
+/// Returns `(user_id, username)` pairs for accounts that exist and are active.
+pub async fn resolve_mentions(db: &PgPool, usernames: &[String]) -> Result<Vec<(Uuid, String)>, sqlx::Error> {
+    if usernames.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let lowered: Vec<String> = usernames.iter().map(|u| u.to_lowercase()).collect();
+
+    sqlx::query_as::<_, (Uuid, String)>(
+        r#"
+        SELECT id, username FROM users
+        WHERE LOWER(username) = ANY($1) AND is_active = true
+        "#
+    )
+    .bind(&lowered)
+    .fetch_all(db)
+    .await
+}
+
+/// Parses `text` for mentions and brings the `mentions` rows for `target` in line with it.
+/// Users mentioned for the first time get a `post_mention`/`comment_mention` notification.
+pub async fn sync_mentions(
+    db: &PgPool,
+    target: MentionTarget,
+    author_id: Option<Uuid>,
+    text: &str,
+) -> Result<Vec<Uuid>, sqlx::Error> {
+    let resolved = resolve_mentions(db, &extract_mentions(text)).await?;
+    let user_ids: Vec<Uuid> = resolved
+        .iter()
+        .map(|(id, _)| *id)
+        .filter(|id| Some(*id) != author_id)
+        .collect();
+
+    let (post_id, comment_id) = match target {
+        MentionTarget::Post(id) => (Some(id), None),
+        MentionTarget::Comment(id) => (None, Some(id)),
+    };
+
+    let mut tx = db.begin().await?;
+
+    // Drop mentions that were edited out
+    sqlx::query(
+        r#"
+        DELETE FROM mentions
+        WHERE post_id IS NOT DISTINCT FROM $1
+          AND comment_id IS NOT DISTINCT FROM $2
+          AND NOT (mentioned_user_id = ANY($3))
+        "#
+    )
+    .bind(post_id)
+    .bind(comment_id)
+    .bind(&user_ids)
+    .execute(&mut *tx)
+    .await?;
+
+    let mut newly_mentioned = Vec::new();
+    for user_id in &user_ids {
+        let conflict_target = match target {
+            MentionTarget::Post(_) => "(post_id, mentioned_user_id) WHERE post_id IS NOT NULL",
+            MentionTarget::Comment(_) => "(comment_id, mentioned_user_id) WHERE comment_id IS NOT NULL",
+        };
+
+        let inserted = sqlx::query_scalar::<_, Uuid>(&format!(
+            r#"
+            INSERT INTO mentions (mentioned_user_id, author_id, post_id, comment_id)
+            VALUES ($1, $2, $3, $4)
+            ON CONFLICT {} DO NOTHING
+            RETURNING mentioned_user_id
+            "#,
+            conflict_target
+        ))
+        .bind(user_id)
+        .bind(author_id)
+        .bind(post_id)
+        .bind(comment_id)
+        .fetch_optional(&mut *tx)
+        .await?;
+
+        if let Some(id) = inserted {
+            newly_mentioned.push(id);
+        }
+    }
+
+    for user_id in &newly_mentioned {
+        let notification = NotificationCreate {
+            user_id: *user_id,
+            notification_type: target.notification_type().to_string(),
+            content: serde_json::json!({
+                "post_id": post_id,
+                "comment_id": comment_id,
+                "author_id": author_id,
+                "excerpt": text.chars().take(140).collect::<String>(),
+            }),
+        };
+        create_notification(&mut *tx, &notification).await?;
+    }
+
+    tx.commit().await?;
+
+    if !newly_mentioned.is_empty() {
+        info!("Recorded {} new mention(s) for {:?}", newly_mentioned.len(), target);
+    }
+    Ok(newly_mentioned)
+}
+
+/// Username autocomplete for the editor
+#[server]
+pub async fn search_usernames(prefix: String) -> Result<Vec<String>, ServerFnError> {
+    let db = get_db().await;
+
+    let prefix = prefix.trim().trim_start_matches('@').to_lowercase();
+    if prefix.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    // Escape LIKE wildcards so `_` in a username is matched literally
+    let pattern = format!("{}%", prefix.replace('\\', "\\\\").replace('%', "\\%").replace('_', "\\_"));
+
+    sqlx::query_scalar::<_, String>(
+        r#"
+        SELECT username FROM users
+        WHERE LOWER(username) LIKE $1 AND is_active = true
+        ORDER BY LENGTH(username), username
+        LIMIT $2
+        "#
+    )
+    .bind(pattern)
+    .bind(SUGGESTION_LIMIT)
+    .fetch_all(db)
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error searching usernames: {}", e);
+        ServerFnError::ServerError("Failed to search usernames".into())
+    })
+}
+
+/// Usernames that resolved to real accounts for a post or comment, used to render profile links
+#[server]
+pub async fn get_mentioned_usernames(target: MentionTarget) -> Result<Vec<String>, ServerFnError> {
+    let db = get_db().await;
+
+    let (post_id, comment_id) = match target {
+        MentionTarget::Post(id) => (Some(id), None),
+        MentionTarget::Comment(id) => (None, Some(id)),
+    };
+
+    sqlx::query_scalar::<_, String>(
+        r#"
+        SELECT u.username FROM mentions m
+        JOIN users u ON u.id = m.mentioned_user_id
+        WHERE m.post_id IS NOT DISTINCT FROM $1
+          AND m.comment_id IS NOT DISTINCT FROM $2
+        "#
+    )
+    .bind(post_id)
+    .bind(comment_id)
+    .fetch_all(db)
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error fetching mentions: {}", e);
+        ServerFnError::ServerError("Failed to fetch mentions".into())
+    })
+}
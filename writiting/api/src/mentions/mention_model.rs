@@ -0,0 +1,173 @@
+use uuid::Uuid;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use lazy_static::lazy_static;
+use regex::Regex;
+
+/// Matches `@username` when the `@` is not part of a word or an email address.
+pub const MENTION_REGEX_STR: &str = r"(?:^|[^A-Za-z0-9_.@])@([A-Za-z0-9_]+(?:[.\-][A-Za-z0-9_]+)*)";
+
+lazy_static! {
+    pub static ref MENTION_REGEX: Regex = Regex::new(MENTION_REGEX_STR).unwrap();
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, sqlx::FromRow)]
+pub struct Mention {
+    pub id: i32,
+    pub mentioned_user_id: Uuid,
+    pub author_id: Option<Uuid>,
+    pub post_id: Option<i32>,
+    pub comment_id: Option<i32>,
+
+    #[serde(with = "chrono::serde::ts_seconds")]
+    pub created_at: DateTime<Utc>,
+}
+
+/// Where a mention was written
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub enum MentionTarget {
+    Post(i32),
+    Comment(i32),
+}
+
+impl MentionTarget {
+    /// Value of the `notification_type` enum used for this target
+    pub fn notification_type(&self) -> &'static str {
+        match self {
+            MentionTarget::Post(_) => "post_mention",
+            MentionTarget::Comment(_) => "comment_mention",
+        }
+    }
+}
+
+/// A piece of text split around mentions, used for rendering
+#[derive(Clone, Debug, PartialEq)]
+pub enum MentionSegment {
+    Text(String),
+    Mention(String),
+}
+
+/// Returns every distinct username mentioned in `text`, in order of first appearance.
+/// Comparison is case-insensitive to match the `LOWER(username)` index.
+pub fn extract_mentions(text: &str) -> Vec<String> {
+    let mut seen: Vec<String> = Vec::new();
+
+    for caps in MENTION_REGEX.captures_iter(text) {
+        let username = &caps[1];
+        if !(3..=50).contains(&username.len()) {
+            continue;
+        }
+        if !seen.iter().any(|s| s.eq_ignore_ascii_case(username)) {
+            seen.push(username.to_string());
+        }
+    }
+
+    seen
+}
+
+/// Splits `text` into plain text and mention segments
+pub fn split_mentions(text: &str) -> Vec<MentionSegment> {
+    let mut segments = Vec::new();
+    let mut last = 0;
+
+    for caps in MENTION_REGEX.captures_iter(text) {
+        let name = caps.get(1).unwrap();
+        // The `@` sits immediately before the captured username
+        let at = name.start() - 1;
+
+        if at > last {
+            segments.push(MentionSegment::Text(text[last..at].to_string()));
+        }
+        segments.push(MentionSegment::Mention(name.as_str().to_string()));
+        last = name.end();
+    }
+
+    if last < text.len() {
+        segments.push(MentionSegment::Text(text[last..].to_string()));
+    }
+
+    segments
+}
+
+/// Finds the partially typed mention at the end of `text`, if any.
+/// Returns the byte offset of the `@` and the prefix typed so far.
+pub fn active_mention_query(text: &str) -> Option<(usize, String)> {
+    let at = text.rfind('@')?;
+    let prefix = &text[at + 1..];
+
+    let starts_word = text[..at]
+        .chars()
+        .next_back()
+        .is_none_or(|c| c.is_whitespace() || "([{\"'".contains(c));
+
+    if starts_word && prefix.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') {
+        Some((at, prefix.to_string()))
+    } else {
+        None
+    }
+}
+
+/// Replaces the partially typed mention at the end of `text` with `username`
+pub fn complete_mention(text: &str, username: &str) -> String {
+    match active_mention_query(text) {
+        Some((at, _)) => format!("{}@{} ", &text[..at], username),
+        None => text.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn extracts_distinct_mentions_in_order() {
+        assert_eq!(
+            extract_mentions("@alice and @bob, then @ALICE again"),
+            vec!["alice".to_string(), "bob".to_string()]
+        );
+        assert_eq!(extract_mentions("thanks (@jane.doe)!"), vec!["jane.doe".to_string()]);
+    }
+
+    #[test]
+    fn skips_emails_and_out_of_range_names() {
+        assert!(extract_mentions("mail me at someone@example.com").is_empty());
+        assert!(extract_mentions("word@alice").is_empty());
+        assert!(extract_mentions("@ab is too short").is_empty());
+        assert!(extract_mentions(&format!("@{}", "a".repeat(51))).is_empty());
+    }
+
+    #[test]
+    fn split_keeps_the_text_around_mentions() {
+        assert_eq!(
+            split_mentions("hi @alice, see @bob"),
+            vec![
+                MentionSegment::Text("hi ".into()),
+                MentionSegment::Mention("alice".into()),
+                MentionSegment::Text(", see ".into()),
+                MentionSegment::Mention("bob".into()),
+            ]
+        );
+        assert_eq!(split_mentions("@alice"), vec![MentionSegment::Mention("alice".into())]);
+        assert_eq!(split_mentions("no mentions"), vec![MentionSegment::Text("no mentions".into())]);
+    }
+
+    #[test]
+    fn split_rejoins_to_the_original_text() {
+        let text = "@carol: ping @dave.e and me@example.com";
+        let joined: String = split_mentions(text)
+            .into_iter()
+            .map(|segment| match segment {
+                MentionSegment::Text(text) => text,
+                MentionSegment::Mention(name) => format!("@{}", name),
+            })
+            .collect();
+        assert_eq!(joined, text);
+    }
+
+    #[test]
+    fn completes_the_mention_being_typed() {
+        assert_eq!(active_mention_query("hello @al"), Some((6, "al".to_string())));
+        assert_eq!(active_mention_query("me@al"), None);
+        assert_eq!(complete_mention("hello @al", "alice"), "hello @alice ");
+    }
+}
@@ -0,0 +1,8 @@
+pub mod mention_model;
+pub mod mention_functions;
+
+pub use mention_model::{
+    Mention, MentionSegment, MentionTarget,
+    extract_mentions, split_mentions, active_mention_query, complete_mention,
+};
+pub use mention_functions::*;
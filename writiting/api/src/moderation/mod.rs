@@ -0,0 +1,8 @@
+pub mod report_model;
+pub mod report_functions;
+
+pub use report_model::{
+    ContentReport, ModerationAction, ModerationNote, NewReport, ReportCounts, ReportFilter,
+    ReportPage, ReportReason, ReportStatus, ReportTarget,
+};
+pub use report_functions::*;
@@ -0,0 +1,498 @@
+use dioxus::prelude::*;
+#[cfg(feature = "server")]
+use serde_json::json;
+use sqlx::PgPool;
+use uuid::Uuid;
+#[cfg(feature = "server")]
+use tracing::info;
+#[cfg(feature = "server")]
+use validator::Validate;
+
+#[cfg(feature = "server")]
+use crate::db::get_db;
+#[cfg(feature = "server")]
+use crate::moderation::report_model::{CONTENT_REPORT_COLUMNS, CONTENT_REPORT_JOINS};
+use crate::moderation::{
+    ModerationAction, ModerationNote, NewReport, ReportCounts, ReportFilter, ReportPage, ReportTarget,
+};
+#[cfg(feature = "server")]
+use crate::moderation::ContentReport;
+#[cfg(feature = "server")]
+use crate::authentication::models::rate_limits;
+#[cfg(feature = "server")]
+use crate::authentication::{audit_entities, audit_entry, record_audit, AuditAction};
+
+/// Permission needed to work the moderation queue
+pub const MODERATION_PERMISSION: &str = "content:moderate";
+
+#[cfg(feature = "server")]
+const REPORT_PAGE_SIZE: i64 = 25;
+// Enough of the reported text for a moderator to see what it was about
+const SNAPSHOT_CHARS: usize = 2000;
+
+/// Author and current text of a reportable item, or `None` if it doesn't exist
+pub async fn report_target_details(
+    db: &PgPool,
+    target_type: ReportTarget,
+    target_id: &str,
+) -> Result<Option<(Uuid, String)>, sqlx::Error> {
+    let row = match target_type {
+        ReportTarget::Post => {
+            let Ok(id) = target_id.parse::<i32>() else {
+                return Ok(None);
+            };
+            sqlx::query_as::<_, (Uuid, String)>(
+                "SELECT user_id, title || E'\\n\\n' || body FROM posts WHERE id = $1 AND is_published = true"
+            )
+            .bind(id)
+            .fetch_optional(db)
+            .await?
+        }
+        ReportTarget::Comment => {
+            let Ok(id) = target_id.parse::<i32>() else {
+                return Ok(None);
+            };
+            sqlx::query_as::<_, (Uuid, String)>(
+                "SELECT user_id, content FROM post_comments WHERE id = $1 AND is_deleted = false"
+            )
+            .bind(id)
+            .fetch_optional(db)
+            .await?
+        }
+        ReportTarget::Profile => {
+            let Ok(id) = target_id.parse::<Uuid>() else {
+                return Ok(None);
+            };
+            sqlx::query_as::<_, (Uuid, String)>(
+                r#"
+                SELECT u.id, concat_ws(E'\n', u.username, NULLIF(concat_ws(' ', p.first_name, p.last_name), ''), p.bio, p.website_url)
+                FROM users u LEFT JOIN profiles p ON p.user_id = u.id
+                WHERE u.id = $1
+                "#
+            )
+            .bind(id)
+            .fetch_optional(db)
+            .await?
+        }
+    };
+
+    Ok(row.map(|(author, text)| (author, text.chars().take(SNAPSHOT_CHARS).collect())))
+}
+
+/// Loads a report for a moderator, failing with a readable message if it's gone
+#[cfg(feature = "server")]
+async fn load_report(db: &PgPool, report_id: Uuid) -> Result<ContentReport, ServerFnError> {
+    sqlx::query_as::<_, ContentReport>(&format!(
+        "SELECT {} {} WHERE r.id = $1",
+        CONTENT_REPORT_COLUMNS, CONTENT_REPORT_JOINS
+    ))
+    .bind(report_id)
+    .fetch_optional(db)
+    .await
+    .map_err(|e| -> ServerFnError {
+        tracing::error!("Database error loading report: {}", e);
+        ServerFnError::ServerError("Failed to load report".into())
+    })?
+    .ok_or_else(|| ServerFnError::Request("Report not found".into()))
+}
+
+#[cfg(feature = "server")]
+async fn insert_note<'e, E>(db: E, report_id: Uuid, author_id: Uuid, body: &str) -> Result<(), sqlx::Error>
+where
+    E: sqlx::PgExecutor<'e>,
+{
+    sqlx::query("INSERT INTO moderation_notes (report_id, author_id, body) VALUES ($1, $2, $3)")
+        .bind(report_id)
+        .bind(author_id)
+        .bind(body)
+        .execute(db)
+        .await?;
+    Ok(())
+}
+
+/// Flags a post, comment or profile for the moderators
+#[server]
+pub async fn report_content(report: NewReport) -> Result<(), ServerFnError> {
+    crate::rate_limit!(rate_limits::REPORT);
+    crate::log::require_writable().await?;
+    let db = get_db().await;
+    let user = crate::session::current_user().await?;
+    let db_error = |e: sqlx::Error| -> ServerFnError {
+        match e {
+            sqlx::Error::Database(ref err) if err.is_unique_violation() => {
+                ServerFnError::Request("You've already reported this".into())
+            }
+            e => {
+                tracing::error!("Database error saving report: {}", e);
+                ServerFnError::ServerError("Failed to send report".into())
+            }
+        }
+    };
+
+    report
+        .validate()
+        .map_err(|e| -> ServerFnError { ServerFnError::Request(e.to_string()) })?;
+
+    let (author, snapshot) = report_target_details(db, report.target_type, report.target_id.trim())
+        .await
+        .map_err(db_error)?
+        .ok_or_else(|| -> ServerFnError { ServerFnError::Request("That no longer exists".into()) })?;
+
+    if author == user.id {
+        return Err(ServerFnError::Request("You can't report your own content".into()));
+    }
+
+    let details = report.details.as_deref().map(str::trim).filter(|d| !d.is_empty());
+
+    let id = sqlx::query_scalar::<_, Uuid>(
+        r#"
+        INSERT INTO content_reports (reporter_id, target_type, target_id, target_user_id, reason, details, content_snapshot)
+        VALUES ($1, $2, $3, $4, $5, $6, $7)
+        RETURNING id
+        "#
+    )
+    .bind(user.id)
+    .bind(report.target_type)
+    .bind(report.target_id.trim())
+    .bind(author)
+    .bind(report.reason)
+    .bind(details)
+    .bind(&snapshot)
+    .fetch_one(db)
+    .await
+    .map_err(db_error)?;
+
+    info!("Report {} on {} {} from {}", id, report.target_type.as_str(), report.target_id, user.id);
+    Ok(())
+}
+
+/// One page of the moderation queue, oldest open reports first
+#[server]
+pub async fn list_reports(filter: ReportFilter) -> Result<ReportPage, ServerFnError> {
+    let db = get_db().await;
+    let moderator = crate::session::require_permission(MODERATION_PERMISSION).await?;
+    let db_error = |e: sqlx::Error| -> ServerFnError {
+        tracing::error!("Database error listing reports: {}", e);
+        ServerFnError::ServerError("Failed to load reports".into())
+    };
+
+    let page = filter.page.max(1);
+    let assignee = filter.mine.then_some(moderator.id);
+
+    let where_clause = r#"
+        WHERE ($1::report_status IS NULL OR r.status = $1)
+          AND ($2::report_target IS NULL OR r.target_type = $2)
+          AND ($3::report_reason IS NULL OR r.reason = $3)
+          AND ($4::uuid IS NULL OR r.assigned_to = $4)
+    "#;
+
+    let total = sqlx::query_scalar::<_, i64>(&format!("SELECT COUNT(*) FROM content_reports r {}", where_clause))
+        .bind(filter.status)
+        .bind(filter.target_type)
+        .bind(filter.reason)
+        .bind(assignee)
+        .fetch_one(db)
+        .await
+        .map_err(db_error)?;
+
+    let reports = sqlx::query_as::<_, ContentReport>(&format!(
+        "SELECT {} {} {} ORDER BY (r.status = 'open') DESC, r.created_at LIMIT $5 OFFSET $6",
+        CONTENT_REPORT_COLUMNS, CONTENT_REPORT_JOINS, where_clause
+    ))
+    .bind(filter.status)
+    .bind(filter.target_type)
+    .bind(filter.reason)
+    .bind(assignee)
+    .bind(REPORT_PAGE_SIZE)
+    .bind((page - 1) * REPORT_PAGE_SIZE)
+    .fetch_all(db)
+    .await
+    .map_err(db_error)?;
+
+    Ok(ReportPage { reports, total, page, per_page: REPORT_PAGE_SIZE })
+}
+
+#[server]
+pub async fn get_report_counts() -> Result<ReportCounts, ServerFnError> {
+    let db = get_db().await;
+    crate::session::require_permission(MODERATION_PERMISSION).await?;
+
+    sqlx::query_as::<_, ReportCounts>(
+        r#"
+        SELECT COUNT(*) AS total,
+               COUNT(*) FILTER (WHERE status = 'open') AS open,
+               COUNT(*) FILTER (WHERE status = 'open' AND assigned_to IS NULL) AS unassigned,
+               COUNT(*) FILTER (WHERE status = 'resolved') AS resolved,
+               COUNT(*) FILTER (WHERE status = 'dismissed') AS dismissed
+        FROM content_reports
+        "#
+    )
+    .fetch_one(db)
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error counting reports: {}", e);
+        ServerFnError::ServerError("Failed to load report counts".into())
+    })
+}
+
+#[server]
+pub async fn list_moderation_notes(report_id: Uuid) -> Result<Vec<ModerationNote>, ServerFnError> {
+    let db = get_db().await;
+    crate::session::require_permission(MODERATION_PERMISSION).await?;
+
+    sqlx::query_as::<_, ModerationNote>(
+        r#"
+        SELECT n.id, n.report_id, n.author_id, u.username AS author_username, n.body, n.created_at
+        FROM moderation_notes n
+        LEFT JOIN users u ON u.id = n.author_id
+        WHERE n.report_id = $1
+        ORDER BY n.created_at
+        "#
+    )
+    .bind(report_id)
+    .fetch_all(db)
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error loading moderation notes: {}", e);
+        ServerFnError::ServerError("Failed to load notes".into())
+    })
+}
+
+#[server]
+pub async fn add_moderation_note(report_id: Uuid, body: String) -> Result<(), ServerFnError> {
+    let db = get_db().await;
+    crate::log::require_writable().await?;
+    let moderator = crate::session::require_permission(MODERATION_PERMISSION).await?;
+
+    let body = body.trim();
+    if body.is_empty() || body.chars().count() > 2000 {
+        return Err(ServerFnError::Request("Notes must be 1-2000 characters".into()));
+    }
+
+    load_report(db, report_id).await?;
+    insert_note(db, report_id, moderator.id, body).await.map_err(|e| {
+        tracing::error!("Database error adding moderation note: {}", e);
+        ServerFnError::ServerError("Failed to add note".into())
+    })
+}
+
+/// Hands an open report to a moderator, or back to the queue with `None`
+#[server]
+pub async fn assign_report(report_id: Uuid, assignee: Option<Uuid>) -> Result<(), ServerFnError> {
+    let db = get_db().await;
+    crate::log::require_writable().await?;
+    let moderator = crate::session::require_permission(MODERATION_PERMISSION).await?;
+    let db_error = |e: sqlx::Error| -> ServerFnError {
+        tracing::error!("Database error assigning report: {}", e);
+        ServerFnError::ServerError("Failed to assign report".into())
+    };
+
+    if let Some(assignee) = assignee {
+        let role_id = sqlx::query_scalar::<_, i32>("SELECT role_id FROM users WHERE id = $1 AND is_active = true")
+            .bind(assignee)
+            .fetch_optional(db)
+            .await
+            .map_err(db_error)?
+            .ok_or_else(|| -> ServerFnError { ServerFnError::Request("User not found".into()) })?;
+
+        if !crate::session::role_has_permission(db, role_id, MODERATION_PERMISSION).await.map_err(db_error)? {
+            return Err(ServerFnError::Request("That user isn't a moderator".into()));
+        }
+    }
+
+    let report = load_report(db, report_id).await?;
+    let mut tx = db.begin().await.map_err(db_error)?;
+
+    let result = sqlx::query(
+        "UPDATE content_reports SET assigned_to = $2, updated_at = NOW() WHERE id = $1 AND status = 'open'"
+    )
+    .bind(report_id)
+    .bind(assignee)
+    .execute(&mut *tx)
+    .await
+    .map_err(db_error)?;
+
+    if result.rows_affected() == 0 {
+        return Err(ServerFnError::Request("That report is already closed".into()));
+    }
+
+    record_audit(
+        &mut *tx,
+        audit_entry(AuditAction::Update, audit_entities::CONTENT_REPORT, report_id)
+            .await
+            .before(&report)
+            .metadata(json!({ "assigned_to": assignee })),
+    )
+    .await;
+
+    tx.commit().await.map_err(db_error)?;
+
+    info!("Report {} assigned to {:?} by {}", report_id, assignee, moderator.id);
+    Ok(())
+}
+
+/// Applies `action` to the reported content and closes every open report on it
+#[server]
+pub async fn resolve_report(report_id: Uuid, action: ModerationAction, note: Option<String>) -> Result<(), ServerFnError> {
+    let db = get_db().await;
+    crate::log::require_writable().await?;
+    let moderator = crate::session::require_permission(MODERATION_PERMISSION).await?;
+    let db_error = |e: sqlx::Error| -> ServerFnError {
+        tracing::error!("Database error resolving report: {}", e);
+        ServerFnError::ServerError("Failed to resolve report".into())
+    };
+
+    let report = load_report(db, report_id).await?;
+    if report.status != crate::moderation::ReportStatus::Open {
+        return Err(ServerFnError::Request("That report is already closed".into()));
+    }
+    if !ModerationAction::for_target(report.target_type).contains(&action) {
+        return Err(ServerFnError::Request(format!(
+            "You can't {} on a {}",
+            action.label().to_lowercase(),
+            report.target_type.as_str()
+        )));
+    }
+
+    // Suspending goes through the same checks as the admin users page
+    let suspend = match action {
+        ModerationAction::SuspendUser => {
+            let Some(author_id) = report.target_user_id else {
+                return Err(ServerFnError::Request("The author's account no longer exists".into()));
+            };
+            let admin = crate::session::require_permission("user:update").await?;
+            let author = crate::users::users_functions::managed_user(db, &admin, author_id).await?;
+            Some((admin, author))
+        }
+        _ => None,
+    };
+
+    // The action and the report closing commit together, or neither does
+    let mut tx = db.begin().await.map_err(db_error)?;
+
+    if let Some((admin, author)) = suspend.filter(|(_, author)| author.is_active) {
+        crate::users::users_functions::set_active_in(&mut tx, &admin, &author, false)
+            .await
+            .map_err(db_error)?;
+    }
+
+    match action {
+        ModerationAction::HideComment => {
+            let id: i32 = report.target_id.parse().map_err(|_| -> ServerFnError { ServerFnError::Request("Bad comment id".into()) })?;
+            sqlx::query("UPDATE post_comments SET is_deleted = true, updated_at = NOW() WHERE id = $1")
+                .bind(id)
+                .execute(&mut *tx)
+                .await
+                .map_err(db_error)?;
+            record_audit(
+                &mut *tx,
+                audit_entry(AuditAction::Update, audit_entities::COMMENT, id)
+                    .await
+                    .metadata(json!({ "hidden": true, "report_id": report_id })),
+            )
+            .await;
+        }
+        ModerationAction::UnpublishPost => {
+            let id: i32 = report.target_id.parse().map_err(|_| -> ServerFnError { ServerFnError::Request("Bad post id".into()) })?;
+            sqlx::query("UPDATE posts SET is_published = false WHERE id = $1")
+                .bind(id)
+                .execute(&mut *tx)
+                .await
+                .map_err(db_error)?;
+            record_audit(
+                &mut *tx,
+                audit_entry(AuditAction::Update, audit_entities::POST, id)
+                    .await
+                    .metadata(json!({ "unpublished": true, "report_id": report_id })),
+            )
+            .await;
+        }
+        ModerationAction::None | ModerationAction::SuspendUser => {}
+    }
+
+    let closed = sqlx::query_scalar::<_, Uuid>(
+        r#"
+        UPDATE content_reports
+        SET status = 'resolved', resolution = $3, resolved_by = $4, resolved_at = NOW(), updated_at = NOW()
+        WHERE target_type = $1 AND target_id = $2 AND status = 'open'
+        RETURNING id
+        "#
+    )
+    .bind(report.target_type)
+    .bind(&report.target_id)
+    .bind(action)
+    .bind(moderator.id)
+    .fetch_all(&mut *tx)
+    .await
+    .map_err(db_error)?;
+
+    if let Some(note) = note.as_deref().map(str::trim).filter(|n| !n.is_empty()) {
+        insert_note(&mut *tx, report_id, moderator.id, note).await.map_err(db_error)?;
+    }
+
+    record_audit(
+        &mut *tx,
+        audit_entry(AuditAction::Update, audit_entities::CONTENT_REPORT, report_id)
+            .await
+            .before(&report)
+            .metadata(json!({ "resolution": action, "closed_reports": closed })),
+    )
+    .await;
+
+    tx.commit().await.map_err(db_error)?;
+
+    info!("Report {} resolved with {} by {}", report_id, action.as_str(), moderator.id);
+    Ok(())
+}
+
+/// Closes every open report on the same content without acting on it
+#[server]
+pub async fn dismiss_report(report_id: Uuid, note: Option<String>) -> Result<(), ServerFnError> {
+    let db = get_db().await;
+    crate::log::require_writable().await?;
+    let moderator = crate::session::require_permission(MODERATION_PERMISSION).await?;
+    let db_error = |e: sqlx::Error| -> ServerFnError {
+        tracing::error!("Database error dismissing report: {}", e);
+        ServerFnError::ServerError("Failed to dismiss report".into())
+    };
+
+    let report = load_report(db, report_id).await?;
+    if report.status != crate::moderation::ReportStatus::Open {
+        return Err(ServerFnError::Request("That report is already closed".into()));
+    }
+
+    let mut tx = db.begin().await.map_err(db_error)?;
+
+    let closed = sqlx::query_scalar::<_, Uuid>(
+        r#"
+        UPDATE content_reports
+        SET status = 'dismissed', resolved_by = $3, resolved_at = NOW(), updated_at = NOW()
+        WHERE target_type = $1 AND target_id = $2 AND status = 'open'
+        RETURNING id
+        "#
+    )
+    .bind(report.target_type)
+    .bind(&report.target_id)
+    .bind(moderator.id)
+    .fetch_all(&mut *tx)
+    .await
+    .map_err(db_error)?;
+
+    if let Some(note) = note.as_deref().map(str::trim).filter(|n| !n.is_empty()) {
+        insert_note(&mut *tx, report_id, moderator.id, note).await.map_err(db_error)?;
+    }
+
+    record_audit(
+        &mut *tx,
+        audit_entry(AuditAction::Update, audit_entities::CONTENT_REPORT, report_id)
+            .await
+            .before(&report)
+            .metadata(json!({ "dismissed": true, "closed_reports": closed })),
+    )
+    .await;
+
+    tx.commit().await.map_err(db_error)?;
+
+    info!("Report {} dismissed by {}", report_id, moderator.id);
+    Ok(())
+}
@@ -0,0 +1,231 @@
+use uuid::Uuid;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use validator::Validate;
+
+/// Mirrors the `report_target` enum in the database
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type)]
+#[sqlx(type_name = "report_target", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum ReportTarget {
+    Post,
+    Comment,
+    Profile,
+}
+
+impl ReportTarget {
+    pub const ALL: [ReportTarget; 3] = [ReportTarget::Post, ReportTarget::Comment, ReportTarget::Profile];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ReportTarget::Post => "post",
+            ReportTarget::Comment => "comment",
+            ReportTarget::Profile => "profile",
+        }
+    }
+}
+
+/// Mirrors the `report_reason` enum in the database
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type)]
+#[sqlx(type_name = "report_reason", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum ReportReason {
+    Spam,
+    Harassment,
+    HateSpeech,
+    Violence,
+    SexualContent,
+    Misinformation,
+    Copyright,
+    Other,
+}
+
+impl ReportReason {
+    pub const ALL: [ReportReason; 8] = [
+        ReportReason::Spam,
+        ReportReason::Harassment,
+        ReportReason::HateSpeech,
+        ReportReason::Violence,
+        ReportReason::SexualContent,
+        ReportReason::Misinformation,
+        ReportReason::Copyright,
+        ReportReason::Other,
+    ];
+
+    /// Database name, e.g. `hate_speech`
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ReportReason::Spam => "spam",
+            ReportReason::Harassment => "harassment",
+            ReportReason::HateSpeech => "hate_speech",
+            ReportReason::Violence => "violence",
+            ReportReason::SexualContent => "sexual_content",
+            ReportReason::Misinformation => "misinformation",
+            ReportReason::Copyright => "copyright",
+            ReportReason::Other => "other",
+        }
+    }
+
+    /// Wording for the report form
+    pub fn label(&self) -> &'static str {
+        match self {
+            ReportReason::Spam => "Spam or advertising",
+            ReportReason::Harassment => "Harassment or bullying",
+            ReportReason::HateSpeech => "Hate speech",
+            ReportReason::Violence => "Violence or threats",
+            ReportReason::SexualContent => "Sexual content",
+            ReportReason::Misinformation => "False or misleading",
+            ReportReason::Copyright => "Copyright infringement",
+            ReportReason::Other => "Something else",
+        }
+    }
+}
+
+/// Mirrors the `report_status` enum in the database
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type)]
+#[sqlx(type_name = "report_status", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum ReportStatus {
+    Open,
+    Resolved,
+    Dismissed,
+}
+
+impl ReportStatus {
+    pub const ALL: [ReportStatus; 3] = [ReportStatus::Open, ReportStatus::Resolved, ReportStatus::Dismissed];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ReportStatus::Open => "open",
+            ReportStatus::Resolved => "resolved",
+            ReportStatus::Dismissed => "dismissed",
+        }
+    }
+}
+
+/// What a moderator did about a report; mirrors `moderation_action`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type)]
+#[sqlx(type_name = "moderation_action", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum ModerationAction {
+    /// Resolved without changing the content, e.g. after a warning
+    None,
+    HideComment,
+    UnpublishPost,
+    SuspendUser,
+}
+
+impl ModerationAction {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ModerationAction::None => "none",
+            ModerationAction::HideComment => "hide_comment",
+            ModerationAction::UnpublishPost => "unpublish_post",
+            ModerationAction::SuspendUser => "suspend_user",
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            ModerationAction::None => "No action",
+            ModerationAction::HideComment => "Hide comment",
+            ModerationAction::UnpublishPost => "Unpublish post",
+            ModerationAction::SuspendUser => "Suspend author",
+        }
+    }
+
+    /// Actions that make sense for a report on `target`
+    pub fn for_target(target: ReportTarget) -> Vec<ModerationAction> {
+        match target {
+            ReportTarget::Post => vec![ModerationAction::None, ModerationAction::UnpublishPost, ModerationAction::SuspendUser],
+            ReportTarget::Comment => vec![ModerationAction::None, ModerationAction::HideComment, ModerationAction::SuspendUser],
+            ReportTarget::Profile => vec![ModerationAction::None, ModerationAction::SuspendUser],
+        }
+    }
+}
+
+// Reporter, author and assignee names are joined in for the queue
+pub const CONTENT_REPORT_COLUMNS: &str = "r.id, r.reporter_id, reporter.username AS reporter_username, \
+    r.target_type, r.target_id, r.target_user_id, author.username AS target_username, r.reason, r.details, \
+    r.content_snapshot, r.status, r.assigned_to, assignee.username AS assignee_username, r.resolution, \
+    r.resolved_by, r.resolved_at, r.created_at";
+
+pub const CONTENT_REPORT_JOINS: &str = "FROM content_reports r \
+    LEFT JOIN users reporter ON reporter.id = r.reporter_id \
+    LEFT JOIN users author ON author.id = r.target_user_id \
+    LEFT JOIN users assignee ON assignee.id = r.assigned_to";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, FromRow)]
+pub struct ContentReport {
+    pub id: Uuid,
+    pub reporter_id: Option<Uuid>,
+    pub reporter_username: Option<String>,
+    pub target_type: ReportTarget,
+    pub target_id: String,
+    pub target_user_id: Option<Uuid>,
+    pub target_username: Option<String>,
+    pub reason: ReportReason,
+    pub details: Option<String>,
+    pub content_snapshot: Option<String>,
+    pub status: ReportStatus,
+    pub assigned_to: Option<Uuid>,
+    pub assignee_username: Option<String>,
+    pub resolution: Option<ModerationAction>,
+    pub resolved_by: Option<Uuid>,
+    pub resolved_at: Option<DateTime<Utc>>,
+    #[serde(with = "chrono::serde::ts_seconds")]
+    pub created_at: DateTime<Utc>,
+}
+
+/// A moderator's note on a report
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, FromRow)]
+pub struct ModerationNote {
+    pub id: Uuid,
+    pub report_id: Uuid,
+    pub author_id: Option<Uuid>,
+    pub author_username: Option<String>,
+    pub body: String,
+    #[serde(with = "chrono::serde::ts_seconds")]
+    pub created_at: DateTime<Utc>,
+}
+
+/// A user's report, as submitted from a post, comment or profile
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Validate)]
+pub struct NewReport {
+    pub target_type: ReportTarget,
+    /// Post or comment id, or the profile owner's user id
+    pub target_id: String,
+    pub reason: ReportReason,
+    #[validate(length(max = 1000, message = "Keep the details under 1000 characters"))]
+    pub details: Option<String>,
+}
+
+/// Filters for the moderation queue; every field is optional
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ReportFilter {
+    pub status: Option<ReportStatus>,
+    pub target_type: Option<ReportTarget>,
+    pub reason: Option<ReportReason>,
+    /// Only reports assigned to the signed-in moderator
+    pub mine: bool,
+    pub page: i64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ReportPage {
+    pub reports: Vec<ContentReport>,
+    pub total: i64,
+    pub page: i64,
+    pub per_page: i64,
+}
+
+/// Counts for the top of the reports page
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, FromRow)]
+pub struct ReportCounts {
+    pub total: i64,
+    pub open: i64,
+    pub unassigned: i64,
+    pub resolved: i64,
+    pub dismissed: i64,
+}
@@ -1,8 +1,14 @@
+use dioxus::prelude::*;
+#[cfg(feature = "server")]
+use crate::authentication::models::rate_limits;
+
+/// Sends `prompt` to the Ollama server and model set in the app settings
 pub async fn send_prompt_to_ollama(prompt: String) -> Result<String, reqwest::Error> {
+    let settings = crate::settings::app_settings().await;
     let client = reqwest::Client::new();
-    let res = client.post("http://localhost:11434/api/generate")
+    let res = client.post(format!("{}/api/generate", settings.ai_base_url))
         .json(&serde_json::json!({
-            "model": "llama3",
+            "model": settings.ai_model,
             "prompt": prompt,
             "stream": false,
         }))
@@ -11,4 +17,36 @@ pub async fn send_prompt_to_ollama(prompt: String) -> Result<String, reqwest::Er
         .text()
         .await?;
     Ok(res)
-}
\ No newline at end of file
+}
+
+/// Sends a chat prompt to the local model and returns its reply
+#[server]
+pub async fn ask_ai(prompt: String) -> Result<String, ServerFnError> {
+    crate::rate_limit!(rate_limits::AI_PROMPT);
+
+    let provider = crate::settings::app_settings().await.ai_provider;
+    if provider != crate::settings::AiProvider::Ollama {
+        return Err(ServerFnError::ServerError(format!(
+            "{} isn't available for chat yet",
+            provider.label()
+        )));
+    }
+
+    let prompt = prompt.trim().to_string();
+    if prompt.is_empty() {
+        return Err(ServerFnError::Request("Prompt cannot be empty".into()));
+    }
+
+    let body = send_prompt_to_ollama(prompt).await.map_err(|e| -> ServerFnError {
+        tracing::error!("Ollama request failed: {}", e);
+        ServerFnError::ServerError("The AI service is unavailable".into())
+    })?;
+
+    serde_json::from_str::<serde_json::Value>(&body)
+        .ok()
+        .and_then(|v| v["response"].as_str().map(str::to_string))
+        .ok_or_else(|| {
+            tracing::error!("Unexpected Ollama response: {}", body);
+            ServerFnError::ServerError("The AI service returned an invalid response".into())
+        })
+}
@@ -0,0 +1,359 @@
+//! Renders post bodies from CommonMark with the GitHub extensions (tables,
+//! footnotes, task lists, strikethrough) into sanitized HTML.
+use std::borrow::Cow;
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::sync::OnceLock;
+
+use ammonia::Builder;
+use pulldown_cmark::{html, CodeBlockKind, CowStr, Event, HeadingLevel, Options, Parser, Tag, TagEnd, TextMergeStream};
+use syntect::highlighting::{Theme, ThemeSet};
+use syntect::html::highlighted_html_for_string;
+use syntect::parsing::SyntaxSet;
+
+use crate::mentions::{split_mentions, MentionSegment};
+use crate::posts::{RenderedMarkdown, TocEntry};
+
+/// Bump when the output changes, so cached `post_html` rows are rebuilt
+pub const RENDERER_VERSION: i32 = 2;
+
+/// Put in front of every `id` in a post, so a heading can't take the id of
+/// an element on the page around it
+pub const ID_PREFIX: &str = "user-content-";
+
+const CODE_THEME: &str = "base16-ocean.dark";
+
+fn syntaxes() -> &'static SyntaxSet {
+    static SYNTAXES: OnceLock<SyntaxSet> = OnceLock::new();
+    SYNTAXES.get_or_init(SyntaxSet::load_defaults_newlines)
+}
+
+fn code_theme() -> &'static Theme {
+    static THEME: OnceLock<Theme> = OnceLock::new();
+    THEME.get_or_init(|| {
+        let mut themes = ThemeSet::load_defaults().themes;
+        themes.remove(CODE_THEME).expect("syntect ships the default code theme")
+    })
+}
+
+/// What survives sanitizing: ammonia's defaults plus what the renderer itself
+/// writes. Inline styles are limited to the colours syntax highlighting uses
+/// and table alignment. Ids get [`ID_PREFIX`], and in-page links with it.
+fn sanitizer() -> &'static Builder<'static> {
+    static SANITIZER: OnceLock<Builder<'static>> = OnceLock::new();
+    SANITIZER.get_or_init(|| {
+        let mut builder = Builder::default();
+        builder
+            .add_tags(["input"])
+            // Task list boxes only; `type` is only kept when it is `checkbox`
+            .add_tag_attributes("input", ["checked", "disabled"])
+            .add_tag_attribute_values("input", "type", ["checkbox"])
+            .set_tag_attribute_value("input", "disabled", "")
+            .add_tag_attributes("span", ["style"])
+            .add_tag_attributes("pre", ["style"])
+            .add_tag_attributes("th", ["style"])
+            .add_tag_attributes("td", ["style"])
+            .filter_style_properties(HashSet::from([
+                "color",
+                "background-color",
+                "font-weight",
+                "font-style",
+                "text-decoration",
+                "text-align",
+            ]))
+            .add_allowed_classes("a", ["heading-anchor", "mention"])
+            .add_allowed_classes("sup", ["footnote-reference", "footnote-definition-label"])
+            .add_allowed_classes("div", ["footnote-definition"])
+            .id_prefix(Some(ID_PREFIX))
+            .attribute_filter(|element, attribute, value| match value.strip_prefix('#') {
+                Some(target) if element == "a" && attribute == "href" && !target.starts_with(ID_PREFIX) => {
+                    Some(Cow::Owned(format!("#{}{}", ID_PREFIX, target)))
+                }
+                _ => Some(Cow::Borrowed(value)),
+            });
+        for tag in ["h1", "h2", "h3", "h4", "h5", "h6", "div"] {
+            builder.add_tag_attributes(tag, ["id"]);
+        }
+        builder
+    })
+}
+
+/// GitHub-style anchor for a heading: lowercase words joined by `-`
+fn slugify(text: &str) -> String {
+    let mut slug = String::new();
+    for c in text.trim().chars() {
+        if c.is_alphanumeric() {
+            slug.extend(c.to_lowercase());
+        } else if (c.is_whitespace() || c == '-' || c == '_') && !slug.ends_with('-') {
+            slug.push('-');
+        }
+    }
+    let slug = slug.trim_matches('-');
+    if slug.is_empty() { "section".to_string() } else { slug.to_string() }
+}
+
+/// `@name` in ordinary text becomes a profile link when `name` is one of `mentions`
+fn link_mentions<'a>(text: CowStr<'a>, mentions: &[String], out: &mut Vec<Event<'a>>) {
+    if mentions.is_empty() || !text.contains('@') {
+        out.push(Event::Text(text));
+        return;
+    }
+    for segment in split_mentions(&text) {
+        match segment {
+            MentionSegment::Mention(name) if mentions.iter().any(|m| m.eq_ignore_ascii_case(&name)) => {
+                // Usernames are limited to letters, digits, `_`, `.` and `-`, so need no escaping
+                out.push(Event::InlineHtml(format!("<a class=\"mention\" href=\"/u/{0}\">@{0}</a>", name).into()));
+            }
+            MentionSegment::Mention(name) => out.push(Event::Text(format!("@{}", name).into())),
+            MentionSegment::Text(text) => out.push(Event::Text(text.into())),
+        }
+    }
+}
+
+/// A fenced block in a known language becomes highlighted HTML; anything else
+/// is left to the default `<pre><code>` rendering
+fn code_block(language: String, code: String) -> Vec<Event<'static>> {
+    let highlighted = syntaxes()
+        .find_syntax_by_token(&language)
+        .filter(|_| !language.is_empty())
+        .map(|syntax| highlighted_html_for_string(&code, syntaxes(), syntax, code_theme()));
+    match highlighted {
+        Some(Ok(html)) => vec![Event::Html(html.into())],
+        failed => {
+            if let Some(Err(e)) = failed {
+                tracing::warn!("Failed to highlight a {} code block: {}", language, e);
+            }
+            vec![
+                Event::Start(Tag::CodeBlock(CodeBlockKind::Fenced(language.into()))),
+                Event::Text(code.into()),
+                Event::End(TagEnd::CodeBlock),
+            ]
+        }
+    }
+}
+
+/// A heading's events, held back until its text is known so it can get an id
+struct PendingHeading<'a> {
+    level: HeadingLevel,
+    classes: Vec<CowStr<'a>>,
+    attrs: Vec<(CowStr<'a>, Option<CowStr<'a>>)>,
+    events: Vec<Event<'a>>,
+    title: String,
+}
+
+/// Renders `body` to sanitized HTML and lists its headings. `@mentions` of
+/// names in `mentions` (the ones that resolved to accounts) become links.
+pub fn render_markdown(body: &str, mentions: &[String]) -> RenderedMarkdown {
+    let options = Options::ENABLE_TABLES
+        | Options::ENABLE_FOOTNOTES
+        | Options::ENABLE_TASKLISTS
+        | Options::ENABLE_STRIKETHROUGH
+        | Options::ENABLE_GFM;
+
+    let mut events = Vec::new();
+    let mut toc = Vec::new();
+    let mut used_ids: HashMap<String, usize> = HashMap::new();
+    let mut heading: Option<PendingHeading> = None;
+    // Language and text of the code block being read
+    let mut code: Option<(String, String)> = None;
+    let mut in_link = false;
+
+    for event in TextMergeStream::new(Parser::new_ext(body, options)) {
+        if let Some((_, text)) = code.as_mut() {
+            match event {
+                Event::Text(t) => text.push_str(&t),
+                Event::End(TagEnd::CodeBlock) => {
+                    let (language, text) = code.take().unwrap_or_default();
+                    events.extend(code_block(language, text));
+                }
+                _ => {}
+            }
+            continue;
+        }
+
+        match event {
+            Event::Start(Tag::CodeBlock(kind)) => {
+                let language = match kind {
+                    CodeBlockKind::Fenced(info) => info.split_whitespace().next().unwrap_or_default().to_string(),
+                    CodeBlockKind::Indented => String::new(),
+                };
+                code = Some((language, String::new()));
+            }
+            Event::Start(Tag::Heading { level, classes, attrs, .. }) => {
+                heading = Some(PendingHeading { level, classes, attrs, events: Vec::new(), title: String::new() });
+            }
+            Event::End(TagEnd::Heading(_)) => {
+                let Some(PendingHeading { level, classes, attrs, events: inner, title }) = heading.take() else {
+                    continue;
+                };
+                let base = slugify(&title);
+                let seen = used_ids.entry(base.clone()).or_insert(0);
+                let id = if *seen == 0 { base } else { format!("{}-{}", base, seen) };
+                *seen += 1;
+
+                toc.push(TocEntry { level: level as u8, id: format!("{}{}", ID_PREFIX, id), title: title.trim().to_string() });
+                events.push(Event::Start(Tag::Heading { level, id: Some(id.clone().into()), classes, attrs }));
+                events.extend(inner);
+                events.push(Event::InlineHtml(format!("<a class=\"heading-anchor\" href=\"#{}\">#</a>", id).into()));
+                events.push(Event::End(TagEnd::Heading(level)));
+            }
+            event => {
+                let target = match heading.as_mut() {
+                    Some(pending) => {
+                        if let Event::Text(t) | Event::Code(t) = &event {
+                            pending.title.push_str(t);
+                        }
+                        &mut pending.events
+                    }
+                    None => &mut events,
+                };
+                match event {
+                    Event::Start(Tag::Link { .. }) => {
+                        in_link = true;
+                        target.push(event);
+                    }
+                    Event::End(TagEnd::Link) => {
+                        in_link = false;
+                        target.push(event);
+                    }
+                    Event::Text(text) if !in_link => link_mentions(text, mentions, target),
+                    event => target.push(event),
+                }
+            }
+        }
+    }
+
+    let mut unsafe_html = String::with_capacity(body.len() * 3 / 2);
+    html::push_html(&mut unsafe_html, events.into_iter());
+
+    RenderedMarkdown {
+        html: sanitizer().clean(&unsafe_html).to_string(),
+        toc,
+    }
+}
+
+/// [`render_markdown`] on the blocking pool, since highlighting a long post
+/// keeps the thread busy for a while
+pub async fn render_markdown_blocking(body: String, mentions: Vec<String>) -> Result<RenderedMarkdown, tokio::task::JoinError> {
+    tokio::task::spawn_blocking(move || render_markdown(&body, &mentions)).await
+}
+
+/// Why a post body couldn't be rendered
+#[derive(Debug)]
+pub enum RenderError {
+    Database(sqlx::Error),
+    /// The rendering task panicked
+    Task(tokio::task::JoinError),
+}
+
+impl fmt::Display for RenderError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RenderError::Database(e) => write!(f, "database error: {}", e),
+            RenderError::Task(e) => write!(f, "rendering failed: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for RenderError {}
+
+impl From<sqlx::Error> for RenderError {
+    fn from(e: sqlx::Error) -> Self {
+        RenderError::Database(e)
+    }
+}
+
+impl From<tokio::task::JoinError> for RenderError {
+    fn from(e: tokio::task::JoinError) -> Self {
+        RenderError::Task(e)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn render(body: &str) -> String {
+        render_markdown(body, &[]).html
+    }
+
+    #[test]
+    fn drops_scripts() {
+        let html = render("Hello <script>alert(1)</script>\n\n<script>alert(2)</script>");
+
+        assert!(!html.contains("<script"), "{}", html);
+        assert!(!html.contains("alert"), "{}", html);
+    }
+
+    #[test]
+    fn drops_javascript_links() {
+        let html = render("[click](javascript:alert(1)) <a href=\"javascript:alert(2)\">me</a>");
+
+        assert!(!html.contains("javascript:"), "{}", html);
+        assert!(html.contains("click"), "{}", html);
+    }
+
+    #[test]
+    fn drops_event_handlers() {
+        let html = render("<img src=\"x.png\" onerror=\"alert(1)\"> <a href=\"/\" onclick=\"alert(2)\">x</a>");
+
+        assert!(!html.contains("onerror"), "{}", html);
+        assert!(!html.contains("onclick"), "{}", html);
+        assert!(html.contains("x.png"), "{}", html);
+    }
+
+    #[test]
+    fn keeps_only_allowed_style_properties() {
+        let html = render("<span style=\"color: red; position: fixed; background-image: url(x)\">x</span> <p style=\"color: red\">y</p>");
+
+        assert!(html.contains("color"), "{}", html);
+        assert!(!html.contains("position"), "{}", html);
+        assert!(!html.contains("url("), "{}", html);
+        assert!(!html.contains("<p style"), "{}", html);
+    }
+
+    #[test]
+    fn task_lists_keep_disabled_checkboxes_only() {
+        let html = render("- [x] done\n- [ ] todo\n\n<input type=\"text\" value=\"x\" onfocus=\"alert(1)\">");
+
+        assert_eq!(html.matches("type=\"checkbox\"").count(), 2, "{}", html);
+        assert_eq!(html.matches("disabled").count(), 3, "{}", html);
+        assert_eq!(html.matches("checked").count(), 1, "{}", html);
+        assert!(!html.contains("type=\"text\""), "{}", html);
+        assert!(!html.contains("value="), "{}", html);
+        assert!(!html.contains("onfocus"), "{}", html);
+    }
+
+    #[test]
+    fn heading_ids_and_anchors_are_prefixed() {
+        let rendered = render_markdown("# Intro\n\n## Intro\n\n<div id=\"main\">x</div>", &[]);
+        let html = &rendered.html;
+
+        assert!(html.contains("id=\"user-content-intro\""), "{}", html);
+        assert!(html.contains("id=\"user-content-intro-1\""), "{}", html);
+        assert!(html.contains("href=\"#user-content-intro\""), "{}", html);
+        assert!(html.contains("href=\"#user-content-intro-1\""), "{}", html);
+        assert!(html.contains("id=\"user-content-main\""), "{}", html);
+        assert!(!html.contains("id=\"intro\""), "{}", html);
+
+        let ids: Vec<&str> = rendered.toc.iter().map(|entry| entry.id.as_str()).collect();
+        assert_eq!(ids, vec!["user-content-intro", "user-content-intro-1"]);
+    }
+
+    #[test]
+    fn footnote_links_match_their_definitions() {
+        let html = render("Text[^note]\n\n[^note]: The note");
+
+        assert!(html.contains("href=\"#user-content-note\""), "{}", html);
+        assert!(html.contains("id=\"user-content-note\""), "{}", html);
+    }
+
+    #[test]
+    fn links_only_resolved_mentions() {
+        let html = render_markdown("Hi @alice and @bob", &["alice".to_string()]).html;
+
+        assert!(html.contains("<a class=\"mention\" href=\"/u/alice\""), "{}", html);
+        assert!(!html.contains("/u/bob"), "{}", html);
+        assert!(html.contains("@bob"), "{}", html);
+    }
+}
@@ -1,8 +1,14 @@
 //pg_app/server/src/lib.rs
 pub mod post_functions;  // Contains server logic (e.g., handling requests, etc.)
 pub use post_functions::{
-    create_post, get_all_posts, find_post, update_post, delete_post, get_post_count
+    create_post, get_all_posts, find_post, update_post, delete_post, get_post_count,
+    get_post_html, preview_markdown
 };
+#[cfg(feature = "server")]
+pub use post_functions::rendered_body;
+
+#[cfg(feature = "server")]
+pub mod markdown;
 
 pub mod post_model;
 pub use post_model::*;
\ No newline at end of file
@@ -1,31 +1,135 @@
 // pg_app/server/src/server_functions.rs
 use dioxus::prelude::*;
 use crate::Post;
+use crate::posts::RenderedMarkdown;
 use crate::ServerFnError;
+#[cfg(feature = "server")]
 use crate::db::connection_pool::get_db; 
+#[cfg(feature = "server")]
+use crate::mentions::{sync_mentions, MentionTarget};
+#[cfg(feature = "server")]
+use crate::authentication::{audit_entities, audit_entry, record_audit, AuditAction};
+#[cfg(feature = "server")]
+use crate::authentication::models::rate_limits;
+#[cfg(feature = "server")]
+use crate::mentions::{extract_mentions, resolve_mentions};
+#[cfg(feature = "server")]
+use crate::posts::markdown::{render_markdown_blocking, RenderError, RENDERER_VERSION};
+#[cfg(feature = "server")]
+use crate::posts::TocEntry;
+#[cfg(feature = "server")]
 use tracing::info;
 
+/// The editor's preview is refused past this many bytes
+#[cfg(feature = "server")]
+const MAX_PREVIEW_BYTES: usize = 200_000;
+
+/// Usernames mentioned in `body` that belong to accounts, for linking, sorted
+/// so the same set always compares equal
+#[cfg(feature = "server")]
+async fn mentioned_usernames(db: &sqlx::PgPool, body: &str) -> Result<Vec<String>, sqlx::Error> {
+    let mut usernames: Vec<String> = resolve_mentions(db, &extract_mentions(body))
+        .await?
+        .into_iter()
+        .map(|(_, username)| username)
+        .collect();
+    usernames.sort();
+    Ok(usernames)
+}
+
+/// `body` as HTML, from `post_html` if it was rendered from this same body,
+/// with the same mentions resolving to accounts, by this renderer version;
+/// otherwise rendered now and stored. Checking the mentions means a renamed
+/// or deleted account's link is dropped on the next view.
+#[cfg(feature = "server")]
+pub async fn rendered_body(db: &sqlx::PgPool, post_id: i32, body: &str) -> Result<RenderedMarkdown, RenderError> {
+    let mentions = mentioned_usernames(db, body).await?;
+    let cached = sqlx::query_as::<_, (String, sqlx::types::Json<Vec<TocEntry>>)>(
+        r#"
+        SELECT html, toc FROM post_html
+        WHERE post_id = $1 AND body_md5 = md5($2) AND renderer_version = $3 AND mentions = $4
+        "#
+    )
+    .bind(post_id)
+    .bind(body)
+    .bind(RENDERER_VERSION)
+    .bind(&mentions)
+    .fetch_optional(db)
+    .await?;
+    if let Some((html, toc)) = cached {
+        return Ok(RenderedMarkdown { html, toc: toc.0 });
+    }
+
+    let rendered = render_markdown_blocking(body.to_string(), mentions.clone()).await?;
+    sqlx::query(
+        r#"
+        INSERT INTO post_html (post_id, body_md5, renderer_version, mentions, html, toc)
+        VALUES ($1, md5($2), $3, $4, $5, $6)
+        ON CONFLICT (post_id) DO UPDATE SET
+            body_md5 = EXCLUDED.body_md5,
+            renderer_version = EXCLUDED.renderer_version,
+            mentions = EXCLUDED.mentions,
+            html = EXCLUDED.html,
+            toc = EXCLUDED.toc,
+            rendered_at = CURRENT_TIMESTAMP
+        "#
+    )
+    .bind(post_id)
+    .bind(body)
+    .bind(RENDERER_VERSION)
+    .bind(&mentions)
+    .bind(&rendered.html)
+    .bind(sqlx::types::Json(&rendered.toc))
+    .execute(db)
+    .await?;
+
+    Ok(rendered)
+}
+
 
 
 #[server]
 pub async fn create_post(title: String, body: String) -> Result<i32, ServerFnError> {
     let db = get_db().await;
+    crate::log::require_writable().await?;
+    let user = crate::session::current_user().await?;
     
     // Input validation
     if title.trim().is_empty() || body.trim().is_empty() {
         return Err(ServerFnError::Request("Title and body cannot be empty".into()));
     }
 
+    let visibility = crate::settings::app_settings().await.default_post_visibility;
+
     match sqlx::query!(
-        "INSERT INTO posts (title, body) VALUES ($1, $2) RETURNING id",
+        "INSERT INTO posts (user_id, title, body, is_published) VALUES ($1, $2, $3, $4) RETURNING id, user_id",
+        user.id,
         title.trim(),
-        body.trim()
+        body.trim(),
+        visibility.is_published()
     )
     .fetch_one(db)
     .await
     {
         Ok(record) => {
             info!("Created new post with ID: {}", record.id);
+            record_audit(
+                db,
+                audit_entry(AuditAction::Create, audit_entities::POST, record.id)
+                    .await
+                    .after(&serde_json::json!({
+                        "title": title.trim(),
+                        "body": body.trim(),
+                        "is_published": visibility.is_published(),
+                    })),
+            )
+            .await;
+            if let Err(e) = sync_mentions(db, MentionTarget::Post(record.id), Some(record.user_id), body.trim()).await {
+                tracing::error!("Failed to record mentions for post {}: {}", record.id, e);
+            }
+            if let Err(e) = rendered_body(db, record.id, body.trim()).await {
+                tracing::error!("Failed to render post {}: {}", record.id, e);
+            }
             Ok(record.id)
         },
         Err(e) => {
@@ -41,7 +145,7 @@ pub async fn create_post(title: String, body: String) -> Result<i32, ServerFnErr
 pub async fn get_all_posts() -> Result<Vec<Post>, ServerFnError> {
     let db = get_db().await;
 
-    let result = sqlx::query_as::<_, Post>("SELECT * FROM posts")
+    let result = sqlx::query_as::<_, Post>("SELECT * FROM posts WHERE is_published = true")
         .fetch_all(db)
         .await?;
 
@@ -56,19 +160,85 @@ pub async fn find_post(id: i32) -> Result<Post, ServerFnError> {
     let db = get_db().await;
 
     let result = sqlx::query_as::<_, Post>("SELECT * FROM posts WHERE id = $1")
+        .bind(id)
         .fetch_one(db)
         .await?;
 
+    // Unpublished posts are only visible to their author and moderators
+    if !result.is_published {
+        let user = crate::session::current_user().await.ok();
+        let is_author = match &user {
+            Some(user) => sqlx::query_scalar::<_, bool>("SELECT EXISTS(SELECT 1 FROM posts WHERE id = $1 AND user_id = $2)")
+                .bind(id)
+                .bind(user.id)
+                .fetch_one(db)
+                .await?,
+            None => false,
+        };
+        let is_moderator = match &user {
+            Some(user) => crate::session::role_has_permission(db, user.role_id, "content:moderate").await?,
+            None => false,
+        };
+        if !is_author && !is_moderator {
+            return Err(ServerFnError::Request("Post not found".into()));
+        }
+    }
+
     Ok(result)
 }
 
+/// A post's body rendered from Markdown to sanitized HTML, with its headings.
+/// Unpublished posts follow the same rules as `find_post`.
+#[server]
+pub async fn get_post_html(id: i32) -> Result<RenderedMarkdown, ServerFnError> {
+    let post = find_post(id).await?;
+    let db = get_db().await;
+
+    rendered_body(db, post.id, &post.body).await.map_err(|e| {
+        tracing::error!("Failed to render post {}: {}", id, e);
+        ServerFnError::ServerError("Failed to render post".into())
+    })
+}
+
+/// Renders Markdown the way a post would show it, for the editor's live preview.
+/// Nothing is stored.
+#[server]
+pub async fn preview_markdown(body: String) -> Result<RenderedMarkdown, ServerFnError> {
+    let db = get_db().await;
+    crate::session::current_user().await?;
+    crate::rate_limit!(rate_limits::PREVIEW);
+
+    if body.len() > MAX_PREVIEW_BYTES {
+        return Err(ServerFnError::Request("Too long to preview".into()));
+    }
 
+    let mentions = mentioned_usernames(db, &body).await.map_err(|e| -> ServerFnError {
+        tracing::error!("Database error resolving mentions for a preview: {}", e);
+        ServerFnError::ServerError("Failed to render preview".into())
+    })?;
+    render_markdown_blocking(body, mentions).await.map_err(|e| {
+        tracing::error!("Failed to render a preview: {}", e);
+        ServerFnError::ServerError("Failed to render preview".into())
+    })
+}
 
 
 
 #[server]
 pub async fn delete_post(id: i32) -> Result<(), ServerFnError> {
     let db = get_db().await;
+    crate::log::require_writable().await?;
+    let user = crate::session::current_user().await?;
+
+    let before = sqlx::query_as::<_, Post>("SELECT * FROM posts WHERE id = $1")
+        .bind(id)
+        .fetch_optional(db)
+        .await?
+        .ok_or_else(|| -> ServerFnError { ServerFnError::Request("Post not found".into()) })?;
+
+    if before.user_id != user.id && !crate::session::role_has_permission(db, user.role_id, "content:delete").await? {
+        return Err(ServerFnError::Request("You can only delete your own posts".into()));
+    }
 
     let result = sqlx::query!("DELETE FROM posts WHERE id = $1", id)
         .execute(db)
@@ -76,7 +246,10 @@ pub async fn delete_post(id: i32) -> Result<(), ServerFnError> {
 
     match result.rows_affected() {
         0 => Err(ServerFnError::Request("No rows deleted".to_string())),
-        _ => Ok(()),
+        _ => {
+            record_audit(db, audit_entry(AuditAction::Delete, audit_entities::POST, id).await.before(&before)).await;
+            Ok(())
+        }
     }
 }
 
@@ -85,12 +258,35 @@ pub async fn delete_post(id: i32) -> Result<(), ServerFnError> {
 #[server]
 pub async fn update_post(id: i32, title: String, body: String) -> Result<Post, ServerFnError> {
     let db = get_db().await;
+    crate::log::require_writable().await?;
+    let user = crate::session::current_user().await?;
     
     if title.is_empty() || body.is_empty() {
         return Err(ServerFnError::Request("Title and body cannot be empty".into()));
     }
+
+    let before = sqlx::query_as::<_, Post>("SELECT * FROM posts WHERE id = $1")
+        .bind(id)
+        .fetch_optional(db)
+        .await
+        .map_err(|e| -> ServerFnError {
+            tracing::error!("Failed to load post for update: {}", e);
+            ServerFnError::ServerError("Failed to update post".into())
+        })?
+        .ok_or_else(|| -> ServerFnError { ServerFnError::Request("Post not found".into()) })?;
+
+    let may_edit = before.user_id == user.id
+        || crate::session::role_has_permission(db, user.role_id, "content:update")
+            .await
+            .map_err(|e| -> ServerFnError {
+                tracing::error!("Failed to check permissions for post update: {}", e);
+                ServerFnError::ServerError("Failed to update post".into())
+            })?;
+    if !may_edit {
+        return Err(ServerFnError::Request("You can only edit your own posts".into()));
+    }
     
-    sqlx::query_as::<_, Post>(
+    let post = sqlx::query_as::<_, Post>(
         "UPDATE posts SET title = $1, body = $2 WHERE id = $3 RETURNING *"
     )
     .bind(title.trim())
@@ -98,10 +294,29 @@ pub async fn update_post(id: i32, title: String, body: String) -> Result<Post, S
     .bind(id)
     .fetch_one(db)
     .await
-    .map_err(|e| {
+    .map_err(|e| -> ServerFnError {
         tracing::error!("Failed to update post: {}", e);
         ServerFnError::ServerError("Failed to update post".into())
-    })
+    })?;
+
+    // The author is never notified of mentioning themselves
+    if let Err(e) = sync_mentions(db, MentionTarget::Post(post.id), Some(post.user_id), &post.body).await {
+        tracing::error!("Failed to record mentions for post {}: {}", post.id, e);
+    }
+    if let Err(e) = rendered_body(db, post.id, &post.body).await {
+        tracing::error!("Failed to render post {}: {}", post.id, e);
+    }
+
+    record_audit(
+        db,
+        audit_entry(AuditAction::Update, audit_entities::POST, post.id)
+            .await
+            .before(&before)
+            .after(&post),
+    )
+    .await;
+
+    Ok(post)
 }
 
 #[server]
@@ -1,10 +1,14 @@
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
-use validator::{Validate, ValidationErrors};
+use uuid::Uuid;
+use validator::Validate;
 
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize, sqlx::FromRow, Validate)]
 pub struct Post {
     pub id: i32,
+
+    /// The author
+    pub user_id: Uuid,
     
     #[validate(length(min = 1, max = 100, message = "Title must be 1-100 characters"))]
     pub title: String,
@@ -18,37 +22,28 @@ pub struct Post {
 
     #[serde(with = "chrono::serde::ts_seconds")]
     pub updated_at: DateTime<Utc>,
-    
-}
-
 
+    /// Cleared when a moderator takes the post down
+    #[serde(default = "default_published")]
+    pub is_published: bool,
+}
 
-impl Post {
-    pub(super) fn new(
-        title: String,
-        body: String,
-    ) -> Result<Self, ValidationErrors> {
-        let post = Self {
-            id: 0, // Temporary ID before DB insertion
-            title,
-            body,
-            created_at: Utc::now(),
-            updated_at: Utc::now(),
+fn default_published() -> bool { true }
 
-        };
-        post.validate()?;
-        Ok(post)
-    }
-     
 
-    
-    /// Updates the post content
-    pub(super) fn update_content(&mut self, title: String, body: String) -> Result<(), ValidationErrors> {
-        self.title = title;
-        self.body = body;
-        self.updated_at = Utc::now();
-        self.validate()
-    }
-    
+/// A heading in a rendered post, for its table of contents
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct TocEntry {
+    /// 1 for `#`, up to 6
+    pub level: u8,
+    /// The heading's `id`, linked as `#id`
+    pub id: String,
+    pub title: String,
+}
 
-}
\ No newline at end of file
+/// A Markdown body rendered to sanitized HTML
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct RenderedMarkdown {
+    pub html: String,
+    pub toc: Vec<TocEntry>,
+}
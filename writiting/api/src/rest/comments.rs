@@ -0,0 +1,125 @@
+use axum::extract::{Path, Query};
+use axum::http::{HeaderMap, StatusCode, Uri};
+use axum::response::{IntoResponse, Response};
+use axum::Json;
+use validator::Validate;
+
+use crate::authentication::{ApiKeyAuth, ApiScope};
+use crate::db::get_db;
+use crate::mentions::{sync_mentions, MentionTarget};
+use crate::rest::etag::json_with_etag;
+use crate::rest::models::{ApiComment, NewComment};
+use crate::rest::posts::load_post;
+use crate::rest::{ApiError, ErrorBody, PageParams};
+
+fn db_error(e: sqlx::Error) -> ApiError {
+    tracing::error!("Database error in comments API: {}", e);
+    ApiError::internal("Failed to access comments")
+}
+
+/// Comments on a post, oldest first
+#[utoipa::path(
+    get, path = "/api/v1/posts/{id}/comments", tag = "comments",
+    params(("id" = i32, Path, description = "Post id"), PageParams),
+    responses(
+        (status = 200, body = [ApiComment]),
+        (status = 404, body = ErrorBody),
+    ),
+    security(("api_key" = [])),
+)]
+pub async fn list_comments(
+    auth: ApiKeyAuth,
+    uri: Uri,
+    headers: HeaderMap,
+    Path(post_id): Path<i32>,
+    Query(page): Query<PageParams>,
+) -> Result<Response, ApiError> {
+    auth.require(ApiScope::Read)?;
+    load_post(post_id).await?;
+    let db = get_db().await;
+
+    let total = sqlx::query_scalar::<_, i64>(
+        "SELECT COUNT(*) FROM post_comments WHERE post_id = $1 AND is_deleted = false"
+    )
+    .bind(post_id)
+    .fetch_one(db)
+    .await
+    .map_err(db_error)?;
+
+    let comments = sqlx::query_as::<_, ApiComment>(
+        r#"
+        SELECT id, post_id, user_id, parent_id, content, created_at, updated_at
+        FROM post_comments
+        WHERE post_id = $1 AND is_deleted = false
+        ORDER BY created_at, id
+        LIMIT $2 OFFSET $3
+        "#
+    )
+    .bind(post_id)
+    .bind(page.per_page())
+    .bind(page.offset())
+    .fetch_all(db)
+    .await
+    .map_err(db_error)?;
+
+    json_with_etag(&headers, &comments, page.headers(&uri, total))
+}
+
+#[utoipa::path(
+    post, path = "/api/v1/posts/{id}/comments", tag = "comments",
+    params(("id" = i32, Path, description = "Post id")),
+    request_body = NewComment,
+    responses(
+        (status = 201, body = ApiComment),
+        (status = 404, body = ErrorBody),
+        (status = 422, body = ErrorBody),
+    ),
+    security(("api_key" = ["write"])),
+)]
+pub async fn create_comment(
+    auth: ApiKeyAuth,
+    Path(post_id): Path<i32>,
+    Json(input): Json<NewComment>,
+) -> Result<Response, ApiError> {
+    auth.require(ApiScope::Write)?;
+    let input = NewComment { content: input.content.trim().to_string(), parent_id: input.parent_id };
+    input.validate().map_err(ApiError::validation)?;
+    load_post(post_id).await?;
+    let db = get_db().await;
+
+    if let Some(parent_id) = input.parent_id {
+        let parent_on_post = sqlx::query_scalar::<_, bool>(
+            "SELECT EXISTS(SELECT 1 FROM post_comments WHERE id = $1 AND post_id = $2 AND is_deleted = false)"
+        )
+        .bind(parent_id)
+        .bind(post_id)
+        .fetch_one(db)
+        .await
+        .map_err(db_error)?;
+
+        if !parent_on_post {
+            return Err(ApiError::bad_request("parent_id must be a comment on the same post"));
+        }
+    }
+
+    let comment = sqlx::query_as::<_, ApiComment>(
+        r#"
+        INSERT INTO post_comments (post_id, user_id, parent_id, content)
+        VALUES ($1, $2, $3, $4)
+        RETURNING id, post_id, user_id, parent_id, content, created_at, updated_at
+        "#
+    )
+    .bind(post_id)
+    .bind(auth.user.id)
+    .bind(input.parent_id)
+    .bind(&input.content)
+    .fetch_one(db)
+    .await
+    .map_err(db_error)?;
+
+    if let Err(e) = sync_mentions(db, MentionTarget::Comment(comment.id), Some(auth.user.id), &comment.content).await {
+        tracing::error!("Failed to record mentions for comment {}: {}", comment.id, e);
+    }
+
+    Ok((StatusCode::CREATED, Json(comment)).into_response())
+}
@@ -0,0 +1,64 @@
+use axum::http::header::{ETAG, IF_MATCH, IF_NONE_MATCH};
+use axum::http::{HeaderMap, HeaderValue, StatusCode};
+use axum::response::{IntoResponse, Response};
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+
+use crate::rest::ApiError;
+
+/// Strong validator derived from the serialized body
+pub fn etag_of(bytes: &[u8]) -> String {
+    let digest = Sha256::digest(bytes);
+    let hex: String = digest.iter().take(16).map(|b| format!("{:02x}", b)).collect();
+    format!("\"{}\"", hex)
+}
+
+pub fn etag_for<T: Serialize>(value: &T) -> Result<String, ApiError> {
+    serde_json::to_vec(value)
+        .map(|bytes| etag_of(&bytes))
+        .map_err(|_| ApiError::internal("Failed to encode response"))
+}
+
+fn matches(header: Option<&HeaderValue>, etag: &str) -> bool {
+    header
+        .and_then(|v| v.to_str().ok())
+        .map(|v| v.split(',').map(str::trim).any(|tag| tag == "*" || tag == etag))
+        .unwrap_or(false)
+}
+
+/// JSON body with an `ETag`, or `304 Not Modified` when `If-None-Match` already has it
+pub fn json_with_etag<T: Serialize>(
+    request: &HeaderMap,
+    value: &T,
+    mut extra: HeaderMap,
+) -> Result<Response, ApiError> {
+    let bytes = serde_json::to_vec(value).map_err(|_| ApiError::internal("Failed to encode response"))?;
+    let etag = etag_of(&bytes);
+
+    if let Ok(value) = HeaderValue::from_str(&etag) {
+        extra.insert(ETAG, value);
+    }
+
+    if matches(request.get(IF_NONE_MATCH), &etag) {
+        return Ok((StatusCode::NOT_MODIFIED, extra).into_response());
+    }
+
+    extra.insert(
+        axum::http::header::CONTENT_TYPE,
+        HeaderValue::from_static("application/json"),
+    );
+    Ok((StatusCode::OK, extra, bytes).into_response())
+}
+
+/// Rejects a write whose `If-Match` names a different version than `current`.
+/// Requests without `If-Match` are let through.
+pub fn check_if_match(request: &HeaderMap, current: &str) -> Result<(), ApiError> {
+    match request.get(IF_MATCH) {
+        Some(_) if !matches(request.get(IF_MATCH), current) => Err(ApiError::new(
+            StatusCode::PRECONDITION_FAILED,
+            "precondition_failed",
+            "The resource has changed since it was fetched",
+        )),
+        _ => Ok(()),
+    }
+}
@@ -0,0 +1,47 @@
+//! Versioned JSON HTTP API for scripts and other tools, authenticated with API keys.
+//! The OpenAPI document is served at `/api/v1/openapi.json`.
+use axum::routing::get;
+use axum::{Json, Router};
+use utoipa::OpenApi;
+
+mod comments;
+mod error;
+mod etag;
+pub mod models;
+mod openapi;
+mod pagination;
+mod posts;
+mod reactions;
+mod search;
+mod users;
+
+pub use error::{ApiError, ErrorBody, ErrorDetail};
+pub use openapi::ApiDoc;
+pub use pagination::PageParams;
+
+/// Routes mounted under `/api/v1`
+pub fn router() -> Router {
+    let v1 = Router::new()
+        .route("/openapi.json", get(|| async { Json(ApiDoc::openapi()) }))
+        .route("/me", get(users::me))
+        .route("/users/:username", get(users::get_user))
+        .route("/posts", get(posts::list_posts).post(posts::create_post))
+        .route(
+            "/posts/:id",
+            get(posts::get_post).patch(posts::update_post).delete(posts::delete_post),
+        )
+        .route(
+            "/posts/:id/comments",
+            get(comments::list_comments).post(comments::create_comment),
+        )
+        .route(
+            "/posts/:id/reactions",
+            get(reactions::get_reactions)
+                .put(reactions::set_reaction)
+                .delete(reactions::remove_reaction),
+        )
+        .route("/search", get(search::search_posts))
+        .fallback(|| async { ApiError::not_found("No such endpoint") });
+
+    Router::new().nest("/api/v1", v1)
+}
@@ -0,0 +1,124 @@
+//! Request and response bodies of the HTTP API. These are kept apart from the
+//! app models so the public format (RFC 3339 timestamps, no internal fields)
+//! can stay stable while the app changes.
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use utoipa::{IntoParams, ToSchema};
+use uuid::Uuid;
+use validator::Validate;
+
+#[derive(Debug, Clone, Serialize, FromRow, ToSchema)]
+pub struct ApiPost {
+    pub id: i32,
+    pub user_id: Uuid,
+    pub title: String,
+    pub body: String,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct NewPost {
+    #[validate(length(min = 1, max = 100, message = "Title must be 1-100 characters"))]
+    pub title: String,
+    #[validate(length(min = 1, max = 5000, message = "Body must be 1-5000 characters"))]
+    pub body: String,
+}
+
+/// Fields left out are unchanged
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct PostUpdate {
+    #[validate(length(min = 1, max = 100, message = "Title must be 1-100 characters"))]
+    pub title: Option<String>,
+    #[validate(length(min = 1, max = 5000, message = "Body must be 1-5000 characters"))]
+    pub body: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, FromRow, ToSchema)]
+pub struct ApiComment {
+    pub id: i32,
+    pub post_id: i32,
+    pub user_id: Uuid,
+    pub parent_id: Option<i32>,
+    pub content: String,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct NewComment {
+    #[validate(length(min = 1, max = 1000, message = "Comment must be 1-1000 characters"))]
+    pub content: String,
+    pub parent_id: Option<i32>,
+}
+
+/// Mirrors the `reaction_type` enum in the database
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type, ToSchema)]
+#[sqlx(type_name = "reaction_type", rename_all = "lowercase")]
+#[serde(rename_all = "lowercase")]
+pub enum ReactionKind {
+    Like,
+    Upvote,
+    Downvote,
+    Heart,
+    Laugh,
+    Angry,
+}
+
+#[derive(Debug, Clone, Serialize, FromRow, ToSchema)]
+pub struct ReactionCount {
+    pub reaction_type: ReactionKind,
+    pub count: i64,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct PostReactions {
+    pub counts: Vec<ReactionCount>,
+    /// The key owner's own reaction, if any
+    pub mine: Option<ReactionKind>,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct SetReaction {
+    pub reaction_type: ReactionKind,
+}
+
+/// Public view of a user
+#[derive(Debug, Clone, Serialize, FromRow, ToSchema)]
+pub struct ApiUser {
+    pub id: Uuid,
+    pub username: String,
+    pub first_name: Option<String>,
+    pub last_name: Option<String>,
+    pub bio: Option<String>,
+    pub avatar_url: Option<String>,
+    pub website_url: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+/// The account an API key belongs to
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct ApiAccount {
+    pub id: Uuid,
+    pub username: String,
+    pub email: String,
+    pub email_verified: bool,
+    /// Scopes of the key making the request
+    pub scopes: Vec<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct PostFilter {
+    /// Only posts by this username
+    pub author: Option<String>,
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct SearchQuery {
+    /// Words to look for in post titles and bodies
+    pub q: String,
+}
@@ -0,0 +1,48 @@
+use utoipa::openapi::security::{HttpAuthScheme, HttpBuilder, SecurityScheme};
+use utoipa::{Modify, OpenApi};
+
+use crate::rest::models::*;
+use crate::rest::{comments, posts, reactions, search, users, ErrorBody, ErrorDetail};
+
+#[derive(OpenApi)]
+#[openapi(
+    info(
+        title = "Writiting API",
+        version = "1.0.0",
+        description = "Send a personal API key as `Authorization: Bearer wk_...`. \
+            List endpoints are paginated with `page`/`per_page` and return `Link` and `X-Total-Count` headers."
+    ),
+    paths(
+        users::me,
+        users::get_user,
+        posts::list_posts,
+        posts::get_post,
+        posts::create_post,
+        posts::update_post,
+        posts::delete_post,
+        comments::list_comments,
+        comments::create_comment,
+        reactions::get_reactions,
+        reactions::set_reaction,
+        reactions::remove_reaction,
+        search::search_posts,
+    ),
+    components(schemas(
+        ApiPost, NewPost, PostUpdate, ApiComment, NewComment, ReactionKind, ReactionCount,
+        PostReactions, SetReaction, ApiUser, ApiAccount, ErrorBody, ErrorDetail,
+    )),
+    modifiers(&ApiKeySecurity),
+)]
+pub struct ApiDoc;
+
+struct ApiKeySecurity;
+
+impl Modify for ApiKeySecurity {
+    fn modify(&self, openapi: &mut utoipa::openapi::OpenApi) {
+        let components = openapi.components.get_or_insert_with(Default::default);
+        components.add_security_scheme(
+            "api_key",
+            SecurityScheme::Http(HttpBuilder::new().scheme(HttpAuthScheme::Bearer).build()),
+        );
+    }
+}
@@ -0,0 +1,74 @@
+use axum::http::{HeaderMap, HeaderValue, Uri};
+use serde::Deserialize;
+use utoipa::IntoParams;
+
+const DEFAULT_PER_PAGE: i64 = 20;
+const MAX_PER_PAGE: i64 = 100;
+
+/// `?page=&per_page=` on list endpoints. Pages start at 1.
+#[derive(Debug, Clone, Copy, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct PageParams {
+    pub page: Option<i64>,
+    /// Items per page, at most 100
+    pub per_page: Option<i64>,
+}
+
+impl PageParams {
+    pub fn page(&self) -> i64 {
+        self.page.unwrap_or(1).max(1)
+    }
+
+    pub fn per_page(&self) -> i64 {
+        self.per_page.unwrap_or(DEFAULT_PER_PAGE).clamp(1, MAX_PER_PAGE)
+    }
+
+    pub fn offset(&self) -> i64 {
+        (self.page() - 1) * self.per_page()
+    }
+
+    /// `X-Total-Count` and an RFC 8288 `Link` header with first/prev/next/last
+    pub fn headers(&self, uri: &Uri, total: i64) -> HeaderMap {
+        let per_page = self.per_page();
+        let page = self.page();
+        let last = ((total + per_page - 1) / per_page).max(1);
+
+        let mut links = vec![(1, "first")];
+        if page > 1 {
+            links.push(((page - 1).min(last), "prev"));
+        }
+        if page < last {
+            links.push((page + 1, "next"));
+        }
+        links.push((last, "last"));
+
+        let link = links
+            .into_iter()
+            .map(|(p, rel)| format!("<{}>; rel=\"{}\"", page_url(uri, p, per_page), rel))
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        let mut headers = HeaderMap::new();
+        headers.insert("x-total-count", HeaderValue::from(total));
+        if let Ok(value) = HeaderValue::from_str(&link) {
+            headers.insert(axum::http::header::LINK, value);
+        }
+        headers
+    }
+}
+
+/// Same path and query with `page`/`per_page` replaced
+fn page_url(uri: &Uri, page: i64, per_page: i64) -> String {
+    let mut query: Vec<String> = uri
+        .query()
+        .unwrap_or("")
+        .split('&')
+        .filter(|pair| !pair.is_empty())
+        .filter(|pair| !pair.starts_with("page=") && !pair.starts_with("per_page="))
+        .map(str::to_string)
+        .collect();
+    query.push(format!("page={}", page));
+    query.push(format!("per_page={}", per_page));
+
+    format!("{}?{}", uri.path(), query.join("&"))
+}
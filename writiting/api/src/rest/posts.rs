@@ -0,0 +1,287 @@
+use axum::extract::{Path, Query};
+use axum::http::{HeaderMap, StatusCode, Uri};
+use axum::response::{IntoResponse, Response};
+use axum::Json;
+use validator::Validate;
+
+use crate::authentication::{audit_entities, record_audit, ApiKeyAuth, ApiScope, AuditAction, AuditLog, AuditLogBuilder};
+use crate::db::get_db;
+use crate::mentions::{sync_mentions, MentionTarget};
+use crate::posts::rendered_body;
+use crate::rest::etag::{check_if_match, etag_for, json_with_etag};
+use crate::rest::models::{ApiPost, NewPost, PostFilter, PostUpdate};
+use crate::rest::{ApiError, ErrorBody, PageParams};
+use crate::session::role_has_permission;
+
+const POST_COLUMNS: &str = "id, user_id, title, body, created_at, updated_at";
+
+fn db_error(e: sqlx::Error) -> ApiError {
+    tracing::error!("Database error in posts API: {}", e);
+    ApiError::internal("Failed to access posts")
+}
+
+/// A post that is published, for routes that hang things off one
+pub(crate) async fn load_post(id: i32) -> Result<ApiPost, ApiError> {
+    sqlx::query_as::<_, ApiPost>(&format!("SELECT {} FROM posts WHERE id = $1 AND is_published = true", POST_COLUMNS))
+        .bind(id)
+        .fetch_optional(get_db().await)
+        .await
+        .map_err(db_error)?
+        .ok_or_else(|| ApiError::not_found(format!("Post {} not found", id)))
+}
+
+#[derive(sqlx::FromRow)]
+struct PostRow {
+    #[sqlx(flatten)]
+    post: ApiPost,
+    is_published: bool,
+}
+
+/// Loads a post whatever its state, so long as `auth` may see it. Drafts and
+/// taken-down posts are visible to their author and moderators and look
+/// missing to everyone else.
+async fn load_visible_post(auth: &ApiKeyAuth, id: i32) -> Result<ApiPost, ApiError> {
+    let db = get_db().await;
+    let not_found = || ApiError::not_found(format!("Post {} not found", id));
+
+    let row = sqlx::query_as::<_, PostRow>(&format!("SELECT {}, is_published FROM posts WHERE id = $1", POST_COLUMNS))
+        .bind(id)
+        .fetch_optional(db)
+        .await
+        .map_err(db_error)?
+        .ok_or_else(not_found)?;
+
+    if row.is_published || row.post.user_id == auth.user.id {
+        return Ok(row.post);
+    }
+    if role_has_permission(db, auth.user.role_id, "content:moderate")
+        .await
+        .map_err(db_error)?
+    {
+        Ok(row.post)
+    } else {
+        Err(not_found())
+    }
+}
+
+/// Audit entry for a change made through the API, attributed to the key's owner
+fn api_audit(auth: &ApiKeyAuth, action: AuditAction, post_id: i32) -> AuditLogBuilder {
+    AuditLog::builder(action, audit_entities::POST, post_id)
+        .user_id(auth.user.id)
+        .request(&auth.meta)
+        .metadata(serde_json::json!({ "via": "api", "api_key": auth.key.key }))
+}
+
+/// Authors may change their own posts with a `write` key; anyone else needs
+/// an `admin` key and a role with `permission`
+async fn ensure_can_modify(auth: &ApiKeyAuth, post: &ApiPost, permission: &str) -> Result<(), ApiError> {
+    auth.require(ApiScope::Write)?;
+    if post.user_id == auth.user.id {
+        return Ok(());
+    }
+
+    auth.require(ApiScope::Admin)?;
+    if role_has_permission(get_db().await, auth.user.role_id, permission)
+        .await
+        .map_err(db_error)?
+    {
+        Ok(())
+    } else {
+        Err(ApiError::forbidden("You can only change your own posts"))
+    }
+}
+
+/// List posts, newest first
+#[utoipa::path(
+    get, path = "/api/v1/posts", tag = "posts",
+    params(PageParams, PostFilter),
+    responses(
+        (status = 200, description = "A page of posts; see `Link` and `X-Total-Count`", body = [ApiPost]),
+        (status = 401, body = ErrorBody),
+    ),
+    security(("api_key" = [])),
+)]
+pub async fn list_posts(
+    auth: ApiKeyAuth,
+    uri: Uri,
+    headers: HeaderMap,
+    Query(page): Query<PageParams>,
+    Query(filter): Query<PostFilter>,
+) -> Result<Response, ApiError> {
+    auth.require(ApiScope::Read)?;
+    let db = get_db().await;
+
+    let total = sqlx::query_scalar::<_, i64>(
+        r#"
+        SELECT COUNT(*) FROM posts p
+        WHERE p.is_published = true
+          AND ($1::text IS NULL OR p.user_id = (SELECT id FROM users WHERE username = $1))
+        "#
+    )
+    .bind(&filter.author)
+    .fetch_one(db)
+    .await
+    .map_err(db_error)?;
+
+    let posts = sqlx::query_as::<_, ApiPost>(
+        r#"
+        SELECT p.id, p.user_id, p.title, p.body, p.created_at, p.updated_at FROM posts p
+        WHERE p.is_published = true
+          AND ($1::text IS NULL OR p.user_id = (SELECT id FROM users WHERE username = $1))
+        ORDER BY p.created_at DESC, p.id DESC
+        LIMIT $2 OFFSET $3
+        "#
+    )
+    .bind(&filter.author)
+    .bind(page.per_page())
+    .bind(page.offset())
+    .fetch_all(db)
+    .await
+    .map_err(db_error)?;
+
+    json_with_etag(&headers, &posts, page.headers(&uri, total))
+}
+
+#[utoipa::path(
+    get, path = "/api/v1/posts/{id}", tag = "posts",
+    params(("id" = i32, Path, description = "Post id")),
+    responses(
+        (status = 200, body = ApiPost),
+        (status = 304, description = "Matches `If-None-Match`"),
+        (status = 404, body = ErrorBody),
+    ),
+    security(("api_key" = [])),
+)]
+pub async fn get_post(auth: ApiKeyAuth, headers: HeaderMap, Path(id): Path<i32>) -> Result<Response, ApiError> {
+    auth.require(ApiScope::Read)?;
+    let post = load_visible_post(&auth, id).await?;
+    json_with_etag(&headers, &post, HeaderMap::new())
+}
+
+#[utoipa::path(
+    post, path = "/api/v1/posts", tag = "posts",
+    request_body = NewPost,
+    responses(
+        (status = 201, body = ApiPost),
+        (status = 422, body = ErrorBody),
+    ),
+    security(("api_key" = ["write"])),
+)]
+pub async fn create_post(auth: ApiKeyAuth, Json(input): Json<NewPost>) -> Result<Response, ApiError> {
+    auth.require(ApiScope::Write)?;
+    let input = NewPost { title: input.title.trim().to_string(), body: input.body.trim().to_string() };
+    input.validate().map_err(ApiError::validation)?;
+    let db = get_db().await;
+    let visibility = crate::settings::app_settings().await.default_post_visibility;
+
+    let post = sqlx::query_as::<_, ApiPost>(&format!(
+        "INSERT INTO posts (user_id, title, body, is_published) VALUES ($1, $2, $3, $4) RETURNING {}",
+        POST_COLUMNS
+    ))
+    .bind(auth.user.id)
+    .bind(&input.title)
+    .bind(&input.body)
+    .bind(visibility.is_published())
+    .fetch_one(db)
+    .await
+    .map_err(db_error)?;
+
+    if let Err(e) = sync_mentions(db, MentionTarget::Post(post.id), Some(post.user_id), &post.body).await {
+        tracing::error!("Failed to record mentions for post {}: {}", post.id, e);
+    }
+    if let Err(e) = rendered_body(db, post.id, &post.body).await {
+        tracing::error!("Failed to render post {}: {}", post.id, e);
+    }
+
+    record_audit(db, api_audit(&auth, AuditAction::Create, post.id).after(&post)).await;
+
+    tracing::info!("Post {} created through API key {}", post.id, auth.key.key);
+    Ok((StatusCode::CREATED, Json(post)).into_response())
+}
+
+/// Partial update. Send `If-Match` with the post's ETag to avoid overwriting someone else's edit.
+#[utoipa::path(
+    patch, path = "/api/v1/posts/{id}", tag = "posts",
+    params(("id" = i32, Path, description = "Post id")),
+    request_body = PostUpdate,
+    responses(
+        (status = 200, body = ApiPost),
+        (status = 403, body = ErrorBody),
+        (status = 404, body = ErrorBody),
+        (status = 412, description = "`If-Match` is stale", body = ErrorBody),
+    ),
+    security(("api_key" = ["write"])),
+)]
+pub async fn update_post(
+    auth: ApiKeyAuth,
+    headers: HeaderMap,
+    Path(id): Path<i32>,
+    Json(input): Json<PostUpdate>,
+) -> Result<Response, ApiError> {
+    let current = load_visible_post(&auth, id).await?;
+    ensure_can_modify(&auth, &current, "content:update").await?;
+    check_if_match(&headers, &etag_for(&current)?)?;
+
+    let input = PostUpdate {
+        title: input.title.map(|t| t.trim().to_string()),
+        body: input.body.map(|b| b.trim().to_string()),
+    };
+    input.validate().map_err(ApiError::validation)?;
+    let db = get_db().await;
+
+    let post = sqlx::query_as::<_, ApiPost>(&format!(
+        r#"
+        UPDATE posts SET title = COALESCE($1, title), body = COALESCE($2, body)
+        WHERE id = $3
+        RETURNING {}
+        "#,
+        POST_COLUMNS
+    ))
+    .bind(&input.title)
+    .bind(&input.body)
+    .bind(id)
+    .fetch_one(db)
+    .await
+    .map_err(db_error)?;
+
+    if input.body.is_some() {
+        if let Err(e) = sync_mentions(db, MentionTarget::Post(post.id), Some(post.user_id), &post.body).await {
+            tracing::error!("Failed to record mentions for post {}: {}", post.id, e);
+        }
+        if let Err(e) = rendered_body(db, post.id, &post.body).await {
+            tracing::error!("Failed to render post {}: {}", post.id, e);
+        }
+    }
+
+    record_audit(db, api_audit(&auth, AuditAction::Update, post.id).before(&current).after(&post)).await;
+
+    json_with_etag(&HeaderMap::new(), &post, HeaderMap::new())
+}
+
+#[utoipa::path(
+    delete, path = "/api/v1/posts/{id}", tag = "posts",
+    params(("id" = i32, Path, description = "Post id")),
+    responses(
+        (status = 204, description = "Deleted"),
+        (status = 403, body = ErrorBody),
+        (status = 404, body = ErrorBody),
+    ),
+    security(("api_key" = ["write"])),
+)]
+pub async fn delete_post(auth: ApiKeyAuth, headers: HeaderMap, Path(id): Path<i32>) -> Result<StatusCode, ApiError> {
+    let current = load_visible_post(&auth, id).await?;
+    ensure_can_modify(&auth, &current, "content:delete").await?;
+    check_if_match(&headers, &etag_for(&current)?)?;
+
+    let db = get_db().await;
+    sqlx::query("DELETE FROM posts WHERE id = $1")
+        .bind(id)
+        .execute(db)
+        .await
+        .map_err(db_error)?;
+
+    record_audit(db, api_audit(&auth, AuditAction::Delete, id).before(&current)).await;
+
+    tracing::info!("Post {} deleted through API key {}", id, auth.key.key);
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -0,0 +1,112 @@
+use axum::extract::Path;
+use axum::http::StatusCode;
+use axum::Json;
+
+use crate::authentication::{ApiKeyAuth, ApiScope};
+use crate::db::get_db;
+use crate::rest::models::{PostReactions, ReactionCount, ReactionKind, SetReaction};
+use crate::rest::posts::load_post;
+use crate::rest::{ApiError, ErrorBody};
+
+fn db_error(e: sqlx::Error) -> ApiError {
+    tracing::error!("Database error in reactions API: {}", e);
+    ApiError::internal("Failed to access reactions")
+}
+
+async fn post_reactions(auth: &ApiKeyAuth, post_id: i32) -> Result<PostReactions, ApiError> {
+    let db = get_db().await;
+
+    let counts = sqlx::query_as::<_, ReactionCount>(
+        r#"
+        SELECT reaction_type, COUNT(*) AS count FROM post_reactions
+        WHERE post_id = $1
+        GROUP BY reaction_type
+        ORDER BY count DESC
+        "#
+    )
+    .bind(post_id)
+    .fetch_all(db)
+    .await
+    .map_err(db_error)?;
+
+    let mine = sqlx::query_scalar::<_, ReactionKind>(
+        "SELECT reaction_type FROM post_reactions WHERE post_id = $1 AND user_id = $2"
+    )
+    .bind(post_id)
+    .bind(auth.user.id)
+    .fetch_optional(db)
+    .await
+    .map_err(db_error)?;
+
+    Ok(PostReactions { counts, mine })
+}
+
+#[utoipa::path(
+    get, path = "/api/v1/posts/{id}/reactions", tag = "reactions",
+    params(("id" = i32, Path, description = "Post id")),
+    responses(
+        (status = 200, body = PostReactions),
+        (status = 404, body = ErrorBody),
+    ),
+    security(("api_key" = [])),
+)]
+pub async fn get_reactions(auth: ApiKeyAuth, Path(post_id): Path<i32>) -> Result<Json<PostReactions>, ApiError> {
+    auth.require(ApiScope::Read)?;
+    load_post(post_id).await?;
+    Ok(Json(post_reactions(&auth, post_id).await?))
+}
+
+/// Sets the key owner's reaction, replacing any previous one
+#[utoipa::path(
+    put, path = "/api/v1/posts/{id}/reactions", tag = "reactions",
+    params(("id" = i32, Path, description = "Post id")),
+    request_body = SetReaction,
+    responses(
+        (status = 200, body = PostReactions),
+        (status = 404, body = ErrorBody),
+    ),
+    security(("api_key" = ["write"])),
+)]
+pub async fn set_reaction(
+    auth: ApiKeyAuth,
+    Path(post_id): Path<i32>,
+    Json(input): Json<SetReaction>,
+) -> Result<Json<PostReactions>, ApiError> {
+    auth.require(ApiScope::Write)?;
+    load_post(post_id).await?;
+
+    sqlx::query(
+        r#"
+        INSERT INTO post_reactions (user_id, post_id, reaction_type)
+        VALUES ($1, $2, $3)
+        ON CONFLICT (user_id, post_id) DO UPDATE SET reaction_type = EXCLUDED.reaction_type
+        "#
+    )
+    .bind(auth.user.id)
+    .bind(post_id)
+    .bind(input.reaction_type)
+    .execute(get_db().await)
+    .await
+    .map_err(db_error)?;
+
+    Ok(Json(post_reactions(&auth, post_id).await?))
+}
+
+#[utoipa::path(
+    delete, path = "/api/v1/posts/{id}/reactions", tag = "reactions",
+    params(("id" = i32, Path, description = "Post id")),
+    responses((status = 204, description = "Removed, or there was none")),
+    security(("api_key" = ["write"])),
+)]
+pub async fn remove_reaction(auth: ApiKeyAuth, Path(post_id): Path<i32>) -> Result<StatusCode, ApiError> {
+    auth.require(ApiScope::Write)?;
+
+    sqlx::query("DELETE FROM post_reactions WHERE user_id = $1 AND post_id = $2")
+        .bind(auth.user.id)
+        .bind(post_id)
+        .execute(get_db().await)
+        .await
+        .map_err(db_error)?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -0,0 +1,76 @@
+use axum::extract::Query;
+use axum::http::{HeaderMap, Uri};
+use axum::response::Response;
+
+use crate::authentication::{ApiKeyAuth, ApiScope};
+use crate::db::get_db;
+use crate::rest::etag::json_with_etag;
+use crate::rest::models::{ApiPost, SearchQuery};
+use crate::rest::{ApiError, ErrorBody, PageParams};
+
+/// Posts whose title or body contains every word of `q`
+#[utoipa::path(
+    get, path = "/api/v1/search", tag = "search",
+    params(SearchQuery, PageParams),
+    responses(
+        (status = 200, body = [ApiPost]),
+        (status = 400, body = ErrorBody),
+    ),
+    security(("api_key" = [])),
+)]
+pub async fn search_posts(
+    auth: ApiKeyAuth,
+    uri: Uri,
+    headers: HeaderMap,
+    Query(query): Query<SearchQuery>,
+    Query(page): Query<PageParams>,
+) -> Result<Response, ApiError> {
+    auth.require(ApiScope::Read)?;
+
+    // Escape LIKE wildcards so they match literally
+    let terms: Vec<String> = query
+        .q
+        .split_whitespace()
+        .take(10)
+        .map(|w| format!("%{}%", w.replace('\\', "\\\\").replace('%', "\\%").replace('_', "\\_")))
+        .collect();
+    if terms.is_empty() {
+        return Err(ApiError::bad_request("q must not be empty"));
+    }
+
+    let db = get_db().await;
+    let db_error = |e: sqlx::Error| {
+        tracing::error!("Database error in search API: {}", e);
+        ApiError::internal("Search failed")
+    };
+
+    let total = sqlx::query_scalar::<_, i64>(
+        r#"
+        SELECT COUNT(*) FROM posts
+        WHERE is_published = true
+          AND (SELECT bool_and(title ILIKE t OR body ILIKE t) FROM unnest($1::text[]) t)
+        "#
+    )
+    .bind(&terms)
+    .fetch_one(db)
+    .await
+    .map_err(db_error)?;
+
+    let posts = sqlx::query_as::<_, ApiPost>(
+        r#"
+        SELECT id, user_id, title, body, created_at, updated_at FROM posts
+        WHERE is_published = true
+          AND (SELECT bool_and(title ILIKE t OR body ILIKE t) FROM unnest($1::text[]) t)
+        ORDER BY created_at DESC, id DESC
+        LIMIT $2 OFFSET $3
+        "#
+    )
+    .bind(&terms)
+    .bind(page.per_page())
+    .bind(page.offset())
+    .fetch_all(db)
+    .await
+    .map_err(db_error)?;
+
+    json_with_etag(&headers, &posts, page.headers(&uri, total))
+}
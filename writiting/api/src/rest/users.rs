@@ -0,0 +1,63 @@
+use axum::extract::Path;
+use axum::http::HeaderMap;
+use axum::response::Response;
+use axum::Json;
+
+use crate::authentication::{ApiKeyAuth, ApiScope};
+use crate::db::get_db;
+use crate::rest::etag::json_with_etag;
+use crate::rest::models::{ApiAccount, ApiUser};
+use crate::rest::{ApiError, ErrorBody};
+
+/// The account the key belongs to
+#[utoipa::path(
+    get, path = "/api/v1/me", tag = "users",
+    responses(
+        (status = 200, body = ApiAccount),
+        (status = 401, body = ErrorBody),
+    ),
+    security(("api_key" = [])),
+)]
+pub async fn me(auth: ApiKeyAuth) -> Result<Json<ApiAccount>, ApiError> {
+    auth.require(ApiScope::Read)?;
+    Ok(Json(ApiAccount {
+        id: auth.user.id,
+        username: auth.user.username,
+        email: auth.user.email,
+        email_verified: auth.user.email_verified,
+        scopes: auth.key.scopes.iter().map(|s| s.as_str().to_string()).collect(),
+        created_at: auth.user.created_at,
+    }))
+}
+
+#[utoipa::path(
+    get, path = "/api/v1/users/{username}", tag = "users",
+    params(("username" = String, Path)),
+    responses(
+        (status = 200, body = ApiUser),
+        (status = 404, body = ErrorBody),
+    ),
+    security(("api_key" = [])),
+)]
+pub async fn get_user(auth: ApiKeyAuth, headers: HeaderMap, Path(username): Path<String>) -> Result<Response, ApiError> {
+    auth.require(ApiScope::Read)?;
+
+    let user = sqlx::query_as::<_, ApiUser>(
+        r#"
+        SELECT u.id, u.username, p.first_name, p.last_name, p.bio, p.avatar_url, p.website_url, u.created_at
+        FROM users u
+        LEFT JOIN profiles p ON p.user_id = u.id
+        WHERE u.username = $1 AND u.is_active = true
+        "#
+    )
+    .bind(&username)
+    .fetch_optional(get_db().await)
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error in users API: {}", e);
+        ApiError::internal("Failed to load user")
+    })?
+    .ok_or_else(|| ApiError::not_found(format!("User {} not found", username)))?;
+
+    json_with_etag(&headers, &user, HeaderMap::new())
+}
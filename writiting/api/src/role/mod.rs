@@ -1,9 +1,7 @@
-// pub mod role_model;
-// pub mod role_functions;
+pub mod role_model;
+pub mod role_functions;
 
-
-// pub use role_model::{Role, RoleWithPermissions, Permission};
-// pub use role_functions::{
-//     create_role, update_role, delete_role, list_roles, list_permissions,
-//     CreateRoleRequest, UpdateRoleRequest,
-// };
\ No newline at end of file
+pub use role_model::{
+    CreateRoleRequest, Permission, Role, RoleWithPermissions, UpdateRoleRequest, FIRST_CUSTOM_ROLE_ID,
+};
+pub use role_functions::*;
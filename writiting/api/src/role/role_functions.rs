@@ -1,235 +1,316 @@
 use dioxus::prelude::*;
-use crate::Role;
-use crate::ServerFnError;
-use crate::db::connection_pool::get_db; 
+#[cfg(feature = "server")]
+use sqlx::{PgConnection, PgPool};
+#[cfg(feature = "server")]
 use tracing::info;
-
-
-#[server]
-    pub async fn find_by_id( id: i32) -> Result<Option<RoleWithPermissions>, sqlx::Error> {
-            let db = get_db().await;
-        let role = sqlx::query_as!(
-            Role,
-            r#"
-            SELECT * FROM roles
-            WHERE id = $1
-            "#,
-            id
+#[cfg(feature = "server")]
+use validator::Validate;
+
+#[cfg(feature = "server")]
+use crate::db::get_db;
+use crate::role::role_model::{CreateRoleRequest, Permission, RoleWithPermissions, UpdateRoleRequest};
+#[cfg(feature = "server")]
+use crate::role::role_model::Role;
+#[cfg(feature = "server")]
+use crate::authentication::{audit_entities, audit_entry, record_audit, AuditAction};
+
+/// Only super admins hold the system permissions, and with them the roles
+pub const ROLES_PERMISSION: &str = "system:settings";
+
+#[cfg(feature = "server")]
+async fn role_permissions<'e, E>(db: E, role_id: i32) -> Result<Vec<Permission>, sqlx::Error>
+where
+    E: sqlx::PgExecutor<'e>,
+{
+    sqlx::query_as::<_, Permission>(
+        r#"
+        SELECT p.* FROM permissions p
+        JOIN role_permissions rp ON p.id = rp.permission_id
+        WHERE rp.role_id = $1
+        ORDER BY p.code
+        "#
+    )
+    .bind(role_id)
+    .fetch_all(db)
+    .await
+}
+
+#[cfg(feature = "server")]
+async fn load_role(db: &mut PgConnection, id: i32) -> Result<Option<RoleWithPermissions>, sqlx::Error> {
+    let Some(role) = sqlx::query_as::<_, Role>("SELECT * FROM roles WHERE id = $1")
+        .bind(id)
+        .fetch_optional(&mut *db)
+        .await?
+    else {
+        return Ok(None);
+    };
+
+    let permissions = role_permissions(&mut *db, role.id).await?;
+    Ok(Some(RoleWithPermissions { role, permissions }))
+}
+
+#[cfg(feature = "server")]
+async fn assign_permissions(db: &mut PgConnection, role_id: i32, permission_ids: &[i32]) -> Result<(), sqlx::Error> {
+    sqlx::query(
+        r#"
+        INSERT INTO role_permissions (role_id, permission_id)
+        SELECT $1, UNNEST($2::INTEGER[])
+        ON CONFLICT DO NOTHING
+        "#
+    )
+    .bind(role_id)
+    .bind(permission_ids)
+    .execute(db)
+    .await?;
+    Ok(())
+}
+
+/// Whether `actor_role_id` holds every one of `permission_ids`, so nobody
+/// can build a role stronger than their own
+#[cfg(feature = "server")]
+async fn permissions_within(db: &PgPool, actor_role_id: i32, permission_ids: &[i32]) -> Result<bool, sqlx::Error> {
+    sqlx::query_scalar::<_, bool>(
+        r#"
+        SELECT NOT EXISTS(
+            SELECT 1 FROM UNNEST($2::INTEGER[]) AS wanted(id)
+            WHERE wanted.id NOT IN (SELECT permission_id FROM role_permissions WHERE role_id = $1)
         )
-        .fetch_optional(db)
-        .await?;
-
-        if let Some(role) = role {
-            let permissions = self.get_role_permissions(role.id).await?;
-            Ok(Some(RoleWithPermissions { role, permissions }))
-        } else {
-            Ok(None)
+        "#
+    )
+    .bind(actor_role_id)
+    .bind(permission_ids)
+    .fetch_one(db)
+    .await
+}
+
+#[cfg(feature = "server")]
+fn role_db_error(action: &'static str) -> impl Fn(sqlx::Error) -> ServerFnError {
+    move |e: sqlx::Error| -> ServerFnError {
+        match e {
+            sqlx::Error::Database(ref err) if err.is_unique_violation() => {
+                ServerFnError::Request("A role with that name already exists".into())
+            }
+            e => {
+                tracing::error!("Database error trying to {} role: {}", action, e);
+                ServerFnError::ServerError(format!("Failed to {} role", action))
+            }
         }
     }
+}
 
-    #[server]
-    pub async fn get_default_role() -> Result<Role, sqlx::Error> {
-            let db = get_db().await;
-        sqlx::query_as!(
-            Role,
-            r#"
-            SELECT * FROM roles
-            WHERE is_default = true
-            LIMIT 1
-            "#
-        )
-        .fetch_one(db)
+#[server]
+pub async fn list_roles() -> Result<Vec<RoleWithPermissions>, ServerFnError> {
+    let db = get_db().await;
+    crate::session::require_permission(ROLES_PERMISSION).await?;
+    let db_error = role_db_error("load");
+
+    let roles = sqlx::query_as::<_, Role>("SELECT * FROM roles ORDER BY id")
+        .fetch_all(db)
         .await
+        .map_err(&db_error)?;
+
+    let mut result = Vec::with_capacity(roles.len());
+    for role in roles {
+        let permissions = role_permissions(db, role.id).await.map_err(&db_error)?;
+        result.push(RoleWithPermissions { role, permissions });
     }
 
-    #[server]
-    pub async fn create_role(
-    
-        name: &str,
-        description: Option<&str>,
-        permission_ids: &[i32],
-    ) -> Result<RoleWithPermissions, sqlx::Error> {
-    let db = get_db().await;
+    Ok(result)
+}
 
-        let role = sqlx::query_as!(
-            Role,
-            r#"
-            INSERT INTO roles (name, description)
-            VALUES ($1, $2)
-            RETURNING *
-            "#,
-            name,
-            description
-        )
-        .fetch_one(&mut *db)
-        .await?;
+#[server]
+pub async fn list_permissions() -> Result<Vec<Permission>, ServerFnError> {
+    let db = get_db().await;
+    crate::session::require_permission(ROLES_PERMISSION).await?;
 
-        self.assign_permissions_to_role(&mut *db, role.id, permission_ids)
-            .await?;
+    sqlx::query_as::<_, Permission>("SELECT * FROM permissions ORDER BY id")
+        .fetch_all(db)
+        .await
+        .map_err(|e| {
+            tracing::error!("Database error listing permissions: {}", e);
+            ServerFnError::ServerError("Failed to load permissions".into())
+        })
+}
 
-        let permissions = self.get_role_permissions(role.id).await?;
+#[server]
+pub async fn create_role(request: CreateRoleRequest) -> Result<RoleWithPermissions, ServerFnError> {
+    let db = get_db().await;
+    crate::log::require_writable().await?;
+    let admin = crate::session::require_permission(ROLES_PERMISSION).await?;
+    let db_error = role_db_error("create");
 
-        tx.commit().await?;
+    request
+        .validate()
+        .map_err(|e| -> ServerFnError { ServerFnError::Request(e.to_string()) })?;
 
-        Ok(RoleWithPermissions { role, permissions })
+    if !permissions_within(db, admin.role_id, &request.permission_ids).await.map_err(&db_error)? {
+        return Err(ServerFnError::Request("You can't grant permissions you don't have".into()));
     }
 
+    let mut tx = db.begin().await.map_err(&db_error)?;
+
+    // Role ids are explicit; custom ones count up past the system range
+    let role = sqlx::query_as::<_, Role>(
+        r#"
+        INSERT INTO roles (id, name, description)
+        SELECT GREATEST(COALESCE(MAX(id) + 1, $1), $1), $2, $3 FROM roles
+        RETURNING *
+        "#
+    )
+    .bind(crate::role::FIRST_CUSTOM_ROLE_ID)
+    .bind(request.name.trim())
+    .bind(request.description.as_deref().map(str::trim).filter(|d| !d.is_empty()))
+    .fetch_one(&mut *tx)
+    .await
+    .map_err(&db_error)?;
+
+    assign_permissions(&mut tx, role.id, &request.permission_ids).await.map_err(&db_error)?;
+
+    let created = load_role(&mut tx, role.id)
+        .await
+        .map_err(&db_error)?
+        .ok_or_else(|| -> ServerFnError { ServerFnError::ServerError("Failed to create role".into()) })?;
 
-    #[server]
-    pub async fn update_role(
-        id: i32,
-        name: Option<&str>,
-        description: Option<&str>,
-        permission_ids: Option<&[i32]>,
-    ) -> Result<RoleWithPermissions, sqlx::Error> {
-    let mut db = get_db().await;
-
-        let role = if let Some(name) = name {
-            sqlx::query_as!(
-                Role,
-                r#"
-                UPDATE roles
-                SET name = $1, description = $2, updated_at = NOW()
-                WHERE id = $3
-                RETURNING *
-                "#,
-                name,
-                description,
-                id
-            )
-            .fetch_one(&mut *db)
-            .await?
-        } else {
-            sqlx::query_as!(
-                Role,
-                r#"
-                UPDATE roles
-                SET description = $1, updated_at = NOW()
-                WHERE id = $2
-                RETURNING *
-                "#,
-                description,
-                id
-            )
-            .fetch_one(&mut *db)
-            .await?
-        };
-
-        if let Some(permission_ids) = permission_ids {
-            sqlx::query!(
-                r#"
-                DELETE FROM role_permissions
-                WHERE role_id = $1
-                "#,
-                role.id
-            )
-            .execute(&mut *db)
-            .await?;
-
-            self.assign_permissions_to_role(&mut *db, role.id, permission_ids)
-                .await?;
-        }
+    record_audit(
+        &mut *tx,
+        audit_entry(AuditAction::Create, audit_entities::ROLE, role.id)
+            .await
+            .after(&created),
+    )
+    .await;
 
-        let permissions = self.get_role_permissions(role.id).await?;
+    tx.commit().await.map_err(&db_error)?;
 
-        tx.commit().await?;
+    info!("Role {} created by {}", role.id, admin.id);
+    Ok(created)
+}
 
-        Ok(RoleWithPermissions { role, permissions })
-    }
+/// Renames a role or replaces its permissions. Admins can't change their own
+/// role, nor one that holds permissions they lack.
+#[server]
+pub async fn update_role(id: i32, request: UpdateRoleRequest) -> Result<RoleWithPermissions, ServerFnError> {
+    let db = get_db().await;
+    crate::log::require_writable().await?;
+    let admin = crate::session::require_permission(ROLES_PERMISSION).await?;
+    let db_error = role_db_error("update");
 
-    #[server]
-    pub async fn delete_role(id: i32) -> Result<(), sqlx::Error> {
-            let db = get_db().await;
+    request
+        .validate()
+        .map_err(|e| -> ServerFnError { ServerFnError::Request(e.to_string()) })?;
 
-        sqlx::query!(
-            r#"
-            DELETE FROM roles
-            WHERE id = $1 AND is_default = false
-            "#,
-            id
-        )
-        .execute(db)
-        .await?;
+    if id == admin.role_id {
+        return Err(ServerFnError::Request("You can't change your own role".into()));
+    }
+    if !crate::users::role_within(db, admin.role_id, id).await.map_err(&db_error)? {
+        return Err(ServerFnError::Request("That role has permissions you don't".into()));
+    }
+    if let Some(permission_ids) = &request.permission_ids {
+        if !permissions_within(db, admin.role_id, permission_ids).await.map_err(&db_error)? {
+            return Err(ServerFnError::Request("You can't grant permissions you don't have".into()));
+        }
+    }
 
-        Ok(())
+    let mut tx = db.begin().await.map_err(&db_error)?;
+
+    let before = load_role(&mut tx, id)
+        .await
+        .map_err(&db_error)?
+        .ok_or_else(|| -> ServerFnError { ServerFnError::Request("Role not found".into()) })?;
+
+    sqlx::query(
+        r#"
+        UPDATE roles SET
+            name = COALESCE($2, name),
+            description = COALESCE($3, description),
+            updated_at = NOW()
+        WHERE id = $1
+        "#
+    )
+    .bind(id)
+    .bind(request.name.as_deref().map(str::trim))
+    .bind(request.description.as_deref().map(str::trim))
+    .execute(&mut *tx)
+    .await
+    .map_err(&db_error)?;
+
+    if let Some(permission_ids) = &request.permission_ids {
+        sqlx::query("DELETE FROM role_permissions WHERE role_id = $1")
+            .bind(id)
+            .execute(&mut *tx)
+            .await
+            .map_err(&db_error)?;
+
+        assign_permissions(&mut tx, id, permission_ids).await.map_err(&db_error)?;
     }
 
-    #[server]
-    pub async fn list_roles() -> Result<Vec<RoleWithPermissions>, sqlx::Error> {
-            let db = get_db().await;
+    let after = load_role(&mut tx, id)
+        .await
+        .map_err(&db_error)?
+        .ok_or_else(|| -> ServerFnError { ServerFnError::Request("Role not found".into()) })?;
+
+    record_audit(
+        &mut *tx,
+        audit_entry(AuditAction::Update, audit_entities::ROLE, id)
+            .await
+            .before(&before)
+            .after(&after),
+    )
+    .await;
 
-        let roles = sqlx::query_as!(
-            Role,
-            r#"
-            SELECT * FROM roles
-            ORDER BY name
-            "#
-        )
-        .fetch_all(db)
-        .await?;
+    tx.commit().await.map_err(&db_error)?;
 
-        let mut result = Vec::new();
-        for role in roles {
-            let permissions = self.get_role_permissions(role.id).await?;
-            result.push(RoleWithPermissions { role, permissions });
-        }
+    info!("Role {} updated by {}", id, admin.id);
+    Ok(after)
+}
 
-        Ok(result)
+/// Deletes a custom role nobody holds; the system roles and the default stay
+#[server]
+pub async fn delete_role(id: i32) -> Result<(), ServerFnError> {
+    let db = get_db().await;
+    crate::log::require_writable().await?;
+    let admin = crate::session::require_permission(ROLES_PERMISSION).await?;
+    let db_error = role_db_error("delete");
+
+    if !crate::users::role_within(db, admin.role_id, id).await.map_err(&db_error)? {
+        return Err(ServerFnError::Request("That role has permissions you don't".into()));
     }
 
-    #[server]
-    pub async fn list_permissions() -> Result<Vec<Permission>, sqlx::Error> {
-            let db = get_db().await;
+    let mut tx = db.begin().await.map_err(&db_error)?;
 
-        sqlx::query_as!(
-            Permission,
-            r#"
-            SELECT * FROM permissions
-            ORDER BY code
-            "#
-        )
-        .fetch_all(db)
+    let before = load_role(&mut tx, id)
         .await
+        .map_err(&db_error)?
+        .ok_or_else(|| -> ServerFnError { ServerFnError::Request("Role not found".into()) })?;
+
+    if before.role.is_system() || before.role.is_default {
+        return Err(ServerFnError::Request("Built-in roles can't be deleted".into()));
     }
 
-    #[server]
-    async fn get_role_permissions(role_id: i32) -> Result<Vec<Permission>, sqlx::Error> {
-            let db = get_db().await;
-
-        sqlx::query_as!(
-            Permission,
-            r#"
-            SELECT p.* FROM permissions p
-            JOIN role_permissions rp ON p.id = rp.permission_id
-            WHERE rp.role_id = $1
-            ORDER BY p.code
-            "#,
-            role_id
-        )
-        .fetch_all(db)
+    let in_use = sqlx::query_scalar::<_, bool>("SELECT EXISTS(SELECT 1 FROM users WHERE role_id = $1)")
+        .bind(id)
+        .fetch_one(&mut *tx)
         .await
+        .map_err(&db_error)?;
+    if in_use {
+        return Err(ServerFnError::Request("Move its users to another role first".into()));
     }
 
-    #[server]
-    async fn assign_permissions_to_role(
-        role_id: i32,
-        permission_ids: &[i32],
-    ) -> Result<(), sqlx::Error> {
-        for permission_id in permission_ids {
-                let db = get_db().await;
-
-            sqlx::query!(
-                r#"
-                INSERT INTO role_permissions (role_id, permission_id)
-                VALUES ($1, $2)
-                ON CONFLICT DO NOTHING
-                "#,
-                role_id,
-                permission_id
-            )
-            .execute(db)
-            .await?;
-        }
+    sqlx::query("DELETE FROM roles WHERE id = $1")
+        .bind(id)
+        .execute(&mut *tx)
+        .await
+        .map_err(&db_error)?;
 
-        Ok(())
-    }
+    record_audit(
+        &mut *tx,
+        audit_entry(AuditAction::Delete, audit_entities::ROLE, id)
+            .await
+            .before(&before),
+    )
+    .await;
+
+    tx.commit().await.map_err(&db_error)?;
+
+    info!("Role {} deleted by {}", id, admin.id);
+    Ok(())
+}
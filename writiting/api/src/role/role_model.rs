@@ -1,62 +1,61 @@
+use chrono::{DateTime, Utc};
 use serde::{Serialize, Deserialize};
 use validator::Validate;
-use uuid::Uuid;
-use std::collections::HashSet;
 
-#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow, Validate)]
+/// Ids below this are the system roles seeded by the roles migration
+pub const FIRST_CUSTOM_ROLE_ID: i32 = 100;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, sqlx::FromRow)]
 pub struct Role {
     pub id: i32,
-    
-    #[validate(length(min = 3, max = 50, message = "Name must be 3-50 characters"))]
     pub name: String,
-    
-    #[validate(length(max = 255, message = "Description too long"))]
     pub description: Option<String>,
-    
     pub is_default: bool,
-    pub created_at: chrono::DateTime<Utc>,
-    pub updated_at: chrono::DateTime<Utc>,
-    
-    #[serde(skip_serializing)]
-    pub permissions: Vec<Permission>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl Role {
+    pub fn is_system(&self) -> bool {
+        self.id < FIRST_CUSTOM_ROLE_ID
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow, PartialEq, Eq, Hash)]
 pub struct Permission {
     pub id: i32,
     pub code: String,
-    
-    #[validate(length(max = 255, message = "Description too long"))]
     pub description: Option<String>,
-    
-    pub created_at: chrono::DateTime<Utc>,
+    pub category: String,
+    pub created_at: DateTime<Utc>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Validate)]
 pub struct CreateRoleRequest {
-    #[validate(length(min = 3, max = 50))]
+    #[validate(length(min = 3, max = 50, message = "Name must be 3-50 characters"))]
     pub name: String,
-    
-    #[validate(length(max = 255))]
+
+    #[validate(length(max = 255, message = "Description too long"))]
     pub description: Option<String>,
-    
+
     pub permission_ids: Vec<i32>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Validate)]
 pub struct UpdateRoleRequest {
-    #[validate(length(min = 3, max = 50))]
+    #[validate(length(min = 3, max = 50, message = "Name must be 3-50 characters"))]
     pub name: Option<String>,
-    
-    #[validate(length(max = 255))]
+
+    #[validate(length(max = 255, message = "Description too long"))]
     pub description: Option<String>,
-    
+
     pub permission_ids: Option<Vec<i32>>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// A role and the permissions it grants; also the audit snapshot of a role
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct RoleWithPermissions {
     #[serde(flatten)]
     pub role: Role,
     pub permissions: Vec<Permission>,
-}
\ No newline at end of file
+}
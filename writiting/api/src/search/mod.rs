@@ -0,0 +1,8 @@
+pub mod search_model;
+pub mod search_functions;
+
+pub use search_model::{
+    SearchFilters, SearchHit, SearchKind, SearchPage, SearchSuggestion, SnippetSegment,
+    split_snippet,
+};
+pub use search_functions::*;
@@ -0,0 +1,171 @@
+use dioxus::prelude::*;
+use crate::search::{SearchFilters, SearchPage, SearchSuggestion};
+#[cfg(feature = "server")]
+use crate::search::SearchHit;
+#[cfg(feature = "server")]
+use crate::search::search_model::{HIGHLIGHT_END, HIGHLIGHT_START};
+#[cfg(feature = "server")]
+use crate::features::Language;
+#[cfg(feature = "server")]
+use crate::authentication::models::rate_limits;
+use crate::ServerFnError;
+#[cfg(feature = "server")]
+use crate::db::connection_pool::get_db;
+
+#[cfg(feature = "server")]
+const SEARCH_PAGE_SIZE: i64 = 20;
+#[cfg(feature = "server")]
+const SUGGESTION_LIMIT: i64 = 8;
+/// Longer queries are cut to this many characters
+#[cfg(feature = "server")]
+const MAX_QUERY_CHARS: usize = 200;
+
+#[cfg(feature = "server")]
+#[derive(sqlx::FromRow)]
+struct CountedHit {
+    #[sqlx(flatten)]
+    hit: SearchHit,
+    total: i64,
+}
+
+#[cfg(feature = "server")]
+fn clean_query(query: &str) -> String {
+    query.trim().chars().take(MAX_QUERY_CHARS).collect()
+}
+
+/// `%query%` for ILIKE, with the query's own wildcards escaped
+#[cfg(feature = "server")]
+fn contains_pattern(query: &str) -> String {
+    format!("%{}%", query.replace('\\', "\\\\").replace('%', "\\%").replace('_', "\\_"))
+}
+
+/// Published posts, comments on them and active users matching `query`, best
+/// first. Posts and comments match on stemmed words (title words count more),
+/// and titles and usernames also match when misspelled. `page` starts at 1.
+#[server]
+pub async fn search(query: String, filters: SearchFilters, page: i64) -> Result<SearchPage, ServerFnError> {
+    let db = get_db().await;
+    crate::rate_limit!(rate_limits::SEARCH);
+
+    let query = clean_query(&query);
+    let page = page.max(1);
+    if query.is_empty() {
+        return Ok(SearchPage { hits: Vec::new(), total: 0, page, per_page: SEARCH_PAGE_SIZE });
+    }
+    let languages: Vec<&str> = Language::ALL.iter().map(Language::code).collect();
+    let headline_options = format!(
+        "StartSel={}, StopSel={}, MaxWords=30, MinWords=12, MaxFragments=2, FragmentDelimiter=\" … \"",
+        HIGHLIGHT_START, HIGHLIGHT_END
+    );
+
+    // Snippets are only built for the page being returned, since ts_headline is slow
+    let rows = sqlx::query_as::<_, CountedHit>(
+        r#"
+        WITH q AS (
+            -- The query is stemmed once per configuration, and each post or
+            -- comment is matched against the one it was indexed with
+            SELECT config, websearch_to_tsquery(config, $1) AS query
+            FROM (SELECT DISTINCT search_config_for(code) AS config FROM UNNEST($2::text[]) AS code) configs
+        ),
+        hits AS (
+            SELECT 'post' AS kind, p.id::text AS id, p.title, p.body AS text, p.search_config AS config, q.query,
+                   p.id AS post_id, u.username AS author,
+                   (ts_rank_cd(p.search_vector, q.query) + similarity(p.title, $1))::real AS rank,
+                   p.created_at
+            FROM posts p
+            JOIN q ON q.config = p.search_config
+            JOIN users u ON u.id = p.user_id
+            WHERE p.is_published AND (p.search_vector @@ q.query OR p.title % $1)
+            UNION ALL
+            SELECT 'comment', c.id::text, p.title, c.content, c.search_config, q.query,
+                   p.id, u.username, ts_rank_cd(c.search_vector, q.query)::real, c.created_at
+            FROM post_comments c
+            JOIN q ON q.config = c.search_config
+            JOIN posts p ON p.id = c.post_id
+            LEFT JOIN users u ON u.id = c.user_id
+            WHERE NOT c.is_deleted AND p.is_published AND c.search_vector @@ q.query
+            UNION ALL
+            SELECT 'user', u.username,
+                   COALESCE(NULLIF(CONCAT_WS(' ', pr.first_name, pr.last_name), ''), u.username),
+                   LEFT(COALESCE(pr.bio, ''), 200), 'simple'::regconfig, NULL::tsquery,
+                   NULL, u.username, similarity(u.username, $1)::real, u.created_at
+            FROM users u
+            LEFT JOIN profiles pr ON pr.user_id = u.id
+            WHERE u.is_active AND (u.username % $1 OR u.username ILIKE $10)
+        ),
+        page AS (
+            SELECT *, COUNT(*) OVER () AS total FROM hits
+            WHERE ($3::text IS NULL OR kind = $3)
+              AND ($4::text IS NULL OR LOWER(author) = LOWER($4))
+              AND ($5::date IS NULL OR created_at >= $5)
+              AND ($6::date IS NULL OR created_at < $6 + 1)
+            ORDER BY rank DESC, created_at DESC
+            LIMIT $7 OFFSET $8
+        )
+        SELECT kind, id, title,
+               CASE WHEN kind = 'user' THEN clean_text
+                    ELSE ts_headline(config, clean_text, query, $9) END AS snippet,
+               post_id, author, rank, created_at, total
+        -- Stray highlight markers in the text would fake a match
+        FROM page, LATERAL (SELECT translate(text, chr(2) || chr(3), '') AS clean_text) cleaned
+        ORDER BY rank DESC, created_at DESC
+        "#
+    )
+    .bind(&query)
+    .bind(&languages)
+    .bind(filters.kind.map(|kind| kind.as_str()))
+    .bind(filters.author.as_deref().map(str::trim).filter(|a| !a.is_empty()))
+    .bind(filters.from)
+    .bind(filters.to)
+    .bind(SEARCH_PAGE_SIZE)
+    .bind((page - 1) * SEARCH_PAGE_SIZE)
+    .bind(&headline_options)
+    .bind(contains_pattern(&query))
+    .fetch_all(db)
+    .await
+    .map_err(|e| -> ServerFnError {
+        tracing::error!("Database error searching: {}", e);
+        ServerFnError::ServerError("Search failed".into())
+    })?;
+
+    let total = rows.first().map_or(0, |row| row.total);
+    let hits = rows.into_iter().map(|row| row.hit).collect();
+    Ok(SearchPage { hits, total, page, per_page: SEARCH_PAGE_SIZE })
+}
+
+/// Post titles and usernames close to what has been typed so far, typos included
+#[server]
+pub async fn search_suggestions(query: String) -> Result<Vec<SearchSuggestion>, ServerFnError> {
+    let db = get_db().await;
+    crate::rate_limit!(rate_limits::SEARCH);
+
+    let query = clean_query(&query);
+    if query.chars().count() < 2 {
+        return Ok(Vec::new());
+    }
+
+    sqlx::query_as::<_, SearchSuggestion>(
+        r#"
+        (SELECT 'post' AS kind, title AS label, '/blog/' || id AS url, similarity(title, $1) AS score
+         FROM posts
+         WHERE is_published AND (title % $1 OR title ILIKE $2)
+         ORDER BY score DESC LIMIT $3)
+        UNION ALL
+        (SELECT 'user', username, '/u/' || username, similarity(username, $1) AS score
+         FROM users
+         WHERE is_active AND (username % $1 OR username ILIKE $2)
+         ORDER BY score DESC LIMIT $3)
+        ORDER BY score DESC
+        LIMIT $3
+        "#
+    )
+    .bind(&query)
+    .bind(contains_pattern(&query))
+    .bind(SUGGESTION_LIMIT)
+    .fetch_all(db)
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error loading search suggestions: {}", e);
+        ServerFnError::ServerError("Failed to load suggestions".into())
+    })
+}
@@ -0,0 +1,108 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+
+/// `ts_headline` wraps matched words in these, and `search` removes them from
+/// the text beforehand, so the snippet can be split without rendering any HTML
+/// from the database
+pub const HIGHLIGHT_START: char = '\u{2}';
+pub const HIGHLIGHT_END: char = '\u{3}';
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, sqlx::Type)]
+#[sqlx(type_name = "text", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum SearchKind {
+    Post,
+    Comment,
+    User,
+}
+
+impl SearchKind {
+    pub const ALL: [SearchKind; 3] = [SearchKind::Post, SearchKind::Comment, SearchKind::User];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SearchKind::Post => "post",
+            SearchKind::Comment => "comment",
+            SearchKind::User => "user",
+        }
+    }
+}
+
+/// Narrows a search; every field is optional
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SearchFilters {
+    pub kind: Option<SearchKind>,
+    /// Username of the author, or of the account for user hits
+    pub author: Option<String>,
+    /// Inclusive start date (UTC)
+    pub from: Option<NaiveDate>,
+    /// Inclusive end date (UTC)
+    pub to: Option<NaiveDate>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, FromRow)]
+pub struct SearchHit {
+    pub kind: SearchKind,
+    /// Post or comment id, or the username for user hits
+    pub id: String,
+    /// The post's title (for comments, the post they're on) or the user's name
+    pub title: String,
+    /// Matched text with [`HIGHLIGHT_START`]/[`HIGHLIGHT_END`] around the hits
+    pub snippet: String,
+    pub post_id: Option<i32>,
+    pub author: Option<String>,
+    pub rank: f32,
+    #[serde(with = "chrono::serde::ts_seconds")]
+    pub created_at: DateTime<Utc>,
+}
+
+/// A run of snippet text, highlighted if it matched the query
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SnippetSegment {
+    pub text: String,
+    pub highlighted: bool,
+}
+
+/// Splits a `ts_headline` snippet into plain and highlighted runs
+pub fn split_snippet(snippet: &str) -> Vec<SnippetSegment> {
+    let mut segments = Vec::new();
+    let mut highlighted = false;
+    for part in snippet.split([HIGHLIGHT_START, HIGHLIGHT_END]) {
+        if !part.is_empty() {
+            segments.push(SnippetSegment { text: part.to_string(), highlighted });
+        }
+        highlighted = !highlighted;
+    }
+    segments
+}
+
+impl SearchHit {
+    pub fn url(&self) -> String {
+        match self.kind {
+            SearchKind::Post => format!("/blog/{}", self.id),
+            SearchKind::Comment => format!("/blog/{}#comment-{}", self.post_id.unwrap_or_default(), self.id),
+            SearchKind::User => format!("/u/{}", self.id),
+        }
+    }
+
+    pub fn snippet_segments(&self) -> Vec<SnippetSegment> {
+        split_snippet(&self.snippet)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SearchPage {
+    pub hits: Vec<SearchHit>,
+    pub total: i64,
+    pub page: i64,
+    pub per_page: i64,
+}
+
+/// A title or username offered while typing in the search box
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, FromRow)]
+pub struct SearchSuggestion {
+    pub kind: SearchKind,
+    pub label: String,
+    pub url: String,
+}
@@ -0,0 +1,79 @@
+//! Server entry point: background tasks and the axum router around the Dioxus app.
+use std::net::SocketAddr;
+use dioxus::prelude::*;
+use sqlx::PgPool;
+
+use crate::authentication::{refresh_feature_flags, sweep_expired_rate_limits};
+use crate::db::get_db;
+use crate::log::DatabaseHealthMonitor;
+use crate::mail::{get_mailer, OutboxWorker};
+use crate::settings::refresh_app_settings;
+
+const RATE_LIMIT_SWEEP_SECONDS: u64 = 300;
+const CACHE_REFRESH_SECONDS: u64 = 30;
+
+/// Starts long-running jobs that live for the lifetime of the server
+pub async fn spawn_background_tasks(pool: PgPool) {
+    // Emails stay queued until the server is restarted with a working configuration
+    match get_mailer().await {
+        Ok(mailer) => {
+            let mailer = mailer.clone();
+            let outbox_pool = pool.clone();
+            tokio::spawn(async move {
+                OutboxWorker::new(outbox_pool, mailer).run().await;
+            });
+        }
+        Err(e) => tracing::error!("Email delivery is disabled: {}", e),
+    }
+
+    let health_pool = pool.clone();
+    tokio::spawn(async move {
+        DatabaseHealthMonitor::new(health_pool).run().await;
+    });
+
+    // Picks up flag and settings edits made through other server instances
+    let cache_pool = pool.clone();
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(std::time::Duration::from_secs(CACHE_REFRESH_SECONDS));
+        loop {
+            interval.tick().await;
+            if let Err(e) = refresh_feature_flags(&cache_pool).await {
+                tracing::error!("Failed to refresh feature flags: {}", e);
+            }
+            if let Err(e) = refresh_app_settings(&cache_pool).await {
+                tracing::error!("Failed to refresh app settings: {}", e);
+            }
+        }
+    });
+
+    // Full buckets carry no state, so drop them rather than let the table grow
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(std::time::Duration::from_secs(RATE_LIMIT_SWEEP_SECONDS));
+        loop {
+            interval.tick().await;
+            match sweep_expired_rate_limits(&pool).await {
+                Ok(0) => {}
+                Ok(removed) => tracing::debug!("Swept {} expired rate limit buckets", removed),
+                Err(e) => tracing::error!("Failed to sweep rate limit buckets: {}", e),
+            }
+        }
+    });
+}
+
+/// Builds the router, starts background tasks and serves `app` until shutdown
+pub async fn serve(app: fn() -> Element) -> Result<(), Box<dyn std::error::Error>> {
+    let pool = get_db().await.clone();
+    spawn_background_tasks(pool).await;
+
+    let router = axum::Router::new()
+        .merge(crate::rest::router())
+        .merge(crate::media::router())
+        .serve_dioxus_application(ServeConfig::new()?, app);
+
+    let address = dioxus::cli_config::fullstack_address_or_localhost();
+    let listener = tokio::net::TcpListener::bind(address).await?;
+    tracing::info!("Listening on {}", address);
+
+    axum::serve(listener, router.into_make_service_with_connect_info::<SocketAddr>()).await?;
+    Ok(())
+}
@@ -1 +1,5 @@
-pub mod session_model;
\ No newline at end of file
+pub mod session_model;
+pub mod session_functions;
+
+pub use session_model::{Session, SessionResponse};
+pub use session_functions::*;
@@ -1,164 +1,338 @@
-use jsonwebtoken::{encode, decode, Header, EncodingKey, DecodingKey, Validation};
-use serde::{Serialize, Deserialize};
-
+use chrono::{Duration, Utc};
 use dioxus::prelude::*;
-use crate::Post;
-use crate::ServerFnError;
-use crate::db::connection_pool::get_db; 
+use sqlx::PgPool;
+use uuid::Uuid;
+#[cfg(feature = "server")]
 use tracing::info;
 
+use crate::authentication::models::TokenGenerator;
+#[cfg(feature = "server")]
+use crate::db::get_db;
+use crate::session::Session;
+use crate::users::User;
+use crate::utils::RequestMeta;
+
+/// Name of the cookie carrying the session token
+pub const SESSION_COOKIE: &str = "session";
+/// Holds the admin's own session token while they impersonate someone
+pub const IMPERSONATOR_COOKIE: &str = "impersonator_session";
+const SESSION_TOKEN_LENGTH: usize = 64;
+const SESSION_DAYS: i64 = 30;
+
+// ip_address is INET in the database and a String on the model
+const SESSION_COLUMNS: &str =
+    "id, user_id, token, user_agent, ip_address::text AS ip_address, impersonator_id, expires_at, created_at, updated_at";
 
-#[derive(Debug, Serialize, Deserialize)]
-pub struct SessionTokenClaims {
-    pub sub: Uuid,       // User ID
-    pub jti: Uuid,       // Session ID
-    pub exp: usize,      // Expiration time
-    pub iat: usize,      // Issued at
+/// Opens a new session for `user_id` with a random opaque token
+pub async fn create_session<'e, E>(
+    db: E,
+    user_id: Uuid,
+    meta: &RequestMeta,
+) -> Result<Session, sqlx::Error>
+where
+    E: sqlx::PgExecutor<'e>,
+{
+    let token = TokenGenerator::generate_alphanumeric_token(SESSION_TOKEN_LENGTH);
+    let expires_at = Utc::now() + Duration::days(SESSION_DAYS);
+
+    sqlx::query_as::<_, Session>(&format!(
+        r#"
+        INSERT INTO sessions (user_id, token, user_agent, ip_address, expires_at)
+        VALUES ($1, $2, $3, $4::inet, $5)
+        RETURNING {}
+        "#,
+        SESSION_COLUMNS
+    ))
+    .bind(user_id)
+    .bind(&token)
+    .bind(&meta.user_agent)
+    .bind(meta.ip_string())
+    .bind(expires_at)
+    .fetch_one(db)
+    .await
 }
 
-pub struct SessionService {
-    db: Database,
-    jwt_secret: String,
-    session_duration: chrono::Duration,
+/// Opens a short session on `user_id`'s account for the admin `impersonator_id`
+pub async fn create_impersonation_session<'e, E>(
+    db: E,
+    user_id: Uuid,
+    impersonator_id: Uuid,
+    minutes: i64,
+    meta: &RequestMeta,
+) -> Result<Session, sqlx::Error>
+where
+    E: sqlx::PgExecutor<'e>,
+{
+    let token = TokenGenerator::generate_alphanumeric_token(SESSION_TOKEN_LENGTH);
+    let expires_at = Utc::now() + Duration::minutes(minutes);
+
+    sqlx::query_as::<_, Session>(&format!(
+        r#"
+        INSERT INTO sessions (user_id, token, user_agent, ip_address, impersonator_id, expires_at)
+        VALUES ($1, $2, $3, $4::inet, $5, $6)
+        RETURNING {}
+        "#,
+        SESSION_COLUMNS
+    ))
+    .bind(user_id)
+    .bind(&token)
+    .bind(&meta.user_agent)
+    .bind(meta.ip_string())
+    .bind(impersonator_id)
+    .bind(expires_at)
+    .fetch_one(db)
+    .await
 }
 
-impl SessionService {
-    pub fn new(db: Database, jwt_secret: String) -> Self {
-        Self {
-            db,
-            jwt_secret,
-            session_duration: chrono::Duration::days(30),
-        }
-    }
+/// The live session behind a token
+pub async fn find_session(db: &PgPool, token: &str) -> Result<Option<Session>, sqlx::Error> {
+    sqlx::query_as::<_, Session>(&format!(
+        "SELECT {} FROM sessions WHERE token = $1 AND is_revoked = false AND expires_at > NOW()",
+        SESSION_COLUMNS
+    ))
+    .bind(token)
+    .fetch_optional(db)
+    .await
 }
 
+/// Looks up the active user owning a live session token
+pub async fn find_session_user(db: &PgPool, token: &str) -> Result<Option<User>, sqlx::Error> {
+    sqlx::query_as::<_, User>(
+        r#"
+        SELECT u.* FROM sessions s
+        JOIN users u ON u.id = s.user_id
+        WHERE s.token = $1
+          AND s.is_revoked = false
+          AND s.expires_at > NOW()
+          AND u.is_active = true
+        "#
+    )
+    .bind(token)
+    .fetch_optional(db)
+    .await
+}
 
-#[server]
-    pub async fn create_session(
-        &self,
-        user: &User,
-        user_agent: Option<String>,
-        ip_address: Option<String>,
-    ) -> Result<(Session, String), anyhow::Error> {
-        let session_id = Uuid::new_v4();
-        let now = Utc::now();
-        let expires_at = now + self.session_duration;
-        
-        // Create JWT token
-        let claims = SessionTokenClaims {
-            sub: user.id,
-            jti: session_id,
-            exp: expires_at.timestamp() as usize,
-            iat: now.timestamp() as usize,
-        };
-        
-        let token = encode(
-            &Header::default(),
-            &claims,
-            &EncodingKey::from_secret(self.jwt_secret.as_ref()),
-        )?;
-        
-        // Store session in database
-        let session = sqlx::query_as!(
-            Session,
-            r#"
-            INSERT INTO sessions (id, user_id, token, user_agent, ip_address, expires_at)
-            VALUES ($1, $2, $3, $4, $5, $6)
-            RETURNING *
-            "#,
-            session_id,
-            user.id,
-            token,
-            user_agent,
-            ip_address,
-            expires_at
-        )
-        .fetch_one(&self.db.pool)
+pub async fn revoke_session(db: &PgPool, token: &str) -> Result<(), sqlx::Error> {
+    sqlx::query("UPDATE sessions SET is_revoked = true WHERE token = $1")
+        .bind(token)
+        .execute(db)
         .await?;
-        
-        Ok((session, token))
+    Ok(())
+}
+
+/// Revokes every session of a user, optionally keeping the one making the request
+pub async fn revoke_user_sessions<'e, E>(
+    db: E,
+    user_id: Uuid,
+    keep_token: Option<&str>,
+) -> Result<u64, sqlx::Error>
+where
+    E: sqlx::PgExecutor<'e>,
+{
+    let result = sqlx::query(
+        r#"
+        UPDATE sessions SET is_revoked = true
+        WHERE user_id = $1 AND is_revoked = false
+          AND ($2::text IS NULL OR token <> $2)
+        "#
+    )
+    .bind(user_id)
+    .bind(keep_token)
+    .execute(db)
+    .await?;
+
+    Ok(result.rows_affected())
+}
+
+/// Session token from an `Authorization: Bearer` header or the session cookie
+#[cfg(feature = "server")]
+pub fn session_token_from_headers(headers: &axum::http::HeaderMap) -> Option<String> {
+    use axum::http::header::AUTHORIZATION;
+
+    let bearer = headers
+        .get(AUTHORIZATION)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.strip_prefix("Bearer "))
+        .map(|v| v.trim().to_string());
+
+    bearer.or_else(|| cookie_from_headers(headers, SESSION_COOKIE))
+}
+
+#[cfg(feature = "server")]
+fn cookie_from_headers(headers: &axum::http::HeaderMap, cookie: &str) -> Option<String> {
+    use axum::http::header::COOKIE;
+
+    headers
+        .get_all(COOKIE)
+        .iter()
+        .filter_map(|v| v.to_str().ok())
+        .flat_map(|v| v.split(';'))
+        .filter_map(|pair| pair.trim().split_once('='))
+        .find(|(name, _)| *name == cookie)
+        .map(|(_, value)| value.to_string())
+}
+
+#[cfg(feature = "server")]
+pub async fn current_session_token() -> Option<String> {
+    session_token_from_headers(&crate::utils::request_headers().await)
+}
+
+/// The signed-in user for the current request, or an error if there is none
+#[cfg(feature = "server")]
+pub async fn current_user() -> Result<User, ServerFnError> {
+    let token = current_session_token()
+        .await
+        .ok_or_else(|| -> ServerFnError { ServerFnError::ServerError("Not signed in".into()) })?;
+
+    find_session_user(get_db().await, &token)
+        .await
+        .map_err(|e| -> ServerFnError {
+            tracing::error!("Database error looking up session: {}", e);
+            ServerFnError::ServerError("Failed to load session".into())
+        })?
+        .ok_or_else(|| ServerFnError::ServerError("Not signed in".into()))
+}
+
+/// The admin behind the current session, if it is an impersonation session
+#[cfg(feature = "server")]
+pub async fn current_impersonator() -> Option<Uuid> {
+    let token = current_session_token().await?;
+    match find_session(get_db().await, &token).await {
+        Ok(session) => session.and_then(|s| s.impersonator_id),
+        Err(e) => {
+            tracing::error!("Database error looking up session: {}", e);
+            None
+        }
     }
+}
 
-#[server]
-    pub async fn validate_session(
-        &self,
-        token: &str,
-    ) -> Result<(Session, User), anyhow::Error> {
-        // Verify JWT token
-        let token_data = decode::<SessionTokenClaims>(
-            token,
-            &DecodingKey::from_secret(self.jwt_secret.as_ref()),
-            &Validation::default(),
-        )?;
-        
-        let claims = token_data.claims;
-        
-        // Check if session exists in database
-        let session = sqlx::query_as!(
-            Session,
-            r#"
-            SELECT * FROM sessions
-            WHERE id = $1 AND token = $2 AND expires_at > NOW()
-            "#,
-            claims.jti,
-            token
-        )
-        .fetch_one(&self.db.pool)
-        .await?;
-        
-        // Get associated user
-        let user = sqlx::query_as!(
-            User,
-            r#"
-            SELECT * FROM users
-            WHERE id = $1
-            "#,
-            claims.sub
-        )
-        .fetch_one(&self.db.pool)
-        .await?;
-        
-        // Update session's last activity
-        sqlx::query!(
-            r#"
-            UPDATE sessions
-            SET updated_at = NOW()
-            WHERE id = $1
-            "#,
-            session.id
+/// Whether `role_id` is granted `permission` (e.g. `"user:update"`)
+pub async fn role_has_permission(db: &PgPool, role_id: i32, permission: &str) -> Result<bool, sqlx::Error> {
+    sqlx::query_scalar::<_, bool>(
+        r#"
+        SELECT EXISTS(
+            SELECT 1 FROM role_permissions rp
+            JOIN permissions p ON p.id = rp.permission_id
+            WHERE rp.role_id = $1 AND p.code = $2
         )
-        .execute(&self.db.pool)
-        .await?;
-        
-        Ok((session, user))
+        "#
+    )
+    .bind(role_id)
+    .bind(permission)
+    .fetch_one(db)
+    .await
+}
+
+/// The signed-in user, provided their role grants `permission` (e.g. `"user:update"`)
+#[cfg(feature = "server")]
+pub async fn require_permission(permission: &str) -> Result<User, ServerFnError> {
+    let user = current_user().await?;
+
+    let allowed = role_has_permission(get_db().await, user.role_id, permission)
+        .await
+        .map_err(|e| -> ServerFnError {
+            tracing::error!("Database error checking permission: {}", e);
+            ServerFnError::ServerError("Failed to check permissions".into())
+        })?;
+
+    if allowed {
+        Ok(user)
+    } else {
+        Err(ServerFnError::Request("You don't have permission to do that".into()))
     }
+}
 
-    #[server]
-    pub async fn delete_session(&self, session_id: Uuid) -> Result<(), anyhow::Error> {
-        sqlx::query!(
-            r#"
-            DELETE FROM sessions
-            WHERE id = $1
-            "#,
-            session_id
-        )
-        .execute(&self.db.pool)
-        .await?;
-        
-        Ok(())
+/// Sets the session cookie on the server function response
+#[cfg(feature = "server")]
+pub fn set_session_cookie(session: &Session) {
+    let max_age = (session.expires_at - Utc::now()).num_seconds().max(0);
+    write_session_cookie(&format!(
+        "{}={}; Path=/; HttpOnly; SameSite=Lax; Max-Age={}",
+        SESSION_COOKIE, session.token, max_age
+    ));
+}
+
+#[cfg(feature = "server")]
+pub fn clear_session_cookie() {
+    write_session_cookie(&format!("{}=; Path=/; HttpOnly; SameSite=Lax; Max-Age=0", SESSION_COOKIE));
+}
+
+/// Keeps the admin's session token aside while they impersonate someone
+#[cfg(feature = "server")]
+pub fn stash_impersonator_cookie(token: &str) {
+    write_session_cookie(&format!(
+        "{}={}; Path=/; HttpOnly; SameSite=Lax; Max-Age={}",
+        IMPERSONATOR_COOKIE,
+        token,
+        Duration::days(SESSION_DAYS).num_seconds()
+    ));
+}
+
+#[cfg(feature = "server")]
+pub async fn stashed_impersonator_token() -> Option<String> {
+    cookie_from_headers(&crate::utils::request_headers().await, IMPERSONATOR_COOKIE)
+}
+
+#[cfg(feature = "server")]
+pub fn clear_impersonator_cookie() {
+    write_session_cookie(&format!("{}=; Path=/; HttpOnly; SameSite=Lax; Max-Age=0", IMPERSONATOR_COOKIE));
+}
+
+#[cfg(feature = "server")]
+fn write_session_cookie(value: &str) {
+    use axum::http::{header::SET_COOKIE, HeaderValue};
+
+    if let Ok(value) = HeaderValue::from_str(value) {
+        server_context()
+            .response_parts_mut()
+            .headers
+            .append(SET_COOKIE, value);
     }
-    
-    #[server]
-    pub async fn delete_all_user_sessions(&self, user_id: Uuid) -> Result<(), anyhow::Error> {
-        sqlx::query!(
-            r#"
-            DELETE FROM sessions
-            WHERE user_id = $1
-            "#,
-            user_id
-        )
-        .execute(&self.db.pool)
-        .await?;
-        
-        Ok(())
+}
+
+#[server]
+pub async fn get_current_user() -> Result<Option<User>, ServerFnError> {
+    let Some(token) = current_session_token().await else {
+        return Ok(None);
+    };
+
+    find_session_user(get_db().await, &token)
+        .await
+        .map_err(|e| {
+            tracing::error!("Database error looking up session: {}", e);
+            ServerFnError::ServerError("Failed to load session".into())
+        })
+}
+
+#[server]
+pub async fn logout() -> Result<(), ServerFnError> {
+    let db = get_db().await;
+
+    if let Some(token) = current_session_token().await {
+        let db_error = |e: sqlx::Error| -> ServerFnError {
+            tracing::error!("Database error revoking session: {}", e);
+            ServerFnError::ServerError("Failed to sign out".into())
+        };
+
+        let user = find_session_user(db, &token).await.map_err(db_error)?;
+        revoke_session(db, &token).await.map_err(db_error)?;
+
+        if let Some(user) = user {
+            let meta = crate::utils::request_meta().await;
+            crate::authentication::record_security_event(
+                db,
+                Some(user.id),
+                crate::authentication::SecurityAction::Logout,
+                &meta,
+                serde_json::json!({}),
+            )
+            .await
+            .map_err(db_error)?;
+        }
+        info!("Session revoked");
     }
+
+    clear_session_cookie();
+    clear_impersonator_cookie();
+    Ok(())
+}
@@ -17,6 +17,10 @@ pub struct Session {
     
     #[serde(skip_serializing_if = "Option::is_none")]
     pub ip_address: Option<String>,
+
+    /// The admin acting as `user_id`, for impersonation sessions
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub impersonator_id: Option<Uuid>,
     
     #[serde(with = "chrono::serde::ts_seconds")]
     pub expires_at: DateTime<Utc>,
@@ -43,6 +47,7 @@ impl Session {
             token,
             user_agent,
             ip_address,
+            impersonator_id: None,
             expires_at,
             created_at: now,
             updated_at: now,
@@ -0,0 +1,7 @@
+pub mod settings_model;
+pub mod settings_functions;
+
+pub use settings_model::{
+    setting_keys, AiProvider, AppSettings, PostVisibility, PublicSettings, RegistrationMode,
+};
+pub use settings_functions::*;
@@ -0,0 +1,138 @@
+use std::collections::HashMap;
+use dioxus::prelude::*;
+use sqlx::PgPool;
+use tokio::sync::{OnceCell, RwLock};
+#[cfg(feature = "server")]
+use validator::Validate;
+#[cfg(feature = "server")]
+use tracing::info;
+
+use crate::settings::settings_model::{AppSettings, PublicSettings};
+#[cfg(feature = "server")]
+use crate::settings::settings_model::setting_keys;
+#[cfg(feature = "server")]
+use crate::authentication::{audit_entities, audit_entry, record_audit, AuditAction};
+use crate::db::get_db;
+
+pub const SETTINGS_PERMISSION: &str = "system:settings";
+
+/// Settings as of the last refresh. Registration, posting and uploads all
+/// consult them, so they are read from here rather than the database.
+static SETTINGS_CACHE: OnceCell<RwLock<AppSettings>> = OnceCell::const_new();
+
+async fn settings_cache() -> &'static RwLock<AppSettings> {
+    SETTINGS_CACHE
+        .get_or_init(|| async {
+            let settings = match load_app_settings(get_db().await).await {
+                Ok(settings) => settings,
+                Err(e) => {
+                    // Run on the defaults; the next refresh fills them in
+                    tracing::error!("Failed to load app settings: {}", e);
+                    AppSettings::default()
+                }
+            };
+            RwLock::new(settings)
+        })
+        .await
+}
+
+pub async fn load_app_settings(db: &PgPool) -> Result<AppSettings, sqlx::Error> {
+    let rows = sqlx::query_as::<_, (String, serde_json::Value)>("SELECT key, value FROM app_settings")
+        .fetch_all(db)
+        .await?;
+
+    let values: HashMap<String, serde_json::Value> = rows.into_iter().collect();
+    Ok(AppSettings::from_values(&values))
+}
+
+/// Reloads the settings into the cache
+pub async fn refresh_app_settings(db: &PgPool) -> Result<(), sqlx::Error> {
+    let settings = load_app_settings(db).await?;
+    *settings_cache().await.write().await = settings;
+    Ok(())
+}
+
+/// The current settings, from the cache
+pub async fn app_settings() -> AppSettings {
+    settings_cache().await.read().await.clone()
+}
+
+/// Site name, registration mode and upload limits, for anyone
+#[server]
+pub async fn get_public_settings() -> Result<PublicSettings, ServerFnError> {
+    Ok(app_settings().await.public())
+}
+
+#[server]
+pub async fn get_app_settings() -> Result<AppSettings, ServerFnError> {
+    let db = get_db().await;
+    crate::session::require_permission(SETTINGS_PERMISSION).await?;
+
+    // Read through so the editor never starts from a stale copy
+    load_app_settings(db).await.map_err(|e| {
+        tracing::error!("Database error loading app settings: {}", e);
+        ServerFnError::ServerError("Failed to load settings".into())
+    })
+}
+
+/// Saves every setting that changed and returns the stored settings
+#[server]
+pub async fn update_app_settings(settings: AppSettings) -> Result<AppSettings, ServerFnError> {
+    let db = get_db().await;
+    let admin = crate::session::require_permission(SETTINGS_PERMISSION).await?;
+    crate::log::require_writable().await?;
+    let db_error = |e: sqlx::Error| -> ServerFnError {
+        tracing::error!("Database error saving app settings: {}", e);
+        ServerFnError::ServerError("Failed to save settings".into())
+    };
+
+    let settings = settings.normalized();
+    settings
+        .validate()
+        .map_err(|e| -> ServerFnError { ServerFnError::Request(e.to_string()) })?;
+
+    let before = load_app_settings(db).await.map_err(db_error)?;
+    if before == settings {
+        return Ok(settings);
+    }
+
+    let mut tx = db.begin().await.map_err(db_error)?;
+    // A row missing from the table, e.g. for a setting added after the
+    // migrations last ran, is created rather than silently not saved
+    for ((key, value), (_, default)) in settings.to_values().into_iter().zip(AppSettings::default().to_values()) {
+        sqlx::query(
+            r#"
+            INSERT INTO app_settings (key, value, default_value, value_type, updated_by)
+            VALUES ($1, $2, $3, $4, $5)
+            ON CONFLICT (key) DO UPDATE
+            SET value = EXCLUDED.value, updated_by = EXCLUDED.updated_by, updated_at = NOW()
+            WHERE app_settings.value IS DISTINCT FROM EXCLUDED.value
+            "#,
+        )
+        .bind(key)
+        .bind(&value)
+        .bind(&default)
+        .bind(setting_keys::value_type(key))
+        .bind(admin.id)
+        .execute(&mut *tx)
+        .await
+        .map_err(db_error)?;
+    }
+
+    record_audit(
+        &mut *tx,
+        audit_entry(AuditAction::Update, audit_entities::SETTING, "app")
+            .await
+            .before(&before)
+            .after(&settings),
+    )
+    .await;
+
+    tx.commit().await.map_err(db_error)?;
+
+    // Other servers pick the change up on their next periodic refresh
+    refresh_app_settings(db).await.map_err(db_error)?;
+
+    info!("App settings updated by {}", admin.id);
+    Ok(app_settings().await)
+}
@@ -0,0 +1,291 @@
+use std::collections::HashMap;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use serde_json::Value;
+use validator::Validate;
+
+use crate::utils::{validate_http_url, validate_mime_types};
+
+/// Keys of the rows in `app_settings`
+pub mod setting_keys {
+    pub const SITE_NAME: &str = "site.name";
+    pub const ADMIN_EMAIL: &str = "site.admin_email";
+    pub const REGISTRATION_MODE: &str = "registration.mode";
+    pub const DEFAULT_POST_VISIBILITY: &str = "posts.default_visibility";
+    pub const AI_PROVIDER: &str = "ai.provider";
+    pub const AI_MODEL: &str = "ai.model";
+    pub const AI_BASE_URL: &str = "ai.base_url";
+    pub const MAX_UPLOAD_MB: &str = "uploads.max_size_mb";
+    pub const ALLOWED_UPLOAD_TYPES: &str = "uploads.allowed_types";
+    pub const USER_QUOTA_MB: &str = "uploads.user_quota_mb";
+
+    /// The `value_type` of a setting's row, as `jsonb_typeof` names it
+    pub fn value_type(key: &str) -> &'static str {
+        match key {
+            MAX_UPLOAD_MB | USER_QUOTA_MB => "number",
+            ALLOWED_UPLOAD_TYPES => "array",
+            _ => "string",
+        }
+    }
+}
+
+/// Who may create an account
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RegistrationMode {
+    #[default]
+    Open,
+    Closed,
+    InviteOnly,
+}
+
+impl RegistrationMode {
+    pub const ALL: [RegistrationMode; 3] = [RegistrationMode::Open, RegistrationMode::Closed, RegistrationMode::InviteOnly];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RegistrationMode::Open => "open",
+            RegistrationMode::Closed => "closed",
+            RegistrationMode::InviteOnly => "invite_only",
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            RegistrationMode::Open => "Open to everyone",
+            RegistrationMode::Closed => "Closed",
+            RegistrationMode::InviteOnly => "Invite only",
+        }
+    }
+}
+
+/// Whether a new post is published straight away
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PostVisibility {
+    #[default]
+    Public,
+    /// Only the author and moderators can see it until it is published
+    Private,
+}
+
+impl PostVisibility {
+    pub const ALL: [PostVisibility; 2] = [PostVisibility::Public, PostVisibility::Private];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PostVisibility::Public => "public",
+            PostVisibility::Private => "private",
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            PostVisibility::Public => "Published",
+            PostVisibility::Private => "Draft",
+        }
+    }
+
+    pub fn is_published(&self) -> bool {
+        matches!(self, PostVisibility::Public)
+    }
+}
+
+/// Which AI service answers prompts
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AiProvider {
+    #[default]
+    Ollama,
+    #[serde(rename = "openai")]
+    OpenAi,
+    Anthropic,
+}
+
+impl AiProvider {
+    pub const ALL: [AiProvider; 3] = [AiProvider::Ollama, AiProvider::OpenAi, AiProvider::Anthropic];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AiProvider::Ollama => "ollama",
+            AiProvider::OpenAi => "openai",
+            AiProvider::Anthropic => "anthropic",
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            AiProvider::Ollama => "Ollama (Local)",
+            AiProvider::OpenAi => "OpenAI",
+            AiProvider::Anthropic => "Anthropic",
+        }
+    }
+}
+
+/// Site-wide settings, read from `app_settings`. A row that is missing or
+/// holds a value of the wrong shape falls back to the default for that field.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Validate)]
+pub struct AppSettings {
+    #[validate(length(min = 1, max = 100, message = "Site name must be 1-100 characters"))]
+    pub site_name: String,
+    #[validate(email(message = "Must be a valid email"))]
+    pub admin_email: Option<String>,
+    pub registration_mode: RegistrationMode,
+    pub default_post_visibility: PostVisibility,
+    pub ai_provider: AiProvider,
+    #[validate(length(min = 1, max = 100, message = "Model name must be 1-100 characters"))]
+    pub ai_model: String,
+    #[validate(custom(function = "validate_http_url"))]
+    pub ai_base_url: String,
+    #[validate(range(min = 1, max = 1024, message = "Upload limit must be 1-1024 MB"))]
+    pub max_upload_mb: i64,
+    #[validate(length(min = 1, message = "Allow at least one upload type"), custom(function = "validate_mime_types"))]
+    pub allowed_upload_types: Vec<String>,
+    #[validate(range(min = 1, max = 102400, message = "Storage per user must be 1-102400 MB"))]
+    pub user_quota_mb: i64,
+}
+
+impl Default for AppSettings {
+    fn default() -> Self {
+        Self {
+            site_name: "Writiting".to_string(),
+            admin_email: None,
+            registration_mode: RegistrationMode::Open,
+            default_post_visibility: PostVisibility::Public,
+            ai_provider: AiProvider::Ollama,
+            ai_model: "llama3".to_string(),
+            ai_base_url: "http://localhost:11434".to_string(),
+            max_upload_mb: 10,
+            allowed_upload_types: ["image/png", "image/jpeg", "image/gif", "image/webp"]
+                .into_iter()
+                .map(str::to_string)
+                .collect(),
+            user_quota_mb: 200,
+        }
+    }
+}
+
+fn read_setting<T: DeserializeOwned>(values: &HashMap<String, Value>, key: &str, fallback: T) -> T {
+    values
+        .get(key)
+        .and_then(|value| serde_json::from_value(value.clone()).ok())
+        .unwrap_or(fallback)
+}
+
+impl AppSettings {
+    /// Builds the settings from `key -> value` rows
+    pub fn from_values(values: &HashMap<String, Value>) -> Self {
+        let defaults = Self::default();
+        Self {
+            site_name: read_setting(values, setting_keys::SITE_NAME, defaults.site_name),
+            admin_email: read_setting(values, setting_keys::ADMIN_EMAIL, defaults.admin_email),
+            registration_mode: read_setting(values, setting_keys::REGISTRATION_MODE, defaults.registration_mode),
+            default_post_visibility: read_setting(values, setting_keys::DEFAULT_POST_VISIBILITY, defaults.default_post_visibility),
+            ai_provider: read_setting(values, setting_keys::AI_PROVIDER, defaults.ai_provider),
+            ai_model: read_setting(values, setting_keys::AI_MODEL, defaults.ai_model),
+            ai_base_url: read_setting(values, setting_keys::AI_BASE_URL, defaults.ai_base_url),
+            max_upload_mb: read_setting(values, setting_keys::MAX_UPLOAD_MB, defaults.max_upload_mb),
+            allowed_upload_types: read_setting(values, setting_keys::ALLOWED_UPLOAD_TYPES, defaults.allowed_upload_types),
+            user_quota_mb: read_setting(values, setting_keys::USER_QUOTA_MB, defaults.user_quota_mb),
+        }
+    }
+
+    /// The settings as `key -> value` rows, the inverse of `from_values`
+    pub fn to_values(&self) -> Vec<(&'static str, Value)> {
+        vec![
+            (setting_keys::SITE_NAME, Value::from(self.site_name.clone())),
+            (setting_keys::ADMIN_EMAIL, self.admin_email.clone().map_or(Value::Null, Value::from)),
+            (setting_keys::REGISTRATION_MODE, Value::from(self.registration_mode.as_str())),
+            (setting_keys::DEFAULT_POST_VISIBILITY, Value::from(self.default_post_visibility.as_str())),
+            (setting_keys::AI_PROVIDER, Value::from(self.ai_provider.as_str())),
+            (setting_keys::AI_MODEL, Value::from(self.ai_model.clone())),
+            (setting_keys::AI_BASE_URL, Value::from(self.ai_base_url.clone())),
+            (setting_keys::MAX_UPLOAD_MB, Value::from(self.max_upload_mb)),
+            (setting_keys::ALLOWED_UPLOAD_TYPES, Value::from(self.allowed_upload_types.clone())),
+            (setting_keys::USER_QUOTA_MB, Value::from(self.user_quota_mb)),
+        ]
+    }
+
+    /// Trims text fields and drops blank or duplicate upload types
+    pub fn normalized(mut self) -> Self {
+        self.site_name = self.site_name.trim().to_string();
+        self.admin_email = self.admin_email.map(|e| e.trim().to_string()).filter(|e| !e.is_empty());
+        self.ai_model = self.ai_model.trim().to_string();
+        self.ai_base_url = self.ai_base_url.trim().trim_end_matches('/').to_string();
+        let mut types: Vec<String> = self
+            .allowed_upload_types
+            .iter()
+            .map(|t| t.trim().to_lowercase())
+            .filter(|t| !t.is_empty())
+            .collect();
+        types.sort();
+        types.dedup();
+        self.allowed_upload_types = types;
+        self
+    }
+
+    pub fn max_upload_bytes(&self) -> usize {
+        self.max_upload_mb.max(0) as usize * 1024 * 1024
+    }
+
+    /// Total size of the media one user may keep
+    pub fn user_quota_bytes(&self) -> i64 {
+        self.user_quota_mb.max(0) * 1024 * 1024
+    }
+
+    pub fn allows_upload_type(&self, mime: &str) -> bool {
+        self.allowed_upload_types.iter().any(|t| t.eq_ignore_ascii_case(mime))
+    }
+
+    /// The part of the settings anyone may read
+    pub fn public(&self) -> PublicSettings {
+        PublicSettings {
+            site_name: self.site_name.clone(),
+            registration_mode: self.registration_mode,
+            max_upload_mb: self.max_upload_mb,
+            allowed_upload_types: self.allowed_upload_types.clone(),
+        }
+    }
+}
+
+/// Settings the sign-up and upload forms need before anyone has signed in
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PublicSettings {
+    pub site_name: String,
+    pub registration_mode: RegistrationMode,
+    pub max_upload_mb: i64,
+    pub allowed_upload_types: Vec<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn json_type(value: &Value) -> &'static str {
+        match value {
+            Value::Null => "null",
+            Value::Bool(_) => "boolean",
+            Value::Number(_) => "number",
+            Value::String(_) => "string",
+            Value::Array(_) => "array",
+            Value::Object(_) => "object",
+        }
+    }
+
+    #[test]
+    fn value_types_match_the_values() {
+        let settings = AppSettings { admin_email: Some("admin@example.com".to_string()), ..AppSettings::default() };
+
+        for (key, value) in settings.to_values() {
+            assert_eq!(setting_keys::value_type(key), json_type(&value), "{}", key);
+        }
+    }
+
+    #[test]
+    fn values_round_trip() {
+        let settings = AppSettings { admin_email: Some("admin@example.com".to_string()), ..AppSettings::default() };
+        let values: HashMap<String, Value> =
+            settings.to_values().into_iter().map(|(key, value)| (key.to_string(), value)).collect();
+
+        assert_eq!(AppSettings::from_values(&values), settings);
+    }
+}
@@ -1,7 +1,14 @@
 pub mod users_model;
+pub mod users_functions;
 pub mod user_profile_model;
 pub mod user_profile_functions;
 
-pub use users_model::User;
-pub use user_profile_model::{UserProfile, ProfileUpdate};
-pub use user_profile_functions::*;
\ No newline at end of file
+pub use users_model::{
+    AdminUserFilter, AdminUserPage, AdminUserSummary, BulkUserAction, BulkUserResult,
+    ImpersonationStatus, RoleOption, User, UserSort, UserStats, UserUpdate,
+};
+pub use users_functions::*;
+pub use user_profile_model::{
+    ProfilePost, ProfileUpdate, PublicProfile, SocialLink, SocialLinkInput, SocialPlatform, UserProfile,
+};
+pub use user_profile_functions::*;
@@ -1,17 +1,41 @@
 // pg_app/server/src/server_functions.rs
 use dioxus::prelude::*;
-use crate::users::{UserProfile, ProfileUpdate, User};
+use crate::users::{UserProfile, ProfileUpdate, User, PublicProfile, SocialLink, SocialLinkInput, SocialPlatform};
+#[cfg(feature = "server")]
+use crate::users::user_profile_model::{ProfilePost, SOCIAL_LINK_COLUMNS};
+#[cfg(feature = "server")]
+use crate::utils::validate_social_handle;
 use crate::ServerFnError;
+#[cfg(feature = "server")]
 use crate::db::connection_pool::get_db;
 use uuid::Uuid;
+#[cfg(feature = "server")]
 use tracing::info;
+#[cfg(feature = "server")]
 use validator::Validate;
-use anyhow; // Add to Cargo.toml if not already present
+#[cfg(feature = "server")]
+use crate::authentication::{audit_entities, audit_entry, record_audit, AuditAction};
 
+/// Published posts listed on a public profile
+#[cfg(feature = "server")]
+const PROFILE_POST_LIMIT: i64 = 20;
+
+/// Profiles may be changed by their owner, or by an admin with `user:update`
+#[cfg(feature = "server")]
+async fn require_profile_access(user_id: Uuid) -> Result<(), ServerFnError> {
+    let user = crate::session::current_user().await?;
+    if user.id == user_id {
+        return Ok(());
+    }
+    crate::session::require_permission("user:update").await?;
+    Ok(())
+}
 
 #[server]
 pub async fn create_profile(user_id: Uuid) -> Result<(), ServerFnError> {
     let db = get_db().await;
+    crate::log::require_writable().await?;
+    require_profile_access(user_id).await?;
     
     sqlx::query!(
         "INSERT INTO profiles (user_id) VALUES ($1)",
@@ -19,8 +43,10 @@ pub async fn create_profile(user_id: Uuid) -> Result<(), ServerFnError> {
     )
     .execute(db)
     .await
-// Change error returns to:
-.map_err(|e| ServerFnError::<anyhow::Error>::ServerError(format!("Failed to create profile: {}", e)))?;
+    .map_err(|e| -> ServerFnError {
+        tracing::error!("Database error creating profile: {}", e);
+        ServerFnError::ServerError("Failed to create profile".into())
+    })?;
 
     info!("Created profile for user: {}", user_id);
     Ok(())
@@ -56,14 +82,25 @@ pub async fn update_profile(
     update: ProfileUpdate
 ) -> Result<UserProfile, ServerFnError> {
     let db = get_db().await;
+    crate::log::require_writable().await?;
+    require_profile_access(user_id).await?;
     
     // Validate input
-    update.validate().map_err(|e| {
+    update.validate().map_err(|e| -> ServerFnError {
         tracing::error!("Validation error: {:?}", e);
         ServerFnError::Request("Invalid profile data".into())
     })?;
 
-    sqlx::query_as::<_, UserProfile>(
+    let before = sqlx::query_as::<_, UserProfile>("SELECT * FROM profiles WHERE user_id = $1")
+        .bind(user_id)
+        .fetch_optional(db)
+        .await
+        .map_err(|e| -> ServerFnError {
+            tracing::error!("Database error loading profile: {}", e);
+            ServerFnError::ServerError("Failed to update profile".into())
+        })?;
+
+    let profile = sqlx::query_as::<_, UserProfile>(
         r#"
         UPDATE profiles SET
             first_name = COALESCE($1, first_name),
@@ -84,15 +121,36 @@ pub async fn update_profile(
     .bind(user_id)
     .fetch_one(db)
     .await
-    .map_err(|e| {
+    .map_err(|e| -> ServerFnError {
         tracing::error!("Database error updating profile: {}", e);
         ServerFnError::ServerError("Failed to update profile".into())
-    })
+    })?;
+
+    let mut entry = audit_entry(AuditAction::Update, audit_entities::PROFILE, user_id)
+        .await
+        .after(&profile);
+    if let Some(before) = &before {
+        entry = entry.before(before);
+    }
+    record_audit(db, entry).await;
+
+    Ok(profile)
 }
 
 #[server]
 pub async fn delete_profile(user_id: Uuid) -> Result<(), ServerFnError> {
     let db = get_db().await;
+    crate::log::require_writable().await?;
+    require_profile_access(user_id).await?;
+
+    let before = sqlx::query_as::<_, UserProfile>("SELECT * FROM profiles WHERE user_id = $1")
+        .bind(user_id)
+        .fetch_optional(db)
+        .await
+        .map_err(|e| -> ServerFnError {
+            tracing::error!("Database error loading profile: {}", e);
+            ServerFnError::ServerError("Failed to delete profile".into())
+        })?;
 
     sqlx::query!(
         "DELETE FROM profiles WHERE user_id = $1",
@@ -100,11 +158,21 @@ pub async fn delete_profile(user_id: Uuid) -> Result<(), ServerFnError> {
     )
     .execute(db)
     .await
-    .map_err(|e| {
+    .map_err(|e| -> ServerFnError {
         tracing::error!("Database error deleting profile: {}", e);
         ServerFnError::ServerError("Failed to delete profile".into())
     })?;
 
+    if let Some(before) = &before {
+        record_audit(
+            db,
+            audit_entry(AuditAction::Delete, audit_entities::PROFILE, user_id)
+                .await
+                .before(before),
+        )
+        .await;
+    }
+
     info!("Deleted profile for user: {}", user_id);
     Ok(())
 }
@@ -131,6 +199,231 @@ pub async fn get_profile_with_user(user_id: Uuid) -> Result<(UserProfile, User),
 }
 
 
+#[server]
+pub async fn list_social_platforms() -> Result<Vec<SocialPlatform>, ServerFnError> {
+    let db = get_db().await;
+
+    sqlx::query_as::<_, SocialPlatform>(
+        "SELECT id, name, base_url, icon_class FROM social_media_platforms ORDER BY name"
+    )
+    .fetch_all(db)
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error listing social platforms: {}", e);
+        ServerFnError::ServerError("Failed to load social platforms".into())
+    })
+}
+
+#[cfg(feature = "server")]
+async fn find_social_link(db: &sqlx::PgPool, user_id: Uuid, platform_id: i16) -> Result<Option<SocialLink>, sqlx::Error> {
+    sqlx::query_as::<_, SocialLink>(&format!(
+        "SELECT {} FROM user_social_links l \
+         JOIN social_media_platforms p ON p.id = l.platform_id \
+         WHERE l.user_id = $1 AND l.platform_id = $2",
+        SOCIAL_LINK_COLUMNS
+    ))
+    .bind(user_id)
+    .bind(platform_id)
+    .fetch_optional(db)
+    .await
+}
+
+/// The signed-in user's social links, public or not
+#[server]
+pub async fn list_my_social_links() -> Result<Vec<SocialLink>, ServerFnError> {
+    let db = get_db().await;
+    let user = crate::session::current_user().await?;
+
+    sqlx::query_as::<_, SocialLink>(&format!(
+        "SELECT {} FROM user_social_links l \
+         JOIN social_media_platforms p ON p.id = l.platform_id \
+         WHERE l.user_id = $1 ORDER BY p.name",
+        SOCIAL_LINK_COLUMNS
+    ))
+    .bind(user.id)
+    .fetch_all(db)
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error listing social links: {}", e);
+        ServerFnError::ServerError("Failed to load social links".into())
+    })
+}
+
+/// Adds or replaces the signed-in user's link for a platform. The handle may
+/// be pasted as a full profile URL; it is stored without the platform's base URL.
+#[server]
+pub async fn save_social_link(input: SocialLinkInput) -> Result<SocialLink, ServerFnError> {
+    let db = get_db().await;
+    crate::log::require_writable().await?;
+    let user = crate::session::current_user().await?;
+
+    input
+        .validate()
+        .map_err(|e| -> ServerFnError { ServerFnError::Request(e.to_string()) })?;
+
+    let db_error = |e: sqlx::Error| -> ServerFnError {
+        tracing::error!("Database error saving social link: {}", e);
+        ServerFnError::ServerError("Failed to save social link".into())
+    };
+
+    let platform = sqlx::query_as::<_, SocialPlatform>(
+        "SELECT id, name, base_url, icon_class FROM social_media_platforms WHERE id = $1"
+    )
+    .bind(input.platform_id)
+    .fetch_optional(db)
+    .await
+    .map_err(db_error)?
+    .ok_or_else(|| -> ServerFnError { ServerFnError::Request("Unknown platform".into()) })?;
+
+    let handle = platform.normalize_handle(&input.handle);
+    validate_social_handle(&platform.name, &handle)
+        .map_err(|e| -> ServerFnError { ServerFnError::Request(e.code.to_string()) })?;
+
+    let before = find_social_link(db, user.id, platform.id).await.map_err(db_error)?;
+
+    sqlx::query(
+        r#"
+        INSERT INTO user_social_links (user_id, platform_id, username, is_public)
+        VALUES ($1, $2, $3, $4)
+        ON CONFLICT (user_id, platform_id) DO UPDATE SET
+            username = EXCLUDED.username,
+            is_public = EXCLUDED.is_public,
+            updated_at = NOW()
+        "#
+    )
+    .bind(user.id)
+    .bind(platform.id)
+    .bind(&handle)
+    .bind(input.is_public)
+    .execute(db)
+    .await
+    .map_err(db_error)?;
+
+    let link = find_social_link(db, user.id, platform.id)
+        .await
+        .map_err(db_error)?
+        .ok_or_else(|| -> ServerFnError { ServerFnError::ServerError("Failed to save social link".into()) })?;
+
+    let action = if before.is_some() { AuditAction::Update } else { AuditAction::Create };
+    let mut entry = audit_entry(action, audit_entities::PROFILE, user.id)
+        .await
+        .after(&link);
+    if let Some(before) = &before {
+        entry = entry.before(before);
+    }
+    record_audit(db, entry).await;
+
+    info!("User {} saved their {} link", user.id, platform.name);
+    Ok(link)
+}
+
+#[server]
+pub async fn delete_social_link(platform_id: i16) -> Result<(), ServerFnError> {
+    let db = get_db().await;
+    crate::log::require_writable().await?;
+    let user = crate::session::current_user().await?;
+
+    let db_error = |e: sqlx::Error| -> ServerFnError {
+        tracing::error!("Database error deleting social link: {}", e);
+        ServerFnError::ServerError("Failed to remove social link".into())
+    };
+
+    let Some(before) = find_social_link(db, user.id, platform_id).await.map_err(db_error)? else {
+        return Ok(());
+    };
+
+    sqlx::query("DELETE FROM user_social_links WHERE user_id = $1 AND platform_id = $2")
+        .bind(user.id)
+        .bind(platform_id)
+        .execute(db)
+        .await
+        .map_err(db_error)?;
+
+    record_audit(
+        db,
+        audit_entry(AuditAction::Delete, audit_entities::PROFILE, user.id)
+            .await
+            .before(&before),
+    )
+    .await;
+
+    Ok(())
+}
+
+/// What `/u/:username` shows. Deactivated accounts and unknown names are both
+/// "Profile not found"; only public links and published posts are included.
+#[server]
+pub async fn get_public_profile(username: String) -> Result<PublicProfile, ServerFnError> {
+    let db = get_db().await;
+
+    let db_error = |e: sqlx::Error| -> ServerFnError {
+        tracing::error!("Database error loading public profile: {}", e);
+        ServerFnError::ServerError("Failed to load profile".into())
+    };
+
+    #[allow(clippy::type_complexity)]
+    let row: Option<(
+        Uuid, String, Option<String>, Option<String>, Option<String>, Option<String>, Option<String>,
+        chrono::DateTime<chrono::Utc>, i64, i64,
+    )> = sqlx::query_as(
+        r#"
+        SELECT u.id, u.username, p.first_name, p.last_name, p.bio, p.avatar_url, p.website_url,
+               u.created_at,
+               (SELECT COUNT(*) FROM follows f WHERE f.following_id = u.id),
+               (SELECT COUNT(*) FROM follows f WHERE f.follower_id = u.id)
+        FROM users u
+        LEFT JOIN profiles p ON p.user_id = u.id
+        WHERE u.username = $1 AND u.is_active = true
+        "#
+    )
+    .bind(username.trim())
+    .fetch_optional(db)
+    .await
+    .map_err(db_error)?;
+
+    let Some((user_id, username, first_name, last_name, bio, avatar_url, website_url, joined_at, followers, following)) = row else {
+        return Err(ServerFnError::Request("Profile not found".into()));
+    };
+
+    let social_links = sqlx::query_as::<_, SocialLink>(&format!(
+        "SELECT {} FROM user_social_links l \
+         JOIN social_media_platforms p ON p.id = l.platform_id \
+         WHERE l.user_id = $1 AND l.is_public = true ORDER BY p.name",
+        SOCIAL_LINK_COLUMNS
+    ))
+    .bind(user_id)
+    .fetch_all(db)
+    .await
+    .map_err(db_error)?;
+
+    let posts = sqlx::query_as::<_, ProfilePost>(
+        "SELECT id, title, created_at FROM posts \
+         WHERE user_id = $1 AND is_published = true \
+         ORDER BY created_at DESC LIMIT $2"
+    )
+    .bind(user_id)
+    .bind(PROFILE_POST_LIMIT)
+    .fetch_all(db)
+    .await
+    .map_err(db_error)?;
+
+    Ok(PublicProfile {
+        user_id,
+        username,
+        first_name,
+        last_name,
+        bio,
+        avatar_url,
+        website_url,
+        joined_at,
+        followers,
+        following,
+        social_links,
+        posts,
+    })
+}
+
+
 // // Creating a profile
 // let create_profile = move |user_id| async move {
 //     match create_profile(user_id).await {
@@ -4,7 +4,7 @@ use serde::{Deserialize, Serialize};
 use validator::Validate;
 use sqlx::FromRow;
 use crate::users::users_model::User;
-use crate::utils::validation::validate_http_url;
+use crate::utils::validation::{validate_avatar_url, validate_http_url};
 
 
 
@@ -22,7 +22,7 @@ pub struct UserProfile {
     #[validate(length(max = 2000, message = "Bio must be 2000 characters or less"))]
     pub bio: Option<String>,
 
-    #[validate(custom(function = "validate_http_url"))]
+    #[validate(custom(function = "validate_avatar_url"))]
     pub avatar_url: Option<String>,
     
     #[validate(custom(function = "validate_http_url"))]
@@ -35,6 +35,7 @@ pub struct UserProfile {
     pub updated_at: DateTime<Utc>,
     
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[sqlx(skip)]
     pub user: Option<User>,
 }
 
@@ -76,11 +77,112 @@ pub struct ProfileUpdate {
     #[validate(length(max = 2000))]
     pub bio: Option<String>,
     
-    #[validate(url)]
-    #[validate(custom(function = "validate_http_url"))]
+    #[validate(custom(function = "validate_avatar_url"))]
     pub avatar_url: Option<String>,
 
     #[validate(url)]
     #[validate(custom(function = "validate_http_url"))]
     pub website_url: Option<String>,
-}
\ No newline at end of file
+}
+
+/// A row of `social_media_platforms`
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, FromRow)]
+pub struct SocialPlatform {
+    pub id: i16,
+    pub name: String,
+    /// Profile URLs are this followed by the handle, e.g. `https://github.com/`
+    pub base_url: String,
+    pub icon_class: Option<String>,
+}
+
+impl SocialPlatform {
+    /// Turns what a user typed into the handle stored for this platform: a
+    /// pasted profile URL is cut down to the handle, and platforms whose
+    /// profile URLs use `@name` get the `@`
+    pub fn normalize_handle(&self, input: &str) -> String {
+        let mut handle = input.trim();
+        for prefix in [self.base_url.as_str(), self.base_url.trim_start_matches("https://")] {
+            if let Some(rest) = handle.strip_prefix(prefix) {
+                handle = rest;
+                break;
+            }
+        }
+        let handle = handle.trim_end_matches('/');
+
+        match self.name.as_str() {
+            "YouTube" | "TikTok" => format!("@{}", handle.trim_start_matches('@')),
+            _ => handle.trim_start_matches('@').to_string(),
+        }
+    }
+
+    pub fn profile_url(&self, handle: &str) -> String {
+        format!("{}{}", self.base_url, handle)
+    }
+}
+
+pub const SOCIAL_LINK_COLUMNS: &str = "l.platform_id, p.name AS platform_name, p.icon_class, \
+    l.username AS handle, p.base_url || l.username AS url, l.is_public";
+
+/// One of a user's social accounts
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, FromRow)]
+pub struct SocialLink {
+    pub platform_id: i16,
+    pub platform_name: String,
+    pub icon_class: Option<String>,
+    pub handle: String,
+    /// Built from the platform's `base_url`
+    pub url: String,
+    pub is_public: bool,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Validate)]
+pub struct SocialLinkInput {
+    pub platform_id: i16,
+    /// Handle or full profile URL; checked against the platform's rules on save
+    #[validate(length(min = 1, max = 100, message = "Enter your username on that site"))]
+    pub handle: String,
+    pub is_public: bool,
+}
+
+/// A published post in a profile's list
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, FromRow)]
+pub struct ProfilePost {
+    pub id: i32,
+    pub title: String,
+    #[serde(with = "chrono::serde::ts_seconds")]
+    pub created_at: DateTime<Utc>,
+}
+
+/// Everything `/u/:username` shows. Only active accounts have one.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct PublicProfile {
+    pub user_id: Uuid,
+    pub username: String,
+    pub first_name: Option<String>,
+    pub last_name: Option<String>,
+    pub bio: Option<String>,
+    pub avatar_url: Option<String>,
+    pub website_url: Option<String>,
+    #[serde(with = "chrono::serde::ts_seconds")]
+    pub joined_at: DateTime<Utc>,
+    pub followers: i64,
+    pub following: i64,
+    /// Public links only
+    pub social_links: Vec<SocialLink>,
+    /// Newest first
+    pub posts: Vec<ProfilePost>,
+}
+
+impl PublicProfile {
+    /// "First Last", or the username if neither is set
+    pub fn display_name(&self) -> String {
+        let name = [self.first_name.as_deref(), self.last_name.as_deref()]
+            .into_iter()
+            .flatten()
+            .map(str::trim)
+            .filter(|part| !part.is_empty())
+            .collect::<Vec<_>>()
+            .join(" ");
+        if name.is_empty() { self.username.clone() } else { name }
+    }
+}
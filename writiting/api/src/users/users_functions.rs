@@ -1,134 +1,670 @@
-// pg_app/server/src/server_functions.rs
 use dioxus::prelude::*;
-use crate::users::{UserProfile, ProfileUpdate, User};
-use crate::ServerFnError;
-use crate::db::connection_pool::get_db;
+#[cfg(feature = "server")]
+use serde_json::json;
+use sqlx::PgPool;
 use uuid::Uuid;
+#[cfg(feature = "server")]
 use tracing::info;
+#[cfg(feature = "server")]
+use validator::Validate;
 
-#[server]
-pub async fn create_usesr(user_id: Uuid) -> Result<(), ServerFnError> {
-    let db = get_db().await;
-    
-    sqlx::query!(
-        "INSERT INTO users (user_id) VALUES ($1)",
-        user_id
+#[cfg(feature = "server")]
+use crate::db::get_db;
+#[cfg(feature = "server")]
+use crate::users::users_model::ADMIN_USER_COLUMNS;
+use crate::users::{
+    AdminUserFilter, AdminUserPage, BulkUserAction, BulkUserResult, ImpersonationStatus, RoleOption,
+    User, UserProfile, UserStats, UserUpdate,
+};
+#[cfg(feature = "server")]
+use crate::users::AdminUserSummary;
+#[cfg(feature = "server")]
+use crate::mail::QueueError;
+#[cfg(feature = "server")]
+use crate::authentication::{audit_entities, audit_entry, record_audit, record_security_event, AuditAction, SecurityAction};
+
+#[cfg(feature = "server")]
+const USER_PAGE_SIZE: i64 = 50;
+#[cfg(feature = "server")]
+const BULK_ACTION_LIMIT: usize = 200;
+/// Longest an admin may act as someone else in one go
+pub const MAX_IMPERSONATION_MINUTES: i64 = 60;
+
+/// Whether every permission of `role_id` is also held by `actor_role_id`,
+/// so an admin can't manage accounts or hand out roles above their own
+pub async fn role_within(db: &PgPool, actor_role_id: i32, role_id: i32) -> Result<bool, sqlx::Error> {
+    sqlx::query_scalar::<_, bool>(
+        r#"
+        SELECT NOT EXISTS(
+            SELECT 1 FROM role_permissions rp
+            WHERE rp.role_id = $2
+              AND rp.permission_id NOT IN (SELECT permission_id FROM role_permissions WHERE role_id = $1)
+        )
+        "#
     )
-    .execute(db)
+    .bind(actor_role_id)
+    .bind(role_id)
+    .fetch_one(db)
     .await
-    .map_err(|e| {
-        tracing::error!("Database error creating user: {}", e);
-        ServerFnError::ServerError("Failed to create user".into())
-    })?;
+}
+
+/// Loads an account `admin` is allowed to act on: not their own, and not above their role
+#[cfg(feature = "server")]
+pub(crate) async fn managed_user(db: &PgPool, admin: &User, user_id: Uuid) -> Result<User, ServerFnError> {
+    let db_error = |e: sqlx::Error| -> ServerFnError {
+        tracing::error!("Database error loading user: {}", e);
+        ServerFnError::ServerError("Failed to load user".into())
+    };
+
+    if user_id == admin.id {
+        return Err(ServerFnError::Request("You can't do that to your own account".into()));
+    }
+
+    let user = sqlx::query_as::<_, User>("SELECT * FROM users WHERE id = $1")
+        .bind(user_id)
+        .fetch_optional(db)
+        .await
+        .map_err(db_error)?
+        .ok_or_else(|| -> ServerFnError { ServerFnError::Request("User not found".into()) })?;
+
+    if !role_within(db, admin.role_id, user.role_id).await.map_err(db_error)? {
+        return Err(ServerFnError::Request("That account has permissions you don't".into()));
+    }
+    Ok(user)
+}
+
+/// Deactivating also signs the account out everywhere
+#[cfg(feature = "server")]
+pub(crate) async fn set_active(db: &PgPool, admin: &User, user: &User, active: bool) -> Result<(), sqlx::Error> {
+    let mut tx = db.begin().await?;
+    set_active_in(&mut tx, admin, user, active).await?;
+    tx.commit().await?;
+
+    info!("User {} {} by {}", user.id, if active { "reactivated" } else { "deactivated" }, admin.id);
+    Ok(())
+}
+
+/// `set_active` as part of a transaction the caller commits
+#[cfg(feature = "server")]
+pub(crate) async fn set_active_in(
+    conn: &mut sqlx::PgConnection,
+    admin: &User,
+    user: &User,
+    active: bool,
+) -> Result<(), sqlx::Error> {
+    let meta = crate::utils::request_meta().await;
+
+    let updated = sqlx::query_as::<_, User>(
+        "UPDATE users SET is_active = $2, updated_at = NOW() WHERE id = $1 RETURNING *"
+    )
+    .bind(user.id)
+    .bind(active)
+    .fetch_one(&mut *conn)
+    .await?;
+
+    if !active {
+        crate::session::revoke_user_sessions(&mut *conn, user.id, None).await?;
+    }
+
+    let action = if active { SecurityAction::AccountReactivated } else { SecurityAction::AccountDeactivated };
+    record_security_event(&mut *conn, Some(user.id), action, &meta, json!({ "by": admin.id })).await?;
+
+    record_audit(
+        &mut *conn,
+        audit_entry(AuditAction::Update, audit_entities::USER, user.id)
+            .await
+            .before(user)
+            .after(&updated),
+    )
+    .await;
+
+    Ok(())
+}
+
+/// Replaces the password with one nobody knows, signs the account out and
+/// emails a reset link
+#[cfg(feature = "server")]
+async fn force_reset(db: &PgPool, admin: &User, user: &User) -> Result<(), QueueError> {
+    use crate::authentication::models::TokenGenerator;
+
+    let meta = crate::utils::request_meta().await;
+    let mut scrambled = user.clone();
+    scrambled.set_password(&TokenGenerator::generate_alphanumeric_token(32));
+
+    let mut tx = db.begin().await?;
+
+    sqlx::query("UPDATE users SET password_hash = $2, salt = $3, updated_at = NOW() WHERE id = $1")
+        .bind(user.id)
+        .bind(&scrambled.password_hash)
+        .bind(&scrambled.salt)
+        .execute(&mut *tx)
+        .await?;
+
+    crate::session::revoke_user_sessions(&mut *tx, user.id, None).await?;
+
+    record_audit(
+        &mut *tx,
+        audit_entry(AuditAction::Update, audit_entities::USER, user.id)
+            .await
+            .metadata(json!({ "forced_password_reset": true })),
+    )
+    .await;
+
+    tx.commit().await?;
+
+    // Inactive accounts get no email; the new password is simply unusable
+    crate::authentication::send_password_reset(db, &user.email, &meta).await?;
+
+    info!("Password reset forced for user {} by {}", user.id, admin.id);
+    Ok(())
+}
+
+#[cfg(feature = "server")]
+async fn revoke_sessions(db: &PgPool, admin: &User, user: &User) -> Result<u64, sqlx::Error> {
+    let revoked = crate::session::revoke_user_sessions(db, user.id, None).await?;
 
-    info!("Created user: {}", user_id);
+    record_audit(
+        db,
+        audit_entry(AuditAction::Update, audit_entities::USER, user.id)
+            .await
+            .metadata(json!({ "sessions_revoked": revoked })),
+    )
+    .await;
+
+    info!("Revoked {} sessions of user {} by {}", revoked, user.id, admin.id);
+    Ok(revoked)
+}
+
+#[cfg(feature = "server")]
+async fn remove_user(db: &PgPool, admin: &User, user: &User) -> Result<(), sqlx::Error> {
+    let mut tx = db.begin().await?;
+
+    sqlx::query("DELETE FROM users WHERE id = $1")
+        .bind(user.id)
+        .execute(&mut *tx)
+        .await?;
+
+    record_audit(
+        &mut *tx,
+        audit_entry(AuditAction::Delete, audit_entities::USER, user.id)
+            .await
+            .before(user),
+    )
+    .await;
+
+    tx.commit().await?;
+
+    info!("Deleted user {} by {}", user.id, admin.id);
     Ok(())
-} 
+}
+
+/// Permission each bulk action needs
+#[cfg(feature = "server")]
+fn bulk_permission(action: BulkUserAction) -> &'static str {
+    match action {
+        BulkUserAction::Delete => "user:delete",
+        _ => "user:update",
+    }
+}
 
 #[server]
 pub async fn get_user(user_id: Uuid) -> Result<User, ServerFnError> {
     let db = get_db().await;
+    crate::session::require_permission("user:read").await?;
+
+    sqlx::query_as::<_, User>("SELECT * FROM users WHERE id = $1")
+        .bind(user_id)
+        .fetch_optional(db)
+        .await
+        .map_err(|e| -> ServerFnError {
+            tracing::error!("Database error fetching user: {}", e);
+            ServerFnError::ServerError("Failed to load user".into())
+        })?
+        .ok_or_else(|| ServerFnError::Request("User not found".into()))
+}
+
+#[server]
+pub async fn get_user_with_profile(user_id: Uuid) -> Result<(User, UserProfile), ServerFnError> {
+    let user = get_user(user_id).await?;
+    let profile = crate::users::get_profile(user_id).await?;
+
+    Ok((user, profile))
+}
+
+/// One page of accounts for the admin user list
+#[server]
+pub async fn list_users(filter: AdminUserFilter) -> Result<AdminUserPage, ServerFnError> {
+    let db = get_db().await;
+    crate::session::require_permission("user:read").await?;
+    let db_error = |e: sqlx::Error| -> ServerFnError {
+        tracing::error!("Database error listing users: {}", e);
+        ServerFnError::ServerError("Failed to load users".into())
+    };
+
+    let search = filter
+        .search
+        .as_deref()
+        .map(str::trim)
+        .filter(|v| !v.is_empty())
+        .map(|v| format!("%{}%", v.replace('\\', "\\\\").replace('%', "\\%").replace('_', "\\_")));
+    let page = filter.page.max(1);
+
+    let where_clause = r#"
+        FROM users u
+        JOIN roles r ON r.id = u.role_id
+        LEFT JOIN users inviter ON inviter.id = u.invited_by
+        WHERE ($1::text IS NULL OR u.username ILIKE $1 OR u.email ILIKE $1)
+          AND ($2::int IS NULL OR u.role_id = $2)
+          AND ($3::bool IS NULL OR u.is_active = $3)
+          AND ($4::bool IS NULL OR u.email_verified = $4)
+    "#;
+
+    let total = sqlx::query_scalar::<_, i64>(&format!("SELECT COUNT(*) {}", where_clause))
+        .bind(&search)
+        .bind(filter.role_id)
+        .bind(filter.is_active)
+        .bind(filter.email_verified)
+        .fetch_one(db)
+        .await
+        .map_err(db_error)?;
 
-    sqlx::query_as::<_, User>(
+    let users = sqlx::query_as::<_, AdminUserSummary>(&format!(
+        "SELECT {} {} ORDER BY {} LIMIT $5 OFFSET $6",
+        ADMIN_USER_COLUMNS,
+        where_clause,
+        filter.sort.order_by()
+    ))
+    .bind(&search)
+    .bind(filter.role_id)
+    .bind(filter.is_active)
+    .bind(filter.email_verified)
+    .bind(USER_PAGE_SIZE)
+    .bind((page - 1) * USER_PAGE_SIZE)
+    .fetch_all(db)
+    .await
+    .map_err(db_error)?;
+
+    Ok(AdminUserPage { users, total, page, per_page: USER_PAGE_SIZE })
+}
+
+#[server]
+pub async fn get_user_stats() -> Result<UserStats, ServerFnError> {
+    let db = get_db().await;
+    crate::session::require_permission("user:read").await?;
+
+    sqlx::query_as::<_, UserStats>(
         r#"
-        SELECT * FROM users WHERE id = $1
+        SELECT COUNT(*) AS total,
+               COUNT(*) FILTER (WHERE is_active) AS active,
+               COUNT(*) FILTER (WHERE NOT is_active) AS inactive,
+               COUNT(*) FILTER (WHERE NOT email_verified) AS unverified
+        FROM users
         "#
     )
-    .bind(user_id)
     .fetch_one(db)
     .await
     .map_err(|e| {
-        tracing::error!("Database error fetching user: {}", e);
-        ServerFnError::ServerError("User not found".into())
+        tracing::error!("Database error counting users: {}", e);
+        ServerFnError::ServerError("Failed to load user counts".into())
     })
 }
 
+/// Roles the signed-in admin may give out
 #[server]
-pub async fn get_user_with_profile(user_id: Uuid) -> Result<(User, UserProfile), ServerFnError> {
+pub async fn list_assignable_roles() -> Result<Vec<RoleOption>, ServerFnError> {
     let db = get_db().await;
+    let admin = crate::session::require_permission("user:update").await?;
 
-    let user = get_user(user_id).await?;
-    let profile = get_profile(user_id).await?;
-
-    Ok((user, profile))
+    sqlx::query_as::<_, RoleOption>(
+        r#"
+        SELECT r.id, r.name FROM roles r
+        WHERE NOT EXISTS(
+            SELECT 1 FROM role_permissions rp
+            WHERE rp.role_id = r.id
+              AND rp.permission_id NOT IN (SELECT permission_id FROM role_permissions WHERE role_id = $1)
+        )
+        ORDER BY r.id
+        "#
+    )
+    .bind(admin.role_id)
+    .fetch_all(db)
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error listing roles: {}", e);
+        ServerFnError::ServerError("Failed to load roles".into())
+    })
 }
 
 #[server]
-pub async fn update_user(
-    user_id: Uuid,
-    update: UserUpdate
-) -> Result<User, ServerFnError> {
+pub async fn update_user(user_id: Uuid, update: UserUpdate) -> Result<User, ServerFnError> {
     let db = get_db().await;
-    
-    // Validate input
-    update.validate().map_err(|e| {
-        tracing::error!("Validation error: {:?}", e);
-        ServerFnError::Request("Invalid user data".into())
-    })?;
-
-    sqlx::query_as::<_, User>(
+    crate::log::require_writable().await?;
+    let admin = crate::session::require_permission("user:update").await?;
+    let db_error = |e: sqlx::Error| -> ServerFnError {
+        match e {
+            sqlx::Error::Database(ref err) if err.is_unique_violation() => {
+                ServerFnError::Request("That username or email is already in use".into())
+            }
+            e => {
+                tracing::error!("Database error updating user: {}", e);
+                ServerFnError::ServerError("Failed to update user".into())
+            }
+        }
+    };
+
+    update
+        .validate()
+        .map_err(|e| -> ServerFnError { ServerFnError::Request(e.to_string()) })?;
+
+    let user = managed_user(db, &admin, user_id).await?;
+
+    if let Some(role_id) = update.role_id.filter(|r| *r != user.role_id) {
+        if !role_within(db, admin.role_id, role_id).await.map_err(db_error)? {
+            return Err(ServerFnError::Request("You can't give out a role above your own".into()));
+        }
+    }
+
+    let mut tx = db.begin().await.map_err(db_error)?;
+
+    let updated = sqlx::query_as::<_, User>(
         r#"
         UPDATE users SET
-            email = COALESCE($1, email),
-            username = COALESCE($2, username),
-            role_id = COALESCE($3, role_id),
-            is_active = COALESCE($4, is_active),
-            email_verified = COALESCE($5, email_verified)
-        WHERE id = $6
+            email = COALESCE($2, email),
+            username = COALESCE($3, username),
+            role_id = COALESCE($4, role_id),
+            is_active = COALESCE($5, is_active),
+            email_verified = COALESCE($6, email_verified),
+            updated_at = NOW()
+        WHERE id = $1
         RETURNING *
         "#
     )
-    .bind(update.email)
-    .bind(update.username)
+    .bind(user_id)
+    .bind(update.email.as_deref().map(str::trim))
+    .bind(update.username.as_deref().map(str::trim))
     .bind(update.role_id)
     .bind(update.is_active)
     .bind(update.email_verified)
-    .bind(user_id)
-    .fetch_one(db)
+    .fetch_one(&mut *tx)
     .await
-    .map_err(|e| {
-        tracing::error!("Database error updating user: {}", e);
+    .map_err(db_error)?;
+
+    // A changed role or a deactivation takes effect on the next sign-in
+    if updated.role_id != user.role_id || (user.is_active && !updated.is_active) {
+        crate::session::revoke_user_sessions(&mut *tx, user_id, None)
+            .await
+            .map_err(db_error)?;
+    }
+
+    record_audit(
+        &mut *tx,
+        audit_entry(AuditAction::Update, audit_entities::USER, user_id)
+            .await
+            .before(&user)
+            .after(&updated),
+    )
+    .await;
+
+    tx.commit().await.map_err(db_error)?;
+
+    info!("User {} updated by {}", user_id, admin.id);
+    Ok(updated)
+}
+
+#[server]
+pub async fn set_user_active(user_id: Uuid, active: bool) -> Result<(), ServerFnError> {
+    let db = get_db().await;
+    crate::log::require_writable().await?;
+    let admin = crate::session::require_permission("user:update").await?;
+
+    let user = managed_user(db, &admin, user_id).await?;
+    set_active(db, &admin, &user, active).await.map_err(|e| {
+        tracing::error!("Database error changing user status: {}", e);
         ServerFnError::ServerError("Failed to update user".into())
     })
 }
 
+/// Makes the account choose a new password through an emailed link
+#[server]
+pub async fn force_password_reset(user_id: Uuid) -> Result<(), ServerFnError> {
+    let db = get_db().await;
+    crate::log::require_writable().await?;
+    let admin = crate::session::require_permission("user:update").await?;
+
+    let user = managed_user(db, &admin, user_id).await?;
+    force_reset(db, &admin, &user).await.map_err(|e| {
+        tracing::error!("Failed to force password reset: {}", e);
+        ServerFnError::ServerError("Failed to reset password".into())
+    })
+}
+
+/// Signs the account out everywhere. Returns how many sessions were ended.
+#[server]
+pub async fn revoke_sessions_for_user(user_id: Uuid) -> Result<u64, ServerFnError> {
+    let db = get_db().await;
+    let admin = crate::session::require_permission("user:update").await?;
+
+    let user = managed_user(db, &admin, user_id).await?;
+    revoke_sessions(db, &admin, &user).await.map_err(|e| {
+        tracing::error!("Database error revoking sessions: {}", e);
+        ServerFnError::ServerError("Failed to sign the user out".into())
+    })
+}
+
 #[server]
 pub async fn delete_user(user_id: Uuid) -> Result<(), ServerFnError> {
     let db = get_db().await;
+    crate::log::require_writable().await?;
+    let admin = crate::session::require_permission("user:delete").await?;
 
-    sqlx::query!(
-        "DELETE FROM users WHERE id = $1",
-        user_id
-    )
-    .execute(db)
-    .await
-    .map_err(|e| {
+    let user = managed_user(db, &admin, user_id).await?;
+    remove_user(db, &admin, &user).await.map_err(|e| {
         tracing::error!("Database error deleting user: {}", e);
         ServerFnError::ServerError("Failed to delete user".into())
-    })?;
+    })
+}
 
-    info!("Deleted user: {}", user_id);
-    Ok(())
+/// Applies `action` to each account in turn; one failure doesn't stop the rest
+#[server]
+pub async fn apply_bulk_user_action(user_ids: Vec<Uuid>, action: BulkUserAction) -> Result<BulkUserResult, ServerFnError> {
+    let db = get_db().await;
+    if action != BulkUserAction::RevokeSessions {
+        crate::log::require_writable().await?;
+    }
+    let admin = crate::session::require_permission(bulk_permission(action)).await?;
+
+    if user_ids.len() > BULK_ACTION_LIMIT {
+        return Err(ServerFnError::Request(format!(
+            "Select at most {} users at a time",
+            BULK_ACTION_LIMIT
+        )));
+    }
+
+    let mut result = BulkUserResult::default();
+    for user_id in user_ids {
+        let user = match managed_user(db, &admin, user_id).await {
+            Ok(user) => user,
+            Err(ServerFnError::Request(reason)) => {
+                result.failed.push((user_id, reason));
+                continue;
+            }
+            Err(e) => return Err(e),
+        };
+
+        let outcome: Result<(), QueueError> = match action {
+            BulkUserAction::Activate => set_active(db, &admin, &user, true).await.map_err(Into::into),
+            BulkUserAction::Deactivate => set_active(db, &admin, &user, false).await.map_err(Into::into),
+            BulkUserAction::RevokeSessions => revoke_sessions(db, &admin, &user).await.map(|_| ()).map_err(Into::into),
+            BulkUserAction::ForcePasswordReset => force_reset(db, &admin, &user).await,
+            BulkUserAction::Delete => remove_user(db, &admin, &user).await.map_err(Into::into),
+        };
+
+        match outcome {
+            Ok(()) => result.succeeded += 1,
+            Err(e) => {
+                tracing::error!("Bulk {} failed for {}: {}", action.as_str(), user_id, e);
+                let reason = match e {
+                    QueueError::Database(_) => "Database error",
+                    QueueError::Mailer(_) => "Email is unavailable",
+                };
+                result.failed.push((user_id, reason.to_string()));
+            }
+        }
+    }
+
+    info!(
+        "Bulk {} by {}: {} done, {} skipped",
+        action.as_str(),
+        admin.id,
+        result.succeeded,
+        result.failed.len()
+    );
+    Ok(result)
 }
 
+/// Signs the admin in as `user_id` for up to `minutes`, keeping their own
+/// session aside for `stop_impersonation`
 #[server]
-pub async fn get_profile_with_user(user_id: Uuid) -> Result<(UserProfile, User), ServerFnError> {
+pub async fn impersonate_user(user_id: Uuid, minutes: i64) -> Result<(), ServerFnError> {
+    use crate::session::{create_impersonation_session, current_impersonator, current_session_token, set_session_cookie, stash_impersonator_cookie};
+
     let db = get_db().await;
+    let admin = crate::session::require_permission("user:impersonate").await?;
+    let meta = crate::utils::request_meta().await;
+    let db_error = |e: sqlx::Error| -> ServerFnError {
+        tracing::error!("Database error starting impersonation: {}", e);
+        ServerFnError::ServerError("Failed to sign in as that user".into())
+    };
 
-    let profile = sqlx::query_as::<_, UserProfile>(
-        "SELECT * FROM profiles WHERE user_id = $1"
+    if current_impersonator().await.is_some() {
+        return Err(ServerFnError::Request("Stop the current impersonation first".into()));
+    }
+    let Some(admin_token) = current_session_token().await else {
+        return Err(ServerFnError::ServerError("Not signed in".into()));
+    };
+
+    let user = managed_user(db, &admin, user_id).await?;
+    if !user.is_active {
+        return Err(ServerFnError::Request("That account is deactivated".into()));
+    }
+    let minutes = minutes.clamp(1, MAX_IMPERSONATION_MINUTES);
+
+    let mut tx = db.begin().await.map_err(db_error)?;
+
+    let session = create_impersonation_session(&mut *tx, user.id, admin.id, minutes, &meta)
+        .await
+        .map_err(db_error)?;
+
+    record_security_event(
+        &mut *tx,
+        Some(user.id),
+        SecurityAction::ImpersonationStarted,
+        &meta,
+        json!({ "impersonator_id": admin.id, "impersonator": admin.username, "minutes": minutes }),
     )
-    .bind(user_id)
-    .fetch_one(db)
-    .await?;
+    .await
+    .map_err(db_error)?;
 
-    let user = sqlx::query_as::<_, User>(
-        "SELECT * FROM users WHERE id = $1"
+    record_audit(
+        &mut *tx,
+        audit_entry(AuditAction::View, audit_entities::USER, user.id)
+            .await
+            .metadata(json!({ "impersonation": true, "minutes": minutes, "expires_at": session.expires_at })),
     )
-    .bind(user_id)
-    .fetch_one(db)
-    .await?;
+    .await;
+
+    tx.commit().await.map_err(db_error)?;
+
+    stash_impersonator_cookie(&admin_token);
+    set_session_cookie(&session);
 
-    Ok((profile, user))
+    info!("User {} impersonating {} for {} minutes", admin.id, user.id, minutes);
+    Ok(())
 }
 
+/// Ends the impersonation session and puts the admin's own session back
+#[server]
+pub async fn stop_impersonation() -> Result<(), ServerFnError> {
+    use crate::session::{
+        clear_impersonator_cookie, clear_session_cookie, current_session_token, find_session,
+        revoke_session, set_session_cookie, stashed_impersonator_token,
+    };
+
+    let db = get_db().await;
+    let meta = crate::utils::request_meta().await;
+    let db_error = |e: sqlx::Error| -> ServerFnError {
+        tracing::error!("Database error stopping impersonation: {}", e);
+        ServerFnError::ServerError("Failed to stop impersonating".into())
+    };
+
+    let stashed = stashed_impersonator_token().await;
+    let current = match current_session_token().await {
+        Some(token) => find_session(db, &token).await.map_err(db_error)?,
+        None => None,
+    };
+
+    // The impersonation session may already have expired; the admin still gets theirs back
+    let impersonated = current.filter(|s| s.impersonator_id.is_some());
+    if impersonated.is_none() && stashed.is_none() {
+        return Err(ServerFnError::Request("You aren't signed in as anyone else".into()));
+    }
+
+    if let Some(session) = &impersonated {
+        revoke_session(db, &session.token).await.map_err(db_error)?;
+        record_security_event(
+            db,
+            Some(session.user_id),
+            SecurityAction::ImpersonationEnded,
+            &meta,
+            json!({ "impersonator_id": session.impersonator_id }),
+        )
+        .await
+        .map_err(db_error)?;
+    }
+
+    clear_impersonator_cookie();
+    let restored = match stashed {
+        Some(token) => find_session(db, &token).await.map_err(db_error)?,
+        None => None,
+    };
+    match restored {
+        Some(session) => set_session_cookie(&session),
+        None => clear_session_cookie(),
+    }
+
+    info!("Impersonation ended");
+    Ok(())
+}
+
+/// Set while an admin is signed in as someone else, so the UI can say so
+#[server]
+pub async fn get_impersonation_status() -> Result<Option<ImpersonationStatus>, ServerFnError> {
+    let Some(token) = crate::session::current_session_token().await else {
+        return Ok(None);
+    };
+
+    sqlx::query_as::<_, (Uuid, String, String, chrono::DateTime<chrono::Utc>)>(
+        r#"
+        SELECT s.impersonator_id, a.username, u.username, s.expires_at
+        FROM sessions s
+        JOIN users u ON u.id = s.user_id
+        JOIN users a ON a.id = s.impersonator_id
+        WHERE s.token = $1 AND s.is_revoked = false AND s.expires_at > NOW()
+        "#
+    )
+    .bind(token)
+    .fetch_optional(get_db().await)
+    .await
+    .map(|row| {
+        row.map(|(impersonator_id, impersonator_username, username, expires_at)| ImpersonationStatus {
+            impersonator_id,
+            impersonator_username,
+            username,
+            expires_at,
+        })
+    })
+    .map_err(|e| {
+        tracing::error!("Database error loading impersonation status: {}", e);
+        ServerFnError::ServerError("Failed to load session".into())
+    })
+}
@@ -5,10 +5,18 @@ use validator::{Validate, ValidationErrors};
 use argon2::{Argon2, password_hash::{PasswordHasher, SaltString}};
 use argon2::password_hash::rand_core::OsRng;
 use sqlx::Type;
+use lazy_static::lazy_static;
+
+lazy_static! {
+    /// Hash of a random password nobody holds, made with the same parameters as real ones
+    static ref DUMMY_PASSWORD_HASH: String = Argon2::default()
+        .hash_password(Uuid::new_v4().as_bytes(), &SaltString::generate(&mut OsRng))
+        .unwrap()
+        .to_string();
+}
 
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize, sqlx::FromRow, Validate)]
 pub struct User {
-    #[serde(skip_serializing)]
     pub id: Uuid,
     
     #[validate(email(message = "Must be a valid email"))]
@@ -17,10 +25,10 @@ pub struct User {
     #[validate(length(min = 3, max = 50, message = "Username must be 3-50 characters"))]
     pub username: String,
     
-    #[serde(skip_serializing)]
+    #[serde(skip_serializing, default)]
     pub password_hash: String,
     
-    #[serde(skip_serializing)]
+    #[serde(skip_serializing, default)]
     pub salt: String,
     
     pub role_id: i32,
@@ -38,6 +46,7 @@ pub struct User {
     pub updated_at: DateTime<Utc>,
     
     #[serde(skip_serializing)]
+    #[sqlx(skip)]
     pub role: Option<Role>,
 }
 
@@ -92,6 +101,15 @@ let salt = SaltString::generate(&mut OsRng);
         }
     }
     
+    /// Runs the same check as `verify_password` against a hash nobody holds,
+    /// so signing in as an unknown email takes as long as a real account
+    pub fn verify_dummy_password(password: &str) {
+        use argon2::{PasswordHash, PasswordVerifier};
+        if let Ok(parsed_hash) = PasswordHash::new(&DUMMY_PASSWORD_HASH) {
+            let _ = Argon2::default().verify_password(password.as_bytes(), &parsed_hash);
+        }
+    }
+
     pub fn has_permission(&self, permission: &str) -> bool {
         self.role.as_ref()
             .map(|r| r.permissions.contains(&permission.to_string()))
@@ -107,4 +125,186 @@ let salt = SaltString::generate(&mut OsRng);
         self.salt = salt.to_string();
         self.updated_at = Utc::now();
     }
-} 
\ No newline at end of file
+} 
+/// Fields an admin may change on an account; `None` leaves a field alone
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize, Validate)]
+pub struct UserUpdate {
+    #[validate(email(message = "Must be a valid email"))]
+    pub email: Option<String>,
+
+    #[validate(length(min = 3, max = 50, message = "Username must be 3-50 characters"))]
+    pub username: Option<String>,
+
+    pub role_id: Option<i32>,
+    pub is_active: Option<bool>,
+    pub email_verified: Option<bool>,
+}
+
+// The users table has no role name; listings join it in
+pub const ADMIN_USER_COLUMNS: &str = "u.id, u.username, u.email, u.role_id, r.name AS role_name, \
+    u.is_active, u.email_verified, u.last_login_at, u.invited_by, inviter.username AS invited_by_username, u.created_at";
+
+/// A row in the admin user list
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, sqlx::FromRow)]
+pub struct AdminUserSummary {
+    pub id: Uuid,
+    pub username: String,
+    pub email: String,
+    pub role_id: i32,
+    pub role_name: String,
+    pub is_active: bool,
+    pub email_verified: bool,
+    pub last_login_at: Option<DateTime<Utc>>,
+    /// Whose invitation the account signed up with
+    pub invited_by: Option<Uuid>,
+    pub invited_by_username: Option<String>,
+    #[serde(with = "chrono::serde::ts_seconds")]
+    pub created_at: DateTime<Utc>,
+}
+
+/// How the admin user list is ordered
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum UserSort {
+    #[default]
+    Newest,
+    Oldest,
+    Username,
+    Email,
+    LastLogin,
+}
+
+impl UserSort {
+    pub const ALL: [UserSort; 5] = [
+        UserSort::Newest,
+        UserSort::Oldest,
+        UserSort::Username,
+        UserSort::Email,
+        UserSort::LastLogin,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            UserSort::Newest => "newest",
+            UserSort::Oldest => "oldest",
+            UserSort::Username => "username",
+            UserSort::Email => "email",
+            UserSort::LastLogin => "last_login",
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            UserSort::Newest => "Newest first",
+            UserSort::Oldest => "Oldest first",
+            UserSort::Username => "Username",
+            UserSort::Email => "Email",
+            UserSort::LastLogin => "Last sign-in",
+        }
+    }
+
+    /// ORDER BY clause; ends on the id so pages don't shift between requests
+    pub fn order_by(&self) -> &'static str {
+        match self {
+            UserSort::Newest => "u.created_at DESC, u.id",
+            UserSort::Oldest => "u.created_at ASC, u.id",
+            UserSort::Username => "lower(u.username), u.id",
+            UserSort::Email => "lower(u.email), u.id",
+            UserSort::LastLogin => "u.last_login_at DESC NULLS LAST, u.id",
+        }
+    }
+}
+
+/// Filters for the admin user list; every field is optional
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct AdminUserFilter {
+    /// Matches part of the username or email, case-insensitively
+    pub search: Option<String>,
+    pub role_id: Option<i32>,
+    pub is_active: Option<bool>,
+    pub email_verified: Option<bool>,
+    pub sort: UserSort,
+    pub page: i64,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct AdminUserPage {
+    pub users: Vec<AdminUserSummary>,
+    pub total: i64,
+    pub page: i64,
+    pub per_page: i64,
+}
+
+/// Account counts for the top of the admin user page
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize, sqlx::FromRow)]
+pub struct UserStats {
+    pub total: i64,
+    pub active: i64,
+    pub inactive: i64,
+    pub unverified: i64,
+}
+
+/// A role an admin can pick from when editing an account
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, sqlx::FromRow)]
+pub struct RoleOption {
+    pub id: i32,
+    pub name: String,
+}
+
+/// Something to do to every selected account at once
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BulkUserAction {
+    Activate,
+    Deactivate,
+    RevokeSessions,
+    ForcePasswordReset,
+    Delete,
+}
+
+impl BulkUserAction {
+    pub const ALL: [BulkUserAction; 5] = [
+        BulkUserAction::Activate,
+        BulkUserAction::Deactivate,
+        BulkUserAction::RevokeSessions,
+        BulkUserAction::ForcePasswordReset,
+        BulkUserAction::Delete,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            BulkUserAction::Activate => "activate",
+            BulkUserAction::Deactivate => "deactivate",
+            BulkUserAction::RevokeSessions => "revoke_sessions",
+            BulkUserAction::ForcePasswordReset => "force_password_reset",
+            BulkUserAction::Delete => "delete",
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            BulkUserAction::Activate => "Reactivate",
+            BulkUserAction::Deactivate => "Deactivate",
+            BulkUserAction::RevokeSessions => "Sign out everywhere",
+            BulkUserAction::ForcePasswordReset => "Force password reset",
+            BulkUserAction::Delete => "Delete",
+        }
+    }
+}
+
+/// What the selected accounts came to; accounts that were skipped say why
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct BulkUserResult {
+    pub succeeded: usize,
+    pub failed: Vec<(Uuid, String)>,
+}
+
+/// Who is really behind the current session, when an admin is acting as someone else
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ImpersonationStatus {
+    pub impersonator_id: Uuid,
+    pub impersonator_username: String,
+    pub username: String,
+    #[serde(with = "chrono::serde::ts_seconds")]
+    pub expires_at: DateTime<Utc>,
+}
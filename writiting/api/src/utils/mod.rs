@@ -1,2 +1,7 @@
 pub mod validation;
-pub use validation::{URL_REGEX_STR, validate_http_url};
\ No newline at end of file
+pub mod request_meta;
+
+pub use validation::{URL_REGEX_STR, validate_avatar_url, validate_flag_name, validate_http_url, validate_mime_types, validate_social_handle};
+pub use request_meta::RequestMeta;
+#[cfg(feature = "server")]
+pub use request_meta::{request_headers, request_meta};
@@ -0,0 +1,107 @@
+use std::net::IpAddr;
+
+/// Client details recorded alongside sessions and security events
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RequestMeta {
+    pub ip_address: Option<IpAddr>,
+    pub user_agent: Option<String>,
+}
+
+impl RequestMeta {
+    /// Text form for binding to INET columns
+    pub fn ip_string(&self) -> Option<String> {
+        self.ip_address.map(|ip| ip.to_string())
+    }
+}
+
+/// Headers of the request currently being handled by a server function
+#[cfg(feature = "server")]
+pub async fn request_headers() -> axum::http::HeaderMap {
+    dioxus::prelude::extract::<axum::http::HeaderMap, _>()
+        .await
+        .unwrap_or_default()
+}
+
+/// Client IP and user agent for the current request.
+/// `X-Forwarded-For` is only believed when it comes from a trusted proxy.
+#[cfg(feature = "server")]
+pub async fn request_meta() -> RequestMeta {
+    use std::net::SocketAddr;
+    use axum::extract::ConnectInfo;
+
+    let headers = request_headers().await;
+    let peer = dioxus::prelude::extract::<ConnectInfo<SocketAddr>, _>()
+        .await
+        .ok()
+        .map(|ConnectInfo(addr)| addr);
+
+    RequestMeta::from_parts(&headers, peer)
+}
+
+/// Proxies whose `X-Forwarded-For` header is believed, from `TRUSTED_PROXIES`
+/// (comma-separated addresses or CIDR blocks). Unset means none are, and the
+/// socket address is used as is.
+#[cfg(feature = "server")]
+fn trusted_proxies() -> &'static [String] {
+    use std::sync::OnceLock;
+    use crate::authentication::models::api_key_model::parse_cidr;
+
+    static PROXIES: OnceLock<Vec<String>> = OnceLock::new();
+    PROXIES.get_or_init(|| {
+        std::env::var("TRUSTED_PROXIES")
+            .unwrap_or_default()
+            .split(',')
+            .map(str::trim)
+            .filter(|block| !block.is_empty())
+            .filter(|block| {
+                let valid = parse_cidr(block).is_some();
+                if !valid {
+                    tracing::warn!("Ignoring invalid TRUSTED_PROXIES entry: {}", block);
+                }
+                valid
+            })
+            .map(str::to_string)
+            .collect()
+    })
+}
+
+#[cfg(feature = "server")]
+fn is_trusted_proxy(ip: IpAddr) -> bool {
+    use crate::authentication::models::api_key_model::cidr_contains;
+
+    trusted_proxies().iter().any(|block| cidr_contains(block, ip))
+}
+
+#[cfg(feature = "server")]
+impl RequestMeta {
+    /// Builds the metadata from raw request parts, for plain axum handlers.
+    ///
+    /// Clients can write anything into `X-Forwarded-For`, so it is only read
+    /// when the connection comes from a trusted proxy. The client is then the
+    /// last address in the chain that isn't one of our proxies.
+    pub fn from_parts(headers: &axum::http::HeaderMap, peer: Option<std::net::SocketAddr>) -> Self {
+        let peer = peer.map(|addr| addr.ip());
+        let ip_address = match peer {
+            Some(proxy) if is_trusted_proxy(proxy) => headers
+                .get_all("x-forwarded-for")
+                .iter()
+                .filter_map(|v| v.to_str().ok())
+                .flat_map(|v| v.split(','))
+                .filter_map(|ip| ip.trim().parse::<IpAddr>().ok())
+                .collect::<Vec<_>>()
+                .into_iter()
+                .rev()
+                .find(|ip| !is_trusted_proxy(*ip))
+                .or(peer),
+            _ => peer,
+        };
+
+        RequestMeta {
+            ip_address,
+            user_agent: headers
+                .get(axum::http::header::USER_AGENT)
+                .and_then(|v| v.to_str().ok())
+                .map(|v| v.chars().take(512).collect()),
+        }
+    }
+}
@@ -3,9 +3,21 @@ use regex::Regex;
 use validator::ValidationError;
 
 pub const URL_REGEX_STR: &str = r"^https?://.*";
+pub const FLAG_NAME_REGEX_STR: &str = r"^[a-z0-9_.-]+$";
+pub const MIME_TYPE_REGEX_STR: &str = r"^[a-z]+/[a-z0-9.+-]+$";
 
 lazy_static! {
     pub static ref URL_REGEX: Regex = Regex::new(URL_REGEX_STR).unwrap();
+    pub static ref FLAG_NAME_REGEX: Regex = Regex::new(FLAG_NAME_REGEX_STR).unwrap();
+    pub static ref MIME_TYPE_REGEX: Regex = Regex::new(MIME_TYPE_REGEX_STR).unwrap();
+    static ref MEDIA_PATH_REGEX: Regex = Regex::new(r"^/media/[A-Za-z0-9._-]+(/[A-Za-z0-9._-]+)*$").unwrap();
+    static ref INSTAGRAM_HANDLE_REGEX: Regex = Regex::new(r"^[A-Za-z0-9._]{1,30}$").unwrap();
+    static ref X_HANDLE_REGEX: Regex = Regex::new(r"^[A-Za-z0-9_]{1,15}$").unwrap();
+    static ref LINKEDIN_HANDLE_REGEX: Regex = Regex::new(r"^[A-Za-z0-9-]{3,100}$").unwrap();
+    static ref GITHUB_HANDLE_REGEX: Regex = Regex::new(r"^[A-Za-z0-9](?:[A-Za-z0-9-]{0,38})$").unwrap();
+    static ref FACEBOOK_HANDLE_REGEX: Regex = Regex::new(r"^[A-Za-z0-9.]{5,50}$").unwrap();
+    static ref AT_HANDLE_REGEX: Regex = Regex::new(r"^@[A-Za-z0-9._-]{2,30}$").unwrap();
+    static ref GENERIC_HANDLE_REGEX: Regex = Regex::new(r"^[A-Za-z0-9._@/-]{1,100}$").unwrap();
 }
 
 
@@ -15,4 +27,51 @@ pub fn validate_http_url(url: &str) -> Result<(), ValidationError> {
     } else {
         Err(ValidationError::new("Invalid HTTP URL"))
     }
-}
\ No newline at end of file
+}
+
+/// Avatars are either an http(s) URL or one of our uploads, served under `/media/`
+pub fn validate_avatar_url(url: &str) -> Result<(), ValidationError> {
+    if URL_REGEX.is_match(url) || (MEDIA_PATH_REGEX.is_match(url) && !url.contains("..")) {
+        Ok(())
+    } else {
+        Err(ValidationError::new("Avatar must be an http(s) URL or an uploaded image"))
+    }
+}
+
+/// Feature flag names are lowercase words joined by `_`, `.` or `-`, e.g. `ai_chat`
+pub fn validate_flag_name(name: &str) -> Result<(), ValidationError> {
+    if FLAG_NAME_REGEX.is_match(name) {
+        Ok(())
+    } else {
+        Err(ValidationError::new("Flag names may only contain a-z, 0-9, '_', '.' and '-'"))
+    }
+}
+
+/// Every entry must be a plain MIME type such as `image/png`
+pub fn validate_mime_types(types: &[String]) -> Result<(), ValidationError> {
+    if types.iter().all(|t| MIME_TYPE_REGEX.is_match(t)) {
+        Ok(())
+    } else {
+        Err(ValidationError::new("Upload types must be MIME types like image/png"))
+    }
+}
+
+/// Checks a social handle against the rules of the platform named `platform`.
+/// Platforms without their own rules accept a plain URL path segment.
+pub fn validate_social_handle(platform: &str, handle: &str) -> Result<(), ValidationError> {
+    let (pattern, message): (&Regex, &'static str) = match platform {
+        "Instagram" => (&INSTAGRAM_HANDLE_REGEX, "Instagram usernames are up to 30 letters, numbers, '.' or '_'"),
+        "Twitter/X" => (&X_HANDLE_REGEX, "X handles are up to 15 letters, numbers or '_'"),
+        "LinkedIn" => (&LINKEDIN_HANDLE_REGEX, "LinkedIn profile names are 3-100 letters, numbers or '-'"),
+        "GitHub" => (&GITHUB_HANDLE_REGEX, "GitHub usernames are up to 39 letters, numbers or '-', not starting with '-'"),
+        "Facebook" => (&FACEBOOK_HANDLE_REGEX, "Facebook usernames are 5-50 letters, numbers or '.'"),
+        "YouTube" | "TikTok" => (&AT_HANDLE_REGEX, "Handles look like @name"),
+        _ => (&GENERIC_HANDLE_REGEX, "That doesn't look like a profile name"),
+    };
+
+    if pattern.is_match(handle) && !handle.contains("..") {
+        Ok(())
+    } else {
+        Err(ValidationError::new(message))
+    }
+}
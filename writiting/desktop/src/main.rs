@@ -3,11 +3,12 @@ use dioxus::prelude::*;
 use views::{AppLayout, Blog, Home, Editor, FocusMode, NotFound};
 use views::profile::Profile;
 use views::legal::{PrivacyPolicy, TermsOfService};
-use views::admin::{AdminDashboard, AdminUsers, AdminReports, AdminSettings};
+use views::admin::{AdminDashboard, AdminUsers, AdminReports, AdminSettings, AdminAudit, AdminSecurityLog, AdminFeatureFlags, AdminMaintenance, AdminInvitations};
 use views::ai::AIChat;
 use views::help::{HelpMain};
 
-use ui::auth::{Login, Register, ResetPassword};
+use ui::auth::{Login, Register, ResetPassword, NewPassword, VerifyEmail};
+use ui::{PublicProfile, SearchResults};
 mod views;
 mod state;
 use std::env;
@@ -35,6 +36,10 @@ pub enum Route {
 
         #[route("/profile")]
         Profile {},
+        #[route("/u/:username")]
+        PublicProfile { username: String },
+        #[route("/search?:q")]
+        SearchResults { q: String },
         #[route("/help")]
         HelpMain {},
         
@@ -43,11 +48,17 @@ pub enum Route {
             #[route("/login")]
             Login {},
             
-            #[route("/register")]
-            Register {},
+            #[route("/register?:invite")]
+            Register { invite: String },
             
             #[route("/reset-password")]
             ResetPassword {},
+
+            #[route("/new-password?:token")]
+            NewPassword { token: String },
+
+            #[route("/verify-email?:token")]
+            VerifyEmail { token: String },
         #[end_nest]
     
         // #[nest("/help")]
@@ -73,6 +84,21 @@ pub enum Route {
 
             #[route("/settings")]
             AdminSettings {},
+
+            #[route("/audit")]
+            AdminAudit {},
+
+            #[route("/security")]
+            AdminSecurityLog {},
+
+            #[route("/flags")]
+            AdminFeatureFlags {},
+
+            #[route("/maintenance")]
+            AdminMaintenance {},
+
+            #[route("/invitations")]
+            AdminInvitations {},
         #[end_nest]
 
         #[nest("/legal")]
@@ -101,6 +127,7 @@ pub enum Route {
 // Update the path if your CSS file is located elsewhere, e.g. "/assets/css/tailwind.css"
 const MAIN_CSS: Asset = asset!("/assets/tailwind.css");
 
+#[cfg(not(feature = "server"))]
 fn main() -> Result<(), Box<dyn Error>> {
 
 
@@ -115,12 +142,23 @@ fn main() -> Result<(), Box<dyn Error>> {
     Ok(dioxus::launch(App))
 }
 
+// The server build runs our own axum router so background tasks start with it
+#[cfg(feature = "server")]
+fn main() -> Result<(), Box<dyn Error>> {
+    dotenvy::dotenv()?;
+
+    tokio::runtime::Runtime::new()?.block_on(api::server::serve(App))
+}
+
     
 
 
 #[component]
 fn App() -> Element {
-    
+    ui::auth::init_session();
+    state::init_theme();
+    ui::init_i18n();
+
     rsx! {
         // Global app resources
         document::Link { rel: "stylesheet", href: MAIN_CSS }
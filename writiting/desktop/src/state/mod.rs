@@ -1,3 +1,2 @@
 mod theme;
-pub use theme::{use_theme, Theme, init_theme};
-
+pub use theme::{use_theme, Theme, ThemeContext, init_theme};
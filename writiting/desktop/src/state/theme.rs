@@ -1,20 +1,79 @@
 // writing/desktop/src/state/theme.rs
 use dioxus::prelude::*;
+use api::features::{get_preferences, update_preferences, UpdatePreferences};
 
-/// Theme variants (Dark/Light)
-#[derive(Clone, Copy, Debug, PartialEq)]
-pub enum Theme {
-    Dark,
-    Light,
+/// Shared with the api crate, so it is what gets stored in `user_preferences`
+pub use api::features::Theme;
+
+/// Reports whether the OS prefers dark now and whenever that changes
+const WATCH_SYSTEM_THEME: &str = r#"
+    const query = window.matchMedia("(prefers-color-scheme: dark)");
+    dioxus.send(query.matches);
+    query.addEventListener("change", (event) => dioxus.send(event.matches));
+"#;
+
+/// The theme the user picked, and what the OS currently prefers for `System`
+#[derive(Clone, Copy, PartialEq)]
+pub struct ThemeContext {
+    pub theme: Signal<Theme>,
+    pub system_dark: Signal<bool>,
+}
+
+impl ThemeContext {
+    /// Whether the app is drawn dark right now
+    pub fn is_dark(&self) -> bool {
+        self.theme.read().is_dark((self.system_dark)())
+    }
+
+    /// Switches theme and saves it to the signed-in user's preferences
+    pub fn set(&mut self, theme: Theme) {
+        self.theme.set(theme);
+        spawn(async move {
+            let update = UpdatePreferences { theme: Some(theme), ..Default::default() };
+            if let Err(err) = update_preferences(update).await {
+                tracing::warn!("Theme not saved: {}", err);
+            }
+        });
+    }
 }
 
-/// Initialize the theme provider (call this in `main.rs`)
-pub fn init_theme() -> Signal<Theme> {
-    // Provide a Signal<Theme> to the component tree (default: Dark)
-    use_hook(|| Signal::new(Theme::Dark))
+/// Initialize the theme provider (call this in `main.rs`).
+///
+/// Starts from the default theme, then switches to the signed-in user's saved
+/// one, again whenever someone signs in or out. The `dark` class on `<html>`
+/// follows it, tracking the OS for `System`.
+pub fn init_theme() -> ThemeContext {
+    let mut theme = use_signal(Theme::default);
+    let mut system_dark = use_signal(|| false);
+    let context = use_context_provider(|| ThemeContext { theme, system_dark });
+
+    use_resource(move || async move {
+        ui::auth::session_version();
+        match get_preferences().await {
+            Ok(preferences) => theme.set(preferences.theme),
+            Err(err) => {
+                tracing::debug!("Using the default theme: {}", err);
+                theme.set(Theme::default());
+            }
+        }
+    });
+
+    use_future(move || async move {
+        let mut watcher = document::eval(WATCH_SYSTEM_THEME);
+        while let Ok(dark) = watcher.recv::<bool>().await {
+            system_dark.set(dark);
+        }
+    });
+
+    use_effect(move || {
+        let dark = context.is_dark();
+        document::eval(&format!("document.documentElement.classList.toggle('dark', {});", dark));
+    });
+
+    context
 }
 
-/// Hook to access the theme Signal from any component
-pub fn use_theme() -> Signal<Theme> {
-    consume_context::<Signal<Theme>>()
-}
\ No newline at end of file
+/// Hook to access the theme from any component
+pub fn use_theme() -> ThemeContext {
+    use_context::<ThemeContext>()
+}
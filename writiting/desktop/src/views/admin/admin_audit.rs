@@ -0,0 +1,226 @@
+use dioxus::prelude::*;
+use ui::t;
+use chrono::NaiveDate;
+use api::authentication::{
+    export_audit_logs_csv, list_audit_logs, AuditAction, AuditLog, AuditLogFilter,
+};
+
+#[component]
+pub fn AdminAudit() -> Element {
+    let mut user = use_signal(String::new);
+    let mut entity_type = use_signal(String::new);
+    let mut action = use_signal(|| None::<AuditAction>);
+    let mut from = use_signal(String::new);
+    let mut to = use_signal(String::new);
+    let mut page = use_signal(|| 1i64);
+    let mut csv_href = use_signal(|| None::<String>);
+    let mut error = use_signal(|| None::<String>);
+
+    let filter = move || AuditLogFilter {
+        user: Some(user()),
+        entity_type: Some(entity_type()),
+        entity_id: None,
+        action: action(),
+        from: NaiveDate::parse_from_str(&from(), "%Y-%m-%d").ok(),
+        to: NaiveDate::parse_from_str(&to(), "%Y-%m-%d").ok(),
+        page: page(),
+    };
+
+    let logs = use_resource(move || async move { list_audit_logs(filter()).await });
+
+    let export = move |_| {
+        spawn(async move {
+            match export_audit_logs_csv(filter()).await {
+                Ok(csv) => {
+                    error.set(None);
+                    csv_href.set(Some(format!("data:text/csv;charset=utf-8,{}", percent_encode(&csv))));
+                }
+                Err(err) => {
+                    tracing::error!("Audit export failed: {}", err);
+                    error.set(Some(err.to_string()));
+                }
+            }
+        });
+    };
+
+    let (entries, total, per_page) = match &*logs.read() {
+        Some(Ok(p)) => (p.entries.clone(), p.total, p.per_page),
+        Some(Err(err)) => {
+            tracing::error!("Failed to load audit log: {}", err);
+            (Vec::new(), 0, 50)
+        }
+        None => (Vec::new(), 0, 50),
+    };
+    let last_page = ((total + per_page - 1) / per_page).max(1);
+
+    rsx! {
+        div { class: "min-h-screen bg-gray-100",
+            header { class: "bg-white shadow",
+                div { class: "max-w-7xl mx-auto py-6 px-4 sm:px-6 lg:px-8",
+                    h1 { class: "text-3xl font-bold text-gray-900", {t!("audit-title")} }
+                    p { class: "mt-1 text-gray-600", {t!("audit-subtitle")} }
+                }
+            }
+
+            main { class: "max-w-7xl mx-auto py-6 sm:px-6 lg:px-8",
+                // Filters
+                div { class: "bg-white shadow rounded-lg p-4 mb-6 grid grid-cols-1 md:grid-cols-6 gap-3",
+                    input {
+                        class: "px-3 py-2 border border-gray-300 rounded",
+                        placeholder: t!("admin-user-filter"),
+                        value: "{user}",
+                        oninput: move |e| { user.set(e.value()); page.set(1); },
+                    }
+                    select {
+                        class: "px-3 py-2 border border-gray-300 rounded",
+                        onchange: move |e| { entity_type.set(e.value()); page.set(1); },
+                        option { value: "", {t!("audit-all-entities")} }
+                        for entity in ["post", "user", "profile", "role", "setting"] {
+                            option { value: entity, selected: entity_type() == entity, "{entity}" }
+                        }
+                    }
+                    select {
+                        class: "px-3 py-2 border border-gray-300 rounded",
+                        onchange: move |e| {
+                            action.set(AuditAction::ALL.into_iter().find(|a| a.as_str() == e.value()));
+                            page.set(1);
+                        },
+                        option { value: "", {t!("audit-all-actions")} }
+                        for a in AuditAction::ALL {
+                            option { value: a.as_str(), selected: action() == Some(a), "{a.as_str()}" }
+                        }
+                    }
+                    input {
+                        r#type: "date",
+                        class: "px-3 py-2 border border-gray-300 rounded",
+                        value: "{from}",
+                        oninput: move |e| { from.set(e.value()); page.set(1); },
+                    }
+                    input {
+                        r#type: "date",
+                        class: "px-3 py-2 border border-gray-300 rounded",
+                        value: "{to}",
+                        oninput: move |e| { to.set(e.value()); page.set(1); },
+                    }
+                    button {
+                        class: "px-4 py-2 bg-gray-600 text-white rounded hover:bg-gray-700",
+                        onclick: export,
+                        {t!("audit-export")}
+                    }
+                }
+
+                if let Some(err) = error() {
+                    p { class: "mb-4 text-sm text-red-600", "{err}" }
+                }
+
+                if let Some(href) = csv_href() {
+                    div { class: "mb-4 p-3 bg-green-50 border border-green-200 rounded text-sm",
+                        a {
+                            class: "text-blue-600 hover:underline",
+                            href: "{href}",
+                            download: "audit-log.csv",
+                            {t!("audit-download", file = "audit-log.csv")}
+                        }
+                    }
+                }
+
+                div { class: "bg-white shadow rounded-lg overflow-x-auto",
+                    table { class: "min-w-full divide-y divide-gray-200 text-sm",
+                        thead { class: "bg-gray-50",
+                            tr {
+                                for heading in [
+                                    t!("admin-col-when"),
+                                    t!("admin-col-user"),
+                                    t!("audit-col-action"),
+                                    t!("audit-col-entity"),
+                                    t!("audit-col-changed"),
+                                    t!("admin-col-ip"),
+                                ] {
+                                    th { class: "px-4 py-2 text-left font-medium text-gray-500", "{heading}" }
+                                }
+                            }
+                        }
+                        tbody { class: "divide-y divide-gray-200",
+                            for entry in entries {
+                                AuditRow { key: "{entry.id}", entry: entry.clone() }
+                            }
+                        }
+                    }
+                }
+
+                div { class: "flex justify-between items-center mt-4 text-sm text-gray-600",
+                    p { {t!("audit-count", count = total)} }
+                    div { class: "flex gap-2 items-center",
+                        button {
+                            class: "px-3 py-1 bg-gray-200 rounded disabled:opacity-50",
+                            disabled: page() <= 1,
+                            onclick: move |_| page -= 1,
+                            {t!("common-previous")}
+                        }
+                        span { {t!("common-page", page = page(), pages = last_page)} }
+                        button {
+                            class: "px-3 py-1 bg-gray-200 rounded disabled:opacity-50",
+                            disabled: page() >= last_page,
+                            onclick: move |_| page += 1,
+                            {t!("common-next")}
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn AuditRow(entry: AuditLog) -> Element {
+    let mut expanded = use_signal(|| false);
+    let when = entry.created_at.format("%Y-%m-%d %H:%M:%S").to_string();
+    let who = entry.username.clone().unwrap_or_else(|| t!("audit-system"));
+    let changed = entry.changed_fields().join(", ");
+    let ip = entry.ip_address.clone().unwrap_or_default();
+    let pretty = |v: &Option<serde_json::Value>| {
+        v.as_ref()
+            .and_then(|v| serde_json::to_string_pretty(v).ok())
+            .unwrap_or_else(|| "-".to_string())
+    };
+    let old_values = pretty(&entry.old_values);
+    let new_values = pretty(&entry.new_values);
+
+    rsx! {
+        tr {
+            class: "hover:bg-gray-50 cursor-pointer",
+            onclick: move |_| expanded.toggle(),
+            td { class: "px-4 py-2 whitespace-nowrap", "{when}" }
+            td { class: "px-4 py-2", "{who}" }
+            td { class: "px-4 py-2", "{entry.action.as_str()}" }
+            td { class: "px-4 py-2", "{entry.entity_type} #{entry.entity_id}" }
+            td { class: "px-4 py-2 text-gray-600", "{changed}" }
+            td { class: "px-4 py-2 text-gray-600", "{ip}" }
+        }
+        if expanded() {
+            tr {
+                td { class: "px-4 py-2 bg-gray-50", colspan: "6",
+                    div { class: "grid grid-cols-2 gap-4",
+                        div {
+                            p { class: "font-medium text-gray-700 mb-1", {t!("audit-before")} }
+                            pre { class: "text-xs whitespace-pre-wrap", "{old_values}" }
+                        }
+                        div {
+                            p { class: "font-medium text-gray-700 mb-1", {t!("audit-after")} }
+                            pre { class: "text-xs whitespace-pre-wrap", "{new_values}" }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn percent_encode(text: &str) -> String {
+    text.bytes()
+        .map(|b| match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => (b as char).to_string(),
+            _ => format!("%{:02X}", b),
+        })
+        .collect()
+}
@@ -1,4 +1,6 @@
 use dioxus::prelude::*;
+use ui::t;
+use super::database_health::DatabaseHealthPanel;
 
 #[component]
 pub fn AdminDashboard() -> Element {
@@ -7,7 +9,7 @@ pub fn AdminDashboard() -> Element {
             // Header
             header { class: "bg-white shadow",
                 div { class: "max-w-7xl mx-auto py-6 px-4 sm:px-6 lg:px-8",
-                    h1 { class: "text-3xl font-bold text-gray-900", "Admin Dashboard" }
+                    h1 { class: "text-3xl font-bold text-gray-900", {t!("nav-admin-dashboard")} }
                 }
             }
 
@@ -17,10 +19,10 @@ pub fn AdminDashboard() -> Element {
                 div { class: "px-4 py-6 sm:px-0",
                     div { class: "border-4 border-dashed border-gray-200 rounded-lg p-6",
                         h2 { class: "text-xl font-semibold text-gray-800",
-                            "Welcome to the Admin Dashboard!"
+                            {t!("admin-welcome")}
                         }
                         p { class: "mt-2 text-gray-600",
-                            "Here you can manage users, view reports, and perform administrative tasks."
+                            {t!("admin-welcome-hint")}
                         }
                     }
                 }
@@ -30,7 +32,7 @@ pub fn AdminDashboard() -> Element {
                     // Users Card
                     div { class: "bg-white overflow-hidden shadow rounded-lg",
                         div { class: "px-4 py-5 sm:p-6",
-                            h3 { class: "text-lg font-medium text-gray-900", "Total Users" }
+                            h3 { class: "text-lg font-medium text-gray-900", {t!("admin-total-users")} }
                             p { class: "mt-1 text-3xl font-semibold text-indigo-600",
                                 "1,024"
                             }
@@ -39,7 +41,7 @@ pub fn AdminDashboard() -> Element {
                     // Reports Card
                     div { class: "bg-white overflow-hidden shadow rounded-lg",
                         div { class: "px-4 py-5 sm:p-6",
-                            h3 { class: "text-lg font-medium text-gray-900", "Reported Content" }
+                            h3 { class: "text-lg font-medium text-gray-900", {t!("admin-reported-content")} }
                             p { class: "mt-1 text-3xl font-semibold text-red-600",
                                 "50"
                             }
@@ -48,7 +50,7 @@ pub fn AdminDashboard() -> Element {
                     // Sessions Card
                     div { class: "bg-white overflow-hidden shadow rounded-lg",
                         div { class: "px-4 py-5 sm:p-6",
-                            h3 { class: "text-lg font-medium text-gray-900", "Active Sessions" }
+                            h3 { class: "text-lg font-medium text-gray-900", {t!("admin-active-sessions")} }
                             p { class: "mt-1 text-3xl font-semibold text-green-600",
                                 "200"
                             }
@@ -56,22 +58,49 @@ pub fn AdminDashboard() -> Element {
                     }
                 }
 
+                DatabaseHealthPanel {}
+
                 // Action Buttons
-                div { class: "px-4 py-6 grid grid-cols-1 gap-6 sm:grid-cols-3",
+                div { class: "px-4 py-6 grid grid-cols-1 gap-6 sm:grid-cols-4",
                     a {
                         href: "/admin/users",
                         class: "bg-indigo-600 hover:bg-indigo-700 text-white font-bold py-3 px-4 rounded-lg text-center transition duration-150 ease-in-out",
-                        "Manage Users"
+                        {t!("admin-manage-users")}
                     }
                     a {
                         href: "/admin/reportes",
                         class: "bg-purple-600 hover:bg-purple-700 text-white font-bold py-3 px-4 rounded-lg text-center transition duration-150 ease-in-out",
-                        "View Reports"
+                        {t!("admin-view-reports")}
                     }
                     a {
                         href: "/admin/settings",
                         class: "bg-gray-600 hover:bg-gray-700 text-white font-bold py-3 px-4 rounded-lg text-center transition duration-150 ease-in-out",
-                        "Settings"
+                        {t!("admin-settings")}
+                    }
+                    a {
+                        href: "/admin/audit",
+                        class: "bg-slate-600 hover:bg-slate-700 text-white font-bold py-3 px-4 rounded-lg text-center transition duration-150 ease-in-out",
+                        {t!("audit-title")}
+                    }
+                    a {
+                        href: "/admin/security",
+                        class: "bg-red-600 hover:bg-red-700 text-white font-bold py-3 px-4 rounded-lg text-center transition duration-150 ease-in-out",
+                        {t!("security-title")}
+                    }
+                    a {
+                        href: "/admin/flags",
+                        class: "bg-teal-600 hover:bg-teal-700 text-white font-bold py-3 px-4 rounded-lg text-center transition duration-150 ease-in-out",
+                        {t!("flags-title")}
+                    }
+                    a {
+                        href: "/admin/maintenance",
+                        class: "bg-orange-600 hover:bg-orange-700 text-white font-bold py-3 px-4 rounded-lg text-center transition duration-150 ease-in-out",
+                        {t!("maintenance-title")}
+                    }
+                    a {
+                        href: "/admin/invitations",
+                        class: "bg-emerald-600 hover:bg-emerald-700 text-white font-bold py-3 px-4 rounded-lg text-center transition duration-150 ease-in-out",
+                        {t!("invitations-title")}
                     }
                 }
             }
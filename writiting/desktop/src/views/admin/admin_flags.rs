@@ -0,0 +1,261 @@
+use dioxus::prelude::*;
+use ui::t;
+use chrono::Utc;
+use uuid::Uuid;
+use api::authentication::{
+    delete_feature_flag, list_feature_flags, save_feature_flag, FeatureFlag, FeatureFlagUpdate,
+};
+
+#[component]
+pub fn AdminFeatureFlags() -> Element {
+    let mut flags = use_resource(|| async move { list_feature_flags().await });
+    // The flag being edited, if the form is open
+    let mut editing = use_signal(|| None::<FeatureFlagUpdate>);
+    let mut is_new = use_signal(|| false);
+    let mut target_users = use_signal(String::new);
+    let mut error = use_signal(|| None::<String>);
+
+    let save = move |evt: FormEvent| {
+        evt.prevent_default();
+        let Some(mut update) = editing() else { return };
+
+        let mut targets = Vec::new();
+        for id in target_users().split(|c: char| c == ',' || c.is_whitespace()).filter(|id| !id.is_empty()) {
+            match Uuid::parse_str(id) {
+                Ok(id) => targets.push(id),
+                Err(_) => {
+                    error.set(Some(t!("flags-bad-user-id", id = id.to_string())));
+                    return;
+                }
+            }
+        }
+        update.target_users = targets;
+
+        spawn(async move {
+            match save_feature_flag(update).await {
+                Ok(_) => {
+                    error.set(None);
+                    editing.set(None);
+                    flags.restart();
+                }
+                Err(err) => {
+                    tracing::error!("Failed to save feature flag: {}", err);
+                    error.set(Some(err.to_string()));
+                }
+            }
+        });
+    };
+
+    let flag_list = match &*flags.read() {
+        Some(Ok(list)) => list.clone(),
+        Some(Err(err)) => {
+            tracing::error!("Failed to load feature flags: {}", err);
+            Vec::new()
+        }
+        None => Vec::new(),
+    };
+
+    rsx! {
+        div { class: "min-h-screen bg-gray-100",
+            header { class: "bg-white shadow",
+                div { class: "max-w-7xl mx-auto py-6 px-4 sm:px-6 lg:px-8 flex justify-between items-center",
+                    div {
+                        h1 { class: "text-3xl font-bold text-gray-900", {t!("flags-title")} }
+                        p { class: "mt-1 text-gray-600", {t!("flags-subtitle")} }
+                    }
+                    button {
+                        class: "px-4 py-2 bg-blue-600 text-white rounded hover:bg-blue-700",
+                        onclick: move |_| {
+                            error.set(None);
+                            is_new.set(true);
+                            target_users.set(String::new());
+                            editing.set(Some(FeatureFlagUpdate::default()));
+                        },
+                        {t!("flags-new")}
+                    }
+                }
+            }
+
+            main { class: "max-w-7xl mx-auto py-6 sm:px-6 lg:px-8",
+                if let Some(err) = error() {
+                    p { class: "mb-4 text-sm text-red-600", "{err}" }
+                }
+
+                if let Some(flag) = editing() {
+                    form { class: "bg-white shadow rounded-lg p-4 mb-6 grid grid-cols-1 md:grid-cols-2 gap-4", onsubmit: save,
+                        label { class: "text-sm text-gray-700",
+                            {t!("common-name")}
+                            input {
+                                class: "mt-1 w-full px-3 py-2 border border-gray-300 rounded",
+                                placeholder: t!("flags-name-placeholder"),
+                                disabled: !is_new(),
+                                value: "{flag.name}",
+                                oninput: move |e| {
+                                    if let Some(f) = editing.write().as_mut() {
+                                        f.name = e.value();
+                                    }
+                                },
+                            }
+                        }
+                        label { class: "text-sm text-gray-700",
+                            {t!("common-description")}
+                            input {
+                                class: "mt-1 w-full px-3 py-2 border border-gray-300 rounded",
+                                value: flag.description.clone().unwrap_or_default(),
+                                oninput: move |e| {
+                                    if let Some(f) = editing.write().as_mut() {
+                                        f.description = Some(e.value());
+                                    }
+                                },
+                            }
+                        }
+                        label { class: "text-sm text-gray-700",
+                            {t!("flags-rollout")}
+                            input {
+                                r#type: "number",
+                                min: "0",
+                                max: "100",
+                                class: "mt-1 w-full px-3 py-2 border border-gray-300 rounded",
+                                value: "{flag.rollout_percentage}",
+                                oninput: move |e| {
+                                    if let Some(f) = editing.write().as_mut() {
+                                        f.rollout_percentage = e.value().parse().unwrap_or(0);
+                                    }
+                                },
+                            }
+                        }
+                        label { class: "text-sm text-gray-700",
+                            {t!("flags-increment")}
+                            input {
+                                r#type: "number",
+                                min: "0",
+                                max: "100",
+                                class: "mt-1 w-full px-3 py-2 border border-gray-300 rounded",
+                                value: "{flag.increment_per_day}",
+                                oninput: move |e| {
+                                    if let Some(f) = editing.write().as_mut() {
+                                        f.increment_per_day = e.value().parse().unwrap_or(0);
+                                    }
+                                },
+                            }
+                        }
+                        label { class: "text-sm text-gray-700 md:col-span-2",
+                            {t!("flags-targets")}
+                            textarea {
+                                class: "mt-1 w-full px-3 py-2 border border-gray-300 rounded font-mono text-xs",
+                                rows: "2",
+                                value: "{target_users}",
+                                oninput: move |e| target_users.set(e.value()),
+                            }
+                        }
+                        label { class: "flex items-center gap-2 text-sm text-gray-700",
+                            input {
+                                r#type: "checkbox",
+                                checked: flag.is_enabled,
+                                onchange: move |e| {
+                                    if let Some(f) = editing.write().as_mut() {
+                                        f.is_enabled = e.checked();
+                                    }
+                                },
+                            }
+                            {t!("flags-enabled")}
+                        }
+                        div { class: "flex gap-2 justify-end",
+                            button {
+                                r#type: "submit",
+                                class: "px-4 py-2 bg-blue-600 text-white rounded hover:bg-blue-700",
+                                {t!("common-save")}
+                            }
+                            button {
+                                r#type: "button",
+                                class: "px-4 py-2 bg-gray-200 rounded hover:bg-gray-300",
+                                onclick: move |_| editing.set(None),
+                                {t!("common-cancel")}
+                            }
+                        }
+                    }
+                }
+
+                div { class: "bg-white shadow rounded-lg overflow-x-auto",
+                    table { class: "min-w-full divide-y divide-gray-200 text-sm",
+                        thead { class: "bg-gray-50",
+                            tr {
+                                for heading in [
+                                    t!("flags-col-flag"),
+                                    t!("flags-col-status"),
+                                    t!("flags-col-reaching"),
+                                    t!("flags-col-targets"),
+                                    String::new(),
+                                ] {
+                                    th { class: "px-4 py-2 text-left font-medium text-gray-500", "{heading}" }
+                                }
+                            }
+                        }
+                        tbody { class: "divide-y divide-gray-200",
+                            for flag in flag_list {
+                                FeatureFlagRow {
+                                    key: "{flag.name}",
+                                    flag: flag.clone(),
+                                    on_edit: move |flag: FeatureFlag| {
+                                        let ids: Vec<String> = flag.target_users.iter().map(|id| id.to_string()).collect();
+                                        error.set(None);
+                                        is_new.set(false);
+                                        target_users.set(ids.join(", "));
+                                        editing.set(Some(FeatureFlagUpdate::from(&flag)));
+                                    },
+                                    on_delete: move |name: String| {
+                                        spawn(async move {
+                                            match delete_feature_flag(name).await {
+                                                Ok(()) => flags.restart(),
+                                                Err(err) => error.set(Some(err.to_string())),
+                                            }
+                                        });
+                                    },
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn FeatureFlagRow(flag: FeatureFlag, on_edit: EventHandler<FeatureFlag>, on_delete: EventHandler<String>) -> Element {
+    let status = if !flag.is_enabled {
+        t!("flags-status-off")
+    } else if flag.increment_per_day > 0 {
+        t!("flags-status-gradual", from = flag.rollout_percentage, step = flag.increment_per_day)
+    } else {
+        t!("flags-status-on")
+    };
+    let reaching = flag.percentage_at(Utc::now());
+    let description = flag.description.clone().unwrap_or_default();
+    let targets = flag.target_users.len();
+    let name = flag.name.clone();
+
+    rsx! {
+        tr {
+            td { class: "px-4 py-2",
+                p { class: "font-mono text-gray-900", "{flag.name}" }
+                p { class: "text-xs text-gray-500", "{description}" }
+            }
+            td { class: "px-4 py-2", "{status}" }
+            td { class: "px-4 py-2", "{reaching}%" }
+            td { class: "px-4 py-2", "{targets}" }
+            td { class: "px-4 py-2 text-right whitespace-nowrap",
+                button {
+                    class: "text-blue-600 hover:underline mr-3",
+                    onclick: move |_| on_edit.call(flag.clone()),
+                    {t!("common-edit")}
+                }
+                button {
+                    class: "text-red-600 hover:underline",
+                    onclick: move |_| on_delete.call(name.clone()),
+                    {t!("common-delete")}
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,166 @@
+use dioxus::prelude::*;
+use ui::t;
+use api::authentication::{create_invitation, list_invitations, revoke_invitation, CreateInvitationRequest};
+use api::users::list_assignable_roles;
+use crate::views::profile::InvitationRow;
+
+#[component]
+pub fn AdminInvitations() -> Element {
+    let mut page = use_signal(|| 1i64);
+    let mut invitations = use_resource(move || async move { list_invitations(page()).await });
+    let roles = use_resource(|| async move { list_assignable_roles().await });
+
+    let mut email = use_signal(String::new);
+    let mut role_id = use_signal(|| None::<i32>);
+    let mut max_uses = use_signal(|| "1".to_string());
+    let mut expires_in_days = use_signal(|| "7".to_string());
+    let mut note = use_signal(String::new);
+    let mut error = use_signal(|| None::<String>);
+
+    let create = move |evt: FormEvent| {
+        evt.prevent_default();
+        // Blank uses or expiry means unlimited
+        let request = CreateInvitationRequest {
+            email: Some(email()).filter(|e| !e.trim().is_empty()),
+            role_id: role_id(),
+            max_uses: max_uses().trim().parse().ok(),
+            expires_in_days: expires_in_days().trim().parse().ok(),
+            note: Some(note()).filter(|n| !n.trim().is_empty()),
+        };
+
+        spawn(async move {
+            match create_invitation(request).await {
+                Ok(_) => {
+                    error.set(None);
+                    email.set(String::new());
+                    note.set(String::new());
+                    page.set(1);
+                    invitations.restart();
+                }
+                Err(err) => {
+                    tracing::error!("Failed to create invitation: {}", err);
+                    error.set(Some(err.to_string()));
+                }
+            }
+        });
+    };
+
+    let role_options = match &*roles.read() {
+        Some(Ok(list)) => list.clone(),
+        _ => Vec::new(),
+    };
+
+    let (invitation_list, total, per_page) = match &*invitations.read() {
+        Some(Ok(p)) => (p.invitations.clone(), p.total, p.per_page),
+        Some(Err(err)) => {
+            tracing::error!("Failed to load invitations: {}", err);
+            (Vec::new(), 0, 25)
+        }
+        None => (Vec::new(), 0, 25),
+    };
+    let last_page = ((total + per_page - 1) / per_page).max(1);
+
+    rsx! {
+        div { class: "min-h-screen bg-gray-100",
+            header { class: "bg-white shadow",
+                div { class: "max-w-7xl mx-auto py-6 px-4 sm:px-6 lg:px-8",
+                    h1 { class: "text-3xl font-bold text-gray-900", {t!("invitations-title")} }
+                    p { class: "mt-1 text-gray-600",
+                        {t!("invitations-subtitle")}
+                    }
+                }
+            }
+
+            main { class: "max-w-7xl mx-auto py-6 sm:px-6 lg:px-8",
+                if let Some(err) = error() {
+                    p { class: "mb-4 text-sm text-red-600", "{err}" }
+                }
+
+                form { class: "bg-white shadow rounded-lg p-4 mb-6 grid grid-cols-1 md:grid-cols-3 gap-3", onsubmit: create,
+                    input {
+                        r#type: "email",
+                        class: "px-3 py-2 border border-gray-300 rounded",
+                        placeholder: t!("invitations-email"),
+                        value: "{email}",
+                        oninput: move |e| email.set(e.value()),
+                    }
+                    select {
+                        class: "px-3 py-2 border border-gray-300 rounded",
+                        onchange: move |e| role_id.set(e.value().parse().ok()),
+                        option { value: "", selected: role_id().is_none(), {t!("invitations-default-role")} }
+                        for role in role_options {
+                            option { value: "{role.id}", selected: role_id() == Some(role.id), "{role.name}" }
+                        }
+                    }
+                    input {
+                        class: "px-3 py-2 border border-gray-300 rounded",
+                        placeholder: t!("invitations-note"),
+                        value: "{note}",
+                        oninput: move |e| note.set(e.value()),
+                    }
+                    input {
+                        r#type: "number",
+                        min: "1",
+                        class: "px-3 py-2 border border-gray-300 rounded",
+                        placeholder: t!("invitations-uses"),
+                        value: "{max_uses}",
+                        oninput: move |e| max_uses.set(e.value()),
+                    }
+                    input {
+                        r#type: "number",
+                        min: "1",
+                        max: "365",
+                        class: "px-3 py-2 border border-gray-300 rounded",
+                        placeholder: t!("invitations-expires"),
+                        value: "{expires_in_days}",
+                        oninput: move |e| expires_in_days.set(e.value()),
+                    }
+                    button {
+                        r#type: "submit",
+                        class: "px-4 py-2 bg-blue-600 text-white rounded hover:bg-blue-700",
+                        {t!("invitations-create")}
+                    }
+                }
+
+                div { class: "bg-white shadow rounded-lg px-4",
+                    if invitation_list.is_empty() {
+                        p { class: "py-4 text-sm text-gray-500", {t!("invitations-empty")} }
+                    }
+                    ul { class: "divide-y divide-gray-200",
+                        for invitation in invitation_list {
+                            InvitationRow {
+                                key: "{invitation.id}",
+                                invitation: invitation.clone(),
+                                show_creator: true,
+                                on_revoke: move |id| {
+                                    spawn(async move {
+                                        match revoke_invitation(id).await {
+                                            Ok(()) => invitations.restart(),
+                                            Err(err) => error.set(Some(err.to_string())),
+                                        }
+                                    });
+                                },
+                            }
+                        }
+                    }
+                }
+
+                div { class: "px-4 py-4 flex justify-between items-center text-sm text-gray-600",
+                    button {
+                        class: "px-3 py-1 bg-gray-200 rounded disabled:opacity-50",
+                        disabled: page() <= 1,
+                        onclick: move |_| page -= 1,
+                        {t!("common-previous")}
+                    }
+                    span { {t!("common-page", page = page(), pages = last_page)} }
+                    button {
+                        class: "px-3 py-1 bg-gray-200 rounded disabled:opacity-50",
+                        disabled: page() >= last_page,
+                        onclick: move |_| page += 1,
+                        {t!("common-next")}
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,156 @@
+use dioxus::prelude::*;
+use ui::t;
+use chrono::NaiveDateTime;
+use uuid::Uuid;
+use api::log::{
+    cancel_maintenance_window, list_maintenance_windows, schedule_maintenance_window,
+    MaintenanceWindow, ScheduleMaintenanceRequest,
+};
+
+// What a datetime-local input produces
+const INPUT_FORMAT: &str = "%Y-%m-%dT%H:%M";
+
+#[component]
+pub fn AdminMaintenance() -> Element {
+    let mut windows = use_resource(|| async move { list_maintenance_windows().await });
+    let mut starts_at = use_signal(String::new);
+    let mut ends_at = use_signal(String::new);
+    let mut description = use_signal(String::new);
+    let mut error = use_signal(|| None::<String>);
+
+    let schedule = move |evt: FormEvent| {
+        evt.prevent_default();
+        let (Ok(start), Ok(end)) = (
+            NaiveDateTime::parse_from_str(&starts_at(), INPUT_FORMAT),
+            NaiveDateTime::parse_from_str(&ends_at(), INPUT_FORMAT),
+        ) else {
+            error.set(Some(t!("maintenance-times-required")));
+            return;
+        };
+
+        let request = ScheduleMaintenanceRequest {
+            starts_at: start.and_utc(),
+            ends_at: end.and_utc(),
+            description: description(),
+        };
+
+        spawn(async move {
+            match schedule_maintenance_window(request).await {
+                Ok(_) => {
+                    error.set(None);
+                    starts_at.set(String::new());
+                    ends_at.set(String::new());
+                    description.set(String::new());
+                    windows.restart();
+                }
+                Err(err) => {
+                    tracing::error!("Failed to schedule maintenance: {}", err);
+                    error.set(Some(err.to_string()));
+                }
+            }
+        });
+    };
+
+    let window_list = match &*windows.read() {
+        Some(Ok(list)) => list.clone(),
+        Some(Err(err)) => {
+            tracing::error!("Failed to load maintenance windows: {}", err);
+            Vec::new()
+        }
+        None => Vec::new(),
+    };
+
+    rsx! {
+        div { class: "min-h-screen bg-gray-100",
+            header { class: "bg-white shadow",
+                div { class: "max-w-7xl mx-auto py-6 px-4 sm:px-6 lg:px-8",
+                    h1 { class: "text-3xl font-bold text-gray-900", {t!("maintenance-title")} }
+                    p { class: "mt-1 text-gray-600",
+                        {t!("maintenance-subtitle")}
+                    }
+                }
+            }
+
+            main { class: "max-w-7xl mx-auto py-6 sm:px-6 lg:px-8",
+                if let Some(err) = error() {
+                    p { class: "mb-4 text-sm text-red-600", "{err}" }
+                }
+
+                form { class: "bg-white shadow rounded-lg p-4 mb-6 grid grid-cols-1 md:grid-cols-4 gap-3", onsubmit: schedule,
+                    input {
+                        r#type: "datetime-local",
+                        class: "px-3 py-2 border border-gray-300 rounded",
+                        value: "{starts_at}",
+                        oninput: move |e| starts_at.set(e.value()),
+                    }
+                    input {
+                        r#type: "datetime-local",
+                        class: "px-3 py-2 border border-gray-300 rounded",
+                        value: "{ends_at}",
+                        oninput: move |e| ends_at.set(e.value()),
+                    }
+                    input {
+                        class: "px-3 py-2 border border-gray-300 rounded",
+                        placeholder: t!("maintenance-description"),
+                        value: "{description}",
+                        oninput: move |e| description.set(e.value()),
+                    }
+                    button {
+                        r#type: "submit",
+                        class: "px-4 py-2 bg-blue-600 text-white rounded hover:bg-blue-700",
+                        {t!("maintenance-schedule")}
+                    }
+                }
+
+                div { class: "bg-white shadow rounded-lg",
+                    if window_list.is_empty() {
+                        p { class: "p-4 text-sm text-gray-500", {t!("maintenance-empty")} }
+                    }
+                    ul { class: "divide-y divide-gray-200",
+                        for window in window_list {
+                            MaintenanceWindowRow {
+                                key: "{window.id}",
+                                window: window.clone(),
+                                on_cancel: move |id: Uuid| {
+                                    spawn(async move {
+                                        match cancel_maintenance_window(id).await {
+                                            Ok(()) => windows.restart(),
+                                            Err(err) => error.set(Some(err.to_string())),
+                                        }
+                                    });
+                                },
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn MaintenanceWindowRow(window: MaintenanceWindow, on_cancel: EventHandler<Uuid>) -> Element {
+    let from = window.starts_at.format("%Y-%m-%d %H:%M").to_string();
+    let to = window.ends_at.format("%Y-%m-%d %H:%M UTC").to_string();
+    let minutes = window.duration().num_minutes();
+    let id = window.id;
+
+    rsx! {
+        li { class: "p-4 flex justify-between items-center",
+            div {
+                p { class: "font-medium text-gray-800",
+                    {t!("maintenance-window", from = from, to = to, minutes = minutes)}
+                    if window.is_active {
+                        span { class: "ml-2 px-2 py-0.5 text-xs rounded bg-red-100 text-red-700", {t!("maintenance-running")} }
+                    }
+                }
+                p { class: "text-sm text-gray-600", "{window.description}" }
+            }
+            button {
+                class: "px-3 py-1 text-sm bg-gray-200 rounded hover:bg-gray-300",
+                onclick: move |_| on_cancel.call(id),
+                if window.is_active { {t!("maintenance-end-now")} } else { {t!("common-cancel")} }
+            }
+        }
+    }
+}
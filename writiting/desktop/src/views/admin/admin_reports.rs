@@ -1,138 +1,359 @@
 use dioxus::prelude::*;
+use ui::t;
+use uuid::Uuid;
+use api::moderation::{
+    add_moderation_note, assign_report, dismiss_report, get_report_counts, list_moderation_notes,
+    list_reports, resolve_report, ContentReport, ModerationAction, ReportFilter, ReportReason,
+    ReportStatus, ReportTarget,
+};
+use api::session::get_current_user;
 
 #[component]
 pub fn AdminReports() -> Element {
+    let mut status = use_signal(|| Some(ReportStatus::Open));
+    let mut target_type = use_signal(|| None::<ReportTarget>);
+    let mut reason = use_signal(|| None::<ReportReason>);
+    let mut mine = use_signal(|| false);
+    let mut page = use_signal(|| 1i64);
+
+    let mut reports = use_resource(move || async move {
+        list_reports(ReportFilter {
+            status: status(),
+            target_type: target_type(),
+            reason: reason(),
+            mine: mine(),
+            page: page(),
+        })
+        .await
+    });
+    let mut counts = use_resource(|| async move { get_report_counts().await });
+    let me = use_resource(|| async move { get_current_user().await });
+
+    let my_id = match &*me.read() {
+        Some(Ok(Some(user))) => Some(user.id),
+        _ => None,
+    };
+
+    let (total_reports, open_reports, unassigned_reports, resolved_reports, dismissed_reports) = match &*counts.read() {
+        Some(Ok(c)) => (
+            c.total.to_string(),
+            c.open.to_string(),
+            c.unassigned.to_string(),
+            c.resolved.to_string(),
+            c.dismissed.to_string(),
+        ),
+        _ => ("–".to_string(), "–".to_string(), "–".to_string(), "–".to_string(), "–".to_string()),
+    };
+
+    let (report_list, total, per_page, error) = match &*reports.read() {
+        Some(Ok(p)) => (p.reports.clone(), p.total, p.per_page, None),
+        Some(Err(err)) => (Vec::new(), 0, 25, Some(err.to_string())),
+        None => (Vec::new(), 0, 25, None),
+    };
+    let last_page = ((total + per_page - 1) / per_page).max(1);
+
     rsx! {
         div { class: "min-h-screen bg-gray-100",
             // Header
             header { class: "bg-white shadow",
                 div { class: "max-w-7xl mx-auto py-6 px-4 sm:px-6 lg:px-8",
-                    h1 { class: "text-3xl font-bold text-gray-900", "Admin Reports" }
-                    p { class: "mt-1 text-gray-600", "Here you can view and manage reported content." }
+                    h1 { class: "text-3xl font-bold text-gray-900", {t!("nav-admin-reports")} }
+                    p { class: "mt-1 text-gray-600", {t!("reports-subtitle")} }
                 }
             }
 
             // Main Content
             main { class: "max-w-7xl mx-auto py-6 sm:px-6 lg:px-8",
-                // Welcome Banner
-                div { class: "px-4 pb-6 sm:px-0",
-                    div { class: "bg-blue-50 border-l-4 border-blue-400 p-4",
-                        div { class: "flex items-start",
-                            div { class: "flex-shrink-0",
-                                svg {
-                                    class: "h-5 w-5 text-blue-400",
-                                    xmlns: "http://www.w3.org/2000/svg",
-                                    view_box: "0 0 20 20",
-                                    fill: "currentColor",
-                                    path {
-                                        fill_rule: "evenodd",
-                                        d: "M18 10a8 8 0 11-16 0 8 8 0 0116 0zm-7-4a1 1 0 11-2 0 1 1 0 012 0zM9 9a1 1 0 000 2v3a1 1 0 001 1h1a1 1 0 100-2h-1V9z",
-                                        clip_rule: "evenodd",
-                                    }
-                                }
-                            }
-                            div { class: "ml-3",
-                                p { class: "text-sm text-blue-700",
-                                    "Welcome to the Admin Reports Page! Review and take action on reported content below."
-                                }
-                            }
+                // Stats Cards
+                div { class: "grid grid-cols-1 md:grid-cols-4 gap-6 px-4 py-6",
+                    div { class: "bg-white overflow-hidden shadow rounded-lg",
+                        div { class: "px-4 py-5 sm:p-6",
+                            h3 { class: "text-lg font-medium text-gray-900", {t!("reports-total")} }
+                            p { class: "mt-1 text-3xl font-semibold text-indigo-600", "{total_reports}" }
                         }
                     }
-                }
-
-                // Stats Cards
-                div { class: "grid grid-cols-1 md:grid-cols-3 gap-6 px-4 py-6",
-                    // Total Reports Card
                     div { class: "bg-white overflow-hidden shadow rounded-lg",
                         div { class: "px-4 py-5 sm:p-6",
-                            h3 { class: "text-lg font-medium text-gray-900", "Total Reports" }
-                            p { class: "mt-1 text-3xl font-semibold text-indigo-600",
-                                "50"
-                            }
+                            h3 { class: "text-lg font-medium text-gray-900", {t!("reports-pending")} }
+                            p { class: "mt-1 text-3xl font-semibold text-yellow-600", "{open_reports}" }
+                            p { class: "text-sm text-gray-500", {t!("reports-unassigned-count", count = unassigned_reports)} }
                         }
                     }
-                    // Resolved Reports Card
                     div { class: "bg-white overflow-hidden shadow rounded-lg",
                         div { class: "px-4 py-5 sm:p-6",
-                            h3 { class: "text-lg font-medium text-gray-900", "Resolved Reports" }
-                            p { class: "mt-1 text-3xl font-semibold text-green-600",
-                                "30"
-                            }
+                            h3 { class: "text-lg font-medium text-gray-900", {t!("reports-resolved")} }
+                            p { class: "mt-1 text-3xl font-semibold text-green-600", "{resolved_reports}" }
                         }
                     }
-                    // Pending Reviews Card
                     div { class: "bg-white overflow-hidden shadow rounded-lg",
                         div { class: "px-4 py-5 sm:p-6",
-                            h3 { class: "text-lg font-medium text-gray-900", "Pending Reviews" }
-                            p { class: "mt-1 text-3xl font-semibold text-yellow-600",
-                                "20"
-                            }
+                            h3 { class: "text-lg font-medium text-gray-900", {t!("reports-dismissed")} }
+                            p { class: "mt-1 text-3xl font-semibold text-gray-600", "{dismissed_reports}" }
                         }
                     }
                 }
 
+                // Filters
+                div { class: "mx-4 bg-white shadow rounded-lg p-4 mb-6 grid grid-cols-1 md:grid-cols-4 gap-3 items-center",
+                    select {
+                        class: "px-3 py-2 border border-gray-300 rounded",
+                        onchange: move |e| {
+                            status.set(ReportStatus::ALL.into_iter().find(|s| s.as_str() == e.value()));
+                            page.set(1);
+                        },
+                        option { value: "", selected: status().is_none(), {t!("reports-any-status")} }
+                        for s in ReportStatus::ALL {
+                            option { value: s.as_str(), selected: status() == Some(s), "{s.as_str()}" }
+                        }
+                    }
+                    select {
+                        class: "px-3 py-2 border border-gray-300 rounded",
+                        onchange: move |e| {
+                            target_type.set(ReportTarget::ALL.into_iter().find(|t| t.as_str() == e.value()));
+                            page.set(1);
+                        },
+                        option { value: "", {t!("reports-any-target")} }
+                        for t in ReportTarget::ALL {
+                            option { value: t.as_str(), selected: target_type() == Some(t), "{t.as_str()}" }
+                        }
+                    }
+                    select {
+                        class: "px-3 py-2 border border-gray-300 rounded",
+                        onchange: move |e| {
+                            reason.set(ReportReason::ALL.into_iter().find(|r| r.as_str() == e.value()));
+                            page.set(1);
+                        },
+                        option { value: "", {t!("reports-any-reason")} }
+                        for r in ReportReason::ALL {
+                            option { value: r.as_str(), selected: reason() == Some(r), "{r.label()}" }
+                        }
+                    }
+                    label { class: "flex items-center gap-2 text-sm text-gray-700",
+                        input {
+                            r#type: "checkbox",
+                            checked: mine(),
+                            onchange: move |e| { mine.set(e.checked()); page.set(1); },
+                        }
+                        {t!("reports-assigned-to-me")}
+                    }
+                }
+
+                if let Some(err) = error {
+                    p { class: "mx-4 mb-4 text-sm text-red-600", "{err}" }
+                }
+
                 // Reports List
-                div { class: "px-4 py-6",
-                    div { class: "bg-white shadow overflow-hidden sm:rounded-lg",
-                        div { class: "px-4 py-5 sm:px-6 border-b border-gray-200",
-                            h2 { class: "text-lg font-medium leading-6 text-gray-900",
-                                "Reported Content"
-                            }
+                div { class: "px-4 space-y-4",
+                    if report_list.is_empty() {
+                        div { class: "bg-white shadow rounded-lg p-6 text-sm text-gray-500", {t!("reports-empty")} }
+                    }
+                    for report in report_list {
+                        ReportCard {
+                            key: "{report.id}",
+                            report: report.clone(),
+                            me: my_id,
+                            on_changed: move |_| {
+                                reports.restart();
+                                counts.restart();
+                            },
                         }
-                        ul { class: "divide-y divide-gray-200",
-                            li { class: "px-4 py-4 sm:px-6",
-                                div { class: "flex items-center justify-between",
-                                    p { class: "text-sm font-medium text-indigo-600 truncate",
-                                        "Report 1: Inappropriate content"
-                                    }
-                                    div { class: "ml-2 flex-shrink-0 flex",
-                                        span { class: "px-2 inline-flex text-xs leading-5 font-semibold rounded-full bg-red-100 text-red-800",
-                                            "Urgent"
-                                        }
-                                    }
-                                }
-                            }
-                            li { class: "px-4 py-4 sm:px-6",
-                                div { class: "flex items-center justify-between",
-                                    p { class: "text-sm font-medium text-indigo-600 truncate",
-                                        "Report 2: Spam"
-                                    }
-                                    div { class: "ml-2 flex-shrink-0 flex",
-                                        span { class: "px-2 inline-flex text-xs leading-5 font-semibold rounded-full bg-yellow-100 text-yellow-800",
-                                            "Medium"
-                                        }
-                                    }
-                                }
-                            }
-                            li { class: "px-4 py-4 sm:px-6",
-                                div { class: "flex items-center justify-between",
-                                    p { class: "text-sm font-medium text-indigo-600 truncate",
-                                        "Report 3: Harassment"
-                                    }
-                                    div { class: "ml-2 flex-shrink-0 flex",
-                                        span { class: "px-2 inline-flex text-xs leading-5 font-semibold rounded-full bg-red-100 text-red-800",
-                                            "Urgent"
-                                        }
-                                    }
-                                }
+                    }
+                }
+
+                div { class: "px-4 py-4 flex justify-between items-center text-sm text-gray-600",
+                    button {
+                        class: "px-3 py-1 bg-gray-200 rounded disabled:opacity-50",
+                        disabled: page() <= 1,
+                        onclick: move |_| page -= 1,
+                        {t!("common-previous")}
+                    }
+                    span { {t!("common-page", page = page(), pages = last_page)} }
+                    button {
+                        class: "px-3 py-1 bg-gray-200 rounded disabled:opacity-50",
+                        disabled: page() >= last_page,
+                        onclick: move |_| page += 1,
+                        {t!("common-next")}
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// One report with its notes and, while it's open, the moderation actions
+#[component]
+fn ReportCard(report: ContentReport, me: Option<Uuid>, on_changed: EventHandler<()>) -> Element {
+    let id = report.id;
+    let target_type = report.target_type;
+    let is_open = report.status == ReportStatus::Open;
+    let actions = ModerationAction::for_target(report.target_type);
+
+    let mut action = use_signal(|| ModerationAction::None);
+    let mut note = use_signal(String::new);
+    let mut show_notes = use_signal(|| false);
+    let mut error = use_signal(|| None::<String>);
+    let mut notes = use_resource(move || async move {
+        if show_notes() {
+            list_moderation_notes(id).await
+        } else {
+            Ok(Vec::new())
+        }
+    });
+
+    let target = format!("{} {}", report.target_type.as_str(), report.target_id);
+    let author = report.target_username.clone().unwrap_or_else(|| t!("reports-deleted-account"));
+    let reporter = report.reporter_username.clone().unwrap_or_else(|| t!("reports-deleted-account"));
+    let reported_at = report.created_at.with_timezone(&chrono::Local).format("%Y-%m-%d %H:%M").to_string();
+    let details = report.details.clone().unwrap_or_default();
+    let snapshot = report.content_snapshot.clone().unwrap_or_default();
+    let assigned_to_me = me.is_some() && report.assigned_to == me;
+    let (badge_class, badge) = match (report.status, report.resolution, &report.assignee_username) {
+        (ReportStatus::Open, _, Some(name)) => ("text-gray-600", t!("reports-assigned-to", name = name.clone())),
+        (ReportStatus::Open, _, None) => ("text-yellow-700", t!("reports-unassigned")),
+        (ReportStatus::Dismissed, _, _) => ("bg-gray-100 text-gray-700", t!("reports-dismissed")),
+        (ReportStatus::Resolved, Some(action), _) => {
+            ("bg-green-100 text-green-800", t!("reports-resolved-with", action = action.label()))
+        }
+        (ReportStatus::Resolved, None, _) => ("bg-green-100 text-green-800", t!("reports-resolved-badge")),
+    };
+
+    let note_list: Vec<(String, String, String)> = match &*notes.read() {
+        Some(Ok(list)) => list
+            .iter()
+            .map(|n| {
+                (
+                    n.id.to_string(),
+                    format!(
+                        "{} · {}",
+                        n.author_username.clone().unwrap_or_else(|| t!("reports-deleted-account")),
+                        n.created_at.with_timezone(&chrono::Local).format("%Y-%m-%d %H:%M")
+                    ),
+                    n.body.clone(),
+                )
+            })
+            .collect(),
+        _ => Vec::new(),
+    };
+
+    let assign = move |assignee: Option<Uuid>| {
+        spawn(async move {
+            match assign_report(id, assignee).await {
+                Ok(()) => on_changed.call(()),
+                Err(err) => error.set(Some(err.to_string())),
+            }
+        });
+    };
+
+    let resolve = move |_| {
+        let chosen = action();
+        let text = Some(note());
+        spawn(async move {
+            match resolve_report(id, chosen, text).await {
+                Ok(()) => on_changed.call(()),
+                Err(err) => error.set(Some(err.to_string())),
+            }
+        });
+    };
+
+    let dismiss = move |_| {
+        let text = Some(note());
+        spawn(async move {
+            match dismiss_report(id, text).await {
+                Ok(()) => on_changed.call(()),
+                Err(err) => error.set(Some(err.to_string())),
+            }
+        });
+    };
+
+    let save_note = move |_| {
+        let text = note();
+        spawn(async move {
+            match add_moderation_note(id, text).await {
+                Ok(()) => {
+                    note.set(String::new());
+                    show_notes.set(true);
+                    notes.restart();
+                }
+                Err(err) => error.set(Some(err.to_string())),
+            }
+        });
+    };
+
+    rsx! {
+        div { class: "bg-white shadow rounded-lg p-4",
+            div { class: "flex justify-between items-start",
+                div {
+                    p { class: "font-medium text-gray-900", {t!("reports-heading", reason = report.reason.label(), target = target.clone(), author = author.clone())} }
+                    p { class: "text-sm text-gray-500", {t!("reports-reported-by", reporter = reporter.clone(), date = reported_at.clone())} }
+                }
+                span { class: "text-sm px-2 py-0.5 rounded {badge_class}", "{badge}" }
+            }
+
+            if !details.is_empty() {
+                p { class: "mt-2 text-sm text-gray-700", "“{details}”" }
+            }
+            if !snapshot.is_empty() {
+                pre { class: "mt-2 p-3 bg-gray-50 rounded text-xs text-gray-800 whitespace-pre-wrap max-h-48 overflow-y-auto", "{snapshot}" }
+            }
+
+            if let Some(err) = error() {
+                p { class: "mt-2 text-sm text-red-600", "{err}" }
+            }
+
+            if is_open {
+                div { class: "mt-3 flex flex-wrap gap-2 items-center text-sm",
+                    if assigned_to_me {
+                        button { class: "px-3 py-1 bg-gray-200 rounded hover:bg-gray-300", onclick: move |_| assign(None), {t!("reports-unassign")} }
+                    } else {
+                        button { class: "px-3 py-1 bg-gray-200 rounded hover:bg-gray-300", onclick: move |_| assign(me), {t!("reports-assign-to-me")} }
+                    }
+                    select {
+                        class: "px-2 py-1 border border-gray-300 rounded",
+                        onchange: move |e| {
+                            if let Some(a) = ModerationAction::for_target(target_type).into_iter().find(|a| a.as_str() == e.value()) {
+                                action.set(a);
                             }
+                        },
+                        for a in actions {
+                            option { value: a.as_str(), selected: action() == a, "{a.label()}" }
                         }
                     }
+                    button { class: "px-3 py-1 bg-red-600 text-white rounded hover:bg-red-700", onclick: resolve, {t!("reports-resolve")} }
+                    button { class: "px-3 py-1 bg-gray-200 rounded hover:bg-gray-300", onclick: dismiss, {t!("reports-dismiss")} }
                 }
+            }
 
-                // Action Buttons
-                div { class: "px-4 py-6 grid grid-cols-1 gap-4 sm:grid-cols-3",
-                    button { class: "bg-green-600 hover:bg-green-700 text-white font-bold py-2 px-4 rounded-md text-center transition duration-150 ease-in-out",
-                        "Approve"
+            div { class: "mt-3",
+                textarea {
+                    class: "w-full px-2 py-1 border border-gray-300 rounded text-sm",
+                    rows: "2",
+                    placeholder: t!("reports-note-placeholder"),
+                    value: "{note}",
+                    oninput: move |e| note.set(e.value()),
+                }
+                div { class: "flex gap-3 text-sm",
+                    button { class: "text-indigo-600 hover:text-indigo-900", onclick: save_note, {t!("reports-add-note")} }
+                    button {
+                        class: "text-gray-600 hover:text-gray-900",
+                        onclick: move |_| show_notes.set(!show_notes()),
+                        if show_notes() { {t!("reports-hide-notes")} } else { {t!("reports-show-notes")} }
                     }
-                    button { class: "bg-red-600 hover:bg-red-700 text-white font-bold py-2 px-4 rounded-md text-center transition duration-150 ease-in-out",
-                        "Reject"
+                }
+            }
+
+            if show_notes() {
+                ul { class: "mt-2 divide-y text-sm",
+                    if note_list.is_empty() {
+                        li { class: "py-1 text-gray-500", {t!("reports-no-notes")} }
                     }
-                    button { class: "bg-yellow-500 hover:bg-yellow-600 text-white font-bold py-2 px-4 rounded-md text-center transition duration-150 ease-in-out",
-                        "Review Later"
+                    for (key, by, body) in note_list {
+                        li { key: "{key}", class: "py-1",
+                            p { class: "text-xs text-gray-500", "{by}" }
+                            p { class: "text-gray-800", "{body}" }
+                        }
                     }
                 }
             }
         }
     }
-}
\ No newline at end of file
+}
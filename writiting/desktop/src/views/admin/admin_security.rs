@@ -0,0 +1,153 @@
+use dioxus::prelude::*;
+use ui::t;
+use chrono::NaiveDate;
+use api::authentication::{search_security_logs, SecurityAction, SecurityLogFilter};
+
+#[component]
+pub fn AdminSecurityLog() -> Element {
+    let mut user = use_signal(String::new);
+    let mut action = use_signal(|| None::<SecurityAction>);
+    let mut ip = use_signal(String::new);
+    let mut from = use_signal(String::new);
+    let mut to = use_signal(String::new);
+    let mut page = use_signal(|| 1i64);
+
+    let logs = use_resource(move || async move {
+        search_security_logs(SecurityLogFilter {
+            user: Some(user()),
+            action: action(),
+            ip: Some(ip()),
+            from: NaiveDate::parse_from_str(&from(), "%Y-%m-%d").ok(),
+            to: NaiveDate::parse_from_str(&to(), "%Y-%m-%d").ok(),
+            page: page(),
+        })
+        .await
+    });
+
+    let (rows, total, per_page, error) = match &*logs.read() {
+        Some(Ok(p)) => {
+            let rows: Vec<(String, String, String, bool, String, String, String)> = p
+                .entries
+                .iter()
+                .map(|e| {
+                    (
+                        e.id.to_string(),
+                        e.created_at.format("%Y-%m-%d %H:%M:%S").to_string(),
+                        e.username.clone().unwrap_or_else(|| "-".to_string()),
+                        e.action.is_warning(),
+                        e.action.as_str().to_string(),
+                        e.ip_address.clone(),
+                        e.metadata.as_ref().map(|m| m.to_string()).unwrap_or_default(),
+                    )
+                })
+                .collect();
+            (rows, p.total, p.per_page, None)
+        }
+        Some(Err(err)) => (Vec::new(), 0, 50, Some(err.to_string())),
+        None => (Vec::new(), 0, 50, None),
+    };
+    let last_page = ((total + per_page - 1) / per_page).max(1);
+
+    rsx! {
+        div { class: "min-h-screen bg-gray-100",
+            header { class: "bg-white shadow",
+                div { class: "max-w-7xl mx-auto py-6 px-4 sm:px-6 lg:px-8",
+                    h1 { class: "text-3xl font-bold text-gray-900", {t!("security-title")} }
+                    p { class: "mt-1 text-gray-600", {t!("security-subtitle")} }
+                }
+            }
+
+            main { class: "max-w-7xl mx-auto py-6 sm:px-6 lg:px-8",
+                div { class: "bg-white shadow rounded-lg p-4 mb-6 grid grid-cols-1 md:grid-cols-5 gap-3",
+                    input {
+                        class: "px-3 py-2 border border-gray-300 rounded",
+                        placeholder: t!("admin-user-filter"),
+                        value: "{user}",
+                        oninput: move |e| { user.set(e.value()); page.set(1); },
+                    }
+                    select {
+                        class: "px-3 py-2 border border-gray-300 rounded",
+                        onchange: move |e| {
+                            action.set(SecurityAction::ALL.into_iter().find(|a| a.as_str() == e.value()));
+                            page.set(1);
+                        },
+                        option { value: "", {t!("security-all-events")} }
+                        for a in SecurityAction::ALL {
+                            option { value: a.as_str(), selected: action() == Some(a), "{a.description()}" }
+                        }
+                    }
+                    input {
+                        class: "px-3 py-2 border border-gray-300 rounded",
+                        placeholder: t!("security-ip-filter"),
+                        value: "{ip}",
+                        oninput: move |e| { ip.set(e.value()); page.set(1); },
+                    }
+                    input {
+                        r#type: "date",
+                        class: "px-3 py-2 border border-gray-300 rounded",
+                        value: "{from}",
+                        oninput: move |e| { from.set(e.value()); page.set(1); },
+                    }
+                    input {
+                        r#type: "date",
+                        class: "px-3 py-2 border border-gray-300 rounded",
+                        value: "{to}",
+                        oninput: move |e| { to.set(e.value()); page.set(1); },
+                    }
+                }
+
+                if let Some(err) = error {
+                    p { class: "mb-4 text-sm text-red-600", "{err}" }
+                }
+
+                div { class: "bg-white shadow rounded-lg overflow-x-auto",
+                    table { class: "min-w-full divide-y divide-gray-200 text-sm",
+                        thead { class: "bg-gray-50",
+                            tr {
+                                for heading in [
+                                    t!("admin-col-when"),
+                                    t!("admin-col-user"),
+                                    t!("security-col-event"),
+                                    t!("admin-col-ip"),
+                                    t!("security-col-details"),
+                                ] {
+                                    th { class: "px-4 py-2 text-left font-medium text-gray-500", "{heading}" }
+                                }
+                            }
+                        }
+                        tbody { class: "divide-y divide-gray-200",
+                            for (id, when, who, warning, event, address, details) in rows {
+                                tr { key: "{id}",
+                                    td { class: "px-4 py-2 whitespace-nowrap", "{when}" }
+                                    td { class: "px-4 py-2", "{who}" }
+                                    td { class: if warning { "px-4 py-2 text-red-700" } else { "px-4 py-2" }, "{event}" }
+                                    td { class: "px-4 py-2 text-gray-600", "{address}" }
+                                    td { class: "px-4 py-2 text-gray-500 font-mono text-xs break-all", "{details}" }
+                                }
+                            }
+                        }
+                    }
+                }
+
+                div { class: "flex justify-between items-center mt-4 text-sm text-gray-600",
+                    p { {t!("security-count", count = total)} }
+                    div { class: "flex gap-2 items-center",
+                        button {
+                            class: "px-3 py-1 bg-gray-200 rounded disabled:opacity-50",
+                            disabled: page() <= 1,
+                            onclick: move |_| page -= 1,
+                            {t!("common-previous")}
+                        }
+                        span { {t!("common-page", page = page(), pages = last_page)} }
+                        button {
+                            class: "px-3 py-1 bg-gray-200 rounded disabled:opacity-50",
+                            disabled: page() >= last_page,
+                            onclick: move |_| page += 1,
+                            {t!("common-next")}
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
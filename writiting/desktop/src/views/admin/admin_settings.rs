@@ -1,14 +1,52 @@
 use dioxus::prelude::*;
+use ui::t;
+use api::authentication::{audit_entities, list_audit_logs, AuditLogFilter};
+use api::settings::{
+    get_app_settings, update_app_settings, AiProvider, AppSettings, PostVisibility, RegistrationMode,
+};
+
+const INPUT_CLASS: &str = "block w-full rounded-md border-gray-300 shadow-sm focus:border-indigo-500 focus:ring-indigo-500 sm:text-sm";
 
 #[component]
 pub fn AdminSettings() -> Element {
+    let mut settings = use_resource(|| async move { get_app_settings().await });
+    let mut history = use_resource(|| async move {
+        list_audit_logs(AuditLogFilter {
+            entity_type: Some(audit_entities::SETTING.to_string()),
+            ..Default::default()
+        })
+        .await
+    });
+
+    // Changes by user, newest first: (who, what, when)
+    let recent_changes: Vec<(String, String, String)> = match &*history.read() {
+        Some(Ok(page)) => page
+            .entries
+            .iter()
+            .take(5)
+            .map(|entry| {
+                (
+                    entry.username.clone().unwrap_or_else(|| t!("settings-system")),
+                    entry.changed_fields().join(", "),
+                    entry.created_at.format("%Y-%m-%d %H:%M").to_string(),
+                )
+            })
+            .collect(),
+        Some(Err(err)) => {
+            // Admins without system:audit just don't see the history
+            tracing::debug!("Failed to load settings history: {}", err);
+            Vec::new()
+        }
+        None => Vec::new(),
+    };
+
     rsx! {
         div { class: "min-h-screen bg-gray-100",
             // Header
             header { class: "bg-white shadow",
                 div { class: "max-w-7xl mx-auto py-6 px-4 sm:px-6 lg:px-8",
-                    h1 { class: "text-3xl font-bold text-gray-900", "Admin Settings" }
-                    p { class: "mt-1 text-gray-600", "Configure application settings and preferences" }
+                    h1 { class: "text-3xl font-bold text-gray-900", {t!("nav-admin-settings")} }
+                    p { class: "mt-1 text-gray-600", {t!("settings-subtitle")} }
                 }
             }
 
@@ -33,88 +71,36 @@ pub fn AdminSettings() -> Element {
                             }
                             div { class: "ml-3",
                                 p { class: "text-sm text-indigo-700",
-                                    "Welcome to the Admin Settings Page! Make changes carefully as they affect the entire application."
+                                    {t!("settings-banner")}
                                 }
                             }
                         }
                     }
                 }
 
-                // Settings Form
                 div { class: "grid grid-cols-1 gap-6 lg:grid-cols-3 px-4 py-6",
                     // Main Settings Card
                     div { class: "bg-white shadow overflow-hidden sm:rounded-lg lg:col-span-2",
                         div { class: "px-4 py-5 sm:p-6",
                             h2 { class: "text-lg font-medium leading-6 text-gray-900",
-                                "Application Settings"
+                                {t!("settings-application")}
                             }
-                            form { class: "mt-6 space-y-6",
-                                // Site Name Field
-                                div {
-                                    label {
-                                        class: "block text-sm font-medium text-gray-700",
-                                        r#for: "site-name",
-                                        "Site Name"
-                                    }
-                                    div { class: "mt-1",
-                                        input {
-                                            class: "block w-full rounded-md border-gray-300 shadow-sm focus:border-indigo-500 focus:ring-indigo-500 sm:text-sm",
-                                            id: "site-name",
-                                            name: "site-name",
-                                            r#type: "text",
-                                            placeholder: "Enter your site name",
-                                        }
+                            match &*settings.read() {
+                                Some(Ok(current)) => rsx! {
+                                    SettingsForm {
+                                        settings: current.clone(),
+                                        on_saved: move |_| {
+                                            settings.restart();
+                                            history.restart();
+                                        },
                                     }
-                                }
-
-                                // Admin Email Field
-                                div {
-                                    label {
-                                        class: "block text-sm font-medium text-gray-700",
-                                        r#for: "admin-email",
-                                        "Admin Email"
-                                    }
-                                    div { class: "mt-1",
-                                        input {
-                                            class: "block w-full rounded-md border-gray-300 shadow-sm focus:border-indigo-500 focus:ring-indigo-500 sm:text-sm",
-                                            id: "admin-email",
-                                            name: "admin-email",
-                                            r#type: "email",
-                                            placeholder: "admin@example.com",
-                                        }
-                                    }
-                                }
-
-                                // Maintenance Mode Toggle
-                                div { class: "flex items-start",
-                                    div { class: "flex items-center h-5",
-                                        input {
-                                            class: "focus:ring-indigo-500 h-4 w-4 text-indigo-600 border-gray-300 rounded",
-                                            id: "maintenance-mode",
-                                            name: "maintenance-mode",
-                                            r#type: "checkbox",
-                                        }
-                                    }
-                                    div { class: "ml-3 text-sm",
-                                        label {
-                                            class: "font-medium text-gray-700",
-                                            r#for: "maintenance-mode",
-                                            "Maintenance Mode"
-                                        }
-                                        p { class: "text-gray-500",
-                                            "When enabled, the site will be unavailable to non-admin users"
-                                        }
-                                    }
-                                }
-
-                                // Save Button
-                                div {
-                                    button {
-                                        r#type: "submit",
-                                        class: "inline-flex justify-center rounded-md border border-transparent bg-indigo-600 py-2 px-4 text-sm font-medium text-white shadow-sm hover:bg-indigo-700 focus:outline-none focus:ring-2 focus:ring-indigo-500 focus:ring-offset-2",
-                                        "Save Settings"
-                                    }
-                                }
+                                },
+                                Some(Err(err)) => rsx! {
+                                    p { class: "mt-4 text-sm text-red-600", {t!("settings-load-failed", error = err.to_string())} }
+                                },
+                                None => rsx! {
+                                    p { class: "mt-4 text-sm text-gray-500", {t!("common-loading")} }
+                                },
                             }
                         }
                     }
@@ -125,18 +111,23 @@ pub fn AdminSettings() -> Element {
                         div { class: "bg-white shadow overflow-hidden sm:rounded-lg",
                             div { class: "px-4 py-5 sm:p-6",
                                 h2 { class: "text-lg font-medium leading-6 text-gray-900",
-                                    "Need Help?"
+                                    {t!("settings-help")}
                                 }
                                 div { class: "mt-2",
                                     p { class: "text-sm text-gray-600",
-                                        "If you need assistance with the settings, please contact our support team."
+                                        {t!("settings-help-maintenance")}
                                     }
                                 }
-                                div { class: "mt-4",
+                                div { class: "mt-4 flex gap-2",
+                                    a {
+                                        href: "/admin/maintenance",
+                                        class: "inline-flex items-center rounded-md border border-transparent bg-gray-100 px-3 py-2 text-sm font-medium leading-4 text-gray-700 hover:bg-gray-200 focus:outline-none focus:ring-2 focus:ring-indigo-500 focus:ring-offset-2",
+                                        {t!("maintenance-title")}
+                                    }
                                     a {
                                         href: "/help/contact",
                                         class: "inline-flex items-center rounded-md border border-transparent bg-gray-100 px-3 py-2 text-sm font-medium leading-4 text-gray-700 hover:bg-gray-200 focus:outline-none focus:ring-2 focus:ring-indigo-500 focus:ring-offset-2",
-                                        "Contact Support"
+                                        {t!("settings-contact")}
                                     }
                                 }
                             }
@@ -146,61 +137,20 @@ pub fn AdminSettings() -> Element {
                         div { class: "bg-white shadow overflow-hidden sm:rounded-lg",
                             div { class: "px-4 py-5 sm:p-6",
                                 h2 { class: "text-lg font-medium leading-6 text-gray-900",
-                                    "Recent Activity"
+                                    {t!("settings-recent")}
                                 }
                                 div { class: "mt-2",
+                                    if recent_changes.is_empty() {
+                                        p { class: "text-sm text-gray-500", {t!("settings-no-changes")} }
+                                    }
                                     ul { class: "divide-y divide-gray-200",
-                                        li { class: "py-3",
-                                            div { class: "flex space-x-3",
-                                                svg {
-                                                    class: "h-5 w-5 text-gray-400",
-                                                    xmlns: "http://www.w3.org/2000/svg",
-                                                    view_box: "0 0 20 20",
-                                                    fill: "currentColor",
-                                                    path {
-                                                        fill_rule: "evenodd",
-                                                        d: "M10 18a8 8 0 100-16 8 8 0 000 16zm1-12a1 1 0 10-2 0v4a1 1 0 00.293.707l2.828 2.829a1 1 0 101.415-1.415L11 9.586V6z",
-                                                        clip_rule: "evenodd",
-                                                    }
-                                                }
-                                                div { class: "flex-1 space-y-1",
-                                                    div { class: "flex items-center justify-between",
-                                                        h3 { class: "text-sm font-medium",
-                                                            "Changed site name"
-                                                        }
-                                                        p { class: "text-sm text-gray-500",
-                                                            "2 hours ago"
-                                                        }
-                                                    }
-                                                    p { class: "text-sm text-gray-500",
-                                                        "Updated to 'My Awesome Site'"
-                                                    }
-                                                }
-                                            }
-                                        }
-                                        li { class: "py-3",
-                                            div { class: "flex space-x-3",
-                                                svg {
-                                                    class: "h-5 w-5 text-gray-400",
-                                                    xmlns: "http://www.w3.org/2000/svg",
-                                                    view_box: "0 0 20 20",
-                                                    fill: "currentColor",
-                                                    path { d: "M2.003 5.884L10 9.882l7.997-3.998A2 2 0 0016 4H4a2 2 0 00-1.997 1.884z" }
-                                                    path { d: "M18 8.118l-8 4-8-4V14a2 2 0 002 2h12a2 2 0 002-2V8.118z" }
-                                                }
-                                                div { class: "flex-1 space-y-1",
-                                                    div { class: "flex items-center justify-between",
-                                                        h3 { class: "text-sm font-medium",
-                                                            "Updated admin email"
-                                                        }
-                                                        p { class: "text-sm text-gray-500",
-                                                            "1 day ago"
-                                                        }
-                                                    }
-                                                    p { class: "text-sm text-gray-500",
-                                                        "Changed to admin@example.com"
-                                                    }
+                                        for (who, what, when) in recent_changes {
+                                            li { class: "py-3",
+                                                div { class: "flex items-center justify-between",
+                                                    h3 { class: "text-sm font-medium", "{who}" }
+                                                    p { class: "text-sm text-gray-500", "{when}" }
                                                 }
+                                                p { class: "text-sm text-gray-500", {t!("settings-changed", fields = what)} }
                                             }
                                         }
                                     }
@@ -212,4 +162,221 @@ pub fn AdminSettings() -> Element {
             }
         }
     }
-}
\ No newline at end of file
+}
+
+#[component]
+fn SettingsForm(settings: AppSettings, on_saved: EventHandler<()>) -> Element {
+    let mut form = use_signal(|| settings.clone());
+    // One MIME type per line is easier to edit than a list
+    let mut upload_types = use_signal(|| settings.allowed_upload_types.join("\n"));
+    let mut error = use_signal(|| None::<String>);
+    let mut saved = use_signal(|| false);
+
+    let save = move |evt: FormEvent| {
+        evt.prevent_default();
+        let mut update = form();
+        update.allowed_upload_types = upload_types().lines().map(str::to_string).collect();
+
+        spawn(async move {
+            match update_app_settings(update).await {
+                Ok(_) => {
+                    error.set(None);
+                    saved.set(true);
+                    on_saved.call(());
+                }
+                Err(err) => {
+                    tracing::error!("Failed to save settings: {}", err);
+                    saved.set(false);
+                    error.set(Some(err.to_string()));
+                }
+            }
+        });
+    };
+
+    let current = form();
+
+    rsx! {
+        form { class: "mt-6 space-y-6", onsubmit: save,
+            if let Some(err) = error() {
+                p { class: "text-sm text-red-600", "{err}" }
+            }
+            if saved() {
+                p { class: "text-sm text-green-700", {t!("settings-saved")} }
+            }
+
+            // Site
+            div { class: "grid grid-cols-1 gap-4 md:grid-cols-2",
+                div {
+                    label { class: "block text-sm font-medium text-gray-700", r#for: "site-name", {t!("settings-site-name")} }
+                    div { class: "mt-1",
+                        input {
+                            class: INPUT_CLASS,
+                            id: "site-name",
+                            r#type: "text",
+                            placeholder: t!("settings-site-name-placeholder"),
+                            value: "{current.site_name}",
+                            oninput: move |e| form.write().site_name = e.value(),
+                        }
+                    }
+                }
+                div {
+                    label { class: "block text-sm font-medium text-gray-700", r#for: "admin-email", {t!("settings-admin-email")} }
+                    div { class: "mt-1",
+                        input {
+                            class: INPUT_CLASS,
+                            id: "admin-email",
+                            r#type: "email",
+                            placeholder: "admin@example.com",
+                            value: current.admin_email.clone().unwrap_or_default(),
+                            oninput: move |e| form.write().admin_email = Some(e.value()),
+                        }
+                    }
+                }
+            }
+
+            // Accounts and posts
+            div { class: "grid grid-cols-1 gap-4 md:grid-cols-2",
+                div {
+                    label { class: "block text-sm font-medium text-gray-700", r#for: "registration-mode", {t!("settings-registration")} }
+                    select {
+                        class: "mt-1 {INPUT_CLASS}",
+                        id: "registration-mode",
+                        onchange: move |e| {
+                            if let Some(mode) = RegistrationMode::ALL.into_iter().find(|m| m.as_str() == e.value()) {
+                                form.write().registration_mode = mode;
+                            }
+                        },
+                        for mode in RegistrationMode::ALL {
+                            option {
+                                value: mode.as_str(),
+                                selected: current.registration_mode == mode,
+                                "{mode.label()}"
+                            }
+                        }
+                    }
+                }
+                div {
+                    label { class: "block text-sm font-medium text-gray-700", r#for: "post-visibility", {t!("settings-post-visibility")} }
+                    select {
+                        class: "mt-1 {INPUT_CLASS}",
+                        id: "post-visibility",
+                        onchange: move |e| {
+                            if let Some(visibility) = PostVisibility::ALL.into_iter().find(|v| v.as_str() == e.value()) {
+                                form.write().default_post_visibility = visibility;
+                            }
+                        },
+                        for visibility in PostVisibility::ALL {
+                            option {
+                                value: visibility.as_str(),
+                                selected: current.default_post_visibility == visibility,
+                                "{visibility.label()}"
+                            }
+                        }
+                    }
+                }
+            }
+
+            // AI
+            div { class: "grid grid-cols-1 gap-4 md:grid-cols-3",
+                div {
+                    label { class: "block text-sm font-medium text-gray-700", r#for: "ai-provider", {t!("settings-ai-provider")} }
+                    select {
+                        class: "mt-1 {INPUT_CLASS}",
+                        id: "ai-provider",
+                        onchange: move |e| {
+                            if let Some(provider) = AiProvider::ALL.into_iter().find(|p| p.as_str() == e.value()) {
+                                form.write().ai_provider = provider;
+                            }
+                        },
+                        for provider in AiProvider::ALL {
+                            option {
+                                value: provider.as_str(),
+                                selected: current.ai_provider == provider,
+                                "{provider.label()}"
+                            }
+                        }
+                    }
+                }
+                div {
+                    label { class: "block text-sm font-medium text-gray-700", r#for: "ai-model", {t!("settings-ai-model")} }
+                    input {
+                        class: "mt-1 {INPUT_CLASS}",
+                        id: "ai-model",
+                        placeholder: "llama3",
+                        value: "{current.ai_model}",
+                        oninput: move |e| form.write().ai_model = e.value(),
+                    }
+                }
+                div {
+                    label { class: "block text-sm font-medium text-gray-700", r#for: "ai-base-url", {t!("settings-ai-address")} }
+                    input {
+                        class: "mt-1 {INPUT_CLASS}",
+                        id: "ai-base-url",
+                        r#type: "url",
+                        placeholder: "http://localhost:11434",
+                        value: "{current.ai_base_url}",
+                        oninput: move |e| form.write().ai_base_url = e.value(),
+                    }
+                }
+            }
+
+            // Uploads
+            div { class: "grid grid-cols-1 gap-4 md:grid-cols-3",
+                div { class: "space-y-4",
+                    div {
+                        label { class: "block text-sm font-medium text-gray-700", r#for: "max-upload", {t!("settings-max-upload")} }
+                        input {
+                            class: "mt-1 {INPUT_CLASS}",
+                            id: "max-upload",
+                            r#type: "number",
+                            min: "1",
+                            max: "1024",
+                            value: "{current.max_upload_mb}",
+                            oninput: move |e| {
+                                if let Ok(mb) = e.value().parse() {
+                                    form.write().max_upload_mb = mb;
+                                }
+                            },
+                        }
+                    }
+                    div {
+                        label { class: "block text-sm font-medium text-gray-700", r#for: "user-quota", {t!("settings-user-quota")} }
+                        input {
+                            class: "mt-1 {INPUT_CLASS}",
+                            id: "user-quota",
+                            r#type: "number",
+                            min: "1",
+                            max: "102400",
+                            value: "{current.user_quota_mb}",
+                            oninput: move |e| {
+                                if let Ok(mb) = e.value().parse() {
+                                    form.write().user_quota_mb = mb;
+                                }
+                            },
+                        }
+                    }
+                }
+                div { class: "md:col-span-2",
+                    label { class: "block text-sm font-medium text-gray-700", r#for: "upload-types", {t!("settings-upload-types")} }
+                    textarea {
+                        class: "mt-1 {INPUT_CLASS}",
+                        id: "upload-types",
+                        rows: "4",
+                        placeholder: t!("settings-upload-types-placeholder"),
+                        value: "{upload_types}",
+                        oninput: move |e| upload_types.set(e.value()),
+                    }
+                }
+            }
+
+            // Save Button
+            div {
+                button {
+                    r#type: "submit",
+                    class: "inline-flex justify-center rounded-md border border-transparent bg-indigo-600 py-2 px-4 text-sm font-medium text-white shadow-sm hover:bg-indigo-700 focus:outline-none focus:ring-2 focus:ring-indigo-500 focus:ring-offset-2",
+                    {t!("settings-save")}
+                }
+            }
+        }
+    }
+}
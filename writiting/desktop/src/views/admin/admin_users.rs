@@ -1,251 +1,598 @@
+use std::collections::HashSet;
 use dioxus::prelude::*;
+use ui::t;
+use uuid::Uuid;
+use api::authentication::{list_locked_users, unlock_user};
+use api::users::{
+    apply_bulk_user_action, delete_user, force_password_reset, get_user_stats, impersonate_user,
+    list_assignable_roles, list_users, revoke_sessions_for_user, set_user_active, update_user,
+    AdminUserFilter, AdminUserSummary, BulkUserAction, RoleOption, UserSort, UserUpdate,
+};
+use crate::Route;
+
+// How long "Sign in as" lasts before the admin is put back
+const IMPERSONATION_MINUTES: i64 = 15;
+
+/// What a row's buttons ask the page to do
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum UserRowAction {
+    Edit,
+    SetActive(bool),
+    ResetPassword,
+    RevokeSessions,
+    Impersonate,
+    Delete,
+}
+
+/// Value of the yes/no filter selects; anything else means "any"
+fn parse_flag(value: &str) -> Option<bool> {
+    match value {
+        "yes" => Some(true),
+        "no" => Some(false),
+        _ => None,
+    }
+}
 
 #[component]
 pub fn AdminUsers() -> Element {
+    let mut search = use_signal(String::new);
+    let mut role_id = use_signal(|| None::<i32>);
+    let mut active = use_signal(|| None::<bool>);
+    let mut verified = use_signal(|| None::<bool>);
+    let mut sort = use_signal(UserSort::default);
+    let mut page = use_signal(|| 1i64);
+    let mut selected = use_signal(HashSet::<Uuid>::new);
+    let mut editing = use_signal(|| None::<AdminUserSummary>);
+    let mut bulk_action = use_signal(|| None::<BulkUserAction>);
+    let mut confirm_bulk_delete = use_signal(|| false);
+    let mut message = use_signal(|| None::<String>);
+    let mut error = use_signal(|| None::<String>);
+    let navigator = use_navigator();
+
+    let mut users = use_resource(move || async move {
+        list_users(AdminUserFilter {
+            search: Some(search()),
+            role_id: role_id(),
+            is_active: active(),
+            email_verified: verified(),
+            sort: sort(),
+            page: page(),
+        })
+        .await
+    });
+    let mut stats = use_resource(|| async move { get_user_stats().await });
+    let roles = use_resource(|| async move { list_assignable_roles().await });
+
+    let on_row_action = move |(user, action): (AdminUserSummary, UserRowAction)| {
+        if action == UserRowAction::Edit {
+            editing.set(Some(user));
+            return;
+        }
+
+        spawn(async move {
+            let id = user.id;
+            let outcome = match action {
+                UserRowAction::Edit => return,
+                UserRowAction::SetActive(true) => set_user_active(id, true)
+                    .await
+                    .map(|_| t!("users-reactivated", name = user.username.clone())),
+                UserRowAction::SetActive(false) => set_user_active(id, false)
+                    .await
+                    .map(|_| t!("users-deactivated", name = user.username.clone())),
+                UserRowAction::ResetPassword => force_password_reset(id)
+                    .await
+                    .map(|_| t!("users-reset-sent", name = user.username.clone())),
+                UserRowAction::RevokeSessions => revoke_sessions_for_user(id)
+                    .await
+                    .map(|n| t!("users-sessions-ended", count = n, name = user.username.clone())),
+                UserRowAction::Delete => delete_user(id)
+                    .await
+                    .map(|_| t!("users-deleted", name = user.username.clone())),
+                UserRowAction::Impersonate => match impersonate_user(id, IMPERSONATION_MINUTES).await {
+                    Ok(()) => {
+                        ui::auth::session_changed();
+                        navigator.push(Route::Home {});
+                        return;
+                    }
+                    Err(err) => Err(err),
+                },
+            };
+
+            match outcome {
+                Ok(text) => {
+                    error.set(None);
+                    message.set(Some(text));
+                    users.restart();
+                    stats.restart();
+                }
+                Err(err) => {
+                    tracing::error!("User action failed: {}", err);
+                    message.set(None);
+                    error.set(Some(err.to_string()));
+                }
+            }
+        });
+    };
+
+    let apply_bulk = move |_| {
+        let Some(action) = bulk_action() else {
+            return;
+        };
+        let ids: Vec<Uuid> = selected().into_iter().collect();
+        if ids.is_empty() {
+            return;
+        }
+        if action == BulkUserAction::Delete && !confirm_bulk_delete() {
+            confirm_bulk_delete.set(true);
+            return;
+        }
+        confirm_bulk_delete.set(false);
+
+        spawn(async move {
+            match apply_bulk_user_action(ids, action).await {
+                Ok(result) => {
+                    let mut text = t!("users-bulk-done", action = action.label(), count = result.succeeded);
+                    if !result.failed.is_empty() {
+                        let reasons: Vec<&str> = result.failed.iter().map(|(_, why)| why.as_str()).collect();
+                        text.push_str(&t!("users-bulk-skipped", count = result.failed.len(), reasons = reasons.join("; ")));
+                    }
+                    error.set(None);
+                    message.set(Some(text));
+                    selected.set(HashSet::new());
+                    users.restart();
+                    stats.restart();
+                }
+                Err(err) => {
+                    tracing::error!("Bulk user action failed: {}", err);
+                    error.set(Some(err.to_string()));
+                }
+            }
+        });
+    };
+
+    let (total_users, active_users, inactive_users, unverified_users) = match &*stats.read() {
+        Some(Ok(s)) => (s.total.to_string(), s.active.to_string(), s.inactive.to_string(), s.unverified.to_string()),
+        _ => ("–".to_string(), "–".to_string(), "–".to_string(), "–".to_string()),
+    };
+
+    // Readers without user:update can't list roles; the role filter just stays empty
+    let role_list: Vec<RoleOption> = match &*roles.read() {
+        Some(Ok(list)) => list.clone(),
+        _ => Vec::new(),
+    };
+
+    let (user_list, total, per_page) = match &*users.read() {
+        Some(Ok(p)) => (p.users.clone(), p.total, p.per_page),
+        Some(Err(err)) => {
+            tracing::error!("Failed to load users: {}", err);
+            (Vec::new(), 0, 50)
+        }
+        None => (Vec::new(), 0, 50),
+    };
+    let last_page = ((total + per_page - 1) / per_page).max(1);
+    let chosen = selected();
+    let page_ids: Vec<Uuid> = user_list.iter().map(|u| u.id).collect();
+    let all_chosen = !page_ids.is_empty() && page_ids.iter().all(|id| chosen.contains(id));
+    let chosen_count = chosen.len();
+    let apply_label = if confirm_bulk_delete() {
+        t!("users-confirm-bulk-delete", count = chosen_count)
+    } else {
+        t!("users-apply")
+    };
+
     rsx! {
         div { class: "min-h-screen bg-gray-100",
             // Header
             header { class: "bg-white shadow",
                 div { class: "max-w-7xl mx-auto py-6 px-4 sm:px-6 lg:px-8",
-                    h1 { class: "text-3xl font-bold text-gray-900", "User Management" }
+                    h1 { class: "text-3xl font-bold text-gray-900", {t!("users-title")} }
                     p { class: "mt-1 text-gray-600",
-                        "Manage users, view profiles, and perform administrative tasks"
+                        {t!("users-subtitle")}
                     }
                 }
             }
 
             // Main Content
             main { class: "max-w-7xl mx-auto py-6 sm:px-6 lg:px-8",
-                // Welcome Banner
-                div { class: "px-4 pb-6 sm:px-0",
-                    div { class: "bg-indigo-50 border-l-4 border-indigo-400 p-4 rounded-lg",
-                        div { class: "flex items-center",
-                            svg {
-                                class: "h-5 w-5 text-indigo-400 mr-3",
-                                xmlns: "http://www.w3.org/2000/svg",
-                                view_box: "0 0 20 20",
-                                fill: "currentColor",
-                                path { d: "M13 6a3 3 0 11-6 0 3 3 0 016 0zM18 8a2 2 0 11-4 0 2 2 0 014 0zM14 15a4 4 0 00-8 0v1h8v-1zM6 8a2 2 0 11-4 0 2 2 0 014 0zM16 18v-1a5.972 5.972 0 00-.75-2.906A3.005 3.005 0 0119 15v1h-3zM4.75 12.094A5.973 5.973 0 004 15v1H1v-1a3 3 0 013.75-2.906z" }
-                            }
-                            p { class: "text-sm text-indigo-700",
-                                "Welcome to the Admin Users Page! Manage your user base efficiently."
-                            }
+                // Stats Cards
+                div { class: "grid grid-cols-1 md:grid-cols-4 gap-6 px-4 py-6",
+                    div { class: "bg-white overflow-hidden shadow rounded-lg",
+                        div { class: "px-4 py-5 sm:p-6",
+                            h3 { class: "text-lg font-medium text-gray-900", {t!("admin-total-users")} }
+                            p { class: "mt-1 text-3xl font-semibold text-indigo-600", "{total_users}" }
                         }
                     }
-                }
-
-                // Stats Cards
-                div { class: "grid grid-cols-1 md:grid-cols-3 gap-6 px-4 py-6",
-                    // Total Users Card
                     div { class: "bg-white overflow-hidden shadow rounded-lg",
                         div { class: "px-4 py-5 sm:p-6",
-                            h3 { class: "text-lg font-medium text-gray-900", "Total Users" }
-                            p { class: "mt-1 text-3xl font-semibold text-indigo-600",
-                                "1,000"
-                            }
+                            h3 { class: "text-lg font-medium text-gray-900", {t!("users-active-count")} }
+                            p { class: "mt-1 text-3xl font-semibold text-green-600", "{active_users}" }
                         }
                     }
-                    // Active Users Card
                     div { class: "bg-white overflow-hidden shadow rounded-lg",
                         div { class: "px-4 py-5 sm:p-6",
-                            h3 { class: "text-lg font-medium text-gray-900", "Active Users" }
-                            p { class: "mt-1 text-3xl font-semibold text-green-600",
-                                "800"
-                            }
+                            h3 { class: "text-lg font-medium text-gray-900", {t!("users-inactive-count")} }
+                            p { class: "mt-1 text-3xl font-semibold text-gray-600", "{inactive_users}" }
                         }
                     }
-                    // Inactive Users Card
                     div { class: "bg-white overflow-hidden shadow rounded-lg",
                         div { class: "px-4 py-5 sm:p-6",
-                            h3 { class: "text-lg font-medium text-gray-900", "Inactive Users" }
-                            p { class: "mt-1 text-3xl font-semibold text-gray-600",
-                                "200"
+                            h3 { class: "text-lg font-medium text-gray-900", {t!("users-unverified-count")} }
+                            p { class: "mt-1 text-3xl font-semibold text-yellow-600", "{unverified_users}" }
+                        }
+                    }
+                }
+
+                LockedAccounts {}
+
+                if let Some(text) = message() {
+                    p { class: "mx-4 mb-4 p-3 rounded bg-green-50 text-sm text-green-800", "{text}" }
+                }
+                if let Some(err) = error() {
+                    p { class: "mx-4 mb-4 p-3 rounded bg-red-50 text-sm text-red-700", "{err}" }
+                }
+
+                if let Some(user) = editing() {
+                    UserEditor {
+                        key: "{user.id}",
+                        user: user.clone(),
+                        roles: role_list.clone(),
+                        on_close: move |saved: bool| {
+                            editing.set(None);
+                            if saved {
+                                users.restart();
+                                stats.restart();
                             }
+                        },
+                    }
+                }
+
+                // Filters
+                div { class: "mx-4 bg-white shadow rounded-lg p-4 mb-6 grid grid-cols-1 md:grid-cols-5 gap-3",
+                    input {
+                        class: "px-3 py-2 border border-gray-300 rounded",
+                        placeholder: t!("admin-user-filter"),
+                        value: "{search}",
+                        oninput: move |e| { search.set(e.value()); page.set(1); },
+                    }
+                    select {
+                        class: "px-3 py-2 border border-gray-300 rounded",
+                        onchange: move |e| { role_id.set(e.value().parse().ok()); page.set(1); },
+                        option { value: "", {t!("users-any-role")} }
+                        for role in role_list.iter() {
+                            option { value: "{role.id}", selected: role_id() == Some(role.id), "{role.name}" }
+                        }
+                    }
+                    select {
+                        class: "px-3 py-2 border border-gray-300 rounded",
+                        onchange: move |e| { active.set(parse_flag(&e.value())); page.set(1); },
+                        option { value: "", {t!("users-any-active")} }
+                        option { value: "yes", {t!("users-active")} }
+                        option { value: "no", {t!("users-deactivated-filter")} }
+                    }
+                    select {
+                        class: "px-3 py-2 border border-gray-300 rounded",
+                        onchange: move |e| { verified.set(parse_flag(&e.value())); page.set(1); },
+                        option { value: "", {t!("users-any-verified")} }
+                        option { value: "yes", {t!("users-email-verified")} }
+                        option { value: "no", {t!("users-email-unverified")} }
+                    }
+                    select {
+                        class: "px-3 py-2 border border-gray-300 rounded",
+                        onchange: move |e| {
+                            sort.set(UserSort::ALL.into_iter().find(|s| s.as_str() == e.value()).unwrap_or_default());
+                            page.set(1);
+                        },
+                        for s in UserSort::ALL {
+                            option { value: s.as_str(), selected: sort() == s, "{s.label()}" }
                         }
                     }
                 }
 
                 // User Table
-                div { class: "px-4 py-6",
+                div { class: "px-4 pb-6",
                     div { class: "bg-white shadow overflow-hidden sm:rounded-lg",
-                        div { class: "px-4 py-5 sm:px-6 border-b border-gray-200",
-                            h2 { class: "text-lg font-medium leading-6 text-gray-900",
-                                "User List"
+                        div { class: "px-4 py-5 sm:px-6 border-b border-gray-200 flex justify-between items-center",
+                            h2 { class: "text-lg font-medium leading-6 text-gray-900", {t!("users-list", count = total)} }
+
+                            // Bulk Actions
+                            div { class: "flex items-center gap-2 text-sm",
+                                span { class: "text-gray-500", {t!("users-selected", count = chosen_count)} }
+                                select {
+                                    class: "pl-3 pr-10 py-2 border-gray-300 rounded-md",
+                                    onchange: move |e| {
+                                        bulk_action.set(BulkUserAction::ALL.into_iter().find(|a| a.as_str() == e.value()));
+                                        confirm_bulk_delete.set(false);
+                                    },
+                                    option { value: "", {t!("users-bulk-actions")} }
+                                    for a in BulkUserAction::ALL {
+                                        option { value: a.as_str(), selected: bulk_action() == Some(a), "{a.label()}" }
+                                    }
+                                }
+                                button {
+                                    class: "rounded-md px-4 py-2 bg-indigo-600 font-medium text-white hover:bg-indigo-700 disabled:opacity-50",
+                                    disabled: chosen_count == 0 || bulk_action().is_none(),
+                                    onclick: apply_bulk,
+                                    "{apply_label}"
+                                }
                             }
                         }
                         div { class: "overflow-x-auto",
                             table { class: "min-w-full divide-y divide-gray-200",
                                 thead { class: "bg-gray-50",
                                     tr {
-                                        th { class: "px-6 py-3 text-left text-xs font-medium text-gray-500 uppercase tracking-wider",
-                                            "Name"
-                                        }
-                                        th { class: "px-6 py-3 text-left text-xs font-medium text-gray-500 uppercase tracking-wider",
-                                            "Email"
-                                        }
-                                        th { class: "px-6 py-3 text-left text-xs font-medium text-gray-500 uppercase tracking-wider",
-                                            "Status"
-                                        }
-                                        th { class: "px-6 py-3 text-left text-xs font-medium text-gray-500 uppercase tracking-wider",
-                                            "Actions"
-                                        }
-                                    }
-                                }
-                                tbody { class: "bg-white divide-y divide-gray-200",
-                                    // User 1
-                                    tr {
-                                        td { class: "px-6 py-4 whitespace-nowrap",
-                                            div { class: "flex items-center",
-                                                div { class: "flex-shrink-0 h-10 w-10",
-                                                    img {
-                                                        class: "h-10 w-10 rounded-full",
-                                                        src: "https://images.unsplash.com/photo-1494790108377-be9c29b29330?ixlib=rb-1.2.1&ixid=eyJhcHBfaWQiOjEyMDd9&auto=format&fit=facearea&facepad=4&w=256&h=256&q=60",
-                                                        alt: "",
-                                                    }
-                                                }
-                                                div { class: "ml-4",
-                                                    div { class: "text-sm font-medium text-gray-900",
-                                                        "John Doe"
-                                                    }
-                                                    div { class: "text-sm text-gray-500",
-                                                        "Admin"
-                                                    }
-                                                }
-                                            }
-                                        }
-                                        td { class: "px-6 py-4 whitespace-nowrap text-sm text-gray-500",
-                                            "john@example.com"
-                                        }
-                                        td { class: "px-6 py-4 whitespace-nowrap",
-                                            span { class: "px-2 inline-flex text-xs leading-5 font-semibold rounded-full bg-green-100 text-green-800",
-                                                "Active"
-                                            }
-                                        }
-                                        td { class: "px-6 py-4 whitespace-nowrap text-sm font-medium",
-                                            div { class: "flex space-x-2",
-                                                button { class: "text-indigo-600 hover:text-indigo-900",
-                                                    "Edit"
-                                                }
-                                                button { class: "text-red-600 hover:text-red-900",
-                                                    "Delete"
-                                                }
-                                                button { class: "text-gray-600 hover:text-gray-900",
-                                                    "View"
-                                                }
-                                            }
-                                        }
-                                    }
-                                    // User 2
-                                    tr {
-                                        td { class: "px-6 py-4 whitespace-nowrap",
-                                            div { class: "flex items-center",
-                                                div { class: "flex-shrink-0 h-10 w-10",
-                                                    img {
-                                                        class: "h-10 w-10 rounded-full",
-                                                        src: "https://images.unsplash.com/photo-1573496359142-b8d87734a5a2?ixlib=rb-1.2.1&ixid=eyJhcHBfaWQiOjEyMDd9&auto=format&fit=facearea&facepad=4&w=256&h=256&q=60",
-                                                        alt: "",
-                                                    }
-                                                }
-                                                div { class: "ml-4",
-                                                    div { class: "text-sm font-medium text-gray-900",
-                                                        "Jane Smith"
-                                                    }
-                                                    div { class: "text-sm text-gray-500",
-                                                        "Editor"
+                                        th { class: "px-4 py-3",
+                                            input {
+                                                r#type: "checkbox",
+                                                checked: all_chosen,
+                                                onchange: move |_| {
+                                                    let mut now = selected();
+                                                    if all_chosen {
+                                                        page_ids.iter().for_each(|id| { now.remove(id); });
+                                                    } else {
+                                                        now.extend(page_ids.iter().copied());
                                                     }
-                                                }
-                                            }
-                                        }
-                                        td { class: "px-6 py-4 whitespace-nowrap text-sm text-gray-500",
-                                            "jane@example.com"
-                                        }
-                                        td { class: "px-6 py-4 whitespace-nowrap",
-                                            span { class: "px-2 inline-flex text-xs leading-5 font-semibold rounded-full bg-green-100 text-green-800",
-                                                "Active"
-                                            }
-                                        }
-                                        td { class: "px-6 py-4 whitespace-nowrap text-sm font-medium",
-                                            div { class: "flex space-x-2",
-                                                button { class: "text-indigo-600 hover:text-indigo-900",
-                                                    "Edit"
-                                                }
-                                                button { class: "text-red-600 hover:text-red-900",
-                                                    "Delete"
-                                                }
-                                                button { class: "text-gray-600 hover:text-gray-900",
-                                                    "View"
-                                                }
+                                                    selected.set(now);
+                                                },
                                             }
                                         }
+                                        th { class: "px-6 py-3 text-left text-xs font-medium text-gray-500 uppercase tracking-wider", {t!("common-name")} }
+                                        th { class: "px-6 py-3 text-left text-xs font-medium text-gray-500 uppercase tracking-wider", {t!("auth-email")} }
+                                        th { class: "px-6 py-3 text-left text-xs font-medium text-gray-500 uppercase tracking-wider", {t!("users-col-status")} }
+                                        th { class: "px-6 py-3 text-left text-xs font-medium text-gray-500 uppercase tracking-wider", {t!("users-col-last-login")} }
+                                        th { class: "px-6 py-3 text-left text-xs font-medium text-gray-500 uppercase tracking-wider", {t!("users-col-actions")} }
                                     }
-                                    // User 3
-                                    tr {
-                                        td { class: "px-6 py-4 whitespace-nowrap",
-                                            div { class: "flex items-center",
-                                                div { class: "flex-shrink-0 h-10 w-10",
-                                                    img {
-                                                        class: "h-10 w-10 rounded-full",
-                                                        src: "https://images.unsplash.com/photo-1550525811-e5869dd03032?ixlib=rb-1.2.1&ixid=eyJhcHBfaWQiOjEyMDd9&auto=format&fit=facearea&facepad=4&w=256&h=256&q=60",
-                                                        alt: "",
-                                                    }
-                                                }
-                                                div { class: "ml-4",
-                                                    div { class: "text-sm font-medium text-gray-900",
-                                                        "Alice Johnson"
-                                                    }
-                                                    div { class: "text-sm text-gray-500",
-                                                        "Subscriber"
+                                }
+                                tbody { class: "bg-white divide-y divide-gray-200",
+                                    for user in user_list {
+                                        UserRow {
+                                            key: "{user.id}",
+                                            selected: chosen.contains(&user.id),
+                                            user: user.clone(),
+                                            on_toggle: move |id: Uuid| {
+                                                selected.with_mut(|s| {
+                                                    if !s.remove(&id) {
+                                                        s.insert(id);
                                                     }
-                                                }
-                                            }
-                                        }
-                                        td { class: "px-6 py-4 whitespace-nowrap text-sm text-gray-500",
-                                            "alice@example.com"
-                                        }
-                                        td { class: "px-6 py-4 whitespace-nowrap",
-                                            span { class: "px-2 inline-flex text-xs leading-5 font-semibold rounded-full bg-gray-100 text-gray-800",
-                                                "Inactive"
-                                            }
-                                        }
-                                        td { class: "px-6 py-4 whitespace-nowrap text-sm font-medium",
-                                            div { class: "flex space-x-2",
-                                                button { class: "text-indigo-600 hover:text-indigo-900",
-                                                    "Edit"
-                                                }
-                                                button { class: "text-red-600 hover:text-red-900",
-                                                    "Delete"
-                                                }
-                                                button { class: "text-gray-600 hover:text-gray-900",
-                                                    "View"
-                                                }
-                                            }
+                                                });
+                                            },
+                                            on_action: on_row_action,
                                         }
                                     }
                                 }
                             }
                         }
+                        div { class: "px-6 py-3 flex justify-between items-center text-sm text-gray-600 border-t border-gray-200",
+                            button {
+                                class: "px-3 py-1 bg-gray-200 rounded disabled:opacity-50",
+                                disabled: page() <= 1,
+                                onclick: move |_| page -= 1,
+                                {t!("common-previous")}
+                            }
+                            span { {t!("common-page", page = page(), pages = last_page)} }
+                            button {
+                                class: "px-3 py-1 bg-gray-200 rounded disabled:opacity-50",
+                                disabled: page() >= last_page,
+                                onclick: move |_| page += 1,
+                                {t!("common-next")}
+                            }
+                        }
                     }
                 }
+            }
+        }
+    }
+}
 
-                // Bulk Actions
-                div { class: "px-4 py-6 bg-white shadow sm:rounded-lg",
-                    div { class: "flex flex-col sm:flex-row justify-between items-center space-y-4 sm:space-y-0 sm:space-x-4",
-                        div { class: "w-full sm:w-auto",
-                            select { class: "block w-full pl-3 pr-10 py-2 text-base border-gray-300 focus:outline-none focus:ring-indigo-500 focus:border-indigo-500 sm:text-sm rounded-md",
-                                option { "Bulk Actions" }
-                                option { "Activate Users" }
-                                option { "Deactivate Users" }
-                                option { "Delete Users" }
-                            }
+#[component]
+fn UserRow(
+    user: AdminUserSummary,
+    selected: bool,
+    on_toggle: EventHandler<Uuid>,
+    on_action: EventHandler<(AdminUserSummary, UserRowAction)>,
+) -> Element {
+    let mut confirming_delete = use_signal(|| false);
+
+    let id = user.id;
+    let is_active = user.is_active;
+    let last_login = user
+        .last_login_at
+        .map(|t| t.with_timezone(&chrono::Local).format("%Y-%m-%d %H:%M").to_string())
+        .unwrap_or_else(|| t!("users-never"));
+    let (status_class, status) = if is_active {
+        ("bg-green-100 text-green-800", t!("users-active"))
+    } else {
+        ("bg-gray-100 text-gray-800", t!("users-inactive"))
+    };
+    let verified = if user.email_verified { String::new() } else { format!(" · {}", t!("users-unverified")) };
+    let username = user.username.clone();
+    let email = user.email.clone();
+    let role_name = user.role_name.clone();
+    let invited_by = user.invited_by_username.as_ref().map(|name| format!(" · {}", t!("users-invited-by", name = name.clone()))).unwrap_or_default();
+
+    let act = use_callback(move |action: UserRowAction| on_action.call((user.clone(), action)));
+
+    rsx! {
+        tr {
+            td { class: "px-4 py-4",
+                input { r#type: "checkbox", checked: selected, onchange: move |_| on_toggle.call(id) }
+            }
+            td { class: "px-6 py-4 whitespace-nowrap",
+                div { class: "text-sm font-medium text-gray-900", "{username}" }
+                div { class: "text-sm text-gray-500", "{role_name}{invited_by}" }
+            }
+            td { class: "px-6 py-4 whitespace-nowrap text-sm text-gray-500", "{email}{verified}" }
+            td { class: "px-6 py-4 whitespace-nowrap",
+                span { class: "px-2 inline-flex text-xs leading-5 font-semibold rounded-full {status_class}", "{status}" }
+            }
+            td { class: "px-6 py-4 whitespace-nowrap text-sm text-gray-500", "{last_login}" }
+            td { class: "px-6 py-4 whitespace-nowrap text-sm font-medium",
+                div { class: "flex flex-wrap gap-3",
+                    button { class: "text-indigo-600 hover:text-indigo-900", onclick: move |_| act.call(UserRowAction::Edit), {t!("common-edit")} }
+                    button {
+                        class: "text-yellow-700 hover:text-yellow-900",
+                        onclick: move |_| act.call(UserRowAction::SetActive(!is_active)),
+                        if is_active { {t!("users-deactivate")} } else { {t!("users-reactivate")} }
+                    }
+                    button { class: "text-gray-600 hover:text-gray-900", onclick: move |_| act.call(UserRowAction::ResetPassword), {t!("users-reset-password")} }
+                    button { class: "text-gray-600 hover:text-gray-900", onclick: move |_| act.call(UserRowAction::RevokeSessions), {t!("users-sign-out")} }
+                    if is_active {
+                        button { class: "text-purple-600 hover:text-purple-900", onclick: move |_| act.call(UserRowAction::Impersonate), {t!("users-impersonate")} }
+                    }
+                    if confirming_delete() {
+                        button { class: "text-red-700 font-bold", onclick: move |_| act.call(UserRowAction::Delete), {t!("users-confirm-delete")} }
+                        button { class: "text-gray-500", onclick: move |_| confirming_delete.set(false), {t!("users-keep")} }
+                    } else {
+                        button { class: "text-red-600 hover:text-red-900", onclick: move |_| confirming_delete.set(true), {t!("common-delete")} }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Inline form for an account's name, email, role and verification
+#[component]
+fn UserEditor(user: AdminUserSummary, roles: Vec<RoleOption>, on_close: EventHandler<bool>) -> Element {
+    let mut username = use_signal(|| user.username.clone());
+    let mut email = use_signal(|| user.email.clone());
+    let mut role_id = use_signal(|| user.role_id);
+    let mut verified = use_signal(|| user.email_verified);
+    let mut error = use_signal(|| None::<String>);
+    let id = user.id;
+
+    let save = move |evt: FormEvent| {
+        evt.prevent_default();
+        let update = UserUpdate {
+            email: Some(email()),
+            username: Some(username()),
+            role_id: Some(role_id()),
+            is_active: None,
+            email_verified: Some(verified()),
+        };
+
+        spawn(async move {
+            match update_user(id, update).await {
+                Ok(_) => on_close.call(true),
+                Err(err) => error.set(Some(err.to_string())),
+            }
+        });
+    };
+
+    rsx! {
+        form { class: "mx-4 mb-6 bg-white shadow rounded-lg p-4", onsubmit: save,
+            h2 { class: "text-lg font-medium text-gray-900 mb-3", {t!("users-edit", name = user.username.clone())} }
+            if let Some(err) = error() {
+                p { class: "mb-3 text-sm text-red-600", "{err}" }
+            }
+            div { class: "grid grid-cols-1 md:grid-cols-4 gap-3 items-center",
+                input {
+                    class: "px-3 py-2 border border-gray-300 rounded",
+                    value: "{username}",
+                    oninput: move |e| username.set(e.value()),
+                }
+                input {
+                    r#type: "email",
+                    class: "px-3 py-2 border border-gray-300 rounded",
+                    value: "{email}",
+                    oninput: move |e| email.set(e.value()),
+                }
+                select {
+                    class: "px-3 py-2 border border-gray-300 rounded",
+                    onchange: move |e| {
+                        if let Ok(id) = e.value().parse() {
+                            role_id.set(id);
                         }
-                        div { class: "w-full sm:w-auto",
-                            button { class: "w-full inline-flex justify-center rounded-md border border-transparent shadow-sm px-4 py-2 bg-indigo-600 text-base font-medium text-white hover:bg-indigo-700 focus:outline-none focus:ring-2 focus:ring-offset-2 focus:ring-indigo-500 sm:w-auto sm:text-sm",
-                                "Apply"
+                    },
+                    // The current role may be missing from `roles` if it's above the admin's own
+                    if !roles.iter().any(|r| r.id == user.role_id) {
+                        option { value: "{user.role_id}", selected: true, "{user.role_name}" }
+                    }
+                    for role in roles.iter() {
+                        option { value: "{role.id}", selected: role_id() == role.id, "{role.name}" }
+                    }
+                }
+                label { class: "flex items-center gap-2 text-sm text-gray-700",
+                    input {
+                        r#type: "checkbox",
+                        checked: verified(),
+                        onchange: move |e| verified.set(e.checked()),
+                    }
+                    {t!("users-email-verified")}
+                }
+            }
+            div { class: "mt-4 flex gap-2",
+                button { r#type: "submit", class: "px-4 py-2 bg-indigo-600 text-white rounded hover:bg-indigo-700", {t!("common-save")} }
+                button {
+                    r#type: "button",
+                    class: "px-4 py-2 bg-gray-200 rounded hover:bg-gray-300",
+                    onclick: move |_| on_close.call(false),
+                    {t!("common-cancel")}
+                }
+            }
+        }
+    }
+}
+
+/// Accounts locked after repeated failed logins, with an unlock action
+#[component]
+fn LockedAccounts() -> Element {
+    let mut locked = use_resource(|| async move { list_locked_users().await });
+    let mut error = use_signal(|| None::<String>);
+
+    let users: Vec<_> = match &*locked.read() {
+        Some(Ok(users)) => users
+            .iter()
+            .map(|u| {
+                let until = u.locked_until.with_timezone(&chrono::Local).format("%Y-%m-%d %H:%M").to_string();
+                (u.clone(), until)
+            })
+            .collect(),
+        Some(Err(err)) => {
+            tracing::error!("Failed to load locked users: {}", err);
+            Vec::new()
+        }
+        None => Vec::new(),
+    };
+
+    if users.is_empty() {
+        return rsx! {};
+    }
+
+    rsx! {
+        div { class: "px-4 py-6",
+            div { class: "bg-white shadow overflow-hidden sm:rounded-lg",
+                div { class: "px-4 py-5 sm:px-6 border-b border-gray-200",
+                    h2 { class: "text-lg font-medium leading-6 text-gray-900", {t!("users-locked")} }
+                    if let Some(err) = error() {
+                        p { class: "mt-1 text-sm text-red-600", "{err}" }
+                    }
+                }
+                ul { class: "divide-y divide-gray-200",
+                    for (user, until) in users {
+                        li {
+                            key: "{user.user_id}",
+                            class: "px-6 py-4 flex items-center justify-between",
+                            div {
+                                div { class: "text-sm font-medium text-gray-900", "{user.username}" }
+                                div { class: "text-sm text-gray-500",
+                                    {t!("users-locked-until", email = user.email.clone(), time = until.clone())}
+                                }
                             }
-                        }
-                        div { class: "w-full sm:w-auto",
-                            button { class: "w-full inline-flex justify-center rounded-md border border-gray-300 shadow-sm px-4 py-2 bg-white text-base font-medium text-gray-700 hover:bg-gray-50 focus:outline-none focus:ring-2 focus:ring-offset-2 focus:ring-indigo-500 sm:w-auto sm:text-sm",
-                                "Export Users"
+                            button {
+                                class: "text-indigo-600 hover:text-indigo-900 text-sm font-medium",
+                                onclick: move |_| {
+                                    let user_id = user.user_id;
+                                    spawn(async move {
+                                        match unlock_user(user_id).await {
+                                            Ok(()) => {
+                                                error.set(None);
+                                                locked.restart();
+                                            }
+                                            Err(err) => error.set(Some(err.to_string())),
+                                        }
+                                    });
+                                },
+                                {t!("users-unlock")}
                             }
                         }
                     }
@@ -253,4 +600,4 @@ pub fn AdminUsers() -> Element {
             }
         }
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,166 @@
+use dioxus::prelude::*;
+use ui::t;
+use api::log::{get_database_health, run_database_health_check, DatabaseHealthSample, HealthLevel};
+
+const GRAPH_WIDTH: f64 = 600.0;
+const GRAPH_HEIGHT: f64 = 120.0;
+
+/// SVG polyline points for `values`, scaled so `max` touches the top
+fn polyline(values: &[f64], max: f64) -> String {
+    if values.is_empty() || max <= 0.0 {
+        return String::new();
+    }
+    let step = if values.len() > 1 { GRAPH_WIDTH / (values.len() - 1) as f64 } else { 0.0 };
+    values
+        .iter()
+        .enumerate()
+        .map(|(i, v)| format!("{:.1},{:.1}", i as f64 * step, GRAPH_HEIGHT - (v / max).min(1.0) * GRAPH_HEIGHT))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Connection usage, pool wait times and slow queries from the health monitor
+#[component]
+pub fn DatabaseHealthPanel() -> Element {
+    let mut hours = use_signal(|| 24i64);
+    let mut health = use_resource(move || async move { get_database_health(hours()).await });
+    let mut checking = use_signal(|| false);
+
+    let check_now = move |_| {
+        checking.set(true);
+        spawn(async move {
+            if let Err(err) = run_database_health_check().await {
+                tracing::error!("Database health check failed: {}", err);
+            }
+            checking.set(false);
+            health.restart();
+        });
+    };
+
+    let overview = match &*health.read() {
+        Some(Ok(overview)) => Some(overview.clone()),
+        Some(Err(err)) => {
+            tracing::error!("Failed to load database health: {}", err);
+            None
+        }
+        None => None,
+    };
+    let Some(overview) = overview else {
+        return rsx! {
+            div { class: "bg-white shadow rounded-lg p-6 mx-4", {t!("common-loading")} }
+        };
+    };
+
+    let (badge_class, badge_text) = match overview.latest.as_ref().map(|s| s.level) {
+        Some(HealthLevel::Critical) => ("bg-red-100 text-red-800", t!("db-health-critical")),
+        Some(HealthLevel::Warning) => ("bg-yellow-100 text-yellow-800", t!("db-health-warning")),
+        Some(HealthLevel::Normal) => ("bg-green-100 text-green-800", t!("db-health-normal")),
+        None => ("bg-gray-100 text-gray-700", t!("db-health-no-data")),
+    };
+    let (connections, pool, wait, message) = match &overview.latest {
+        Some(s) => (
+            format!("{} / {} ({:.0}%)", s.server_connections, s.max_connections, s.utilization_pct()),
+            t!("db-health-pool-usage", used = s.pool_size - s.pool_idle, idle = s.pool_idle, max = s.pool_max),
+            t!("db-health-latency-value", wait = format!("{:.1}", s.acquire_ms), ping = format!("{:.1}", s.ping_ms)),
+            s.message.clone().unwrap_or_default(),
+        ),
+        None => (String::new(), String::new(), String::new(), String::new()),
+    };
+
+    let utilization: Vec<f64> = overview.history.iter().map(DatabaseHealthSample::utilization_pct).collect();
+    let waits: Vec<f64> = overview.history.iter().map(|s| s.acquire_ms).collect();
+    let max_wait = waits.iter().cloned().fold(1.0, f64::max);
+    let utilization_points = polyline(&utilization, 100.0);
+    let wait_points = polyline(&waits, max_wait);
+    let max_wait_label = format!("{:.0} ms", max_wait);
+
+    // (key, calls and average, query) for each slow statement
+    let slow: Vec<(i64, String, String)> = overview
+        .slow_queries
+        .iter()
+        .map(|q| {
+            let stats = t!("db-health-slow-stats", count = q.calls, mean = format!("{:.0}", q.mean_ms()));
+            (q.queryid, stats, q.query.clone())
+        })
+        .collect();
+
+    rsx! {
+        div { class: "bg-white shadow rounded-lg p-6 mx-4",
+            div { class: "flex justify-between items-center mb-4",
+                div { class: "flex items-center gap-3",
+                    h2 { class: "text-lg font-medium text-gray-900", {t!("db-health-title")} }
+                    span { class: "px-2 py-0.5 rounded text-sm font-medium {badge_class}", "{badge_text}" }
+                }
+                div { class: "flex items-center gap-2 text-sm",
+                    select {
+                        class: "px-2 py-1 border border-gray-300 rounded",
+                        onchange: move |e| hours.set(e.value().parse().unwrap_or(24)),
+                        for (value, label) in [(6, t!("db-health-hours", count = 6)), (24, t!("db-health-hours", count = 24)), (168, t!("db-health-days", count = 7))] {
+                            option { value: "{value}", selected: hours() == value, "{label}" }
+                        }
+                    }
+                    button {
+                        class: "px-3 py-1 bg-gray-200 rounded hover:bg-gray-300 disabled:opacity-50",
+                        disabled: checking(),
+                        onclick: check_now,
+                        {t!("db-health-check-now")}
+                    }
+                }
+            }
+
+            if !message.is_empty() {
+                pre { class: "mb-4 p-3 bg-yellow-50 text-sm text-yellow-900 rounded whitespace-pre-wrap", "{message}" }
+            }
+
+            div { class: "grid grid-cols-1 md:grid-cols-3 gap-4 mb-4 text-sm",
+                div {
+                    p { class: "text-gray-500", {t!("db-health-connections")} }
+                    p { class: "font-medium text-gray-900", "{connections}" }
+                }
+                div {
+                    p { class: "text-gray-500", {t!("db-health-pool")} }
+                    p { class: "font-medium text-gray-900", "{pool}" }
+                }
+                div {
+                    p { class: "text-gray-500", {t!("db-health-latency")} }
+                    p { class: "font-medium text-gray-900", "{wait}" }
+                }
+            }
+
+            div { class: "mb-2 flex gap-4 text-xs text-gray-600",
+                span { class: "text-indigo-600", "● " {t!("db-health-usage-legend")} }
+                span { class: "text-orange-500", "● " {t!("db-health-wait-legend", max = max_wait_label.clone())} }
+            }
+            svg {
+                class: "w-full h-32 bg-gray-50 rounded",
+                view_box: "0 0 {GRAPH_WIDTH} {GRAPH_HEIGHT}",
+                preserve_aspect_ratio: "none",
+                polyline {
+                    fill: "none",
+                    stroke: "#4f46e5",
+                    stroke_width: "2",
+                    points: "{utilization_points}",
+                }
+                polyline {
+                    fill: "none",
+                    stroke: "#f97316",
+                    stroke_width: "2",
+                    points: "{wait_points}",
+                }
+            }
+
+            h3 { class: "mt-6 mb-2 font-medium text-gray-800", {t!("db-health-slow")} }
+            if slow.is_empty() {
+                p { class: "text-sm text-gray-500", {t!("db-health-slow-empty")} }
+            }
+            ul { class: "divide-y text-sm",
+                for (key, stats, query) in slow {
+                    li { key: "{key}", class: "py-2",
+                        p { class: "text-gray-600", "{stats}" }
+                        p { class: "font-mono text-xs text-gray-800 break-all", "{query}" }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -1,9 +1,20 @@
+pub mod admin_audit;
 pub mod admin_dashboard;
+pub mod admin_flags;
+pub mod admin_invitations;
+pub mod admin_maintenance;
 pub mod admin_reports;
+pub mod admin_security;
 pub mod admin_users;
+pub mod database_health;
 pub mod admin_settings;
 
+pub use admin_audit::AdminAudit;
 pub use admin_dashboard::AdminDashboard;
+pub use admin_flags::AdminFeatureFlags;
+pub use admin_invitations::AdminInvitations;
+pub use admin_maintenance::AdminMaintenance;
 pub use admin_reports::AdminReports;
+pub use admin_security::AdminSecurityLog;
 pub use admin_users::AdminUsers;
 pub use admin_settings::AdminSettings;
@@ -46,11 +46,16 @@ pub fn AIChat() -> Element {
                     s.is_loading = true;
                 });
 
-                // Simulate AI response (replace with actual API call)
-                tokio::time::sleep(std::time::Duration::from_secs(1)).await;
-                
+                let reply = match api::ask_ai(prompt).await {
+                    Ok(text) => Message::AI(text),
+                    Err(err) => {
+                        tracing::error!("AI prompt failed: {}", err);
+                        Message::Error(err.to_string())
+                    }
+                };
+
                 state.with_mut(|s| {
-                    s.messages.push(Message::AI(format!("Response to: {}", prompt)));
+                    s.messages.push(reply);
                     s.is_loading = false;
                 });
             });
@@ -0,0 +1,131 @@
+use std::collections::HashMap;
+use dioxus::prelude::*;
+
+// Update the path below to the correct module location for components
+// wrtiting/ui/components/mod.rs
+// wrtiting/desktop/src/views/testing.rs
+use ui::{t, DocumentNode, MarkdownPreview, MentionAutocomplete, NodeType, Sidebar};
+use super::find_replace::{document_order, FindReplacePanel};
+use super::revisions::Revision;
+
+
+#[component]
+pub fn Editor() -> Element {
+    let documents = use_signal(|| vec![
+        DocumentNode {
+            id: "1".to_string(),
+            title: "Chapter 1".to_string(),
+            children: vec![
+                DocumentNode {
+                    id: "1-1".to_string(),
+                    title: "Opening Scene".to_string(),
+                    children: vec![],
+                    is_expanded: false,
+                    node_type: NodeType::Scene,
+                },
+                DocumentNode {
+                    id: "1-2".to_string(),
+                    title: "Research Notes".to_string(),
+                    children: vec![],
+                    is_expanded: false,
+                    node_type: NodeType::Note,
+                },
+            ],
+            is_expanded: true,
+            node_type: NodeType::Chapter,
+        },
+    ]);
+
+    let mut selected_doc = use_signal( || String::new());
+    // Text of every document by id, so find & replace can reach the whole manuscript
+    let mut contents = use_signal(|| {
+        document_order(&documents.peek())
+            .into_iter()
+            .map(|(id, _)| {
+                let text = "Sample content for ".to_string() + &id;
+                (id, text)
+            })
+            .collect::<HashMap<String, String>>()
+    });
+    // Session-only, see `Revision`
+    let history = use_signal(Vec::<Revision>::new);
+    let mut show_find_replace = use_signal(|| false);
+    let mut show_preview = use_signal(|| false);
+
+    let editor_content = contents.read().get(&selected_doc()).cloned().unwrap_or_default();
+
+    rsx! {
+        div {
+            class: "flex h-screen bg-gray-50 text-gray-800",
+            onkeydown: move |e: KeyboardEvent| {
+                if e.modifiers().ctrl() && e.key() == Key::Character("h".to_string()) {
+                    e.prevent_default();
+                    show_find_replace.set(true);
+                }
+            },
+            // Left Sidebar (Documents)
+            Sidebar {
+                documents: documents.read().clone(),
+                on_select: move |id: String| selected_doc.set(id),
+            }
+            // Right Panel (Editor)
+            main { class: "flex-1 flex flex-col overflow-hidden",
+                // Editor Toolbar
+                header { class: "bg-white border-b border-gray-200 p-2 flex items-center",
+                    button { class: "p-2 hover:bg-gray-100 rounded", {t!("editor-save")} }
+                    button {
+                        class: "p-2 hover:bg-gray-100 rounded",
+                        title: "Ctrl+H",
+                        onclick: move |_| show_find_replace.toggle(),
+                        {t!("find-replace-title")}
+                    }
+                    button {
+                        class: { if show_preview() { "p-2 bg-gray-100 rounded" } else { "p-2 hover:bg-gray-100 rounded" } },
+                        onclick: move |_| show_preview.toggle(),
+                        {t!("markdown-preview")}
+                    }
+                    div { class: "ml-auto text-sm text-gray-500",
+                        {t!("editor-words", count = editor_content.split_whitespace().count())}
+                    }
+                }
+                // Main Editor Area, split with the Markdown preview when it's open
+                div { class: "flex-1 flex overflow-hidden",
+                    div { class: "relative flex-1 overflow-auto bg-white p-6",
+                        textarea {
+                            class: "w-full h-full p-2 outline-none resize-none",
+                            value: editor_content.as_str(),
+                            oninput: move |e| {
+                                contents.write().insert(selected_doc(), e.value());
+                            },
+                        }
+                        // Username suggestions while typing an @mention
+                        MentionAutocomplete {
+                            text: editor_content.clone(),
+                            on_complete: move |text: String| {
+                                contents.write().insert(selected_doc(), text);
+                            },
+                        }
+                    }
+                    if show_preview() {
+                        div { class: "flex-1 overflow-auto bg-white p-6 border-l border-gray-200",
+                            MarkdownPreview { source: editor_content.clone() }
+                        }
+                    }
+                }
+                // Status Bar
+                footer { class: "bg-gray-100 border-t border-gray-200 p-2 text-sm text-gray-500",
+                    {t!("editor-selected", document = selected_doc())}
+                }
+            }
+            if show_find_replace() {
+                FindReplacePanel {
+                    documents: documents.read().clone(),
+                    current_document: selected_doc(),
+                    contents,
+                    history,
+                    on_close: move |_| show_find_replace.set(false),
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,508 @@
+use std::collections::{HashMap, HashSet};
+use dioxus::prelude::*;
+use regex::{Regex, RegexBuilder};
+use ui::{t, DocumentNode};
+use super::revisions::{undo_last, DocumentChange, Revision, RevisionHistory, RevisionKind};
+
+/// Characters of the surrounding line shown on each side of a match
+const CONTEXT_CHARS: usize = 40;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Scope {
+    /// Only the open document
+    #[default]
+    Document,
+    /// Every document in the tree
+    Manuscript,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct FindOptions {
+    pub query: String,
+    pub match_case: bool,
+    pub whole_word: bool,
+    /// Treat `query` as a regular expression; the replacement can then use `$1`
+    pub regex: bool,
+}
+
+impl FindOptions {
+    /// The query compiled with the options applied, or the error in a regex query
+    pub fn pattern(&self) -> Result<Regex, regex::Error> {
+        let source = if self.regex { self.query.clone() } else { regex::escape(&self.query) };
+        // Half boundaries only look outward, so a query that starts or ends
+        // with punctuation ("e.g.") still matches as a whole word
+        let source = if self.whole_word { format!(r"\b{{start-half}}(?:{})\b{{end-half}}", source) } else { source };
+        RegexBuilder::new(&source)
+            .case_insensitive(!self.match_case)
+            .multi_line(true)
+            .build()
+    }
+}
+
+/// One match, with the rest of its line around it for the preview
+#[derive(Debug, Clone, PartialEq)]
+pub struct FindMatch {
+    pub document_id: String,
+    /// Byte offsets into the document
+    pub start: usize,
+    pub end: usize,
+    /// 1-based
+    pub line: usize,
+    pub before: String,
+    pub matched: String,
+    pub after: String,
+    pub replacement: String,
+}
+
+impl FindMatch {
+    /// Identifies the match while the text and options stay the same
+    pub fn key(&self) -> (String, usize) {
+        (self.document_id.clone(), self.start)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DocumentMatches {
+    pub document_id: String,
+    pub title: String,
+    pub matches: Vec<FindMatch>,
+}
+
+/// `(id, title)` of every document, in the order the tree shows them
+pub fn document_order(nodes: &[DocumentNode]) -> Vec<(String, String)> {
+    let mut order = Vec::new();
+    for node in nodes {
+        order.push((node.id.clone(), node.title.clone()));
+        order.extend(document_order(&node.children));
+    }
+    order
+}
+
+fn context_before(text: &str, start: usize) -> String {
+    let line = &text[text[..start].rfind('\n').map_or(0, |i| i + 1)..start];
+    let count = line.chars().count();
+    if count <= CONTEXT_CHARS {
+        line.to_string()
+    } else {
+        format!("…{}", line.chars().skip(count - CONTEXT_CHARS).collect::<String>())
+    }
+}
+
+fn context_after(text: &str, end: usize) -> String {
+    let line = &text[end..text[end..].find('\n').map_or(text.len(), |i| end + i)];
+    if line.chars().count() <= CONTEXT_CHARS {
+        line.to_string()
+    } else {
+        format!("{}…", line.chars().take(CONTEXT_CHARS).collect::<String>())
+    }
+}
+
+/// Every match of `pattern` in `text`, with what each would be replaced by.
+/// Empty matches (e.g. from `a*`) are left out since there's nothing to replace.
+pub fn find_matches(pattern: &Regex, options: &FindOptions, replacement: &str, document_id: &str, text: &str) -> Vec<FindMatch> {
+    pattern
+        .captures_iter(text)
+        .filter_map(|captures| {
+            let found = captures.get(0)?;
+            if found.is_empty() {
+                return None;
+            }
+            let replacement = if options.regex {
+                let mut expanded = String::new();
+                captures.expand(replacement, &mut expanded);
+                expanded
+            } else {
+                replacement.to_string()
+            };
+            Some(FindMatch {
+                document_id: document_id.to_string(),
+                start: found.start(),
+                end: found.end(),
+                line: text[..found.start()].matches('\n').count() + 1,
+                before: context_before(text, found.start()),
+                matched: found.as_str().to_string(),
+                after: context_after(text, found.end()),
+                replacement,
+            })
+        })
+        .collect()
+}
+
+/// `text` with each match swapped for its replacement. `matches` must come
+/// from `find_matches` on this same text, in order.
+pub fn replace_matches(text: &str, matches: &[&FindMatch]) -> String {
+    let mut replaced = String::with_capacity(text.len());
+    let mut copied = 0;
+    for found in matches {
+        replaced.push_str(&text[copied..found.start]);
+        replaced.push_str(&found.replacement);
+        copied = found.end;
+    }
+    replaced.push_str(&text[copied..]);
+    replaced
+}
+
+/// Find and replace over the open document or the whole manuscript. Every
+/// match is previewed and can be skipped; applying writes them all as one
+/// revision, which "Undo replace" reverts in one go.
+#[component]
+pub fn FindReplacePanel(
+    documents: Vec<DocumentNode>,
+    current_document: String,
+    contents: Signal<HashMap<String, String>>,
+    history: Signal<Vec<Revision>>,
+    on_close: EventHandler<()>,
+) -> Element {
+    let mut options = use_signal(FindOptions::default);
+    let mut replacement = use_signal(String::new);
+    let mut scope = use_signal(Scope::default);
+    let mut skipped = use_signal(HashSet::<(String, usize)>::new);
+    let mut message = use_signal(|| None::<String>);
+
+    let targets: Vec<(String, String)> = match scope() {
+        Scope::Document => document_order(&documents)
+            .into_iter()
+            .filter(|(id, _)| *id == current_document)
+            .collect(),
+        Scope::Manuscript => document_order(&documents),
+    };
+
+    let current = options();
+    let results: Result<Vec<DocumentMatches>, String> = if current.query.is_empty() {
+        Ok(Vec::new())
+    } else {
+        match current.pattern() {
+            Ok(pattern) => {
+                let contents = contents.read();
+                Ok(targets
+                    .iter()
+                    .filter_map(|(id, title)| {
+                        let text = contents.get(id)?;
+                        let matches = find_matches(&pattern, &current, &replacement(), id, text);
+                        (!matches.is_empty()).then(|| DocumentMatches {
+                            document_id: id.clone(),
+                            title: title.clone(),
+                            matches,
+                        })
+                    })
+                    .collect())
+            }
+            Err(err) => Err(t!("find-replace-invalid-regex", error = err.to_string())),
+        }
+    };
+
+    let (found, pattern_error) = match results {
+        Ok(found) => (found, None),
+        Err(err) => (Vec::new(), Some(err)),
+    };
+    let total: usize = found.iter().map(|doc| doc.matches.len()).sum();
+    let included = total - found
+        .iter()
+        .flat_map(|doc| doc.matches.iter())
+        .filter(|m| skipped.read().contains(&m.key()))
+        .count();
+    let can_undo = history.read().iter().any(|r| r.kind == RevisionKind::Replace && !r.undone);
+
+    // Changing what is searched for invalidates the skipped matches
+    let mut reset = move || {
+        skipped.write().clear();
+        message.set(None);
+    };
+
+    let apply_found = found.clone();
+    let apply = move |_: MouseEvent| {
+        let skipped_now = skipped.read().clone();
+        let mut changes = Vec::new();
+        let mut replaced = 0;
+        for doc in &apply_found {
+            let kept: Vec<&FindMatch> = doc.matches.iter().filter(|m| !skipped_now.contains(&m.key())).collect();
+            if kept.is_empty() {
+                continue;
+            }
+            let before = contents.read().get(&doc.document_id).cloned().unwrap_or_default();
+            let after = replace_matches(&before, &kept);
+            replaced += kept.len();
+            changes.push(DocumentChange { document_id: doc.document_id.clone(), title: doc.title.clone(), before, after });
+        }
+        if changes.is_empty() {
+            return;
+        }
+
+        let summary = t!(
+            "find-replace-summary",
+            query = options.read().query.clone(),
+            replacement = replacement(),
+            count = replaced,
+            documents = changes.len()
+        );
+        let revision = Revision::new(RevisionKind::Replace, summary.clone(), changes);
+        revision.apply(&mut contents.write());
+        history.write().push(revision);
+        skipped.write().clear();
+        message.set(Some(summary));
+    };
+
+    let undo = move |_: MouseEvent| {
+        let result = undo_last(&mut history.write(), &mut contents.write());
+        match result {
+            Ok(()) => message.set(Some(t!("find-replace-undone"))),
+            Err(err) => message.set(Some(err)),
+        }
+    };
+
+    rsx! {
+        aside { class: "w-96 flex flex-col border-l border-gray-200 bg-white overflow-hidden",
+            div { class: "flex items-center justify-between p-3 border-b border-gray-200",
+                h2 { class: "font-semibold", {t!("find-replace-title")} }
+                button {
+                    class: "p-1 text-gray-400 hover:text-gray-600 rounded",
+                    aria_label: t!("find-replace-close"),
+                    onclick: move |_| on_close.call(()),
+                    "✕"
+                }
+            }
+
+            div { class: "p-3 space-y-2 border-b border-gray-200 text-sm",
+                input {
+                    class: "w-full px-2 py-1 border border-gray-300 rounded",
+                    placeholder: t!("find-replace-find"),
+                    autofocus: true,
+                    value: "{options.read().query}",
+                    oninput: move |e| {
+                        options.write().query = e.value();
+                        reset();
+                    },
+                }
+                input {
+                    class: "w-full px-2 py-1 border border-gray-300 rounded",
+                    placeholder: t!("find-replace-replace"),
+                    value: "{replacement}",
+                    oninput: move |e| {
+                        replacement.set(e.value());
+                        message.set(None);
+                    },
+                }
+                div { class: "flex flex-wrap gap-3",
+                    label { class: "flex items-center gap-1",
+                        input {
+                            r#type: "checkbox",
+                            checked: current.match_case,
+                            onchange: move |e| {
+                                options.write().match_case = e.checked();
+                                reset();
+                            },
+                        }
+                        {t!("find-replace-match-case")}
+                    }
+                    label { class: "flex items-center gap-1",
+                        input {
+                            r#type: "checkbox",
+                            checked: current.whole_word,
+                            onchange: move |e| {
+                                options.write().whole_word = e.checked();
+                                reset();
+                            },
+                        }
+                        {t!("find-replace-whole-word")}
+                    }
+                    label { class: "flex items-center gap-1",
+                        input {
+                            r#type: "checkbox",
+                            checked: current.regex,
+                            onchange: move |e| {
+                                options.write().regex = e.checked();
+                                reset();
+                            },
+                        }
+                        {t!("find-replace-regex")}
+                    }
+                }
+                select {
+                    class: "w-full px-2 py-1 border border-gray-300 rounded",
+                    onchange: move |e| {
+                        scope.set(if e.value() == "manuscript" { Scope::Manuscript } else { Scope::Document });
+                        reset();
+                    },
+                    option { value: "document", selected: scope() == Scope::Document, {t!("find-replace-scope-document")} }
+                    option { value: "manuscript", selected: scope() == Scope::Manuscript, {t!("find-replace-scope-manuscript")} }
+                }
+            }
+
+            div { class: "flex-1 overflow-y-auto p-3 space-y-3 text-sm",
+                if let Some(err) = pattern_error {
+                    p { class: "text-xs text-red-600 font-mono whitespace-pre-wrap", "{err}" }
+                } else if !current.query.is_empty() && found.is_empty() {
+                    p { class: "text-gray-500", {t!("find-replace-no-matches")} }
+                } else if !found.is_empty() {
+                    p { class: "text-gray-600", {t!("find-replace-count", count = total, documents = found.len())} }
+                }
+
+                for doc in found {
+                    div { key: "{doc.document_id}",
+                        h3 { class: "font-medium text-gray-800 mb-1", "{doc.title}" }
+                        ul { class: "space-y-1",
+                            for found in doc.matches {
+                                MatchRow { key: "{found.start}", found, skipped }
+                            }
+                        }
+                    }
+                }
+
+                RevisionHistory { history }
+            }
+
+            div { class: "p-3 border-t border-gray-200 space-y-2",
+                if let Some(text) = message() {
+                    p { class: "text-xs text-gray-600", "{text}" }
+                }
+                div { class: "flex gap-2",
+                    button {
+                        class: "flex-1 px-3 py-2 text-sm text-white bg-blue-600 hover:bg-blue-700 rounded disabled:opacity-50",
+                        disabled: included == 0,
+                        onclick: apply,
+                        {t!("find-replace-apply", count = included)}
+                    }
+                    button {
+                        class: "px-3 py-2 text-sm bg-gray-200 hover:bg-gray-300 rounded disabled:opacity-50",
+                        disabled: !can_undo,
+                        onclick: undo,
+                        {t!("find-replace-undo")}
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// A match in context, with the text that would replace it and a box to skip it
+#[component]
+fn MatchRow(found: FindMatch, skipped: Signal<HashSet<(String, usize)>>) -> Element {
+    let key = found.key();
+    let is_skipped = skipped.read().contains(&key);
+
+    rsx! {
+        li { class: "flex items-start gap-2",
+            input {
+                r#type: "checkbox",
+                class: "mt-1",
+                title: t!("find-replace-include"),
+                checked: !is_skipped,
+                onchange: move |_| {
+                    let mut skipped = skipped.write();
+                    if !skipped.remove(&key) {
+                        skipped.insert(key.clone());
+                    }
+                },
+            }
+            div { class: { if is_skipped { "flex-1 opacity-50" } else { "flex-1" } },
+                span { class: "text-xs text-gray-400 mr-1", {t!("find-replace-line", line = found.line)} }
+                span { class: "whitespace-pre-wrap break-words",
+                    "{found.before}"
+                    del { class: "bg-red-100 text-red-700", "{found.matched}" }
+                    if !is_skipped {
+                        ins { class: "bg-green-100 text-green-700 no-underline", "{found.replacement}" }
+                    }
+                    "{found.after}"
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn options(query: &str) -> FindOptions {
+        FindOptions { query: query.to_string(), ..Default::default() }
+    }
+
+    fn find(options: &FindOptions, replacement: &str, text: &str) -> Vec<FindMatch> {
+        find_matches(&options.pattern().unwrap(), options, replacement, "doc", text)
+    }
+
+    #[test]
+    fn finds_every_match_with_its_line_and_context() {
+        let found = find(&options("cat"), "dog", "The Cat sat.\nA cat, a concat");
+
+        assert_eq!(found.len(), 3);
+        assert_eq!(found[0].matched, "Cat");
+        assert_eq!((found[0].line, found[0].before.as_str(), found[0].after.as_str()), (1, "The ", " sat."));
+        assert_eq!((found[1].line, found[1].before.as_str(), found[1].after.as_str()), (2, "A ", ", a concat"));
+        assert_eq!(found[2].start, "The Cat sat.\nA cat, a con".len());
+        assert!(found.iter().all(|m| m.replacement == "dog" && m.document_id == "doc"));
+    }
+
+    #[test]
+    fn match_case_and_whole_word() {
+        let text = "Cat cat concat cats";
+
+        let case = FindOptions { match_case: true, ..options("cat") };
+        assert_eq!(find(&case, "", text).len(), 3);
+
+        let word = FindOptions { whole_word: true, ..options("cat") };
+        let found = find(&word, "", text);
+        assert_eq!(found.iter().map(|m| m.start).collect::<Vec<_>>(), vec![0, 4]);
+    }
+
+    #[test]
+    fn whole_word_allows_punctuation_at_the_edges() {
+        let word = FindOptions { whole_word: true, ..options("e.g.") };
+        let found = find(&word, "", "e.g. this, e.g.x and xe.g.");
+
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].start, 0);
+    }
+
+    #[test]
+    fn plain_queries_are_not_regexes() {
+        assert!(find(&options("a.c"), "", "abc").is_empty());
+        assert_eq!(find(&options("a.c"), "", "a.c").len(), 1);
+    }
+
+    #[test]
+    fn empty_matches_are_left_out() {
+        let any = FindOptions { regex: true, ..options("a*") };
+        let found = find(&any, "", "bab");
+
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].matched, "a");
+    }
+
+    #[test]
+    fn replaces_every_match() {
+        let text = "one cat, two cats";
+        let found = find(&options("cat"), "dog", text);
+        let all: Vec<&FindMatch> = found.iter().collect();
+
+        assert_eq!(replace_matches(text, &all), "one dog, two dogs");
+    }
+
+    #[test]
+    fn regex_replacements_expand_groups() {
+        let text = "alice@example.com, bob@example.com";
+        let emails = FindOptions { regex: true, ..options(r"(\w+)@example\.com") };
+        let found = find(&emails, "<$1>", text);
+        let all: Vec<&FindMatch> = found.iter().collect();
+
+        assert_eq!(found[0].replacement, "<alice>");
+        assert_eq!(replace_matches(text, &all), "<alice>, <bob>");
+    }
+
+    #[test]
+    fn plain_replacements_are_literal() {
+        let found = find(&options("cat"), "$1", "cat");
+
+        assert_eq!(found[0].replacement, "$1");
+    }
+
+    #[test]
+    fn skipped_matches_are_kept() {
+        let text = "cat cat cat";
+        let found = find(&options("cat"), "dog", text);
+        let kept: Vec<&FindMatch> = found.iter().filter(|m| m.start != 4).collect();
+
+        assert_eq!(replace_matches(text, &kept), "dog cat dog");
+        assert_eq!(replace_matches(text, &[]), text);
+    }
+}
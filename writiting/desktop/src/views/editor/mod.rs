@@ -0,0 +1,5 @@
+mod editor;
+pub use editor::Editor;
+
+mod find_replace;
+mod revisions;
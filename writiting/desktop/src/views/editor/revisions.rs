@@ -0,0 +1,159 @@
+use std::collections::HashMap;
+use chrono::{DateTime, Local};
+use dioxus::prelude::*;
+use ui::t;
+
+/// One document's text before and after a revision
+#[derive(Debug, Clone, PartialEq)]
+pub struct DocumentChange {
+    pub document_id: String,
+    pub title: String,
+    pub before: String,
+    pub after: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RevisionKind {
+    Replace,
+    Undo,
+}
+
+/// A change to the manuscript that can be listed and, for replaces, undone.
+/// Revisions are kept for the session only, like the document text itself;
+/// nothing here is saved, so closing the editor clears the list.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Revision {
+    pub kind: RevisionKind,
+    pub summary: String,
+    pub at: DateTime<Local>,
+    pub changes: Vec<DocumentChange>,
+    /// Set on a replace once it has been undone
+    pub undone: bool,
+}
+
+impl Revision {
+    pub fn new(kind: RevisionKind, summary: String, changes: Vec<DocumentChange>) -> Self {
+        Self { kind, summary, at: Local::now(), changes, undone: false }
+    }
+
+    /// Writes every change's `after` text into `contents`
+    pub fn apply(&self, contents: &mut HashMap<String, String>) {
+        for change in &self.changes {
+            contents.insert(change.document_id.clone(), change.after.clone());
+        }
+    }
+}
+
+/// Reverts the newest replace that hasn't been undone and records the undo as
+/// a revision. Refuses if a document it touched has been edited since, rather
+/// than throwing that edit away.
+pub fn undo_last(history: &mut Vec<Revision>, contents: &mut HashMap<String, String>) -> Result<(), String> {
+    let Some(index) = history.iter().rposition(|r| r.kind == RevisionKind::Replace && !r.undone) else {
+        return Err(t!("revision-nothing-to-undo"));
+    };
+
+    let revision = &history[index];
+    if let Some(edited) = revision
+        .changes
+        .iter()
+        .find(|change| contents.get(&change.document_id) != Some(&change.after))
+    {
+        return Err(t!("revision-edited-since", title = edited.title.clone()));
+    }
+
+    let changes = revision
+        .changes
+        .iter()
+        .map(|change| DocumentChange {
+            document_id: change.document_id.clone(),
+            title: change.title.clone(),
+            before: change.after.clone(),
+            after: change.before.clone(),
+        })
+        .collect();
+    let undo = Revision::new(RevisionKind::Undo, t!("revision-undo-summary", summary = revision.summary.clone()), changes);
+    undo.apply(contents);
+
+    history[index].undone = true;
+    history.push(undo);
+    Ok(())
+}
+
+/// The manuscript's revisions, newest first
+#[component]
+pub fn RevisionHistory(history: Signal<Vec<Revision>>) -> Element {
+    let revisions: Vec<Revision> = history.read().iter().rev().cloned().collect();
+
+    rsx! {
+        div { class: "border-t border-gray-200 pt-3",
+            h3 { class: "text-sm font-semibold text-gray-700 mb-2", {t!("revision-title")} }
+            if revisions.is_empty() {
+                p { class: "text-xs text-gray-500", {t!("revision-empty")} }
+            }
+            ul { class: "space-y-1 text-xs",
+                for revision in revisions {
+                    li {
+                        class: { if revision.undone { "text-gray-400 line-through" } else { "text-gray-700" } },
+                        span { class: "text-gray-500 mr-2", {revision.at.format("%H:%M:%S").to_string()} }
+                        "{revision.summary}"
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn replace(before: &str, after: &str) -> Revision {
+        let change = DocumentChange {
+            document_id: "doc".to_string(),
+            title: "Chapter 1".to_string(),
+            before: before.to_string(),
+            after: after.to_string(),
+        };
+        Revision::new(RevisionKind::Replace, "cat → dog".to_string(), vec![change])
+    }
+
+    fn contents(text: &str) -> HashMap<String, String> {
+        HashMap::from([("doc".to_string(), text.to_string())])
+    }
+
+    #[test]
+    fn undoes_the_newest_replace() {
+        let mut history = vec![replace("a cat", "a dog"), replace("a dog", "a fox")];
+        let mut text = contents("a fox");
+
+        undo_last(&mut history, &mut text).unwrap();
+        assert_eq!(text["doc"], "a dog");
+        assert!(!history[0].undone && history[1].undone);
+        assert_eq!(history[2].kind, RevisionKind::Undo);
+
+        undo_last(&mut history, &mut text).unwrap();
+        assert_eq!(text["doc"], "a cat");
+        assert!(undo_last(&mut history, &mut text).is_err());
+    }
+
+    #[test]
+    fn refuses_if_the_document_was_edited_since() {
+        let mut history = vec![replace("a cat", "a dog")];
+        let mut text = contents("a dog, edited");
+
+        let err = undo_last(&mut history, &mut text).unwrap_err();
+        assert!(err.contains("Chapter 1"), "{}", err);
+        assert_eq!(text["doc"], "a dog, edited");
+        assert!(!history[0].undone);
+        assert_eq!(history.len(), 1);
+    }
+
+    #[test]
+    fn nothing_to_undo() {
+        let mut history = Vec::new();
+        let mut text = contents("a cat");
+
+        assert!(undo_last(&mut history, &mut text).is_err());
+        assert!(history.is_empty());
+    }
+}
@@ -5,11 +5,17 @@ use dioxus::prelude::*;
 use crate::Route;
 use super::Footer;
 use super::DesktopNavbar;
+use ui::{ImpersonationBanner, MaintenanceBanner};
 
 #[component]
 pub fn AppLayout() -> Element {
+    let route = use_route::<Route>();
+
     rsx! {
         div { class: "flex flex-col min-h-screen",
+            // Re-checked on every navigation, so it appears as soon as impersonation starts
+            ImpersonationBanner { key: "{route}" }
+            MaintenanceBanner {}
             DesktopNavbar {}
             main { class: " flex-1",
                 div { class: "mx-auto px-4 py-6", Outlet::<Route> {} }
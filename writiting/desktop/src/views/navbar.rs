@@ -1,11 +1,12 @@
 // writing/desktop/src/views/navbar.rs
 use dioxus::prelude::*;
 use crate::{
-    state::Theme, 
+    state::{use_theme, Theme},
     Route,
 };
 
 use ui::{
+    t, use_feature_flag,
     AvatarDrop, 
     MenuItem, NavDrop, NavMenuItem, Search
 };
@@ -15,34 +16,34 @@ pub fn DesktopNavbar(children: Element) -> Element {
     // Get the current route
     let current_route = use_route::<Route>();
 
-    let mut theme = use_signal(|| Theme::Light);
-    let dark_mode = *theme.read() == Theme::Dark;
+    let mut theme = use_theme();
+    let ai_chat = use_feature_flag("ai_chat");
+    let dark_mode = theme.is_dark();
 
     let toggle_theme = move |_: Event<MouseData>| {
-        let new_theme = if dark_mode { Theme::Light } else { Theme::Dark };
-        *theme.write() = new_theme;
+        theme.set(if dark_mode { Theme::Light } else { Theme::Dark });
     };
 
   let menu_items = vec![
 
         MenuItem {
-            label: "Profile".to_string(),
+            label: t!("menu-profile"),
             to: Some("/profile".to_string()),  // Use string paths
             onclick: None,
         },
 
                 MenuItem {
-            label: "Toggle Theme - DNW".to_string(),
+            label: t!("menu-toggle-theme"),
             to: None,
             onclick: Some(EventHandler::new(toggle_theme)),
         },
             MenuItem {
-            label: "Help Center".to_string(),
+            label: t!("menu-help"),
             to: Some("/help".to_string()),
             onclick: None,
         },
             MenuItem {
-            label: "Sign out".to_string(),
+            label: t!("menu-sign-out"),
             to: Some("#".to_string()),
             onclick: Some(EventHandler::new(|_| {
                 // Handle sign out
@@ -53,58 +54,52 @@ pub fn DesktopNavbar(children: Element) -> Element {
 
     
 
-  let nav_menu_items = vec![
+  let mut nav_menu_items = vec![
 
         NavMenuItem {
-            label: "Login".to_string(),
+            label: t!("nav-login"),
             to: Some("/auth/login".to_string()),  // Use string paths
             onclick: None,
         },
         NavMenuItem {
-            label: "Register".to_string(),
+            label: t!("nav-register"),
             to: Some("/auth/register".to_string()),
             onclick: None,
         },
                 NavMenuItem {
-            label: "Reset Password".to_string(),
+            label: t!("nav-reset-password"),
             to: Some("/auth/reset-password".to_string()),
             onclick: None,
         },
             NavMenuItem {
-            label: "Admin Dashboard".to_string(),
+            label: t!("nav-admin-dashboard"),
             to: Some("/admin".to_string()),
             onclick: None,
         },
              NavMenuItem {
-            label: "Admin Users".to_string(),
+            label: t!("nav-admin-users"),
             to: Some("/admin/users".to_string()),
             onclick: None,
         },
             NavMenuItem {
-            label: "Admin Reports".to_string(),
+            label: t!("nav-admin-reports"),
             to: Some("/admin/reports".to_string()),
             onclick: None,
         },
                 NavMenuItem {
-            label: "Admin Settings".to_string(),
+            label: t!("nav-admin-settings"),
             to: Some("/admin/settings".to_string()),
             onclick: None,
         },
 
-            NavMenuItem {
-            label: "AI Chat".to_string(),
-            to: Some("/ai".to_string()),
-            onclick: None,
-        },
-
                     NavMenuItem {
-            label: "Terms of Service".to_string(),
+            label: t!("nav-terms"),
             to: Some("/legal/terms".to_string()),
             onclick: None,
         },
 
                     NavMenuItem {
-            label: "Privacy Policy".to_string(),
+            label: t!("nav-privacy"),
             to: Some("/legal/privacy".to_string()),
             onclick: None,
         },
@@ -113,6 +108,14 @@ pub fn DesktopNavbar(children: Element) -> Element {
 
 
 
+    if ai_chat {
+        nav_menu_items.push(NavMenuItem {
+            label: t!("nav-ai-chat"),
+            to: Some("/ai".to_string()),
+            onclick: None,
+        });
+    }
+
     rsx! {
         nav { id: "navbar", class: "w-full text-white shadow-md bg-gray-800",
             div { class: "px-8 py-2 mx-auto flex items-center justify-between",
@@ -128,7 +131,7 @@ pub fn DesktopNavbar(children: Element) -> Element {
                                 "text-gray-300"
                             },
                         ),
-                        "Home"
+                        {t!("nav-home")}
                     }
                     Link {
                         to: Route::Blog { initial_id: 1 },
@@ -140,7 +143,7 @@ pub fn DesktopNavbar(children: Element) -> Element {
                                 "text-gray-300"
                             },
                         ),
-                        "Blog"
+                        {t!("nav-blog")}
                     }
 
                     Link {
@@ -153,7 +156,7 @@ pub fn DesktopNavbar(children: Element) -> Element {
                                 "text-gray-300"
                             },
                         ),
-                        "Editor"
+                        {t!("nav-editor")}
                     }
                     Link {
                         to: Route::FocusMode {},
@@ -165,7 +168,7 @@ pub fn DesktopNavbar(children: Element) -> Element {
                                 "text-gray-300"
                             },
                         ),
-                        "Focus Mode"
+                        {t!("nav-focus-mode")}
                     }
                     NavDrop { nav_menu_items }
                     Search {}
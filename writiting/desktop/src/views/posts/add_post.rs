@@ -1,6 +1,7 @@
 use dioxus::{logger::tracing, prelude::*};
+use api::media::{MediaItem, MediaUsage};
 use api::posts::create_post;
-use ui::{Input, InputType};
+use ui::{ImageUploadButton, Input, InputType};
 use ui::{Button, ButtonScheme};
 
 #[component]
@@ -59,6 +60,17 @@ pub fn AddPost(
                     label: Some("Post Content".to_string()),
                     oninput: move |event: FormEvent| body.set(event.value()),
                 }
+                ImageUploadButton {
+                    usage: MediaUsage::Post,
+                    on_uploaded: move |item: MediaItem| {
+                        let mut text = body();
+                        if !text.is_empty() && !text.ends_with('\n') {
+                            text.push('\n');
+                        }
+                        text.push_str(&item.markdown());
+                        body.set(text);
+                    },
+                }
                 div { class: "flex justify-end mt-6",
                     Button {
                         button_scheme: ButtonScheme::Success,
@@ -1,5 +1,7 @@
 use dioxus::prelude::*;
-use api::posts::find_post;
+use api::posts::{find_post, get_post_html};
+use api::moderation::ReportTarget;
+use ui::{MarkdownBody, ReportButton};
 
 #[component]
 pub fn DisplayPostById(id: i32) -> Element {
@@ -14,6 +16,14 @@ pub fn DisplayPostById(id: i32) -> Element {
         }
     });
 
+    // The body as sanitized HTML, with mentions already linked
+    let body = use_resource(move || async move {
+        get_post_html(id).await.map_err(|err| {
+            tracing::error!("Error rendering post {}: {}", id, err);
+            err
+        })
+    });
+
     rsx! {
         div { class: "my-4",
             match post.read().as_ref() {
@@ -26,11 +36,24 @@ pub fn DisplayPostById(id: i32) -> Element {
                 Some(Some(post)) => rsx! {
                     div { class: "bg-white rounded-lg shadow-md p-6",
                         h2 { class: "text-2xl font-bold mb-4", "{post.title}" }
-                        p { class: "text-gray-700 mb-4", "{post.body}" }
+                        div { class: "text-gray-700 mb-4",
+                            match body.read().as_ref() {
+                                Some(Ok(rendered)) => rsx! { MarkdownBody { rendered: rendered.clone() } },
+                                // Fall back to the raw text rather than showing nothing
+                                Some(Err(_)) => rsx! { p { class: "whitespace-pre-wrap", "{post.body}" } },
+                                None => rsx! {},
+                            }
+                        }
                         div { class: "flex justify-between text-sm text-gray-500",
                             span { {format!("Created: {}", post.created_at.format("%Y-%m-%d %H:%M"))} }
                             span { {format!("Updated: {}", post.updated_at.format("%Y-%m-%d %H:%M"))} }
                         }
+                        if !post.is_published {
+                            p { class: "mt-2 text-sm text-red-600", "This post was taken down by a moderator." }
+                        }
+                        div { class: "mt-2 text-right",
+                            ReportButton { target_type: ReportTarget::Post, target_id: post.id.to_string() }
+                        }
                     }
                 },
             }
@@ -0,0 +1,179 @@
+use dioxus::prelude::*;
+use uuid::Uuid;
+use api::authentication::{
+    create_api_key, list_api_keys, revoke_api_key, ApiKey, ApiScope, CreateApiKeyRequest,
+};
+
+#[component]
+pub fn ApiKeysSection() -> Element {
+    let mut keys = use_resource(|| async move { list_api_keys().await });
+    let mut name = use_signal(String::new);
+    let mut scope = use_signal(|| ApiScope::Read);
+    let mut allowed_ips = use_signal(String::new);
+    let mut expires_in_days = use_signal(String::new);
+    let mut new_secret = use_signal(|| None::<String>);
+    let mut error = use_signal(|| None::<String>);
+
+    let create = move |evt: FormEvent| {
+        evt.prevent_default();
+        let request = CreateApiKeyRequest {
+            name: name(),
+            description: None,
+            scopes: vec![scope()],
+            allowed_ips: allowed_ips()
+                .split(|c: char| c == ',' || c.is_whitespace())
+                .filter(|ip| !ip.is_empty())
+                .map(str::to_string)
+                .collect(),
+            expires_in_days: expires_in_days().trim().parse().ok(),
+        };
+
+        spawn(async move {
+            match create_api_key(request).await {
+                Ok(created) => {
+                    error.set(None);
+                    name.set(String::new());
+                    allowed_ips.set(String::new());
+                    expires_in_days.set(String::new());
+                    new_secret.set(Some(created.secret));
+                    keys.restart();
+                }
+                Err(err) => {
+                    tracing::error!("Failed to create API key: {}", err);
+                    error.set(Some(err.to_string()));
+                }
+            }
+        });
+    };
+
+    let keys_list = match &*keys.read() {
+        Some(Ok(list)) => list.clone(),
+        _ => Vec::new(),
+    };
+
+    rsx! {
+        div { class: "bg-white rounded-lg shadow p-6 mb-6",
+            h2 { class: "text-lg font-medium mb-1 text-gray-900", "API Keys" }
+            p { class: "text-sm text-gray-600 mb-4",
+                "Keys let scripts and other tools use the HTTP API as you."
+            }
+
+            if let Some(err) = error() {
+                p { class: "mb-3 text-sm text-red-600", "{err}" }
+            }
+
+            if let Some(secret) = new_secret() {
+                div { class: "mb-4 p-4 bg-yellow-50 border border-yellow-200 rounded",
+                    p { class: "text-sm text-gray-700 mb-2",
+                        "Copy this key now. It won't be shown again."
+                    }
+                    p { class: "font-mono text-sm break-all", "{secret}" }
+                    button {
+                        class: "mt-3 text-sm text-blue-600 hover:underline",
+                        onclick: move |_| new_secret.set(None),
+                        "Done"
+                    }
+                }
+            }
+
+            form { class: "grid grid-cols-2 gap-3 mb-6", onsubmit: create,
+                input {
+                    class: "px-3 py-2 border border-gray-300 rounded",
+                    placeholder: "Key name",
+                    value: "{name}",
+                    oninput: move |e| name.set(e.value()),
+                }
+                select {
+                    class: "px-3 py-2 border border-gray-300 rounded",
+                    onchange: move |e| {
+                        let selected = ApiScope::ALL.into_iter().find(|s| s.as_str() == e.value());
+                        scope.set(selected.unwrap_or(ApiScope::Read));
+                    },
+                    for s in ApiScope::ALL {
+                        option { value: s.as_str(), selected: s == scope(), "{s.as_str()}" }
+                    }
+                }
+                input {
+                    class: "px-3 py-2 border border-gray-300 rounded",
+                    placeholder: "Allowed IPs, e.g. 203.0.113.0/24 (optional)",
+                    value: "{allowed_ips}",
+                    oninput: move |e| allowed_ips.set(e.value()),
+                }
+                input {
+                    class: "px-3 py-2 border border-gray-300 rounded",
+                    r#type: "number",
+                    min: "1",
+                    max: "365",
+                    placeholder: "Expires in days (optional)",
+                    value: "{expires_in_days}",
+                    oninput: move |e| expires_in_days.set(e.value()),
+                }
+                button {
+                    r#type: "submit",
+                    class: "col-span-2 justify-self-end px-4 py-2 bg-blue-600 text-white rounded hover:bg-blue-700",
+                    "Create key"
+                }
+            }
+
+            if keys_list.is_empty() {
+                p { class: "text-gray-500 text-sm", "You have no API keys." }
+            } else {
+                ul { class: "divide-y",
+                    for key in keys_list {
+                        ApiKeyRow {
+                            key: "{key.key}",
+                            api_key: key.clone(),
+                            on_revoke: move |id| {
+                                spawn(async move {
+                                    match revoke_api_key(id).await {
+                                        Ok(()) => keys.restart(),
+                                        Err(err) => error.set(Some(err.to_string())),
+                                    }
+                                });
+                            },
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn ApiKeyRow(api_key: ApiKey, on_revoke: EventHandler<Uuid>) -> Element {
+    let scopes = api_key
+        .scopes
+        .iter()
+        .map(|s| s.as_str())
+        .collect::<Vec<_>>()
+        .join(", ");
+    let last_used = api_key
+        .last_used_at
+        .map(|at| at.format("%Y-%m-%d %H:%M UTC").to_string())
+        .unwrap_or_else(|| "never".to_string());
+    let status = if api_key.revoked_at.is_some() {
+        "Revoked"
+    } else if api_key.is_valid() {
+        "Active"
+    } else {
+        "Expired"
+    };
+    let id = api_key.key;
+
+    rsx! {
+        li { class: "py-3 flex justify-between items-center",
+            div {
+                p { class: "font-medium text-gray-800", "{api_key.name}" }
+                p { class: "text-xs text-gray-500 font-mono", "{api_key.prefix}…  ·  {scopes}" }
+                p { class: "text-xs text-gray-500", "{status} · last used {last_used}" }
+            }
+            if api_key.revoked_at.is_none() {
+                button {
+                    class: "px-3 py-1 text-sm bg-gray-200 rounded hover:bg-gray-300",
+                    onclick: move |_| on_revoke.call(id),
+                    "Revoke"
+                }
+            }
+        }
+    }
+}
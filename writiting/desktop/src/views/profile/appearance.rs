@@ -1,48 +1,83 @@
 use dioxus::prelude::*;
+use api::features::{update_preferences, Language, UpdatePreferences};
+use ui::{t, use_language};
+use crate::state::{use_theme, Theme};
+
+fn theme_label(theme: Theme) -> String {
+    match theme {
+        Theme::Light => t!("appearance-theme-light"),
+        Theme::Dark => t!("appearance-theme-dark"),
+        Theme::System => t!("appearance-theme-system"),
+    }
+}
 
 #[component]
-pub fn AppearanceSection(app_theme: Signal<String>) -> Element {
+pub fn AppearanceSection() -> Element {
+    let mut theme = use_theme();
+    let mut language = use_language();
+    let mut error = use_signal(|| None::<String>);
+
+    let current_language = language();
+    let current_theme = (theme.theme)();
+
+    let save_language = move |evt: FormEvent| {
+        let Some(picked) = Language::from_code(&evt.value()) else {
+            return;
+        };
+        language.set(picked);
+        spawn(async move {
+            let update = UpdatePreferences { language: Some(picked), ..Default::default() };
+            match update_preferences(update).await {
+                Ok(_) => error.set(None),
+                Err(err) => {
+                    tracing::error!("Failed to save language: {}", err);
+                    error.set(Some(err.to_string()));
+                }
+            }
+        });
+    };
+
     rsx! {
         div { class: "bg-white rounded-lg shadow p-6",
-            h2 { class: "text-lg font-medium mb-4 text-gray-900", "Appearance" }
+            h2 { class: "text-lg font-medium mb-4 text-gray-900", {t!("appearance-title")} }
             div { class: "flex gap-4",
-                button {
-                    class: {
-                        let base = "px-4 py-2 rounded border-2 ";
-                        if app_theme() == "light" {
-                            format!("{}border-blue-500 bg-blue-50", base)
-                        } else {
-                            format!("{}border-transparent", base)
-                        }
-                    },
-                    onclick: move |_| app_theme.set("light".to_string()),
-                    "Light"
-                }
-                button {
-                    class: {
-                        let base = "px-4 py-2 rounded border-2 ";
-                        if app_theme() == "dark" {
-                            format!("{}border-blue-500 bg-blue-50", base)
-                        } else {
-                            format!("{}border-transparent", base)
-                        }
-                    },
-                    onclick: move |_| app_theme.set("dark".to_string()),
-                    "Dark"
+                for choice in Theme::ALL {
+                    button {
+                        key: "{choice.as_str()}",
+                        class: {
+                            let base = "px-4 py-2 rounded border-2 ";
+                            if current_theme == choice {
+                                format!("{}border-blue-500 bg-blue-50", base)
+                            } else {
+                                format!("{}border-transparent", base)
+                            }
+                        },
+                        onclick: move |_| theme.set(choice),
+                        {theme_label(choice)}
+                    }
                 }
-                button {
-                    class: {
-                        let base = "px-4 py-2 rounded border-2 ";
-                        if app_theme() == "system" {
-                            format!("{}border-blue-500 bg-blue-50", base)
-                        } else {
-                            format!("{}border-transparent", base)
+            }
+            if current_theme == Theme::System {
+                p { class: "mt-2 text-sm text-gray-600", {t!("appearance-system-hint")} }
+            }
+
+            div { class: "mt-6",
+                label { class: "block text-sm font-medium text-gray-700 mb-1", {t!("appearance-language")} }
+                select {
+                    class: "px-3 py-2 border border-gray-300 rounded",
+                    onchange: save_language,
+                    for choice in Language::ALL {
+                        option {
+                            value: "{choice.code()}",
+                            selected: current_language == choice,
+                            "{choice.native_name()}"
                         }
-                    },
-                    onclick: move |_| app_theme.set("system".to_string()),
-                    "System"
+                    }
                 }
             }
+            if let Some(err) = error() {
+                p { class: "mt-3 text-sm text-red-600", "{err}" }
+            }
         }
     }
-}
\ No newline at end of file
+}
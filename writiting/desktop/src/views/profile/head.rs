@@ -1,14 +1,15 @@
 use dioxus::prelude::*;
-use ui::Avatar;
+use api::media::MediaUsage;
+use ui::{upload_picked_image, Avatar};
 
 
 #[component]
 pub fn ProfileHeader(
-    avatar_url: Signal<Option<String>>,
+    mut avatar_url: Signal<Option<String>>,
     first_name: Signal<String>,
     last_name: Signal<String>,
     is_editing: Signal<bool>,
-    is_uploading: Signal<bool>,
+    mut is_uploading: Signal<bool>,
 ) -> Element {
     rsx! {
         div { class: "flex items-center gap-6 mb-8",
@@ -26,16 +27,16 @@ pub fn ProfileHeader(
                                 id: "avatar-upload",
                                 accept: "image/*",
                                 onchange: move |evt| {
-                                    if let Some(file_engine) = &evt.files() {
-                                        if let Some(file) = file_engine.files().get(0) {
-                                            is_uploading.set(true);
-                                            let file = file.clone();
-                                            spawn(async move {
-                                                tokio::time::sleep(std::time::Duration::from_secs(1)).await;
-                                                is_uploading.set(false);
-                                            });
+                                    is_uploading.set(true);
+                                    // The server makes the upload the profile picture
+                                    spawn(async move {
+                                        match upload_picked_image(evt, MediaUsage::Avatar).await {
+                                            Some(Ok(item)) => avatar_url.set(Some(item.thumbnail_url())),
+                                            Some(Err(err)) => tracing::error!("Failed to upload avatar: {}", err),
+                                            None => {}
                                         }
-                                    }
+                                        is_uploading.set(false);
+                                    });
                                 },
                             }
                             label { class: "block", r#for: "avatar-upload",
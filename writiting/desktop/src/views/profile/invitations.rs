@@ -0,0 +1,211 @@
+use dioxus::prelude::*;
+use uuid::Uuid;
+use api::authentication::{
+    create_invitation, list_invitation_redemptions, list_my_invitations, revoke_invitation,
+    CreateInvitationRequest, Invitation, InvitationStatus,
+};
+
+#[component]
+pub fn InvitationsSection() -> Element {
+    let mut invitations = use_resource(|| async move { list_my_invitations().await });
+    let mut email = use_signal(String::new);
+    let mut max_uses = use_signal(|| "1".to_string());
+    let mut expires_in_days = use_signal(|| "7".to_string());
+    let mut note = use_signal(String::new);
+    let mut error = use_signal(|| None::<String>);
+
+    let create = move |evt: FormEvent| {
+        evt.prevent_default();
+        let request = CreateInvitationRequest {
+            email: Some(email()).filter(|e| !e.trim().is_empty()),
+            role_id: None,
+            max_uses: max_uses().trim().parse().ok(),
+            expires_in_days: expires_in_days().trim().parse().ok(),
+            note: Some(note()).filter(|n| !n.trim().is_empty()),
+        };
+
+        spawn(async move {
+            match create_invitation(request).await {
+                Ok(_) => {
+                    error.set(None);
+                    email.set(String::new());
+                    note.set(String::new());
+                    invitations.restart();
+                }
+                Err(err) => {
+                    tracing::error!("Failed to create invitation: {}", err);
+                    error.set(Some(err.to_string()));
+                }
+            }
+        });
+    };
+
+    let invitation_list = match &*invitations.read() {
+        Some(Ok(list)) => list.clone(),
+        _ => Vec::new(),
+    };
+
+    rsx! {
+        div { class: "bg-white rounded-lg shadow p-6 mb-6",
+            h2 { class: "text-lg font-medium mb-1 text-gray-900", "Invitations" }
+            p { class: "text-sm text-gray-600 mb-4",
+                "Invite people you know. Each invitation can be used up to 5 times and lasts at most 30 days."
+            }
+
+            if let Some(err) = error() {
+                p { class: "mb-3 text-sm text-red-600", "{err}" }
+            }
+
+            form { class: "grid grid-cols-2 gap-3 mb-6", onsubmit: create,
+                input {
+                    class: "px-3 py-2 border border-gray-300 rounded",
+                    r#type: "email",
+                    placeholder: "Only for this email (optional)",
+                    value: "{email}",
+                    oninput: move |e| email.set(e.value()),
+                }
+                input {
+                    class: "px-3 py-2 border border-gray-300 rounded",
+                    placeholder: "Note to yourself (optional)",
+                    value: "{note}",
+                    oninput: move |e| note.set(e.value()),
+                }
+                label { class: "text-sm text-gray-700",
+                    "Uses"
+                    input {
+                        class: "mt-1 w-full px-3 py-2 border border-gray-300 rounded",
+                        r#type: "number",
+                        min: "1",
+                        max: "5",
+                        value: "{max_uses}",
+                        oninput: move |e| max_uses.set(e.value()),
+                    }
+                }
+                label { class: "text-sm text-gray-700",
+                    "Expires in days"
+                    input {
+                        class: "mt-1 w-full px-3 py-2 border border-gray-300 rounded",
+                        r#type: "number",
+                        min: "1",
+                        max: "30",
+                        value: "{expires_in_days}",
+                        oninput: move |e| expires_in_days.set(e.value()),
+                    }
+                }
+                button {
+                    r#type: "submit",
+                    class: "col-span-2 justify-self-end px-4 py-2 bg-blue-600 text-white rounded hover:bg-blue-700",
+                    "Create invitation"
+                }
+            }
+
+            if invitation_list.is_empty() {
+                p { class: "text-gray-500 text-sm", "You haven't invited anyone yet." }
+            } else {
+                ul { class: "divide-y",
+                    for invitation in invitation_list {
+                        InvitationRow {
+                            key: "{invitation.id}",
+                            invitation: invitation.clone(),
+                            show_creator: false,
+                            on_revoke: move |id| {
+                                spawn(async move {
+                                    match revoke_invitation(id).await {
+                                        Ok(()) => invitations.restart(),
+                                        Err(err) => error.set(Some(err.to_string())),
+                                    }
+                                });
+                            },
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// One invitation with its link, limits and the accounts that used it.
+/// Also used by the admin invitations page.
+#[component]
+pub fn InvitationRow(invitation: Invitation, show_creator: bool, on_revoke: EventHandler<Uuid>) -> Element {
+    let mut show_redemptions = use_signal(|| false);
+    let id = invitation.id;
+    let redemptions = use_resource(move || async move {
+        if show_redemptions() {
+            list_invitation_redemptions(id).await.ok()
+        } else {
+            None
+        }
+    });
+
+    let status = invitation.status();
+    let status_class = match status {
+        InvitationStatus::Active => "bg-green-100 text-green-800",
+        _ => "bg-gray-100 text-gray-700",
+    };
+    let uses = match invitation.max_uses {
+        Some(max) => format!("{} of {} uses", invitation.use_count, max),
+        None => format!("{} uses", invitation.use_count),
+    };
+    let expires = invitation
+        .expires_at
+        .map(|at| format!("expires {}", at.format("%Y-%m-%d %H:%M UTC")))
+        .unwrap_or_else(|| "never expires".to_string());
+    let link = format!("/auth/register?invite={}", invitation.code);
+    let mut details = Vec::new();
+    if show_creator {
+        details.push(format!("by {}", invitation.created_by_username.clone().unwrap_or_else(|| "deleted user".to_string())));
+    }
+    if let Some(email) = &invitation.email {
+        details.push(format!("for {}", email));
+    }
+    if let Some(role) = &invitation.role_name {
+        details.push(format!("joins as {}", role));
+    }
+    let details = details.join(" · ");
+    let joined = match &*redemptions.read() {
+        Some(Some(list)) if list.is_empty() => "Nobody has joined with it yet.".to_string(),
+        Some(Some(list)) => list.iter().map(|r| r.username.clone()).collect::<Vec<_>>().join(", "),
+        _ => String::new(),
+    };
+
+    rsx! {
+        li { class: "py-3",
+            div { class: "flex justify-between items-center",
+                div {
+                    p { class: "font-mono text-sm text-gray-800",
+                        "{invitation.code}"
+                        span { class: "ml-2 px-2 py-0.5 text-xs rounded {status_class}", "{status.label()}" }
+                    }
+                    p { class: "text-xs text-gray-500 break-all", "{link}" }
+                    p { class: "text-xs text-gray-500", "{uses} · {expires}" }
+                    if !details.is_empty() {
+                        p { class: "text-xs text-gray-500", "{details}" }
+                    }
+                    if let Some(note) = &invitation.note {
+                        p { class: "text-xs text-gray-600 italic", "{note}" }
+                    }
+                }
+                div { class: "flex gap-2",
+                    if invitation.use_count > 0 {
+                        button {
+                            class: "px-3 py-1 text-sm bg-gray-100 rounded hover:bg-gray-200",
+                            onclick: move |_| show_redemptions.set(!show_redemptions()),
+                            if show_redemptions() { "Hide" } else { "Who joined" }
+                        }
+                    }
+                    if status == InvitationStatus::Active {
+                        button {
+                            class: "px-3 py-1 text-sm bg-gray-200 rounded hover:bg-gray-300",
+                            onclick: move |_| on_revoke.call(id),
+                            "Revoke"
+                        }
+                    }
+                }
+            }
+            if show_redemptions() && !joined.is_empty() {
+                p { class: "mt-2 text-sm text-gray-700", "Joined: {joined}" }
+            }
+        }
+    }
+}
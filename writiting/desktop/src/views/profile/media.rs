@@ -0,0 +1,84 @@
+use dioxus::prelude::*;
+use api::media::{delete_media, format_bytes, get_media_quota, list_my_media, MediaUsage};
+use ui::{t, ImageUploadButton};
+
+#[component]
+pub fn MediaSection() -> Element {
+    let mut media = use_resource(|| async move { list_my_media(None).await });
+    let mut quota = use_resource(|| async move { get_media_quota().await });
+    let mut error = use_signal(|| None::<String>);
+
+    let items = match &*media.read() {
+        Some(Ok(list)) => list.clone(),
+        _ => Vec::new(),
+    };
+    let (usage_text, percent) = match &*quota.read() {
+        Some(Ok(q)) => (
+            Some(t!("media-quota", used = format_bytes(q.used_bytes), limit = format_bytes(q.limit_bytes))),
+            q.percent_used(),
+        ),
+        _ => (None, 0),
+    };
+    let bar_class = if percent >= 90 { "bg-red-500" } else { "bg-blue-500" };
+
+    rsx! {
+        div { class: "bg-white rounded-lg shadow p-6 mb-6",
+            div { class: "flex justify-between items-center mb-1",
+                h2 { class: "text-lg font-medium text-gray-900", {t!("media-title")} }
+                ImageUploadButton {
+                    usage: MediaUsage::Post,
+                    label: t!("media-upload-image"),
+                    on_uploaded: move |_| {
+                        media.restart();
+                        quota.restart();
+                    },
+                }
+            }
+            if let Some(usage_text) = usage_text {
+                p { class: "text-sm text-gray-600", "{usage_text}" }
+                div { class: "mt-1 mb-4 h-2 bg-gray-200 rounded",
+                    div { class: "h-2 rounded {bar_class}", style: "width: {percent}%" }
+                }
+            }
+
+            if let Some(err) = error() {
+                p { class: "mb-3 text-sm text-red-600", "{err}" }
+            }
+
+            if items.is_empty() {
+                p { class: "text-gray-500 text-sm", {t!("media-empty")} }
+            } else {
+                ul { class: "grid grid-cols-2 md:grid-cols-4 gap-3",
+                    for item in items {
+                        li { key: "{item.id}", class: "border border-gray-200 rounded p-2",
+                            img { class: "w-full h-24 object-cover rounded", src: "{item.thumbnail_url()}", alt: "" }
+                            p { class: "mt-1 text-xs text-gray-700 truncate",
+                                "{item.original_name.clone().unwrap_or_default()}"
+                            }
+                            p { class: "text-xs text-gray-500",
+                                "{item.width}×{item.height} · {format_bytes(item.byte_size)} · {item.usage.as_str()}"
+                            }
+                            button {
+                                class: "mt-1 text-xs text-red-600 hover:underline",
+                                onclick: move |_| {
+                                    let id = item.id;
+                                    spawn(async move {
+                                        match delete_media(id).await {
+                                            Ok(()) => {
+                                                error.set(None);
+                                                media.restart();
+                                                quota.restart();
+                                            }
+                                            Err(err) => error.set(Some(err.to_string())),
+                                        }
+                                    });
+                                },
+                                {t!("media-delete")}
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -1,4 +1,16 @@
 
+mod api_keys;
+pub use api_keys::ApiKeysSection;
+
+mod invitations;
+pub use invitations::{InvitationRow, InvitationsSection};
+
+mod media;
+pub use media::MediaSection;
+
+mod social_links;
+pub use social_links::SocialLinksSection;
+
 mod appearance;
 pub use appearance::AppearanceSection;
 
@@ -1,7 +1,7 @@
 use dioxus::prelude::*;
 use crate::views::profile::{
     PersonalInfoSection, ProfileHeader, 
-    FormActions, SecuritySection, NotificationsSection, AppearanceSection
+    FormActions, SecuritySection, ApiKeysSection, InvitationsSection, SocialLinksSection, MediaSection, NotificationsSection, AppearanceSection
 };
 
 #[component]
@@ -16,10 +16,8 @@ pub fn Profile() -> Element {
     let  is_uploading = use_signal(|| false);
 
     // User preferences (matches user_preferences table)
-    let  app_theme = use_signal(|| "light".to_string());
     let  email_notifications = use_signal(|| true);
     let  sms_notifications = use_signal(|| false);
-    let  enable_2fa = use_signal(|| false);
 
 
 
@@ -51,9 +49,13 @@ pub fn Profile() -> Element {
                     h1 { class: "text-2xl font-bold text-gray-900", "Account Settings" }
                     p { class: "text-gray-600", "Manage your security and application preferences" }
                 }
-                SecuritySection { enable_2fa }
+                SecuritySection {}
+                SocialLinksSection {}
+                MediaSection {}
+                ApiKeysSection {}
+                InvitationsSection {}
                 NotificationsSection { email_notifications, sms_notifications }
-                AppearanceSection { app_theme }
+                AppearanceSection {}
             }
         }
     }
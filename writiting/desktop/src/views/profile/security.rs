@@ -1,43 +1,352 @@
 use dioxus::prelude::*;
+use api::authentication::{
+    begin_two_factor_enrollment, change_password, confirm_two_factor_enrollment, disable_two_factor,
+    get_recent_security_activity, get_two_factor_status, regenerate_recovery_codes, TotpEnrollment,
+};
 
 #[component]
-pub fn SecuritySection(enable_2fa: Signal<bool>) -> Element {
+pub fn SecuritySection() -> Element {
     rsx! {
         div { class: "bg-white rounded-lg shadow p-6 mb-6",
             h2 { class: "text-lg font-medium mb-4 text-gray-900", "Security" }
             // Password Change
-            div { class: "mb-4 p-4 border-b",
-                h3 { class: "font-medium text-gray-800 mb-2", "Password" }
+            PasswordSettings {}
+
+            // 2FA
+            TwoFactorSettings {}
+
+            RecentActivity {}
+        }
+    }
+}
+
+#[component]
+fn PasswordSettings() -> Element {
+    let mut editing = use_signal(|| false);
+    let mut current = use_signal(String::new);
+    let mut new_password = use_signal(String::new);
+    let mut confirmation = use_signal(String::new);
+    let mut message = use_signal(|| None::<(bool, String)>);
+
+    let submit = move |evt: FormEvent| {
+        evt.prevent_default();
+        if new_password() != confirmation() {
+            message.set(Some((false, "Passwords must match".to_string())));
+            return;
+        }
+        spawn(async move {
+            match change_password(current(), new_password()).await {
+                Ok(()) => {
+                    editing.set(false);
+                    current.set(String::new());
+                    new_password.set(String::new());
+                    confirmation.set(String::new());
+                    message.set(Some((true, "Password changed. Other devices have been signed out.".to_string())));
+                }
+                Err(err) => message.set(Some((false, err.to_string()))),
+            }
+        });
+    };
+
+    rsx! {
+        div { class: "mb-4 p-4 border-b",
+            h3 { class: "font-medium text-gray-800 mb-2", "Password" }
+            if let Some((ok, text)) = message() {
+                p { class: if ok { "mb-3 text-sm text-green-700" } else { "mb-3 text-sm text-red-600" }, "{text}" }
+            }
+            if editing() {
+                form { class: "space-y-2 max-w-sm", onsubmit: submit,
+                    input {
+                        r#type: "password",
+                        class: "w-full px-3 py-2 border border-gray-300 rounded",
+                        placeholder: "Current password",
+                        value: "{current}",
+                        oninput: move |e| current.set(e.value()),
+                    }
+                    input {
+                        r#type: "password",
+                        class: "w-full px-3 py-2 border border-gray-300 rounded",
+                        placeholder: "New password",
+                        value: "{new_password}",
+                        oninput: move |e| new_password.set(e.value()),
+                    }
+                    input {
+                        r#type: "password",
+                        class: "w-full px-3 py-2 border border-gray-300 rounded",
+                        placeholder: "Confirm new password",
+                        value: "{confirmation}",
+                        oninput: move |e| confirmation.set(e.value()),
+                    }
+                    div { class: "flex gap-2",
+                        button {
+                            r#type: "submit",
+                            class: "px-4 py-2 bg-blue-600 text-white rounded hover:bg-blue-700",
+                            "Save password"
+                        }
+                        button {
+                            r#type: "button",
+                            class: "px-4 py-2 bg-gray-200 rounded hover:bg-gray-300",
+                            onclick: move |_| editing.set(false),
+                            "Cancel"
+                        }
+                    }
+                }
+            } else {
                 div { class: "flex justify-between items-center",
-                    p { class: "text-gray-600", "Last changed 3 months ago" }
-                    button { class: "px-4 py-2 bg-blue-600 text-white rounded hover:bg-blue-700",
+                    p { class: "text-gray-600", "Changing it signs you out on other devices" }
+                    button {
+                        class: "px-4 py-2 bg-blue-600 text-white rounded hover:bg-blue-700",
+                        onclick: move |_| {
+                            message.set(None);
+                            editing.set(true);
+                        },
                         "Change Password"
                     }
                 }
             }
+        }
+    }
+}
 
-            // 2FA
-            div { class: "p-4",
-                h3 { class: "font-medium text-gray-800 mb-2", "Two-Factor Authentication" }
-                div { class: "flex justify-between items-center",
-                    p { class: "text-gray-600",
-                        if enable_2fa() {
-                            "Enabled (SMS verification)"
-                        } else {
-                            "Disabled - adds extra security"
+#[component]
+fn RecentActivity() -> Element {
+    let activity = use_resource(|| async move { get_recent_security_activity().await });
+
+    // (id, description, warning, where, when) for each event
+    let entries: Vec<(String, &'static str, bool, String, String)> = match &*activity.read() {
+        Some(Ok(list)) => list
+            .iter()
+            .map(|entry| {
+                (
+                    entry.id.to_string(),
+                    entry.action.description(),
+                    entry.action.is_warning(),
+                    format!("{} · {}", entry.ip_address, entry.user_agent.clone().unwrap_or_default()),
+                    entry.created_at.format("%Y-%m-%d %H:%M UTC").to_string(),
+                )
+            })
+            .collect(),
+        Some(Err(err)) => {
+            tracing::error!("Failed to load security activity: {}", err);
+            Vec::new()
+        }
+        None => Vec::new(),
+    };
+
+    rsx! {
+        div { class: "p-4 border-t",
+            h3 { class: "font-medium text-gray-800 mb-2", "Recent security activity" }
+            if entries.is_empty() {
+                p { class: "text-sm text-gray-500", "No activity yet." }
+            }
+            ul { class: "divide-y text-sm",
+                for (id, description, warning, location, when) in entries {
+                    li { key: "{id}", class: "py-2 flex justify-between",
+                        div {
+                            p {
+                                class: if warning { "text-red-700" } else { "text-gray-800" },
+                                "{description}"
+                            }
+                            p { class: "text-xs text-gray-500", "{location}" }
+                        }
+                        span { class: "text-xs text-gray-500 whitespace-nowrap", "{when}" }
+                    }
+                }
+            }
+            p { class: "mt-2 text-xs text-gray-500",
+                "Don't recognise something? Change your password and revoke your API keys."
+            }
+        }
+    }
+}
+
+/// Actions that ask for the password again before running
+#[derive(Clone, Copy, PartialEq)]
+enum ConfirmAction {
+    Disable,
+    RegenerateCodes,
+}
+
+#[component]
+fn TwoFactorSettings() -> Element {
+    let mut status = use_resource(|| async move { get_two_factor_status().await });
+    let mut enrollment = use_signal(|| None::<TotpEnrollment>);
+    let mut recovery_codes = use_signal(|| None::<Vec<String>>);
+    let mut confirm_action = use_signal(|| None::<ConfirmAction>);
+    let mut code = use_signal(String::new);
+    let mut password = use_signal(String::new);
+    let mut error = use_signal(|| None::<String>);
+
+    let start_enrollment = move |_| {
+        spawn(async move {
+            match begin_two_factor_enrollment().await {
+                Ok(details) => {
+                    error.set(None);
+                    enrollment.set(Some(details));
+                }
+                Err(err) => {
+                    tracing::error!("Failed to start two-factor setup: {}", err);
+                    error.set(Some(err.to_string()));
+                }
+            }
+        });
+    };
+
+    let confirm_enrollment = move |evt: FormEvent| {
+        evt.prevent_default();
+        spawn(async move {
+            match confirm_two_factor_enrollment(code()).await {
+                Ok(codes) => {
+                    error.set(None);
+                    enrollment.set(None);
+                    code.set(String::new());
+                    recovery_codes.set(Some(codes));
+                    status.restart();
+                }
+                Err(err) => error.set(Some(err.to_string())),
+            }
+        });
+    };
+
+    let run_confirmed_action = move |evt: FormEvent| {
+        evt.prevent_default();
+        let Some(action) = confirm_action() else { return };
+        spawn(async move {
+            let result = match action {
+                ConfirmAction::Disable => disable_two_factor(password()).await.map(|_| None),
+                ConfirmAction::RegenerateCodes => regenerate_recovery_codes(password()).await.map(Some),
+            };
+
+            match result {
+                Ok(codes) => {
+                    error.set(None);
+                    confirm_action.set(None);
+                    password.set(String::new());
+                    recovery_codes.set(codes);
+                    status.restart();
+                }
+                Err(err) => error.set(Some(err.to_string())),
+            }
+        });
+    };
+
+    let (enabled, remaining) = match &*status.read() {
+        Some(Ok(s)) => (s.enabled, s.recovery_codes_remaining),
+        _ => (false, 0),
+    };
+
+    rsx! {
+        div { class: "p-4",
+            h3 { class: "font-medium text-gray-800 mb-2", "Two-Factor Authentication" }
+
+            if let Some(err) = error() {
+                p { class: "mb-3 text-sm text-red-600", "{err}" }
+            }
+
+            div { class: "flex justify-between items-center",
+                p { class: "text-gray-600",
+                    if enabled {
+                        "Enabled (authenticator app) - {remaining} recovery codes left"
+                    } else {
+                        "Disabled - adds extra security"
+                    }
+                }
+                if enabled {
+                    div { class: "flex gap-2",
+                        button {
+                            class: "px-4 py-2 bg-gray-200 rounded hover:bg-gray-300",
+                            onclick: move |_| confirm_action.set(Some(ConfirmAction::RegenerateCodes)),
+                            "New recovery codes"
+                        }
+                        button {
+                            class: "px-4 py-2 bg-gray-200 rounded hover:bg-gray-300",
+                            onclick: move |_| confirm_action.set(Some(ConfirmAction::Disable)),
+                            "Disable"
                         }
                     }
+                } else if enrollment.read().is_none() {
                     button {
                         class: "px-4 py-2 bg-gray-200 rounded hover:bg-gray-300",
-                        onclick: move |_| enable_2fa.set(!enable_2fa()),
-                        if enable_2fa() {
-                            "Disable"
+                        onclick: start_enrollment,
+                        "Enable"
+                    }
+                }
+            }
+
+            if let Some(details) = enrollment() {
+                form { class: "mt-4 space-y-3", onsubmit: confirm_enrollment,
+                    p { class: "text-sm text-gray-600",
+                        "Scan this code with your authenticator app, then enter the 6-digit code it shows."
+                    }
+                    img {
+                        class: "w-48 h-48 border rounded",
+                        src: "data:image/png;base64,{details.qr_code_png}",
+                        alt: "Two-factor QR code",
+                    }
+                    p { class: "text-xs text-gray-500 break-all", "Can't scan it? Enter this key: {details.secret}" }
+                    div { class: "flex gap-2",
+                        input {
+                            class: "px-3 py-2 border border-gray-300 rounded w-40",
+                            placeholder: "123456",
+                            value: "{code}",
+                            oninput: move |e| code.set(e.value()),
+                        }
+                        button {
+                            r#type: "submit",
+                            class: "px-4 py-2 bg-blue-600 text-white rounded hover:bg-blue-700",
+                            "Confirm"
+                        }
+                    }
+                }
+            }
+
+            if let Some(action) = confirm_action() {
+                form { class: "mt-4 flex gap-2 items-center", onsubmit: run_confirmed_action,
+                    input {
+                        r#type: "password",
+                        class: "px-3 py-2 border border-gray-300 rounded",
+                        placeholder: "Current password",
+                        value: "{password}",
+                        oninput: move |e| password.set(e.value()),
+                    }
+                    button {
+                        r#type: "submit",
+                        class: "px-4 py-2 bg-blue-600 text-white rounded hover:bg-blue-700",
+                        if action == ConfirmAction::Disable {
+                            "Disable two-factor"
                         } else {
-                            "Enable"
+                            "Generate codes"
                         }
                     }
+                    button {
+                        r#type: "button",
+                        class: "px-4 py-2 bg-gray-200 rounded hover:bg-gray-300",
+                        onclick: move |_| {
+                            confirm_action.set(None);
+                            password.set(String::new());
+                        },
+                        "Cancel"
+                    }
+                }
+            }
+
+            if let Some(codes) = recovery_codes() {
+                div { class: "mt-4 p-4 bg-yellow-50 border border-yellow-200 rounded",
+                    p { class: "text-sm text-gray-700 mb-2",
+                        "Save these recovery codes somewhere safe. Each one works once and they won't be shown again."
+                    }
+                    ul { class: "grid grid-cols-2 gap-1 font-mono text-sm",
+                        for c in codes {
+                            li { key: "{c}", "{c}" }
+                        }
+                    }
+                    button {
+                        class: "mt-3 text-sm text-blue-600 hover:underline",
+                        onclick: move |_| recovery_codes.set(None),
+                        "I've saved them"
+                    }
                 }
             }
         }
     }
-}
\ No newline at end of file
+}
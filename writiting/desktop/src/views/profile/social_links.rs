@@ -0,0 +1,133 @@
+use dioxus::prelude::*;
+use api::session::get_current_user;
+use api::users::{delete_social_link, list_my_social_links, list_social_platforms, save_social_link, SocialLinkInput};
+
+#[component]
+pub fn SocialLinksSection() -> Element {
+    let mut links = use_resource(|| async move { list_my_social_links().await });
+    let platforms = use_resource(|| async move { list_social_platforms().await });
+    let current_user = use_resource(|| async move { get_current_user().await });
+
+    let mut platform_id = use_signal(|| None::<i16>);
+    let mut handle = use_signal(String::new);
+    let mut is_public = use_signal(|| true);
+    let mut error = use_signal(|| None::<String>);
+
+    let save = move |evt: FormEvent| {
+        evt.prevent_default();
+        let Some(platform_id) = platform_id() else {
+            error.set(Some("Pick a platform".to_string()));
+            return;
+        };
+        let input = SocialLinkInput { platform_id, handle: handle(), is_public: is_public() };
+
+        spawn(async move {
+            match save_social_link(input).await {
+                Ok(_) => {
+                    error.set(None);
+                    handle.set(String::new());
+                    links.restart();
+                }
+                Err(err) => {
+                    tracing::error!("Failed to save social link: {}", err);
+                    error.set(Some(err.to_string()));
+                }
+            }
+        });
+    };
+
+    let platform_options = match &*platforms.read() {
+        Some(Ok(list)) => list.clone(),
+        _ => Vec::new(),
+    };
+    let link_list = match &*links.read() {
+        Some(Ok(list)) => list.clone(),
+        _ => Vec::new(),
+    };
+    let profile_href = match &*current_user.read() {
+        Some(Ok(Some(user))) => Some(format!("/u/{}", user.username)),
+        _ => None,
+    };
+
+    rsx! {
+        div { class: "bg-white rounded-lg shadow p-6 mb-6",
+            div { class: "flex justify-between items-center mb-1",
+                h2 { class: "text-lg font-medium text-gray-900", "Social Links" }
+                if let Some(href) = profile_href {
+                    a { class: "text-sm text-blue-600 hover:underline", href: "{href}", "View public profile" }
+                }
+            }
+            p { class: "text-sm text-gray-600 mb-4",
+                "Public links are shown on your profile page. Paste a profile URL or just the username."
+            }
+
+            if let Some(err) = error() {
+                p { class: "mb-3 text-sm text-red-600", "{err}" }
+            }
+
+            form { class: "flex flex-wrap gap-3 items-center mb-6", onsubmit: save,
+                select {
+                    class: "px-3 py-2 border border-gray-300 rounded",
+                    onchange: move |e| platform_id.set(e.value().parse().ok()),
+                    option { value: "", selected: platform_id().is_none(), "Platform" }
+                    for platform in platform_options {
+                        option { value: "{platform.id}", selected: platform_id() == Some(platform.id), "{platform.name}" }
+                    }
+                }
+                input {
+                    class: "flex-1 px-3 py-2 border border-gray-300 rounded",
+                    placeholder: "Username",
+                    value: "{handle}",
+                    oninput: move |e| handle.set(e.value()),
+                }
+                label { class: "text-sm text-gray-700 flex items-center gap-1",
+                    input {
+                        r#type: "checkbox",
+                        checked: is_public(),
+                        onchange: move |e| is_public.set(e.checked()),
+                    }
+                    "Public"
+                }
+                button {
+                    r#type: "submit",
+                    class: "px-4 py-2 bg-blue-600 text-white rounded hover:bg-blue-700",
+                    "Save"
+                }
+            }
+
+            if link_list.is_empty() {
+                p { class: "text-gray-500 text-sm", "No social links yet." }
+            } else {
+                ul { class: "divide-y",
+                    for link in link_list {
+                        li { key: "{link.platform_id}", class: "py-2 flex justify-between items-center",
+                            div {
+                                p { class: "text-sm text-gray-800",
+                                    span { class: "font-medium", "{link.platform_name}" }
+                                    " {link.handle}"
+                                    if !link.is_public {
+                                        span { class: "ml-2 px-2 py-0.5 text-xs rounded bg-gray-100 text-gray-700", "Hidden" }
+                                    }
+                                }
+                                p { class: "text-xs text-gray-500 break-all", "{link.url}" }
+                            }
+                            button {
+                                class: "px-3 py-1 text-sm bg-gray-200 rounded hover:bg-gray-300",
+                                onclick: move |_| {
+                                    let platform_id = link.platform_id;
+                                    spawn(async move {
+                                        match delete_social_link(platform_id).await {
+                                            Ok(()) => links.restart(),
+                                            Err(err) => error.set(Some(err.to_string())),
+                                        }
+                                    });
+                                },
+                                "Remove"
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,6 @@
+-- Drop indexes first (they depend on the table)
+DROP INDEX IF EXISTS idx_mentions_mentioned_user;
+DROP INDEX IF EXISTS idx_mentions_comment_user;
+DROP INDEX IF EXISTS idx_mentions_post_user;
+
+DROP TABLE IF EXISTS mentions;
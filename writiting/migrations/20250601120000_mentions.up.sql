@@ -0,0 +1,20 @@
+-- writiting/migrations/20250601120000_mentions.sql
+
+-- @username mentions found in post bodies and comments
+CREATE TABLE mentions (
+  id SERIAL PRIMARY KEY,
+  mentioned_user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+  author_id UUID REFERENCES users(id) ON DELETE SET NULL,
+  post_id INTEGER REFERENCES posts(id) ON DELETE CASCADE,
+  comment_id INTEGER REFERENCES post_comments(id) ON DELETE CASCADE,
+  created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+  CONSTRAINT mention_single_target CHECK ((post_id IS NULL) <> (comment_id IS NULL))
+);
+
+-- One row per mentioned user per post/comment
+CREATE UNIQUE INDEX idx_mentions_post_user ON mentions(post_id, mentioned_user_id)
+WHERE post_id IS NOT NULL;
+CREATE UNIQUE INDEX idx_mentions_comment_user ON mentions(comment_id, mentioned_user_id)
+WHERE comment_id IS NOT NULL;
+
+CREATE INDEX idx_mentions_mentioned_user ON mentions(mentioned_user_id);
@@ -0,0 +1,7 @@
+ALTER TABLE user_preferences DROP COLUMN IF EXISTS last_digest_sent_at;
+
+-- Drop indexes first (they depend on the table)
+DROP INDEX IF EXISTS idx_email_outbox_user_id;
+DROP INDEX IF EXISTS idx_email_outbox_due;
+
+DROP TABLE IF EXISTS email_outbox;
@@ -0,0 +1,26 @@
+-- writiting/migrations/20250602090000_email_outbox.sql
+
+-- Outgoing email queue, drained by the outbox worker with retries
+CREATE TABLE email_outbox (
+  id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+  user_id UUID REFERENCES users(id) ON DELETE SET NULL,
+  kind VARCHAR(30) NOT NULL
+    CHECK (kind IN ('verification', 'password_reset', 'notification_digest')),
+  to_address VARCHAR(255) NOT NULL,
+  subject TEXT NOT NULL,
+  html_body TEXT NOT NULL,
+  text_body TEXT NOT NULL,
+  status VARCHAR(20) NOT NULL DEFAULT 'pending'
+    CHECK (status IN ('pending', 'sent', 'failed')),
+  attempts INTEGER NOT NULL DEFAULT 0,
+  last_error TEXT,
+  next_attempt_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+  sent_at TIMESTAMPTZ,
+  created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_email_outbox_due ON email_outbox(next_attempt_at) WHERE status = 'pending';
+CREATE INDEX idx_email_outbox_user_id ON email_outbox(user_id);
+
+-- Track when each user last received a notification digest
+ALTER TABLE user_preferences ADD COLUMN last_digest_sent_at TIMESTAMPTZ;
@@ -0,0 +1,9 @@
+-- Drop indexes first (they depend on tables)
+DROP INDEX IF EXISTS idx_two_factor_challenges_user;
+DROP INDEX IF EXISTS idx_totp_recovery_codes_user;
+
+DROP TABLE IF EXISTS two_factor_challenges;
+DROP TABLE IF EXISTS totp_recovery_codes;
+DROP TABLE IF EXISTS user_totp;
+
+-- Postgres cannot drop enum values; the two_factor_* security_action values are left in place
@@ -0,0 +1,40 @@
+-- writiting/migrations/20250603100000_two_factor.sql
+
+-- TOTP secrets (RFC 6238). enabled_at stays NULL until the first code is confirmed
+CREATE TABLE user_totp (
+  user_id UUID PRIMARY KEY REFERENCES users(id) ON DELETE CASCADE,
+  secret TEXT NOT NULL,
+  enabled_at TIMESTAMPTZ,
+  last_used_step BIGINT,
+  created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+  updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+-- Single-use recovery codes, stored hashed
+CREATE TABLE totp_recovery_codes (
+  id SERIAL PRIMARY KEY,
+  user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+  code_hash TEXT NOT NULL,
+  used_at TIMESTAMPTZ,
+  created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_totp_recovery_codes_user ON totp_recovery_codes(user_id) WHERE used_at IS NULL;
+
+-- Pending second login step, issued once the password has been accepted
+CREATE TABLE two_factor_challenges (
+  token TEXT PRIMARY KEY,
+  user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+  attempts INTEGER NOT NULL DEFAULT 0,
+  expires_at TIMESTAMPTZ NOT NULL,
+  created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_two_factor_challenges_user ON two_factor_challenges(user_id);
+
+ALTER TYPE security_action ADD VALUE IF NOT EXISTS 'two_factor_enabled';
+ALTER TYPE security_action ADD VALUE IF NOT EXISTS 'two_factor_disabled';
+ALTER TYPE security_action ADD VALUE IF NOT EXISTS 'two_factor_challenge_passed';
+ALTER TYPE security_action ADD VALUE IF NOT EXISTS 'two_factor_challenge_failed';
+ALTER TYPE security_action ADD VALUE IF NOT EXISTS 'recovery_code_used';
+ALTER TYPE security_action ADD VALUE IF NOT EXISTS 'recovery_codes_regenerated';
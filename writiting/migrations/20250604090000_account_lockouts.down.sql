@@ -0,0 +1,7 @@
+DROP INDEX IF EXISTS idx_security_logs_login_failed_ip;
+DROP INDEX IF EXISTS idx_security_logs_login_failed_user;
+
+DROP INDEX IF EXISTS idx_account_lockouts_active;
+CREATE INDEX idx_account_lockouts_active ON account_lockouts(user_id);
+
+-- Postgres cannot drop enum values; account_locked/account_unlocked are left in place
@@ -0,0 +1,14 @@
+-- writiting/migrations/20250604090000_account_lockouts.sql
+
+-- Replace the plain user_id index with one suited to "is this account locked now?"
+DROP INDEX IF EXISTS idx_account_lockouts_active;
+CREATE INDEX idx_account_lockouts_active ON account_lockouts(user_id, locked_until DESC);
+
+-- Failed-login counting scans security_logs by account and by address
+CREATE INDEX idx_security_logs_login_failed_user ON security_logs(user_id, created_at)
+WHERE action = 'login_failed';
+CREATE INDEX idx_security_logs_login_failed_ip ON security_logs(ip_address, created_at)
+WHERE action = 'login_failed';
+
+ALTER TYPE security_action ADD VALUE IF NOT EXISTS 'account_locked';
+ALTER TYPE security_action ADD VALUE IF NOT EXISTS 'account_unlocked';
@@ -0,0 +1,4 @@
+ALTER TABLE rate_limits
+  ALTER COLUMN tokens TYPE DECIMAL,
+  ALTER COLUMN capacity TYPE DECIMAL,
+  ALTER COLUMN refill_rate TYPE DECIMAL;
@@ -0,0 +1,7 @@
+-- writiting/migrations/20250605090000_rate_limit_floats.sql
+
+-- Token buckets are refilled fractionally; store them as floats so they map to f64
+ALTER TABLE rate_limits
+  ALTER COLUMN tokens TYPE DOUBLE PRECISION,
+  ALTER COLUMN capacity TYPE DOUBLE PRECISION,
+  ALTER COLUMN refill_rate TYPE DOUBLE PRECISION;
@@ -0,0 +1,5 @@
+DROP INDEX IF EXISTS idx_api_keys_hash;
+
+ALTER TABLE api_keys
+  DROP COLUMN IF EXISTS prefix,
+  DROP COLUMN IF EXISTS key_hash;
@@ -0,0 +1,11 @@
+-- writiting/migrations/20250606090000_api_key_secrets.sql
+
+-- Keys are presented as an opaque secret; only its SHA-256 is stored.
+-- No keys could be issued before this, so any rows are placeholders.
+DELETE FROM api_keys;
+
+ALTER TABLE api_keys
+  ADD COLUMN key_hash CHAR(64) NOT NULL,
+  ADD COLUMN prefix VARCHAR(16) NOT NULL;
+
+CREATE UNIQUE INDEX idx_api_keys_hash ON api_keys(key_hash);
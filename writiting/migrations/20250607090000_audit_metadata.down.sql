@@ -0,0 +1,3 @@
+DROP INDEX IF EXISTS idx_audit_logs_action_created;
+
+ALTER TABLE audit_logs DROP COLUMN IF EXISTS metadata;
@@ -0,0 +1,7 @@
+-- writiting/migrations/20250607090000_audit_metadata.sql
+
+-- Free-form context (e.g. "via": "api") alongside the before/after snapshots
+ALTER TABLE audit_logs ADD COLUMN metadata JSONB;
+
+-- The admin viewer filters by action and date
+CREATE INDEX idx_audit_logs_action_created ON audit_logs(action, created_at DESC);
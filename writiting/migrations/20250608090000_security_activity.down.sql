@@ -0,0 +1,3 @@
+DROP INDEX IF EXISTS idx_security_logs_user_recent;
+
+-- Postgres cannot drop enum values; new_login is left in place
@@ -0,0 +1,7 @@
+-- writiting/migrations/20250608090000_security_activity.sql
+
+-- Sent when an account signs in from an address or device it hasn't used before
+ALTER TYPE notification_type ADD VALUE IF NOT EXISTS 'new_login';
+
+-- "Recent security activity" and new-login checks read a user's newest events
+CREATE INDEX idx_security_logs_user_recent ON security_logs(user_id, action, created_at DESC);
@@ -0,0 +1,18 @@
+-- writiting/migrations/20250609090000_feature_flags.sql
+
+CREATE TABLE feature_flags (
+  name VARCHAR(100) PRIMARY KEY CHECK (name ~ '^[a-z0-9_.-]+$'),
+  description TEXT,
+  is_enabled BOOLEAN NOT NULL DEFAULT false,
+  rollout_percentage INTEGER NOT NULL DEFAULT 0 CHECK (rollout_percentage BETWEEN 0 AND 100),
+  -- Gradual rollouts grow by this much each day from rollout_started_at; 0 keeps the percentage fixed
+  increment_per_day INTEGER NOT NULL DEFAULT 0 CHECK (increment_per_day BETWEEN 0 AND 100),
+  target_users UUID[] NOT NULL DEFAULT '{}',
+  rollout_started_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+  created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+  updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+-- Flags guarding features that already ship, so nothing disappears on upgrade
+INSERT INTO feature_flags (name, description, is_enabled, rollout_percentage) VALUES
+  ('ai_chat', 'AI chat assistant in the navigation menu', true, 100);
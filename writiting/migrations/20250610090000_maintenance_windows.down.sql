@@ -0,0 +1,5 @@
+DELETE FROM role_permissions WHERE role_id = 2 AND permission_id = 902;
+
+DROP INDEX IF EXISTS idx_maintenance_windows_ends_at;
+
+DROP TABLE IF EXISTS maintenance_windows;
@@ -0,0 +1,19 @@
+-- writiting/migrations/20250610090000_maintenance_windows.sql
+
+CREATE TABLE maintenance_windows (
+  id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+  starts_at TIMESTAMPTZ NOT NULL,
+  ends_at TIMESTAMPTZ NOT NULL,
+  description TEXT NOT NULL CHECK (LENGTH(description) BETWEEN 10 AND 1000),
+  created_by UUID REFERENCES users(id) ON DELETE SET NULL,
+  created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+  CONSTRAINT maintenance_window_order CHECK (ends_at > starts_at)
+);
+
+-- Status checks look for windows that haven't ended yet
+CREATE INDEX idx_maintenance_windows_ends_at ON maintenance_windows(ends_at);
+
+-- Admins schedule windows and keep working while one is running
+INSERT INTO role_permissions (role_id, permission_id) VALUES
+    (2, 902)   -- system:maintenance
+ON CONFLICT DO NOTHING;
@@ -0,0 +1,9 @@
+DROP INDEX IF EXISTS idx_database_slow_queries_created_at;
+DROP INDEX IF EXISTS idx_database_health_samples_created_at;
+DROP INDEX IF EXISTS idx_database_connections_created_at;
+
+DROP TABLE IF EXISTS database_slow_queries;
+DROP TABLE IF EXISTS database_health_samples;
+DROP TABLE IF EXISTS database_connections;
+
+DROP TYPE IF EXISTS database_health_level;
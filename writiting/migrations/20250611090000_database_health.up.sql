@@ -0,0 +1,46 @@
+-- writiting/migrations/20250611090000_database_health.sql
+
+CREATE TYPE database_health_level AS ENUM ('normal', 'warning', 'critical');
+
+-- Server connections per application, captured by each health check
+CREATE TABLE database_connections (
+  id BIGSERIAL PRIMARY KEY,
+  application_name TEXT NOT NULL,
+  connection_count INTEGER NOT NULL,
+  max_connections INTEGER NOT NULL,
+  created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+-- One row per health check, for the admin dashboard history
+CREATE TABLE database_health_samples (
+  id BIGSERIAL PRIMARY KEY,
+  level database_health_level NOT NULL,
+  message TEXT,
+  server_connections INTEGER NOT NULL,
+  max_connections INTEGER NOT NULL,
+  pool_size INTEGER NOT NULL,
+  pool_idle INTEGER NOT NULL,
+  pool_max INTEGER NOT NULL,
+  -- Time taken to get a connection from the pool, and to run a trivial query on it
+  acquire_ms DOUBLE PRECISION NOT NULL,
+  ping_ms DOUBLE PRECISION NOT NULL,
+  slow_queries INTEGER NOT NULL DEFAULT 0,
+  created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+-- Statements seen running past the slow-query threshold; one row per execution
+CREATE TABLE database_slow_queries (
+  id BIGSERIAL PRIMARY KEY,
+  pid INTEGER NOT NULL,
+  query_start TIMESTAMPTZ NOT NULL,
+  duration_ms DOUBLE PRECISION NOT NULL,
+  application_name TEXT NOT NULL,
+  query TEXT NOT NULL,
+  created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+  CONSTRAINT uq_slow_query_execution UNIQUE (pid, query_start)
+);
+
+-- Retention deletes by age
+CREATE INDEX idx_database_connections_created_at ON database_connections(created_at);
+CREATE INDEX idx_database_health_samples_created_at ON database_health_samples(created_at);
+CREATE INDEX idx_database_slow_queries_created_at ON database_slow_queries(created_at);
@@ -0,0 +1,7 @@
+DROP INDEX IF EXISTS idx_users_last_login_at;
+DROP INDEX IF EXISTS idx_users_created_at;
+
+DROP INDEX IF EXISTS idx_sessions_impersonator;
+ALTER TABLE sessions DROP COLUMN IF EXISTS impersonator_id;
+
+-- Postgres cannot drop enum values; the impersonation and deactivation actions are left in place
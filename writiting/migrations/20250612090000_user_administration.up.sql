@@ -0,0 +1,14 @@
+-- writiting/migrations/20250612090000_user_administration.sql
+
+-- Sessions opened by an admin acting as another user point back at the admin
+ALTER TABLE sessions ADD COLUMN impersonator_id UUID REFERENCES users(id) ON DELETE CASCADE;
+CREATE INDEX idx_sessions_impersonator ON sessions(impersonator_id) WHERE impersonator_id IS NOT NULL;
+
+-- The admin user list searches, filters and sorts on these
+CREATE INDEX idx_users_created_at ON users(created_at DESC);
+CREATE INDEX idx_users_last_login_at ON users(last_login_at DESC NULLS LAST);
+
+ALTER TYPE security_action ADD VALUE IF NOT EXISTS 'impersonation_started';
+ALTER TYPE security_action ADD VALUE IF NOT EXISTS 'impersonation_ended';
+ALTER TYPE security_action ADD VALUE IF NOT EXISTS 'account_deactivated';
+ALTER TYPE security_action ADD VALUE IF NOT EXISTS 'account_reactivated';
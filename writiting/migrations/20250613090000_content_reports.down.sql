@@ -0,0 +1,18 @@
+DELETE FROM role_permissions WHERE permission_id = 205;
+DELETE FROM permissions WHERE id = 205;
+
+DROP INDEX IF EXISTS idx_moderation_notes_report;
+DROP TABLE IF EXISTS moderation_notes;
+
+DROP INDEX IF EXISTS idx_content_reports_assigned;
+DROP INDEX IF EXISTS idx_content_reports_target;
+DROP INDEX IF EXISTS idx_content_reports_queue;
+DROP INDEX IF EXISTS idx_content_reports_open_per_reporter;
+DROP TABLE IF EXISTS content_reports;
+
+ALTER TABLE posts DROP COLUMN IF EXISTS is_published;
+
+DROP TYPE IF EXISTS moderation_action;
+DROP TYPE IF EXISTS report_status;
+DROP TYPE IF EXISTS report_reason;
+DROP TYPE IF EXISTS report_target;
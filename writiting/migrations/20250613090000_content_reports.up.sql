@@ -0,0 +1,57 @@
+-- writiting/migrations/20250613090000_content_reports.sql
+
+CREATE TYPE report_target AS ENUM ('post', 'comment', 'profile');
+CREATE TYPE report_reason AS ENUM (
+  'spam', 'harassment', 'hate_speech', 'violence', 'sexual_content', 'misinformation', 'copyright', 'other'
+);
+CREATE TYPE report_status AS ENUM ('open', 'resolved', 'dismissed');
+CREATE TYPE moderation_action AS ENUM ('none', 'hide_comment', 'unpublish_post', 'suspend_user');
+
+-- Moderators can take a post down without deleting it
+ALTER TABLE posts ADD COLUMN is_published BOOLEAN NOT NULL DEFAULT true;
+
+CREATE TABLE content_reports (
+  id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+  reporter_id UUID REFERENCES users(id) ON DELETE SET NULL,
+  target_type report_target NOT NULL,
+  -- Post or comment id, or the profile owner's user id
+  target_id TEXT NOT NULL,
+  -- Who wrote the reported content
+  target_user_id UUID REFERENCES users(id) ON DELETE SET NULL,
+  reason report_reason NOT NULL,
+  details TEXT CHECK (LENGTH(details) <= 1000),
+  -- What the reporter saw, in case the content is edited or removed
+  content_snapshot TEXT,
+  status report_status NOT NULL DEFAULT 'open',
+  assigned_to UUID REFERENCES users(id) ON DELETE SET NULL,
+  resolution moderation_action,
+  resolved_by UUID REFERENCES users(id) ON DELETE SET NULL,
+  resolved_at TIMESTAMPTZ,
+  created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+  updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+-- One open report per person per item
+CREATE UNIQUE INDEX idx_content_reports_open_per_reporter
+ON content_reports(reporter_id, target_type, target_id) WHERE status = 'open';
+CREATE INDEX idx_content_reports_queue ON content_reports(status, created_at);
+CREATE INDEX idx_content_reports_target ON content_reports(target_type, target_id);
+CREATE INDEX idx_content_reports_assigned ON content_reports(assigned_to) WHERE status = 'open';
+
+CREATE TABLE moderation_notes (
+  id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+  report_id UUID NOT NULL REFERENCES content_reports(id) ON DELETE CASCADE,
+  author_id UUID REFERENCES users(id) ON DELETE SET NULL,
+  body TEXT NOT NULL CHECK (LENGTH(TRIM(body)) > 0 AND LENGTH(body) <= 2000),
+  created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_moderation_notes_report ON moderation_notes(report_id, created_at);
+
+INSERT INTO permissions (id, code, description, category) VALUES
+    (205, 'content:moderate', 'Review reported content', 'content');
+
+INSERT INTO role_permissions (role_id, permission_id) VALUES
+    (1, 205),
+    (2, 205),
+    (3, 205);
@@ -0,0 +1,27 @@
+-- writiting/migrations/20250614090000_app_settings.sql
+
+-- One row per setting. value_type is the jsonb_typeof() every value must have;
+-- null is allowed for optional settings.
+CREATE TABLE app_settings (
+  key VARCHAR(100) PRIMARY KEY CHECK (key ~ '^[a-z0-9_.]+$'),
+  value JSONB NOT NULL,
+  default_value JSONB NOT NULL,
+  value_type VARCHAR(20) NOT NULL CHECK (value_type IN ('string', 'number', 'boolean', 'array')),
+  description TEXT,
+  updated_by UUID REFERENCES users(id) ON DELETE SET NULL,
+  updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+  CHECK (jsonb_typeof(value) IN (value_type, 'null')),
+  CHECK (jsonb_typeof(default_value) IN (value_type, 'null'))
+);
+
+INSERT INTO app_settings (key, value, default_value, value_type, description) VALUES
+  ('site.name', '"Writiting"', '"Writiting"', 'string', 'Shown in the header and in emails'),
+  ('site.admin_email', 'null', 'null', 'string', 'Where contact and abuse mail goes'),
+  ('registration.mode', '"open"', '"open"', 'string', 'open, closed or invite_only'),
+  ('posts.default_visibility', '"public"', '"public"', 'string', 'Whether new posts are published straight away (public) or kept as drafts (private)'),
+  ('ai.provider', '"ollama"', '"ollama"', 'string', 'ollama, openai or anthropic'),
+  ('ai.model', '"llama3"', '"llama3"', 'string', 'Model name passed to the provider'),
+  ('ai.base_url', '"http://localhost:11434"', '"http://localhost:11434"', 'string', 'Provider API address'),
+  ('uploads.max_size_mb', '10', '10', 'number', 'Largest accepted upload in megabytes'),
+  ('uploads.allowed_types', '["image/png", "image/jpeg", "image/gif", "image/webp"]',
+    '["image/png", "image/jpeg", "image/gif", "image/webp"]', 'array', 'MIME types users may upload');
@@ -0,0 +1,7 @@
+DROP INDEX IF EXISTS idx_users_invited_by;
+ALTER TABLE users DROP COLUMN IF EXISTS invited_by;
+
+DROP TABLE IF EXISTS invitation_redemptions;
+
+DROP INDEX IF EXISTS idx_invitations_created_by;
+DROP TABLE IF EXISTS invitations;
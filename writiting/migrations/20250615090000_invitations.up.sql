@@ -0,0 +1,32 @@
+-- writiting/migrations/20250615090000_invitations.sql
+
+CREATE TABLE invitations (
+  id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+  code VARCHAR(32) NOT NULL UNIQUE,
+  created_by UUID REFERENCES users(id) ON DELETE CASCADE,
+  -- When set, only this address can use the invitation
+  email VARCHAR(255),
+  -- Role given to whoever signs up with it; NULL means the default role
+  role_id INTEGER REFERENCES roles(id) ON DELETE SET NULL,
+  -- NULL means unlimited
+  max_uses INTEGER CHECK (max_uses IS NULL OR max_uses > 0),
+  use_count INTEGER NOT NULL DEFAULT 0,
+  note TEXT,
+  expires_at TIMESTAMPTZ,
+  revoked_at TIMESTAMPTZ,
+  created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+  CHECK (max_uses IS NULL OR use_count <= max_uses)
+);
+
+CREATE INDEX idx_invitations_created_by ON invitations(created_by, created_at DESC);
+
+CREATE TABLE invitation_redemptions (
+  invitation_id UUID NOT NULL REFERENCES invitations(id) ON DELETE CASCADE,
+  user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+  redeemed_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+  PRIMARY KEY (invitation_id, user_id)
+);
+
+-- Who brought each account in, kept even if the invitation is deleted
+ALTER TABLE users ADD COLUMN invited_by UUID REFERENCES users(id) ON DELETE SET NULL;
+CREATE INDEX idx_users_invited_by ON users(invited_by) WHERE invited_by IS NOT NULL;
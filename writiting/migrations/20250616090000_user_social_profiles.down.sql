@@ -0,0 +1,15 @@
+DROP INDEX IF EXISTS idx_posts_user_published;
+
+CREATE OR REPLACE VIEW user_social_profiles AS
+SELECT 
+  u.id AS user_id,
+  u.username,
+  jsonb_object_agg(smp.name, smp.base_url || usl.username) AS social_profiles
+FROM 
+  users u
+LEFT JOIN 
+  user_social_links usl ON u.id = usl.user_id AND usl.is_public = true
+LEFT JOIN 
+  social_media_platforms smp ON usl.platform_id = smp.id
+GROUP BY 
+  u.id, u.username;
@@ -0,0 +1,21 @@
+-- writiting/migrations/20250616090000_user_social_profiles.sql
+
+-- jsonb_object_agg fails on the NULL key a user without public links produces
+CREATE OR REPLACE VIEW user_social_profiles AS
+SELECT
+  u.id AS user_id,
+  u.username,
+  COALESCE(
+    jsonb_object_agg(smp.name, smp.base_url || usl.username) FILTER (WHERE smp.id IS NOT NULL),
+    '{}'::jsonb
+  ) AS social_profiles
+FROM
+  users u
+LEFT JOIN
+  user_social_links usl ON u.id = usl.user_id AND usl.is_public = true
+LEFT JOIN
+  social_media_platforms smp ON usl.platform_id = smp.id
+GROUP BY
+  u.id, u.username;
+
+CREATE INDEX IF NOT EXISTS idx_posts_user_published ON posts(user_id, created_at DESC) WHERE is_published;
@@ -0,0 +1,10 @@
+DELETE FROM app_settings WHERE key = 'uploads.user_quota_mb';
+
+UPDATE profiles SET avatar_url = NULL WHERE avatar_url LIKE '/media/%';
+ALTER TABLE profiles DROP CONSTRAINT IF EXISTS profiles_avatar_url_check;
+ALTER TABLE profiles ADD CONSTRAINT profiles_avatar_url_check CHECK (avatar_url ~ '^https?://');
+
+DROP INDEX IF EXISTS idx_media_hash;
+DROP INDEX IF EXISTS idx_media_user_id;
+DROP TABLE IF EXISTS media;
+DROP TYPE IF EXISTS media_usage;
@@ -0,0 +1,30 @@
+-- writiting/migrations/20250617090000_media.sql
+
+CREATE TYPE media_usage AS ENUM ('avatar', 'post');
+
+-- One row per user and file. Stored files are named by the SHA-256 of their
+-- content, so identical uploads share one file in storage.
+CREATE TABLE media (
+  id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+  user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+  hash CHAR(64) NOT NULL CHECK (hash ~ '^[0-9a-f]{64}$'),
+  content_type VARCHAR(100) NOT NULL,
+  -- Size of the stored file after EXIF stripping, counted against the quota
+  byte_size BIGINT NOT NULL CHECK (byte_size > 0),
+  width INTEGER NOT NULL CHECK (width > 0),
+  height INTEGER NOT NULL CHECK (height > 0),
+  original_name VARCHAR(255),
+  usage media_usage NOT NULL,
+  created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+  CONSTRAINT uq_media_user_hash UNIQUE (user_id, hash)
+);
+
+CREATE INDEX idx_media_user_id ON media(user_id, created_at DESC);
+CREATE INDEX idx_media_hash ON media(hash);
+
+-- Avatars can now point at an upload served by the app
+ALTER TABLE profiles DROP CONSTRAINT IF EXISTS profiles_avatar_url_check;
+ALTER TABLE profiles ADD CONSTRAINT profiles_avatar_url_check CHECK (avatar_url ~ '^(https?://|/media/)');
+
+INSERT INTO app_settings (key, value, default_value, value_type, description) VALUES
+  ('uploads.user_quota_mb', '200', '200', 'number', 'Total size of the media each user may keep, in megabytes');
@@ -0,0 +1,17 @@
+DROP INDEX IF EXISTS idx_users_username_trgm;
+DROP INDEX IF EXISTS idx_posts_title_trgm;
+DROP INDEX IF EXISTS idx_post_comments_search;
+DROP INDEX IF EXISTS idx_posts_search;
+
+ALTER TABLE post_comments DROP COLUMN IF EXISTS search_vector;
+ALTER TABLE posts DROP COLUMN IF EXISTS search_vector;
+
+DROP TRIGGER IF EXISTS set_comment_search_config ON post_comments;
+DROP TRIGGER IF EXISTS set_post_search_config ON posts;
+DROP FUNCTION IF EXISTS set_search_config();
+
+ALTER TABLE post_comments DROP COLUMN IF EXISTS search_config;
+ALTER TABLE posts DROP COLUMN IF EXISTS search_config;
+
+DROP FUNCTION IF EXISTS search_config_for(TEXT);
+-- pg_trgm is left installed; other databases on the server may use it
@@ -0,0 +1,61 @@
+-- writiting/migrations/20250618090000_search.sql
+
+CREATE EXTENSION IF NOT EXISTS pg_trgm;
+
+-- Text search configuration for a `user_preferences.language` code. Japanese
+-- and Chinese have no built-in stemmer, so they are only split into words.
+CREATE OR REPLACE FUNCTION search_config_for(language TEXT)
+RETURNS regconfig AS $$
+  SELECT CASE
+    WHEN language = 'es' THEN 'spanish'
+    WHEN language = 'fr' THEN 'french'
+    WHEN language = 'de' THEN 'german'
+    WHEN language IN ('ja', 'zh') THEN 'simple'
+    ELSE 'english'
+  END::regconfig
+$$ LANGUAGE sql IMMUTABLE;
+
+-- Posts and comments are stemmed in their author's language at the time of writing
+ALTER TABLE posts ADD COLUMN search_config regconfig NOT NULL DEFAULT 'english';
+ALTER TABLE post_comments ADD COLUMN search_config regconfig NOT NULL DEFAULT 'english';
+
+UPDATE posts p SET search_config = search_config_for(up.language)
+FROM user_preferences up WHERE up.user_id = p.user_id;
+UPDATE post_comments c SET search_config = search_config_for(up.language)
+FROM user_preferences up WHERE up.user_id = c.user_id;
+
+CREATE OR REPLACE FUNCTION set_search_config()
+RETURNS TRIGGER AS $$
+BEGIN
+    NEW.search_config = search_config_for(
+        (SELECT language FROM user_preferences WHERE user_id = NEW.user_id)
+    );
+    RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER set_post_search_config
+BEFORE INSERT ON posts
+FOR EACH ROW
+EXECUTE FUNCTION set_search_config();
+
+CREATE TRIGGER set_comment_search_config
+BEFORE INSERT ON post_comments
+FOR EACH ROW
+EXECUTE FUNCTION set_search_config();
+
+-- Title matches rank above body matches
+ALTER TABLE posts ADD COLUMN search_vector tsvector GENERATED ALWAYS AS (
+  setweight(to_tsvector(search_config, title), 'A') ||
+  setweight(to_tsvector(search_config, body), 'B')
+) STORED;
+ALTER TABLE post_comments ADD COLUMN search_vector tsvector GENERATED ALWAYS AS (
+  to_tsvector(search_config, content)
+) STORED;
+
+CREATE INDEX idx_posts_search ON posts USING GIN (search_vector);
+CREATE INDEX idx_post_comments_search ON post_comments USING GIN (search_vector);
+
+-- Typo-tolerant matching on titles and usernames
+CREATE INDEX idx_posts_title_trgm ON posts USING GIN (title gin_trgm_ops);
+CREATE INDEX idx_users_username_trgm ON users USING GIN (username gin_trgm_ops);
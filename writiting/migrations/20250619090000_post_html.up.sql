@@ -0,0 +1,14 @@
+-- writiting/migrations/20250619090000_post_html.sql
+
+-- Sanitized HTML rendered from each post's Markdown body. A row is only used
+-- while the body's md5 and the renderer version still match; otherwise the
+-- post is rendered again on its next view and the row replaced.
+CREATE TABLE post_html (
+  post_id INTEGER PRIMARY KEY REFERENCES posts(id) ON DELETE CASCADE,
+  body_md5 CHAR(32) NOT NULL,
+  renderer_version INTEGER NOT NULL,
+  html TEXT NOT NULL,
+  -- Headings for the table of contents: [{"level": 2, "id": "...", "title": "..."}]
+  toc JSONB NOT NULL DEFAULT '[]',
+  rendered_at TIMESTAMPTZ(0) NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
@@ -0,0 +1,12 @@
+-- writiting/migrations/20250620090000_email_outbox_sending.sql
+
+-- The worker marks emails 'sending' and commits before talking to the mail
+-- server, so a failed batch can't roll back emails that already went out.
+-- next_attempt_at doubles as the claim's expiry, after which an email left
+-- 'sending' by a crashed worker is picked up again.
+ALTER TABLE email_outbox DROP CONSTRAINT email_outbox_status_check;
+ALTER TABLE email_outbox ADD CONSTRAINT email_outbox_status_check
+  CHECK (status IN ('pending', 'sending', 'sent', 'failed'));
+
+DROP INDEX idx_email_outbox_due;
+CREATE INDEX idx_email_outbox_due ON email_outbox(next_attempt_at) WHERE status IN ('pending', 'sending');
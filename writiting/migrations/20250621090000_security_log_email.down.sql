@@ -0,0 +1,3 @@
+-- writiting/migrations/20250621090000_security_log_email.sql
+
+DROP INDEX idx_security_logs_unknown_email;
@@ -0,0 +1,7 @@
+-- writiting/migrations/20250621090000_security_log_email.sql
+
+-- Failed sign-ins for unknown email addresses are counted, and locked out, by
+-- the email in their metadata
+CREATE INDEX idx_security_logs_unknown_email
+  ON security_logs (LOWER(metadata->>'email'), created_at)
+  WHERE user_id IS NULL;
@@ -0,0 +1 @@
+ALTER TABLE post_html DROP COLUMN mentions;
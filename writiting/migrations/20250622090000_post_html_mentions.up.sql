@@ -0,0 +1,6 @@
+-- writiting/migrations/20250622090000_post_html_mentions.sql
+
+-- The mentioned usernames that resolved to accounts when a post was rendered.
+-- A cached row is only used while they still do, so renamed or deleted
+-- accounts don't keep their profile links.
+ALTER TABLE post_html ADD COLUMN mentions TEXT[] NOT NULL DEFAULT '{}';
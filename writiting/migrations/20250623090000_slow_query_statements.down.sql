@@ -0,0 +1,15 @@
+DROP INDEX IF EXISTS idx_database_slow_queries_created_at;
+DROP TABLE IF EXISTS database_slow_queries;
+DROP TABLE IF EXISTS database_statement_counters;
+
+CREATE TABLE database_slow_queries (
+  id BIGSERIAL PRIMARY KEY,
+  pid INTEGER NOT NULL,
+  query_start TIMESTAMPTZ NOT NULL,
+  duration_ms DOUBLE PRECISION NOT NULL,
+  application_name TEXT NOT NULL,
+  query TEXT NOT NULL,
+  created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+  CONSTRAINT uq_slow_query_execution UNIQUE (pid, query_start)
+);
+CREATE INDEX idx_database_slow_queries_created_at ON database_slow_queries(created_at);
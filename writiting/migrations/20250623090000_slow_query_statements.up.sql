@@ -0,0 +1,34 @@
+-- writiting/migrations/20250623090000_slow_query_statements.sql
+
+-- Slow queries now come from pg_stat_statements, which sees every execution,
+-- instead of whatever pg_stat_activity happened to show during a check. The
+-- extension also has to be in shared_preload_libraries; until it is, or if
+-- this role can't create it, the slow query list stays empty.
+DO $$
+BEGIN
+  CREATE EXTENSION IF NOT EXISTS pg_stat_statements;
+EXCEPTION WHEN insufficient_privilege THEN
+  RAISE NOTICE 'pg_stat_statements needs a superuser to install';
+END $$;
+
+-- pg_stat_statements' running totals per statement as of the last check, so
+-- each check can work out what ran since the one before
+CREATE TABLE database_statement_counters (
+  queryid BIGINT PRIMARY KEY,
+  calls BIGINT NOT NULL,
+  total_ms DOUBLE PRECISION NOT NULL
+);
+
+-- Statements whose executions between two checks averaged past the
+-- slow-query threshold; one row per statement per check
+DROP TABLE database_slow_queries;
+CREATE TABLE database_slow_queries (
+  id BIGSERIAL PRIMARY KEY,
+  queryid BIGINT NOT NULL,
+  calls BIGINT NOT NULL,
+  total_ms DOUBLE PRECISION NOT NULL,
+  query TEXT NOT NULL,
+  created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_database_slow_queries_created_at ON database_slow_queries(created_at);
@@ -0,0 +1,425 @@
+## Navigation
+
+nav-home = Startseite
+nav-blog = Blog
+nav-editor = Editor
+nav-focus-mode = Fokusmodus
+nav-login = Anmelden
+nav-register = Registrieren
+nav-reset-password = Passwort zurücksetzen
+nav-admin-dashboard = Admin-Dashboard
+nav-admin-users = Admin: Benutzer
+nav-admin-reports = Admin: Meldungen
+nav-admin-settings = Admin: Einstellungen
+nav-terms = Nutzungsbedingungen
+nav-privacy = Datenschutzerklärung
+nav-ai-chat = KI-Chat
+
+## Avatar menu
+
+menu-profile = Profil
+menu-toggle-theme = Design wechseln
+menu-help = Hilfe-Center
+menu-sign-out = Abmelden
+
+common-loading = Wird geladen...
+
+## Appearance settings
+
+appearance-title = Darstellung
+appearance-theme-light = Hell
+appearance-theme-dark = Dunkel
+appearance-theme-system = System
+appearance-system-hint = Folgt der Hell-/Dunkel-Einstellung deines Systems.
+appearance-language = Sprache
+
+## Uploads
+
+upload-add-image = Bild hinzufügen
+upload-uploading = Wird hochgeladen...
+upload-unreadable = Die Datei konnte nicht gelesen werden
+media-title = Uploads
+media-upload-image = Bild hochladen
+media-quota = { $used } von { $limit } belegt
+media-empty = Du hast noch nichts hochgeladen.
+media-delete = Löschen
+
+## Public profiles
+
+profile-not-found = Profil nicht gefunden.
+profile-joined = @{ $username } · dabei seit { $date }
+profile-followers =
+    { $count ->
+        [one] { $count } Follower
+       *[other] { $count } Follower
+    }
+profile-following = folgt { $count }
+profile-posts = Beiträge
+profile-no-posts = Noch nichts veröffentlicht.
+
+## Search
+
+search-placeholder = Beiträge, Kommentare und Personen suchen...
+search-button = Suchen
+search-title = Ergebnisse für „{ $query }“
+search-count =
+    { $count ->
+        [one] { $count } Ergebnis
+       *[other] { $count } Ergebnisse
+    }
+search-no-results = Keine Treffer für diese Suche.
+search-kind-all = Alles
+search-kind-post = Beiträge
+search-kind-comment = Kommentare
+search-kind-user = Personen
+search-author = Autor
+search-from = Von
+search-to = Bis
+search-comment-on = Kommentar zu „{ $title }“
+search-by = von @{ $author }
+search-previous = Zurück
+search-next = Weiter
+search-page = Seite { $page } von { $pages }
+
+## Find and replace
+
+find-replace-title = Suchen und Ersetzen
+find-replace-close = Schließen
+find-replace-find = Suchen
+find-replace-replace = Ersetzen durch
+find-replace-match-case = Groß-/Kleinschreibung
+find-replace-whole-word = Ganzes Wort
+find-replace-regex = Regex
+find-replace-scope-document = Dieses Dokument
+find-replace-scope-manuscript = Ganzes Manuskript
+find-replace-invalid-regex = Ungültiger regulärer Ausdruck: { $error }
+find-replace-no-matches = Keine Treffer.
+find-replace-count =
+    { $count ->
+        [one] { $count } Treffer
+       *[other] { $count } Treffer
+    } in { $documents ->
+        [one] { $documents } Dokument
+       *[other] { $documents } Dokumenten
+    }
+find-replace-include = Diesen Treffer ersetzen
+find-replace-line = Zeile { $line }
+find-replace-apply = { $count } ersetzen
+find-replace-undo = Ersetzen rückgängig machen
+find-replace-undone = Ersetzen rückgängig gemacht.
+find-replace-summary = „{ $query }“ durch „{ $replacement }“ ersetzt ({ $count } in { $documents ->
+        [one] { $documents } Dokument
+       *[other] { $documents } Dokumenten
+    })
+
+## Revisions
+
+revision-title = Revisionen
+revision-empty = Noch keine Revisionen.
+revision-nothing-to-undo = Es gibt kein Ersetzen, das rückgängig gemacht werden kann.
+revision-edited-since = „{ $title }“ wurde seit dem Ersetzen bearbeitet und kann daher nicht zurückgesetzt werden.
+revision-undo-summary = Rückgängig: { $summary }
+
+## Markdown
+
+markdown-contents = Inhalt
+markdown-preview = Vorschau
+markdown-preview-empty = Noch nichts für die Vorschau.
+
+## Posts
+
+post-not-found = Beitrag nicht gefunden.
+post-published = Veröffentlicht am { $date }
+blog-previous = Zurück
+blog-next = Weiter
+
+## Sign in and registration
+
+auth-email = E-Mail-Adresse
+auth-password = Passwort
+auth-sign-in = Anmelden
+auth-signing-in = Anmeldung...
+auth-remember-password = Passwort wieder eingefallen?
+auth-fill-all-fields = Bitte alle Felder ausfüllen
+auth-enter-email = Bitte E-Mail-Adresse eingeben
+auth-invalid-email = Bitte eine gültige E-Mail-Adresse eingeben
+auth-passwords-mismatch = Die Passwörter stimmen nicht überein
+auth-password-too-short = Das Passwort muss mindestens 8 Zeichen lang sein
+login-title = Bei deinem Konto anmelden
+login-no-account = Noch kein Konto?
+login-sign-up = Registrieren
+login-locked = Zu viele fehlgeschlagene Anmeldeversuche. Versuche es nach { $time } Uhr am { $date } erneut.
+login-not-verified = Bitte bestätige deine E-Mail-Adresse, bevor du dich anmeldest
+login-resend = Bestätigungs-E-Mail erneut senden
+login-resent = Falls dieses Konto bestätigt werden muss, ist ein neuer Link unterwegs.
+login-two-factor-hint = Gib den 6-stelligen Code aus deiner Authenticator-App oder einen deiner Wiederherstellungscodes ein.
+login-code = Authentifizierungscode
+login-verify = Bestätigen
+login-verifying = Wird bestätigt...
+login-remember-me = Angemeldet bleiben
+login-forgot = Passwort vergessen?
+register-title = Neues Konto erstellen
+register-have-account = Schon ein Konto?
+register-closed = Die Registrierung ist derzeit geschlossen.
+register-invited-by = { $name } hat dich eingeladen.
+register-invited = Du wurdest eingeladen.
+register-created = Konto erstellt. In deinem Posteingang findest du einen Link zur Bestätigung deiner E-Mail-Adresse.
+register-username = Benutzername
+register-confirm-password = Passwort bestätigen
+register-invite-code = Einladungscode
+register-invite-code-optional = Einladungscode (optional)
+register-submit = Registrieren
+register-submitting = Konto wird erstellt...
+reset-title = Passwort zurücksetzen
+reset-hint = Gib deine E-Mail-Adresse ein, und wir senden dir einen Link zum Zurücksetzen deines Passworts.
+reset-sent = Falls ein Konto für { $email } existiert, haben wir einen Link zum Zurücksetzen gesendet. Bitte prüfe deine E-Mails.
+reset-failed = Etwas ist schiefgelaufen. Bitte versuche es erneut.
+reset-submit = Link senden
+reset-submitting = Wird gesendet...
+new-password-title = Neues Passwort wählen
+new-password-label = Neues Passwort
+new-password-confirm = Neues Passwort bestätigen
+new-password-invalid-link = Dieser Link ist ungültig oder abgelaufen
+new-password-changed = Dein Passwort wurde geändert und du wurdest überall abgemeldet. Melde dich mit dem neuen Passwort an.
+new-password-submit = Passwort ändern
+new-password-submitting = Wird geändert...
+verify-title = E-Mail-Adresse bestätigen
+verify-ready = Bereit zum Schreiben?
+verify-checking = Link wird geprüft...
+verify-done = Danke! { $email } ist jetzt bestätigt.
+verify-invalid-link = Dieser Bestätigungslink ist ungültig oder abgelaufen
+
+## Editor
+
+editor-save = Speichern
+editor-words =
+    { $count ->
+        [one] { $count } Wort
+       *[other] { $count } Wörter
+    }
+editor-selected = Ausgewählt: { $document }
+
+## Shared labels
+
+common-previous = Zurück
+common-next = Weiter
+common-page = Seite { $page } von { $pages }
+common-name = Name
+common-description = Beschreibung
+common-save = Speichern
+common-cancel = Abbrechen
+common-edit = Bearbeiten
+common-delete = Löschen
+
+## Administration
+
+admin-welcome = Willkommen im Admin-Dashboard!
+admin-welcome-hint = Hier kannst du Benutzer verwalten, Meldungen ansehen und Verwaltungsaufgaben erledigen.
+admin-total-users = Benutzer gesamt
+admin-reported-content = Gemeldete Inhalte
+admin-active-sessions = Aktive Sitzungen
+admin-manage-users = Benutzer verwalten
+admin-view-reports = Meldungen ansehen
+admin-settings = Einstellungen
+admin-user-filter = Benutzername oder E-Mail
+admin-col-when = Wann
+admin-col-user = Benutzer
+admin-col-ip = IP
+audit-title = Audit-Log
+audit-subtitle = Wer hat was wann geändert
+audit-all-entities = Alle Objekte
+audit-all-actions = Alle Aktionen
+audit-export = CSV exportieren
+audit-download = { $file } herunterladen
+audit-col-action = Aktion
+audit-col-entity = Objekt
+audit-col-changed = Geändert
+audit-count =
+    { $count ->
+        [one] { $count } Eintrag
+       *[other] { $count } Einträge
+    }
+audit-system = System
+audit-before = Vorher
+audit-after = Nachher
+security-title = Sicherheitsprotokoll
+security-subtitle = Anmeldungen, Passwortänderungen und andere Kontoereignisse auf der ganzen Seite
+security-all-events = Alle Ereignisse
+security-ip-filter = IP oder CIDR, z. B. 203.0.113.0/24
+security-col-event = Ereignis
+security-col-details = Details
+security-count =
+    { $count ->
+        [one] { $count } Ereignis
+       *[other] { $count } Ereignisse
+    }
+flags-title = Feature-Flags
+flags-subtitle = Schalte Funktionen für alle, einen Teil der Benutzer oder ausgewählte Konten ein
+flags-new = Neues Flag
+flags-bad-user-id = { $id } ist keine Benutzer-ID
+flags-name-placeholder = z. B. markdown_preview
+flags-rollout = Rollout-Anteil in Prozent (Startwert bei schrittweisem Rollout)
+flags-increment = Zunahme pro Tag (0 hält den Anteil fest)
+flags-targets = Immer an für diese Benutzer-IDs
+flags-enabled = Aktiviert
+flags-col-flag = Flag
+flags-col-status = Status
+flags-col-reaching = Reichweite
+flags-col-targets = Ausgewählte Benutzer
+flags-status-off = Aus
+flags-status-on = An
+flags-status-gradual = Schrittweise, ab { $from } % +{ $step } %/Tag
+invitations-title = Einladungen
+invitations-subtitle = Alle Einladungen der Seite. Einladungen von hier können eine Rolle festlegen und haben keine Grenzen.
+invitations-email = Nur für diese E-Mail (optional)
+invitations-default-role = Standardrolle
+invitations-note = Notiz (optional)
+invitations-uses = Verwendungen (leer für unbegrenzt)
+invitations-expires = Läuft ab in Tagen (leer für nie)
+invitations-create = Einladung erstellen
+invitations-empty = Noch keine Einladungen.
+maintenance-title = Wartung
+maintenance-subtitle = Während eines Zeitfensters ist die Seite für alle außer Admins schreibgeschützt. Zeiten in UTC.
+maintenance-times-required = Gib eine Start- und Endzeit ein
+maintenance-description = Was passiert, z. B. Datenbank-Upgrade
+maintenance-schedule = Planen
+maintenance-empty = Keine Wartung geplant.
+maintenance-window = { $from } – { $to } ({ $minutes } Min.)
+maintenance-running = Läuft
+maintenance-end-now = Jetzt beenden
+reports-subtitle = Hier kannst du gemeldete Inhalte ansehen und bearbeiten.
+reports-total = Meldungen gesamt
+reports-pending = Ausstehende Prüfungen
+reports-unassigned-count = { $count } nicht zugewiesen
+reports-resolved = Erledigte Meldungen
+reports-dismissed = Verworfen
+reports-any-status = Jeder Status
+reports-any-target = Beiträge, Kommentare und Profile
+reports-any-reason = Jeder Grund
+reports-assigned-to-me = Mir zugewiesen
+reports-empty = Nichts zu prüfen.
+reports-deleted-account = gelöschtes Konto
+reports-assigned-to = { $name } zugewiesen
+reports-unassigned = Nicht zugewiesen
+reports-resolved-with = Erledigt: { $action }
+reports-resolved-badge = Erledigt
+reports-heading = { $reason } · { $target } von { $author }
+reports-reported-by = Gemeldet von { $reporter } am { $date }
+reports-unassign = Zuweisung aufheben
+reports-assign-to-me = Mir zuweisen
+reports-resolve = Erledigen
+reports-dismiss = Verwerfen
+reports-note-placeholder = Moderationsnotiz (bleibt bei der Meldung; wird beim Erledigen oder Verwerfen hinzugefügt)
+reports-add-note = Notiz hinzufügen
+reports-hide-notes = Notizen ausblenden
+reports-show-notes = Notizen anzeigen
+reports-no-notes = Noch keine Notizen.
+settings-subtitle = Anwendungseinstellungen und Voreinstellungen festlegen
+settings-banner = Änderungen gelten für die ganze Seite. Andere Server übernehmen sie innerhalb von 30 Sekunden.
+settings-application = Anwendungseinstellungen
+settings-load-failed = Einstellungen konnten nicht geladen werden: { $error }
+settings-help = Brauchst du Hilfe?
+settings-help-maintenance = Der Wartungsmodus hat jetzt eine eigene Seite, auf der du schreibgeschützte Zeitfenster im Voraus planen kannst.
+settings-contact = Support kontaktieren
+settings-recent = Letzte Aktivität
+settings-no-changes = Noch keine Änderungen.
+settings-changed = Geändert: { $fields }
+settings-system = System
+settings-saved = Einstellungen gespeichert.
+settings-site-name = Name der Seite
+settings-site-name-placeholder = Name der Seite eingeben
+settings-admin-email = Admin-E-Mail
+settings-registration = Registrierung
+settings-post-visibility = Neue Beiträge beginnen als
+settings-ai-provider = KI-Anbieter
+settings-ai-model = Modell
+settings-ai-address = API-Adresse
+settings-max-upload = Maximale Uploadgröße (MB)
+settings-user-quota = Speicher pro Benutzer (MB)
+settings-upload-types = Erlaubte Dateitypen
+settings-upload-types-placeholder = Ein MIME-Typ pro Zeile, z. B. image/png
+settings-save = Einstellungen speichern
+users-title = Benutzerverwaltung
+users-subtitle = Benutzer verwalten, Profile ansehen und Verwaltungsaufgaben erledigen
+users-active-count = Aktive Benutzer
+users-inactive-count = Inaktive Benutzer
+users-unverified-count = Unbestätigte E-Mail
+users-any-role = Jede Rolle
+users-any-active = Aktiv oder nicht
+users-active = Aktiv
+users-inactive = Inaktiv
+users-deactivated-filter = Deaktiviert
+users-any-verified = Bestätigt oder nicht
+users-email-verified = E-Mail bestätigt
+users-email-unverified = E-Mail unbestätigt
+users-unverified = unbestätigt
+users-invited-by = eingeladen von { $name }
+users-never = Nie
+users-list = Benutzerliste ({ $count })
+users-selected = { $count } ausgewählt
+users-bulk-actions = Sammelaktionen
+users-apply = Anwenden
+users-confirm-bulk-delete =
+    { $count ->
+        [one] Wirklich { $count } Benutzer löschen?
+       *[other] Wirklich { $count } Benutzer löschen?
+    }
+users-bulk-done =
+    { $count ->
+        [one] { $action }: { $count } Benutzer
+       *[other] { $action }: { $count } Benutzer
+    }
+users-bulk-skipped = , { $count } übersprungen ({ $reasons })
+users-col-status = Status
+users-col-last-login = Letzte Anmeldung
+users-col-actions = Aktionen
+users-reactivated = { $name } reaktiviert
+users-deactivated = { $name } deaktiviert und abgemeldet
+users-reset-sent = { $name } hat einen Link zum Festlegen eines neuen Passworts erhalten
+users-sessions-ended =
+    { $count ->
+        [one] { $count } Sitzung von { $name } beendet
+       *[other] { $count } Sitzungen von { $name } beendet
+    }
+users-deleted = { $name } gelöscht
+users-deactivate = Deaktivieren
+users-reactivate = Reaktivieren
+users-reset-password = Passwort zurücksetzen
+users-sign-out = Abmelden
+users-impersonate = Anmelden als
+users-confirm-delete = Löschen bestätigen
+users-keep = Behalten
+users-edit = { $name } bearbeiten
+users-locked = Gesperrte Konten
+users-locked-until = { $email } · gesperrt bis { $time }
+users-unlock = Entsperren
+db-health-title = Datenbankzustand
+db-health-critical = Kritisch
+db-health-warning = Warnung
+db-health-normal = In Ordnung
+db-health-no-data = Noch keine Daten
+db-health-hours =
+    { $count ->
+        [one] { $count } Stunde
+       *[other] { $count } Stunden
+    }
+db-health-days =
+    { $count ->
+        [one] { $count } Tag
+       *[other] { $count } Tage
+    }
+db-health-check-now = Jetzt prüfen
+db-health-connections = Serververbindungen
+db-health-pool = Pool dieses Servers
+db-health-pool-usage = { $used } belegt, { $idle } frei, max. { $max }
+db-health-latency = Latenz
+db-health-latency-value = { $wait } ms Wartezeit, { $ping } ms Ping
+db-health-usage-legend = Verbindungsauslastung (0–100 %)
+db-health-wait-legend = Pool-Wartezeit (0–{ $max })
+db-health-slow = Langsame Abfragen
+db-health-slow-stats =
+    { $count ->
+        [one] { $count } Ausführung, durchschnittlich { $mean } ms
+       *[other] { $count } Ausführungen, durchschnittlich { $mean } ms
+    }
+db-health-slow-empty = In diesem Zeitraum keine erfasst.
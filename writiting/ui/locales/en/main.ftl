@@ -0,0 +1,431 @@
+## Navigation
+
+nav-home = Home
+nav-blog = Blog
+nav-editor = Editor
+nav-focus-mode = Focus Mode
+nav-login = Login
+nav-register = Register
+nav-reset-password = Reset Password
+nav-admin-dashboard = Admin Dashboard
+nav-admin-users = Admin Users
+nav-admin-reports = Admin Reports
+nav-admin-settings = Admin Settings
+nav-terms = Terms of Service
+nav-privacy = Privacy Policy
+nav-ai-chat = AI Chat
+
+## Avatar menu
+
+menu-profile = Profile
+menu-toggle-theme = Toggle Theme
+menu-help = Help Center
+menu-sign-out = Sign out
+
+common-loading = Loading...
+
+## Appearance settings
+
+appearance-title = Appearance
+appearance-theme-light = Light
+appearance-theme-dark = Dark
+appearance-theme-system = System
+appearance-system-hint = Follows your system's light or dark setting.
+appearance-language = Language
+
+## Uploads
+
+upload-add-image = Add image
+upload-uploading = Uploading...
+upload-unreadable = That file couldn't be read
+media-title = Uploads
+media-upload-image = Upload image
+# $used and $limit are sizes such as "1.5 MB"
+media-quota = { $used } of { $limit } used
+media-empty = You haven't uploaded anything yet.
+media-delete = Delete
+
+## Public profiles
+
+profile-not-found = Profile not found.
+profile-joined = @{ $username } · joined { $date }
+profile-followers =
+    { $count ->
+        [one] { $count } follower
+       *[other] { $count } followers
+    }
+profile-following = { $count } following
+profile-posts = Posts
+profile-no-posts = Nothing published yet.
+
+## Search
+
+search-placeholder = Search posts, comments and people...
+search-button = Search
+search-title = Results for “{ $query }”
+search-count =
+    { $count ->
+        [one] { $count } result
+       *[other] { $count } results
+    }
+search-no-results = Nothing matched your search.
+search-kind-all = Everything
+search-kind-post = Posts
+search-kind-comment = Comments
+search-kind-user = People
+search-author = Author
+search-from = From
+search-to = To
+search-comment-on = Comment on “{ $title }”
+search-by = by @{ $author }
+search-previous = Previous
+search-next = Next
+search-page = Page { $page } of { $pages }
+
+## Find and replace
+
+find-replace-title = Find & Replace
+find-replace-close = Close
+find-replace-find = Find
+find-replace-replace = Replace with
+find-replace-match-case = Match case
+find-replace-whole-word = Whole word
+find-replace-regex = Regex
+find-replace-scope-document = This document
+find-replace-scope-manuscript = Whole manuscript
+find-replace-invalid-regex = Invalid regular expression: { $error }
+find-replace-no-matches = No matches.
+find-replace-count =
+    { $count ->
+        [one] { $count } match
+       *[other] { $count } matches
+    } in { $documents ->
+        [one] { $documents } document
+       *[other] { $documents } documents
+    }
+find-replace-include = Replace this match
+find-replace-line = line { $line }
+find-replace-apply =
+    { $count ->
+        [one] Replace { $count } match
+       *[other] Replace { $count } matches
+    }
+find-replace-undo = Undo replace
+find-replace-undone = Replace undone.
+find-replace-summary = Replaced “{ $query }” with “{ $replacement }” ({ $count } in { $documents ->
+        [one] { $documents } document
+       *[other] { $documents } documents
+    })
+
+## Revisions
+
+revision-title = Revisions
+revision-empty = No revisions yet.
+revision-nothing-to-undo = There's no replace to undo.
+revision-edited-since = “{ $title }” has been edited since the replace, so it can't be undone.
+revision-undo-summary = Undid: { $summary }
+
+## Markdown
+
+markdown-contents = Contents
+markdown-preview = Preview
+markdown-preview-empty = Nothing to preview yet.
+
+## Posts
+
+post-not-found = Post not found.
+post-published = Published { $date }
+blog-previous = Previous
+blog-next = Next
+
+## Sign in and registration
+
+auth-email = Email address
+auth-password = Password
+auth-sign-in = Sign in
+auth-signing-in = Signing in...
+auth-remember-password = Remember your password?
+auth-fill-all-fields = Please fill in all fields
+auth-enter-email = Please enter your email
+auth-invalid-email = Please enter a valid email
+auth-passwords-mismatch = Passwords do not match
+auth-password-too-short = Password must be at least 8 characters
+login-title = Sign in to your account
+login-no-account = Don't have an account?
+login-sign-up = Sign up
+# $time is the local time and $date the day the lockout ends
+login-locked = Too many failed sign-in attempts. Try again after { $time } on { $date }.
+login-not-verified = Please verify your email address before signing in
+login-resend = Resend verification email
+login-resent = If that account needs verifying, a new link is on its way.
+login-two-factor-hint = Enter the 6-digit code from your authenticator app, or one of your recovery codes.
+login-code = Authentication code
+login-verify = Verify
+login-verifying = Verifying...
+login-remember-me = Remember me
+login-forgot = Forgot password?
+register-title = Create a new account
+register-have-account = Already have an account?
+register-closed = Registration is closed at the moment.
+register-invited-by = { $name } invited you to join.
+register-invited = You've been invited to join.
+register-created = Account created. Check your inbox for a link to verify your email address.
+register-username = Username
+register-confirm-password = Confirm Password
+register-invite-code = Invitation code
+register-invite-code-optional = Invitation code (optional)
+register-submit = Register
+register-submitting = Creating account...
+reset-title = Reset your password
+reset-hint = Enter your email and we'll send you a link to reset your password.
+reset-sent = If an account exists for { $email }, we've sent it a password reset link. Please check your email.
+reset-failed = Something went wrong. Please try again.
+reset-submit = Send reset link
+reset-submitting = Sending...
+new-password-title = Choose a new password
+new-password-label = New password
+new-password-confirm = Confirm new password
+new-password-invalid-link = This reset link is invalid or has expired
+new-password-changed = Your password has been changed and you've been signed out everywhere. Sign in with your new password.
+new-password-submit = Update password
+new-password-submitting = Updating...
+verify-title = Verify your email
+verify-ready = Ready to write?
+verify-checking = Checking your link...
+verify-done = Thanks! { $email } is now verified.
+verify-invalid-link = This verification link is invalid or has expired
+
+## Editor
+
+editor-save = Save
+editor-words =
+    { $count ->
+        [one] { $count } word
+       *[other] { $count } words
+    }
+editor-selected = Selected: { $document }
+
+## Shared labels
+
+common-previous = Previous
+common-next = Next
+common-page = Page { $page } of { $pages }
+common-name = Name
+common-description = Description
+common-save = Save
+common-cancel = Cancel
+common-edit = Edit
+common-delete = Delete
+
+## Administration
+
+admin-welcome = Welcome to the Admin Dashboard!
+admin-welcome-hint = Here you can manage users, view reports, and perform administrative tasks.
+admin-total-users = Total Users
+admin-reported-content = Reported Content
+admin-active-sessions = Active Sessions
+admin-manage-users = Manage Users
+admin-view-reports = View Reports
+admin-settings = Settings
+admin-user-filter = Username or email
+admin-col-when = When
+admin-col-user = User
+admin-col-ip = IP
+audit-title = Audit Log
+audit-subtitle = Who changed what, and when
+audit-all-entities = All entities
+audit-all-actions = All actions
+audit-export = Export CSV
+audit-download = Download { $file }
+audit-col-action = Action
+audit-col-entity = Entity
+audit-col-changed = Changed
+audit-count =
+    { $count ->
+        [one] { $count } entry
+       *[other] { $count } entries
+    }
+audit-system = system
+audit-before = Before
+audit-after = After
+security-title = Security Log
+security-subtitle = Sign-ins, password changes and other account events across the site
+security-all-events = All events
+security-ip-filter = IP or CIDR, e.g. 203.0.113.0/24
+security-col-event = Event
+security-col-details = Details
+security-count =
+    { $count ->
+        [one] { $count } event
+       *[other] { $count } events
+    }
+flags-title = Feature Flags
+flags-subtitle = Turn features on for everyone, a share of users, or chosen accounts
+flags-new = New flag
+flags-bad-user-id = { $id } is not a user ID
+flags-name-placeholder = e.g. markdown_preview
+flags-rollout = Rollout percentage (starting percentage for gradual rollouts)
+flags-increment = Increase per day (0 keeps the percentage fixed)
+flags-targets = Always on for these user IDs
+flags-enabled = Enabled
+flags-col-flag = Flag
+flags-col-status = Status
+flags-col-reaching = Reaching
+flags-col-targets = Targeted users
+flags-status-off = Off
+flags-status-on = On
+flags-status-gradual = Gradual, from { $from }% +{ $step }%/day
+invitations-title = Invitations
+invitations-subtitle = Every invitation on the site. Invitations from here can set a role and have no limits.
+invitations-email = Only for this email (optional)
+invitations-default-role = Default role
+invitations-note = Note (optional)
+invitations-uses = Uses (blank for unlimited)
+invitations-expires = Expires in days (blank for never)
+invitations-create = Create invitation
+invitations-empty = No invitations yet.
+maintenance-title = Maintenance
+maintenance-subtitle = While a window runs the site is read-only for everyone except admins. Times are UTC.
+maintenance-times-required = Enter a start and end time
+maintenance-description = What's happening, e.g. Database upgrade
+maintenance-schedule = Schedule
+maintenance-empty = No maintenance scheduled.
+maintenance-window = { $from } – { $to } ({ $minutes } min)
+maintenance-running = Running
+maintenance-end-now = End now
+reports-subtitle = Here you can view and manage reported content.
+reports-total = Total Reports
+reports-pending = Pending Reviews
+reports-unassigned-count = { $count } unassigned
+reports-resolved = Resolved Reports
+reports-dismissed = Dismissed
+reports-any-status = Any status
+reports-any-target = Posts, comments and profiles
+reports-any-reason = Any reason
+reports-assigned-to-me = Assigned to me
+reports-empty = Nothing to review.
+reports-deleted-account = deleted account
+reports-assigned-to = Assigned to { $name }
+reports-unassigned = Unassigned
+reports-resolved-with = Resolved: { $action }
+reports-resolved-badge = Resolved
+reports-heading = { $reason } · { $target } by { $author }
+reports-reported-by = Reported by { $reporter } on { $date }
+reports-unassign = Unassign
+reports-assign-to-me = Assign to me
+reports-resolve = Resolve
+reports-dismiss = Dismiss
+reports-note-placeholder = Moderator note (kept with the report; added when you resolve or dismiss)
+reports-add-note = Add note
+reports-hide-notes = Hide notes
+reports-show-notes = Show notes
+reports-no-notes = No notes yet.
+settings-subtitle = Configure application settings and preferences
+settings-banner = Changes apply to the whole site. Other servers pick them up within 30 seconds.
+settings-application = Application Settings
+settings-load-failed = Failed to load settings: { $error }
+settings-help = Need Help?
+settings-help-maintenance = Maintenance mode now has its own page, where you can schedule read-only windows ahead of time.
+settings-contact = Contact Support
+settings-recent = Recent Activity
+settings-no-changes = No changes yet.
+settings-changed = Changed { $fields }
+settings-system = System
+settings-saved = Settings saved.
+settings-site-name = Site Name
+settings-site-name-placeholder = Enter your site name
+settings-admin-email = Admin Email
+settings-registration = Registration
+settings-post-visibility = New posts start as
+settings-ai-provider = AI Provider
+settings-ai-model = Model
+settings-ai-address = API Address
+settings-max-upload = Max upload size (MB)
+settings-user-quota = Storage per user (MB)
+settings-upload-types = Allowed file types
+settings-upload-types-placeholder = One MIME type per line, e.g. image/png
+settings-save = Save Settings
+users-title = User Management
+users-subtitle = Manage users, view profiles, and perform administrative tasks
+users-active-count = Active Users
+users-inactive-count = Inactive Users
+users-unverified-count = Unverified Email
+users-any-role = Any role
+users-any-active = Active or not
+users-active = Active
+users-inactive = Inactive
+users-deactivated-filter = Deactivated
+users-any-verified = Verified or not
+users-email-verified = Email verified
+users-email-unverified = Email unverified
+users-unverified = unverified
+users-invited-by = invited by { $name }
+users-never = Never
+users-list = User List ({ $count })
+users-selected = { $count } selected
+users-bulk-actions = Bulk Actions
+users-apply = Apply
+users-confirm-bulk-delete =
+    { $count ->
+        [one] Really delete { $count } user?
+       *[other] Really delete { $count } users?
+    }
+users-bulk-done =
+    { $count ->
+        [one] { $action }: { $count } user
+       *[other] { $action }: { $count } users
+    }
+users-bulk-skipped = , { $count } skipped ({ $reasons })
+users-col-status = Status
+users-col-last-login = Last sign-in
+users-col-actions = Actions
+users-reactivated = { $name } reactivated
+users-deactivated = { $name } deactivated and signed out
+users-reset-sent = { $name } has been sent a link to choose a new password
+users-sessions-ended =
+    { $count ->
+        [one] Ended { $count } session of { $name }
+       *[other] Ended { $count } sessions of { $name }
+    }
+users-deleted = { $name } deleted
+users-deactivate = Deactivate
+users-reactivate = Reactivate
+users-reset-password = Reset password
+users-sign-out = Sign out
+users-impersonate = Sign in as
+users-confirm-delete = Confirm delete
+users-keep = Keep
+users-edit = Edit { $name }
+users-locked = Locked Accounts
+users-locked-until = { $email } · locked until { $time }
+users-unlock = Unlock
+db-health-title = Database health
+db-health-critical = Critical
+db-health-warning = Warning
+db-health-normal = Healthy
+db-health-no-data = No data yet
+db-health-hours =
+    { $count ->
+        [one] { $count } hour
+       *[other] { $count } hours
+    }
+db-health-days =
+    { $count ->
+        [one] { $count } day
+       *[other] { $count } days
+    }
+db-health-check-now = Check now
+db-health-connections = Server connections
+db-health-pool = This server's pool
+db-health-pool-usage = { $used } in use, { $idle } idle, max { $max }
+db-health-latency = Latency
+db-health-latency-value = { $wait } ms wait, { $ping } ms ping
+db-health-usage-legend = Connection usage (0–100%)
+db-health-wait-legend = Pool wait (0–{ $max })
+db-health-slow = Slow queries
+db-health-slow-stats =
+    { $count ->
+        [one] { $count } call, { $mean } ms on average
+       *[other] { $count } calls, { $mean } ms on average
+    }
+db-health-slow-empty = None recorded in this period.
@@ -0,0 +1,429 @@
+## Navigation
+
+nav-home = Inicio
+nav-blog = Blog
+nav-editor = Editor
+nav-focus-mode = Modo concentración
+nav-login = Iniciar sesión
+nav-register = Registrarse
+nav-reset-password = Restablecer contraseña
+nav-admin-dashboard = Panel de administración
+nav-admin-users = Usuarios (admin)
+nav-admin-reports = Denuncias (admin)
+nav-admin-settings = Ajustes (admin)
+nav-terms = Términos del servicio
+nav-privacy = Política de privacidad
+nav-ai-chat = Chat con IA
+
+## Avatar menu
+
+menu-profile = Perfil
+menu-toggle-theme = Cambiar tema
+menu-help = Centro de ayuda
+menu-sign-out = Cerrar sesión
+
+common-loading = Cargando...
+
+## Appearance settings
+
+appearance-title = Apariencia
+appearance-theme-light = Claro
+appearance-theme-dark = Oscuro
+appearance-theme-system = Sistema
+appearance-system-hint = Sigue el modo claro u oscuro de tu sistema.
+appearance-language = Idioma
+
+## Uploads
+
+upload-add-image = Añadir imagen
+upload-uploading = Subiendo...
+upload-unreadable = No se pudo leer ese archivo
+media-title = Archivos subidos
+media-upload-image = Subir imagen
+media-quota = { $used } de { $limit } usados
+media-empty = Todavía no has subido nada.
+media-delete = Eliminar
+
+## Public profiles
+
+profile-not-found = Perfil no encontrado.
+profile-joined = @{ $username } · se unió en { $date }
+profile-followers =
+    { $count ->
+        [one] { $count } seguidor
+       *[other] { $count } seguidores
+    }
+profile-following = { $count } seguidos
+profile-posts = Publicaciones
+profile-no-posts = Todavía no ha publicado nada.
+
+## Search
+
+search-placeholder = Buscar publicaciones, comentarios y personas...
+search-button = Buscar
+search-title = Resultados para «{ $query }»
+search-count =
+    { $count ->
+        [one] { $count } resultado
+       *[other] { $count } resultados
+    }
+search-no-results = No hay nada que coincida con tu búsqueda.
+search-kind-all = Todo
+search-kind-post = Publicaciones
+search-kind-comment = Comentarios
+search-kind-user = Personas
+search-author = Autor
+search-from = Desde
+search-to = Hasta
+search-comment-on = Comentario en «{ $title }»
+search-by = de @{ $author }
+search-previous = Anterior
+search-next = Siguiente
+search-page = Página { $page } de { $pages }
+
+## Find and replace
+
+find-replace-title = Buscar y reemplazar
+find-replace-close = Cerrar
+find-replace-find = Buscar
+find-replace-replace = Reemplazar por
+find-replace-match-case = Distinguir mayúsculas
+find-replace-whole-word = Palabra completa
+find-replace-regex = Regex
+find-replace-scope-document = Este documento
+find-replace-scope-manuscript = Todo el manuscrito
+find-replace-invalid-regex = Expresión regular no válida: { $error }
+find-replace-no-matches = Sin coincidencias.
+find-replace-count =
+    { $count ->
+        [one] { $count } coincidencia
+       *[other] { $count } coincidencias
+    } en { $documents ->
+        [one] { $documents } documento
+       *[other] { $documents } documentos
+    }
+find-replace-include = Reemplazar esta coincidencia
+find-replace-line = línea { $line }
+find-replace-apply =
+    { $count ->
+        [one] Reemplazar { $count } coincidencia
+       *[other] Reemplazar { $count } coincidencias
+    }
+find-replace-undo = Deshacer reemplazo
+find-replace-undone = Reemplazo deshecho.
+find-replace-summary = Se reemplazó «{ $query }» por «{ $replacement }» ({ $count } en { $documents ->
+        [one] { $documents } documento
+       *[other] { $documents } documentos
+    })
+
+## Revisions
+
+revision-title = Revisiones
+revision-empty = Todavía no hay revisiones.
+revision-nothing-to-undo = No hay ningún reemplazo que deshacer.
+revision-edited-since = «{ $title }» se ha editado después del reemplazo, así que no se puede deshacer.
+revision-undo-summary = Deshecho: { $summary }
+
+## Markdown
+
+markdown-contents = Contenido
+markdown-preview = Vista previa
+markdown-preview-empty = Todavía no hay nada que previsualizar.
+
+## Posts
+
+post-not-found = No se encontró la publicación.
+post-published = Publicado el { $date }
+blog-previous = Anterior
+blog-next = Siguiente
+
+## Sign in and registration
+
+auth-email = Correo electrónico
+auth-password = Contraseña
+auth-sign-in = Iniciar sesión
+auth-signing-in = Iniciando sesión...
+auth-remember-password = ¿Recuerdas tu contraseña?
+auth-fill-all-fields = Rellena todos los campos
+auth-enter-email = Introduce tu correo electrónico
+auth-invalid-email = Introduce un correo electrónico válido
+auth-passwords-mismatch = Las contraseñas no coinciden
+auth-password-too-short = La contraseña debe tener al menos 8 caracteres
+login-title = Inicia sesión en tu cuenta
+login-no-account = ¿No tienes cuenta?
+login-sign-up = Regístrate
+login-locked = Demasiados intentos fallidos. Vuelve a intentarlo después de las { $time } del { $date }.
+login-not-verified = Verifica tu correo electrónico antes de iniciar sesión
+login-resend = Reenviar correo de verificación
+login-resent = Si esa cuenta necesita verificación, te hemos enviado un nuevo enlace.
+login-two-factor-hint = Introduce el código de 6 dígitos de tu app de autenticación o uno de tus códigos de recuperación.
+login-code = Código de autenticación
+login-verify = Verificar
+login-verifying = Verificando...
+login-remember-me = Recordarme
+login-forgot = ¿Has olvidado la contraseña?
+register-title = Crea una cuenta nueva
+register-have-account = ¿Ya tienes cuenta?
+register-closed = El registro está cerrado en este momento.
+register-invited-by = { $name } te ha invitado a unirte.
+register-invited = Te han invitado a unirte.
+register-created = Cuenta creada. Revisa tu bandeja de entrada para verificar tu correo electrónico.
+register-username = Nombre de usuario
+register-confirm-password = Confirmar contraseña
+register-invite-code = Código de invitación
+register-invite-code-optional = Código de invitación (opcional)
+register-submit = Registrarse
+register-submitting = Creando cuenta...
+reset-title = Restablece tu contraseña
+reset-hint = Introduce tu correo y te enviaremos un enlace para restablecer tu contraseña.
+reset-sent = Si existe una cuenta para { $email }, le hemos enviado un enlace para restablecer la contraseña. Revisa tu correo.
+reset-failed = Algo salió mal. Inténtalo de nuevo.
+reset-submit = Enviar enlace
+reset-submitting = Enviando...
+new-password-title = Elige una contraseña nueva
+new-password-label = Contraseña nueva
+new-password-confirm = Confirma la contraseña nueva
+new-password-invalid-link = Este enlace no es válido o ha caducado
+new-password-changed = Tu contraseña se ha cambiado y se han cerrado todas tus sesiones. Inicia sesión con la contraseña nueva.
+new-password-submit = Actualizar contraseña
+new-password-submitting = Actualizando...
+verify-title = Verifica tu correo electrónico
+verify-ready = ¿Listo para escribir?
+verify-checking = Comprobando el enlace...
+verify-done = ¡Gracias! { $email } ya está verificado.
+verify-invalid-link = Este enlace de verificación no es válido o ha caducado
+
+## Editor
+
+editor-save = Guardar
+editor-words =
+    { $count ->
+        [one] { $count } palabra
+       *[other] { $count } palabras
+    }
+editor-selected = Seleccionado: { $document }
+
+## Shared labels
+
+common-previous = Anterior
+common-next = Siguiente
+common-page = Página { $page } de { $pages }
+common-name = Nombre
+common-description = Descripción
+common-save = Guardar
+common-cancel = Cancelar
+common-edit = Editar
+common-delete = Eliminar
+
+## Administration
+
+admin-welcome = ¡Bienvenido al panel de administración!
+admin-welcome-hint = Aquí puedes gestionar usuarios, ver denuncias y realizar tareas administrativas.
+admin-total-users = Usuarios totales
+admin-reported-content = Contenido denunciado
+admin-active-sessions = Sesiones activas
+admin-manage-users = Gestionar usuarios
+admin-view-reports = Ver denuncias
+admin-settings = Ajustes
+admin-user-filter = Usuario o correo
+admin-col-when = Cuándo
+admin-col-user = Usuario
+admin-col-ip = IP
+audit-title = Registro de auditoría
+audit-subtitle = Quién cambió qué y cuándo
+audit-all-entities = Todas las entidades
+audit-all-actions = Todas las acciones
+audit-export = Exportar CSV
+audit-download = Descargar { $file }
+audit-col-action = Acción
+audit-col-entity = Entidad
+audit-col-changed = Cambios
+audit-count =
+    { $count ->
+        [one] { $count } entrada
+       *[other] { $count } entradas
+    }
+audit-system = sistema
+audit-before = Antes
+audit-after = Después
+security-title = Registro de seguridad
+security-subtitle = Inicios de sesión, cambios de contraseña y otros eventos de cuentas en todo el sitio
+security-all-events = Todos los eventos
+security-ip-filter = IP o CIDR, p. ej. 203.0.113.0/24
+security-col-event = Evento
+security-col-details = Detalles
+security-count =
+    { $count ->
+        [one] { $count } evento
+       *[other] { $count } eventos
+    }
+flags-title = Funciones experimentales
+flags-subtitle = Activa funciones para todos, para una parte de los usuarios o para cuentas concretas
+flags-new = Nueva función
+flags-bad-user-id = { $id } no es un ID de usuario
+flags-name-placeholder = p. ej. markdown_preview
+flags-rollout = Porcentaje de despliegue (porcentaje inicial en despliegues graduales)
+flags-increment = Aumento diario (0 mantiene el porcentaje fijo)
+flags-targets = Siempre activa para estos ID de usuario
+flags-enabled = Activada
+flags-col-flag = Función
+flags-col-status = Estado
+flags-col-reaching = Alcance
+flags-col-targets = Usuarios elegidos
+flags-status-off = Desactivada
+flags-status-on = Activada
+flags-status-gradual = Gradual, desde { $from } % +{ $step } %/día
+invitations-title = Invitaciones
+invitations-subtitle = Todas las invitaciones del sitio. Las invitaciones creadas aquí pueden asignar un rol y no tienen límites.
+invitations-email = Solo para este correo (opcional)
+invitations-default-role = Rol predeterminado
+invitations-note = Nota (opcional)
+invitations-uses = Usos (vacío para ilimitados)
+invitations-expires = Caduca en días (vacío para nunca)
+invitations-create = Crear invitación
+invitations-empty = Aún no hay invitaciones.
+maintenance-title = Mantenimiento
+maintenance-subtitle = Mientras dura una ventana, el sitio es de solo lectura para todos excepto los administradores. Las horas están en UTC.
+maintenance-times-required = Indica una hora de inicio y de fin
+maintenance-description = Qué ocurre, p. ej. Actualización de la base de datos
+maintenance-schedule = Programar
+maintenance-empty = No hay mantenimiento programado.
+maintenance-window = { $from } – { $to } ({ $minutes } min)
+maintenance-running = En curso
+maintenance-end-now = Terminar ahora
+reports-subtitle = Aquí puedes ver y gestionar el contenido denunciado.
+reports-total = Denuncias totales
+reports-pending = Pendientes de revisión
+reports-unassigned-count = { $count } sin asignar
+reports-resolved = Denuncias resueltas
+reports-dismissed = Descartadas
+reports-any-status = Cualquier estado
+reports-any-target = Entradas, comentarios y perfiles
+reports-any-reason = Cualquier motivo
+reports-assigned-to-me = Asignadas a mí
+reports-empty = Nada que revisar.
+reports-deleted-account = cuenta eliminada
+reports-assigned-to = Asignada a { $name }
+reports-unassigned = Sin asignar
+reports-resolved-with = Resuelta: { $action }
+reports-resolved-badge = Resuelta
+reports-heading = { $reason } · { $target } de { $author }
+reports-reported-by = Denunciado por { $reporter } el { $date }
+reports-unassign = Quitar asignación
+reports-assign-to-me = Asignarme
+reports-resolve = Resolver
+reports-dismiss = Descartar
+reports-note-placeholder = Nota de moderación (se guarda con la denuncia; se añade al resolver o descartar)
+reports-add-note = Añadir nota
+reports-hide-notes = Ocultar notas
+reports-show-notes = Mostrar notas
+reports-no-notes = Aún no hay notas.
+settings-subtitle = Configura los ajustes y preferencias de la aplicación
+settings-banner = Los cambios se aplican a todo el sitio. Los demás servidores los recogen en 30 segundos.
+settings-application = Ajustes de la aplicación
+settings-load-failed = No se pudieron cargar los ajustes: { $error }
+settings-help = ¿Necesitas ayuda?
+settings-help-maintenance = El modo de mantenimiento tiene ahora su propia página, donde puedes programar ventanas de solo lectura con antelación.
+settings-contact = Contactar con soporte
+settings-recent = Actividad reciente
+settings-no-changes = Aún no hay cambios.
+settings-changed = Cambió { $fields }
+settings-system = Sistema
+settings-saved = Ajustes guardados.
+settings-site-name = Nombre del sitio
+settings-site-name-placeholder = Escribe el nombre del sitio
+settings-admin-email = Correo del administrador
+settings-registration = Registro
+settings-post-visibility = Las entradas nuevas empiezan como
+settings-ai-provider = Proveedor de IA
+settings-ai-model = Modelo
+settings-ai-address = Dirección de la API
+settings-max-upload = Tamaño máximo de subida (MB)
+settings-user-quota = Almacenamiento por usuario (MB)
+settings-upload-types = Tipos de archivo permitidos
+settings-upload-types-placeholder = Un tipo MIME por línea, p. ej. image/png
+settings-save = Guardar ajustes
+users-title = Gestión de usuarios
+users-subtitle = Gestiona usuarios, consulta perfiles y realiza tareas administrativas
+users-active-count = Usuarios activos
+users-inactive-count = Usuarios inactivos
+users-unverified-count = Correo sin verificar
+users-any-role = Cualquier rol
+users-any-active = Activos o no
+users-active = Activo
+users-inactive = Inactivo
+users-deactivated-filter = Desactivados
+users-any-verified = Verificados o no
+users-email-verified = Correo verificado
+users-email-unverified = Correo sin verificar
+users-unverified = sin verificar
+users-invited-by = invitado por { $name }
+users-never = Nunca
+users-list = Lista de usuarios ({ $count })
+users-selected = { $count } seleccionados
+users-bulk-actions = Acciones en bloque
+users-apply = Aplicar
+users-confirm-bulk-delete =
+    { $count ->
+        [one] ¿Eliminar de verdad { $count } usuario?
+       *[other] ¿Eliminar de verdad { $count } usuarios?
+    }
+users-bulk-done =
+    { $count ->
+        [one] { $action }: { $count } usuario
+       *[other] { $action }: { $count } usuarios
+    }
+users-bulk-skipped = , { $count } omitidos ({ $reasons })
+users-col-status = Estado
+users-col-last-login = Último inicio de sesión
+users-col-actions = Acciones
+users-reactivated = { $name } reactivado
+users-deactivated = { $name } desactivado y desconectado
+users-reset-sent = Se ha enviado a { $name } un enlace para elegir una contraseña nueva
+users-sessions-ended =
+    { $count ->
+        [one] Se cerró { $count } sesión de { $name }
+       *[other] Se cerraron { $count } sesiones de { $name }
+    }
+users-deleted = { $name } eliminado
+users-deactivate = Desactivar
+users-reactivate = Reactivar
+users-reset-password = Restablecer contraseña
+users-sign-out = Cerrar sesión
+users-impersonate = Entrar como
+users-confirm-delete = Confirmar eliminación
+users-keep = Conservar
+users-edit = Editar a { $name }
+users-locked = Cuentas bloqueadas
+users-locked-until = { $email } · bloqueada hasta { $time }
+users-unlock = Desbloquear
+db-health-title = Estado de la base de datos
+db-health-critical = Crítico
+db-health-warning = Advertencia
+db-health-normal = Correcto
+db-health-no-data = Aún sin datos
+db-health-hours =
+    { $count ->
+        [one] { $count } hora
+       *[other] { $count } horas
+    }
+db-health-days =
+    { $count ->
+        [one] { $count } día
+       *[other] { $count } días
+    }
+db-health-check-now = Comprobar ahora
+db-health-connections = Conexiones del servidor
+db-health-pool = Pool de este servidor
+db-health-pool-usage = { $used } en uso, { $idle } libres, máx. { $max }
+db-health-latency = Latencia
+db-health-latency-value = { $wait } ms de espera, { $ping } ms de ping
+db-health-usage-legend = Uso de conexiones (0–100 %)
+db-health-wait-legend = Espera del pool (0–{ $max })
+db-health-slow = Consultas lentas
+db-health-slow-stats =
+    { $count ->
+        [one] { $count } ejecución, { $mean } ms de media
+       *[other] { $count } ejecuciones, { $mean } ms de media
+    }
+db-health-slow-empty = Ninguna registrada en este periodo.
@@ -0,0 +1,429 @@
+## Navigation
+
+nav-home = Accueil
+nav-blog = Blog
+nav-editor = Éditeur
+nav-focus-mode = Mode concentration
+nav-login = Connexion
+nav-register = Inscription
+nav-reset-password = Réinitialiser le mot de passe
+nav-admin-dashboard = Tableau de bord admin
+nav-admin-users = Utilisateurs (admin)
+nav-admin-reports = Signalements (admin)
+nav-admin-settings = Paramètres (admin)
+nav-terms = Conditions d'utilisation
+nav-privacy = Politique de confidentialité
+nav-ai-chat = Chat IA
+
+## Avatar menu
+
+menu-profile = Profil
+menu-toggle-theme = Changer de thème
+menu-help = Centre d'aide
+menu-sign-out = Se déconnecter
+
+common-loading = Chargement...
+
+## Appearance settings
+
+appearance-title = Apparence
+appearance-theme-light = Clair
+appearance-theme-dark = Sombre
+appearance-theme-system = Système
+appearance-system-hint = Suit le réglage clair ou sombre de votre système.
+appearance-language = Langue
+
+## Uploads
+
+upload-add-image = Ajouter une image
+upload-uploading = Envoi en cours...
+upload-unreadable = Impossible de lire ce fichier
+media-title = Fichiers envoyés
+media-upload-image = Envoyer une image
+media-quota = { $used } utilisés sur { $limit }
+media-empty = Vous n'avez encore rien envoyé.
+media-delete = Supprimer
+
+## Public profiles
+
+profile-not-found = Profil introuvable.
+profile-joined = @{ $username } · membre depuis { $date }
+profile-followers =
+    { $count ->
+        [one] { $count } abonné
+       *[other] { $count } abonnés
+    }
+profile-following = { $count } abonnements
+profile-posts = Articles
+profile-no-posts = Rien de publié pour l'instant.
+
+## Search
+
+search-placeholder = Rechercher des articles, commentaires et personnes...
+search-button = Rechercher
+search-title = Résultats pour « { $query } »
+search-count =
+    { $count ->
+        [one] { $count } résultat
+       *[other] { $count } résultats
+    }
+search-no-results = Aucun résultat pour cette recherche.
+search-kind-all = Tout
+search-kind-post = Articles
+search-kind-comment = Commentaires
+search-kind-user = Personnes
+search-author = Auteur
+search-from = Du
+search-to = Au
+search-comment-on = Commentaire sur « { $title } »
+search-by = par @{ $author }
+search-previous = Précédent
+search-next = Suivant
+search-page = Page { $page } sur { $pages }
+
+## Find and replace
+
+find-replace-title = Rechercher et remplacer
+find-replace-close = Fermer
+find-replace-find = Rechercher
+find-replace-replace = Remplacer par
+find-replace-match-case = Respecter la casse
+find-replace-whole-word = Mot entier
+find-replace-regex = Regex
+find-replace-scope-document = Ce document
+find-replace-scope-manuscript = Tout le manuscrit
+find-replace-invalid-regex = Expression régulière invalide : { $error }
+find-replace-no-matches = Aucune occurrence.
+find-replace-count =
+    { $count ->
+        [one] { $count } occurrence
+       *[other] { $count } occurrences
+    } dans { $documents ->
+        [one] { $documents } document
+       *[other] { $documents } documents
+    }
+find-replace-include = Remplacer cette occurrence
+find-replace-line = ligne { $line }
+find-replace-apply =
+    { $count ->
+        [one] Remplacer { $count } occurrence
+       *[other] Remplacer { $count } occurrences
+    }
+find-replace-undo = Annuler le remplacement
+find-replace-undone = Remplacement annulé.
+find-replace-summary = « { $query } » remplacé par « { $replacement } » ({ $count } dans { $documents ->
+        [one] { $documents } document
+       *[other] { $documents } documents
+    })
+
+## Revisions
+
+revision-title = Révisions
+revision-empty = Aucune révision pour l'instant.
+revision-nothing-to-undo = Aucun remplacement à annuler.
+revision-edited-since = « { $title } » a été modifié depuis le remplacement, il ne peut donc pas être annulé.
+revision-undo-summary = Annulé : { $summary }
+
+## Markdown
+
+markdown-contents = Sommaire
+markdown-preview = Aperçu
+markdown-preview-empty = Rien à prévisualiser pour l'instant.
+
+## Posts
+
+post-not-found = Article introuvable.
+post-published = Publié le { $date }
+blog-previous = Précédent
+blog-next = Suivant
+
+## Sign in and registration
+
+auth-email = Adresse e-mail
+auth-password = Mot de passe
+auth-sign-in = Se connecter
+auth-signing-in = Connexion...
+auth-remember-password = Vous vous souvenez de votre mot de passe ?
+auth-fill-all-fields = Veuillez remplir tous les champs
+auth-enter-email = Veuillez saisir votre adresse e-mail
+auth-invalid-email = Veuillez saisir une adresse e-mail valide
+auth-passwords-mismatch = Les mots de passe ne correspondent pas
+auth-password-too-short = Le mot de passe doit contenir au moins 8 caractères
+login-title = Connectez-vous à votre compte
+login-no-account = Pas encore de compte ?
+login-sign-up = Inscrivez-vous
+login-locked = Trop de tentatives de connexion échouées. Réessayez après { $time } le { $date }.
+login-not-verified = Veuillez vérifier votre adresse e-mail avant de vous connecter
+login-resend = Renvoyer l'e-mail de vérification
+login-resent = Si ce compte doit être vérifié, un nouveau lien est en route.
+login-two-factor-hint = Saisissez le code à 6 chiffres de votre application d'authentification, ou l'un de vos codes de récupération.
+login-code = Code d'authentification
+login-verify = Vérifier
+login-verifying = Vérification...
+login-remember-me = Se souvenir de moi
+login-forgot = Mot de passe oublié ?
+register-title = Créer un nouveau compte
+register-have-account = Vous avez déjà un compte ?
+register-closed = Les inscriptions sont fermées pour le moment.
+register-invited-by = { $name } vous a invité à nous rejoindre.
+register-invited = Vous avez été invité à nous rejoindre.
+register-created = Compte créé. Consultez votre boîte de réception pour vérifier votre adresse e-mail.
+register-username = Nom d'utilisateur
+register-confirm-password = Confirmer le mot de passe
+register-invite-code = Code d'invitation
+register-invite-code-optional = Code d'invitation (facultatif)
+register-submit = S'inscrire
+register-submitting = Création du compte...
+reset-title = Réinitialiser votre mot de passe
+reset-hint = Saisissez votre adresse e-mail et nous vous enverrons un lien pour réinitialiser votre mot de passe.
+reset-sent = Si un compte existe pour { $email }, nous lui avons envoyé un lien de réinitialisation. Consultez vos e-mails.
+reset-failed = Une erreur s'est produite. Veuillez réessayer.
+reset-submit = Envoyer le lien
+reset-submitting = Envoi...
+new-password-title = Choisissez un nouveau mot de passe
+new-password-label = Nouveau mot de passe
+new-password-confirm = Confirmer le nouveau mot de passe
+new-password-invalid-link = Ce lien de réinitialisation est invalide ou a expiré
+new-password-changed = Votre mot de passe a été modifié et vous avez été déconnecté partout. Connectez-vous avec votre nouveau mot de passe.
+new-password-submit = Mettre à jour le mot de passe
+new-password-submitting = Mise à jour...
+verify-title = Vérifiez votre adresse e-mail
+verify-ready = Prêt à écrire ?
+verify-checking = Vérification du lien...
+verify-done = Merci ! { $email } est maintenant vérifiée.
+verify-invalid-link = Ce lien de vérification est invalide ou a expiré
+
+## Editor
+
+editor-save = Enregistrer
+editor-words =
+    { $count ->
+        [one] { $count } mot
+       *[other] { $count } mots
+    }
+editor-selected = Sélection : { $document }
+
+## Shared labels
+
+common-previous = Précédent
+common-next = Suivant
+common-page = Page { $page } sur { $pages }
+common-name = Nom
+common-description = Description
+common-save = Enregistrer
+common-cancel = Annuler
+common-edit = Modifier
+common-delete = Supprimer
+
+## Administration
+
+admin-welcome = Bienvenue dans le tableau de bord d’administration !
+admin-welcome-hint = Ici, vous pouvez gérer les utilisateurs, consulter les signalements et effectuer des tâches d’administration.
+admin-total-users = Utilisateurs au total
+admin-reported-content = Contenu signalé
+admin-active-sessions = Sessions actives
+admin-manage-users = Gérer les utilisateurs
+admin-view-reports = Voir les signalements
+admin-settings = Paramètres
+admin-user-filter = Nom d’utilisateur ou e-mail
+admin-col-when = Quand
+admin-col-user = Utilisateur
+admin-col-ip = IP
+audit-title = Journal d’audit
+audit-subtitle = Qui a modifié quoi, et quand
+audit-all-entities = Toutes les entités
+audit-all-actions = Toutes les actions
+audit-export = Exporter en CSV
+audit-download = Télécharger { $file }
+audit-col-action = Action
+audit-col-entity = Entité
+audit-col-changed = Modifications
+audit-count =
+    { $count ->
+        [one] { $count } entrée
+       *[other] { $count } entrées
+    }
+audit-system = système
+audit-before = Avant
+audit-after = Après
+security-title = Journal de sécurité
+security-subtitle = Connexions, changements de mot de passe et autres événements de compte sur tout le site
+security-all-events = Tous les événements
+security-ip-filter = IP ou CIDR, par ex. 203.0.113.0/24
+security-col-event = Événement
+security-col-details = Détails
+security-count =
+    { $count ->
+        [one] { $count } événement
+       *[other] { $count } événements
+    }
+flags-title = Fonctionnalités expérimentales
+flags-subtitle = Activez des fonctionnalités pour tout le monde, une partie des utilisateurs ou des comptes choisis
+flags-new = Nouvelle fonctionnalité
+flags-bad-user-id = { $id } n’est pas un identifiant d’utilisateur
+flags-name-placeholder = par ex. markdown_preview
+flags-rollout = Pourcentage de déploiement (pourcentage de départ pour les déploiements progressifs)
+flags-increment = Augmentation par jour (0 garde le pourcentage fixe)
+flags-targets = Toujours activée pour ces identifiants d’utilisateur
+flags-enabled = Activée
+flags-col-flag = Fonctionnalité
+flags-col-status = État
+flags-col-reaching = Portée
+flags-col-targets = Utilisateurs ciblés
+flags-status-off = Désactivée
+flags-status-on = Activée
+flags-status-gradual = Progressif, à partir de { $from } % +{ $step } %/jour
+invitations-title = Invitations
+invitations-subtitle = Toutes les invitations du site. Les invitations créées ici peuvent définir un rôle et n’ont pas de limite.
+invitations-email = Uniquement pour cet e-mail (facultatif)
+invitations-default-role = Rôle par défaut
+invitations-note = Note (facultatif)
+invitations-uses = Utilisations (vide pour illimité)
+invitations-expires = Expire dans (jours, vide pour jamais)
+invitations-create = Créer une invitation
+invitations-empty = Aucune invitation pour l’instant.
+maintenance-title = Maintenance
+maintenance-subtitle = Pendant une fenêtre, le site est en lecture seule pour tous sauf les administrateurs. Les heures sont en UTC.
+maintenance-times-required = Saisissez une heure de début et de fin
+maintenance-description = Ce qui se passe, par ex. Mise à niveau de la base de données
+maintenance-schedule = Planifier
+maintenance-empty = Aucune maintenance planifiée.
+maintenance-window = { $from } – { $to } ({ $minutes } min)
+maintenance-running = En cours
+maintenance-end-now = Terminer maintenant
+reports-subtitle = Ici, vous pouvez consulter et gérer le contenu signalé.
+reports-total = Signalements au total
+reports-pending = En attente d’examen
+reports-unassigned-count = { $count } non attribués
+reports-resolved = Signalements traités
+reports-dismissed = Rejetés
+reports-any-status = Tous les états
+reports-any-target = Articles, commentaires et profils
+reports-any-reason = Tous les motifs
+reports-assigned-to-me = Qui me sont attribués
+reports-empty = Rien à examiner.
+reports-deleted-account = compte supprimé
+reports-assigned-to = Attribué à { $name }
+reports-unassigned = Non attribué
+reports-resolved-with = Traité : { $action }
+reports-resolved-badge = Traité
+reports-heading = { $reason } · { $target } de { $author }
+reports-reported-by = Signalé par { $reporter } le { $date }
+reports-unassign = Retirer l’attribution
+reports-assign-to-me = Me l’attribuer
+reports-resolve = Traiter
+reports-dismiss = Rejeter
+reports-note-placeholder = Note de modération (conservée avec le signalement ; ajoutée quand vous traitez ou rejetez)
+reports-add-note = Ajouter une note
+reports-hide-notes = Masquer les notes
+reports-show-notes = Afficher les notes
+reports-no-notes = Aucune note pour l’instant.
+settings-subtitle = Configurez les paramètres et préférences de l’application
+settings-banner = Les modifications s’appliquent à tout le site. Les autres serveurs les prennent en compte sous 30 secondes.
+settings-application = Paramètres de l’application
+settings-load-failed = Impossible de charger les paramètres : { $error }
+settings-help = Besoin d’aide ?
+settings-help-maintenance = Le mode maintenance a désormais sa propre page, où vous pouvez planifier des fenêtres en lecture seule à l’avance.
+settings-contact = Contacter le support
+settings-recent = Activité récente
+settings-no-changes = Aucune modification pour l’instant.
+settings-changed = A modifié { $fields }
+settings-system = Système
+settings-saved = Paramètres enregistrés.
+settings-site-name = Nom du site
+settings-site-name-placeholder = Saisissez le nom du site
+settings-admin-email = E-mail de l’administrateur
+settings-registration = Inscription
+settings-post-visibility = Les nouveaux articles sont d’abord
+settings-ai-provider = Fournisseur d’IA
+settings-ai-model = Modèle
+settings-ai-address = Adresse de l’API
+settings-max-upload = Taille maximale d’envoi (Mo)
+settings-user-quota = Stockage par utilisateur (Mo)
+settings-upload-types = Types de fichiers autorisés
+settings-upload-types-placeholder = Un type MIME par ligne, par ex. image/png
+settings-save = Enregistrer les paramètres
+users-title = Gestion des utilisateurs
+users-subtitle = Gérez les utilisateurs, consultez les profils et effectuez des tâches d’administration
+users-active-count = Utilisateurs actifs
+users-inactive-count = Utilisateurs inactifs
+users-unverified-count = E-mail non vérifié
+users-any-role = Tous les rôles
+users-any-active = Actifs ou non
+users-active = Actif
+users-inactive = Inactif
+users-deactivated-filter = Désactivés
+users-any-verified = Vérifiés ou non
+users-email-verified = E-mail vérifié
+users-email-unverified = E-mail non vérifié
+users-unverified = non vérifié
+users-invited-by = invité par { $name }
+users-never = Jamais
+users-list = Liste des utilisateurs ({ $count })
+users-selected = { $count } sélectionnés
+users-bulk-actions = Actions groupées
+users-apply = Appliquer
+users-confirm-bulk-delete =
+    { $count ->
+        [one] Vraiment supprimer { $count } utilisateur ?
+       *[other] Vraiment supprimer { $count } utilisateurs ?
+    }
+users-bulk-done =
+    { $count ->
+        [one] { $action } : { $count } utilisateur
+       *[other] { $action } : { $count } utilisateurs
+    }
+users-bulk-skipped = , { $count } ignorés ({ $reasons })
+users-col-status = État
+users-col-last-login = Dernière connexion
+users-col-actions = Actions
+users-reactivated = { $name } réactivé
+users-deactivated = { $name } désactivé et déconnecté
+users-reset-sent = Un lien pour choisir un nouveau mot de passe a été envoyé à { $name }
+users-sessions-ended =
+    { $count ->
+        [one] { $count } session de { $name } fermée
+       *[other] { $count } sessions de { $name } fermées
+    }
+users-deleted = { $name } supprimé
+users-deactivate = Désactiver
+users-reactivate = Réactiver
+users-reset-password = Réinitialiser le mot de passe
+users-sign-out = Déconnecter
+users-impersonate = Se connecter en tant que
+users-confirm-delete = Confirmer la suppression
+users-keep = Conserver
+users-edit = Modifier { $name }
+users-locked = Comptes verrouillés
+users-locked-until = { $email } · verrouillé jusqu’à { $time }
+users-unlock = Déverrouiller
+db-health-title = Santé de la base de données
+db-health-critical = Critique
+db-health-warning = Avertissement
+db-health-normal = Sain
+db-health-no-data = Pas encore de données
+db-health-hours =
+    { $count ->
+        [one] { $count } heure
+       *[other] { $count } heures
+    }
+db-health-days =
+    { $count ->
+        [one] { $count } jour
+       *[other] { $count } jours
+    }
+db-health-check-now = Vérifier maintenant
+db-health-connections = Connexions au serveur
+db-health-pool = Pool de ce serveur
+db-health-pool-usage = { $used } utilisées, { $idle } inactives, max { $max }
+db-health-latency = Latence
+db-health-latency-value = { $wait } ms d’attente, { $ping } ms de ping
+db-health-usage-legend = Utilisation des connexions (0–100 %)
+db-health-wait-legend = Attente du pool (0–{ $max })
+db-health-slow = Requêtes lentes
+db-health-slow-stats =
+    { $count ->
+        [one] { $count } exécution, { $mean } ms en moyenne
+       *[other] { $count } exécutions, { $mean } ms en moyenne
+    }
+db-health-slow-empty = Aucune enregistrée sur cette période.
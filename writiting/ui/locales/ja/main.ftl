@@ -0,0 +1,371 @@
+## Navigation
+
+nav-home = ホーム
+nav-blog = ブログ
+nav-editor = エディター
+nav-focus-mode = 集中モード
+nav-login = ログイン
+nav-register = 新規登録
+nav-reset-password = パスワードの再設定
+nav-admin-dashboard = 管理ダッシュボード
+nav-admin-users = ユーザー管理
+nav-admin-reports = 通報管理
+nav-admin-settings = サイト設定
+nav-terms = 利用規約
+nav-privacy = プライバシーポリシー
+nav-ai-chat = AIチャット
+
+## Avatar menu
+
+menu-profile = プロフィール
+menu-toggle-theme = テーマを切り替え
+menu-help = ヘルプセンター
+menu-sign-out = ログアウト
+
+common-loading = 読み込み中...
+
+## Appearance settings
+
+appearance-title = 外観
+appearance-theme-light = ライト
+appearance-theme-dark = ダーク
+appearance-theme-system = システム
+appearance-system-hint = システムのライト/ダーク設定に合わせます。
+appearance-language = 言語
+
+## Uploads
+
+upload-add-image = 画像を追加
+upload-uploading = アップロード中...
+upload-unreadable = ファイルを読み込めませんでした
+media-title = アップロード
+media-upload-image = 画像をアップロード
+media-quota = { $limit } 中 { $used } 使用
+media-empty = まだ何もアップロードしていません。
+media-delete = 削除
+
+## Public profiles
+
+profile-not-found = プロフィールが見つかりません。
+profile-joined = @{ $username } · { $date }から参加
+profile-followers = フォロワー { $count } 人
+profile-following = フォロー中 { $count } 人
+profile-posts = 投稿
+profile-no-posts = まだ公開された投稿はありません。
+
+## Search
+
+search-placeholder = 投稿、コメント、ユーザーを検索...
+search-button = 検索
+search-title = 「{ $query }」の検索結果
+search-count = { $count } 件
+search-no-results = 一致するものはありませんでした。
+search-kind-all = すべて
+search-kind-post = 投稿
+search-kind-comment = コメント
+search-kind-user = ユーザー
+search-author = 作成者
+search-from = 開始日
+search-to = 終了日
+search-comment-on = 「{ $title }」へのコメント
+search-by = @{ $author }
+search-previous = 前へ
+search-next = 次へ
+search-page = { $page } / { $pages } ページ
+
+## Find and replace
+
+find-replace-title = 検索と置換
+find-replace-close = 閉じる
+find-replace-find = 検索
+find-replace-replace = 置換後
+find-replace-match-case = 大文字と小文字を区別
+find-replace-whole-word = 単語単位
+find-replace-regex = 正規表現
+find-replace-scope-document = このドキュメント
+find-replace-scope-manuscript = 原稿全体
+find-replace-invalid-regex = 正規表現が正しくありません: { $error }
+find-replace-no-matches = 一致するものはありません。
+find-replace-count = { $documents } 件のドキュメントで { $count } 件
+find-replace-include = この一致を置換する
+find-replace-line = { $line } 行目
+find-replace-apply = { $count } 件を置換
+find-replace-undo = 置換を元に戻す
+find-replace-undone = 置換を元に戻しました。
+find-replace-summary = 「{ $query }」を「{ $replacement }」に置換しました（{ $documents } 件のドキュメントで { $count } 件）
+
+## Revisions
+
+revision-title = 履歴
+revision-empty = まだ履歴はありません。
+revision-nothing-to-undo = 元に戻せる置換はありません。
+revision-edited-since = 置換後に「{ $title }」が編集されたため、元に戻せません。
+revision-undo-summary = 元に戻しました: { $summary }
+
+## Markdown
+
+markdown-contents = 目次
+markdown-preview = プレビュー
+markdown-preview-empty = プレビューする内容はまだありません。
+
+## Posts
+
+post-not-found = 投稿が見つかりません。
+post-published = { $date } に公開
+blog-previous = 前へ
+blog-next = 次へ
+
+## Sign in and registration
+
+auth-email = メールアドレス
+auth-password = パスワード
+auth-sign-in = サインイン
+auth-signing-in = サインイン中...
+auth-remember-password = パスワードを思い出しましたか？
+auth-fill-all-fields = すべての項目を入力してください
+auth-enter-email = メールアドレスを入力してください
+auth-invalid-email = 有効なメールアドレスを入力してください
+auth-passwords-mismatch = パスワードが一致しません
+auth-password-too-short = パスワードは8文字以上にしてください
+login-title = アカウントにサインイン
+login-no-account = アカウントをお持ちでないですか？
+login-sign-up = 登録
+login-locked = サインインの失敗が多すぎます。{ $date } { $time } 以降に再度お試しください。
+login-not-verified = サインインする前にメールアドレスを確認してください
+login-resend = 確認メールを再送信
+login-resent = 確認が必要なアカウントであれば、新しいリンクを送信しました。
+login-two-factor-hint = 認証アプリの6桁のコード、またはリカバリーコードのいずれかを入力してください。
+login-code = 認証コード
+login-verify = 確認
+login-verifying = 確認中...
+login-remember-me = ログイン状態を保持
+login-forgot = パスワードをお忘れですか？
+register-title = 新しいアカウントを作成
+register-have-account = すでにアカウントをお持ちですか？
+register-closed = 現在、登録は受け付けていません。
+register-invited-by = { $name } さんから招待されました。
+register-invited = 招待されています。
+register-created = アカウントを作成しました。受信トレイに届いたリンクからメールアドレスを確認してください。
+register-username = ユーザー名
+register-confirm-password = パスワード（確認）
+register-invite-code = 招待コード
+register-invite-code-optional = 招待コード（任意）
+register-submit = 登録
+register-submitting = アカウントを作成中...
+reset-title = パスワードをリセット
+reset-hint = メールアドレスを入力すると、パスワードをリセットするリンクをお送りします。
+reset-sent = { $email } のアカウントが存在する場合、パスワードリセット用のリンクを送信しました。メールをご確認ください。
+reset-failed = 問題が発生しました。もう一度お試しください。
+reset-submit = リセットリンクを送信
+reset-submitting = 送信中...
+new-password-title = 新しいパスワードを設定
+new-password-label = 新しいパスワード
+new-password-confirm = 新しいパスワード（確認）
+new-password-invalid-link = このリセットリンクは無効か、有効期限が切れています
+new-password-changed = パスワードを変更し、すべての端末からサインアウトしました。新しいパスワードでサインインしてください。
+new-password-submit = パスワードを更新
+new-password-submitting = 更新中...
+verify-title = メールアドレスの確認
+verify-ready = 書く準備はできましたか？
+verify-checking = リンクを確認中...
+verify-done = ありがとうございます。{ $email } の確認が完了しました。
+verify-invalid-link = この確認リンクは無効か、有効期限が切れています
+
+## Editor
+
+editor-save = 保存
+editor-words = { $count } 語
+editor-selected = 選択中: { $document }
+
+## Shared labels
+
+common-previous = 前へ
+common-next = 次へ
+common-page = { $page } / { $pages } ページ
+common-name = 名前
+common-description = 説明
+common-save = 保存
+common-cancel = キャンセル
+common-edit = 編集
+common-delete = 削除
+
+## Administration
+
+admin-welcome = 管理ダッシュボードへようこそ！
+admin-welcome-hint = ここではユーザーの管理、通報の確認、管理作業ができます。
+admin-total-users = ユーザー総数
+admin-reported-content = 通報されたコンテンツ
+admin-active-sessions = アクティブなセッション
+admin-manage-users = ユーザーを管理
+admin-view-reports = 通報を見る
+admin-settings = 設定
+admin-user-filter = ユーザー名またはメール
+admin-col-when = 日時
+admin-col-user = ユーザー
+admin-col-ip = IP
+audit-title = 監査ログ
+audit-subtitle = 誰が何をいつ変更したか
+audit-all-entities = すべての対象
+audit-all-actions = すべての操作
+audit-export = CSV をエクスポート
+audit-download = { $file } をダウンロード
+audit-col-action = 操作
+audit-col-entity = 対象
+audit-col-changed = 変更内容
+audit-count = { $count } 件
+audit-system = システム
+audit-before = 変更前
+audit-after = 変更後
+security-title = セキュリティログ
+security-subtitle = サイト全体のサインイン、パスワード変更などのアカウントイベント
+security-all-events = すべてのイベント
+security-ip-filter = IP または CIDR（例: 203.0.113.0/24）
+security-col-event = イベント
+security-col-details = 詳細
+security-count = { $count } 件
+flags-title = 機能フラグ
+flags-subtitle = 機能を全員、一部のユーザー、または選んだアカウントに対して有効にします
+flags-new = 新しいフラグ
+flags-bad-user-id = { $id } はユーザー ID ではありません
+flags-name-placeholder = 例: markdown_preview
+flags-rollout = 展開率（段階的展開では開始時の割合）
+flags-increment = 1 日あたりの増加（0 で割合を固定）
+flags-targets = これらのユーザー ID では常に有効
+flags-enabled = 有効
+flags-col-flag = フラグ
+flags-col-status = 状態
+flags-col-reaching = 対象
+flags-col-targets = 指定ユーザー
+flags-status-off = オフ
+flags-status-on = オン
+flags-status-gradual = 段階的: { $from }% から 1 日 +{ $step }%
+invitations-title = 招待
+invitations-subtitle = サイト内のすべての招待です。ここで作成した招待はロールを指定でき、上限はありません。
+invitations-email = このメール専用（任意）
+invitations-default-role = デフォルトのロール
+invitations-note = メモ（任意）
+invitations-uses = 使用回数（空欄で無制限）
+invitations-expires = 有効期限（日数、空欄で無期限）
+invitations-create = 招待を作成
+invitations-empty = 招待はまだありません。
+maintenance-title = メンテナンス
+maintenance-subtitle = メンテナンス中は管理者以外は読み取り専用になります。時刻は UTC です。
+maintenance-times-required = 開始時刻と終了時刻を入力してください
+maintenance-description = 内容（例: データベースのアップグレード）
+maintenance-schedule = 予定に追加
+maintenance-empty = 予定されたメンテナンスはありません。
+maintenance-window = { $from } – { $to }（{ $minutes } 分）
+maintenance-running = 実行中
+maintenance-end-now = 今すぐ終了
+reports-subtitle = ここでは通報されたコンテンツを確認・管理できます。
+reports-total = 通報総数
+reports-pending = 確認待ち
+reports-unassigned-count = 未割り当て { $count } 件
+reports-resolved = 解決済みの通報
+reports-dismissed = 却下
+reports-any-status = すべての状態
+reports-any-target = 投稿・コメント・プロフィール
+reports-any-reason = すべての理由
+reports-assigned-to-me = 自分に割り当て済み
+reports-empty = 確認するものはありません。
+reports-deleted-account = 削除されたアカウント
+reports-assigned-to = { $name } に割り当て
+reports-unassigned = 未割り当て
+reports-resolved-with = 解決済み: { $action }
+reports-resolved-badge = 解決済み
+reports-heading = { $reason } · { $author } の{ $target }
+reports-reported-by = { $date } に { $reporter } が通報
+reports-unassign = 割り当てを解除
+reports-assign-to-me = 自分に割り当て
+reports-resolve = 解決
+reports-dismiss = 却下
+reports-note-placeholder = モデレーターメモ（通報に保存され、解決または却下時に追加されます）
+reports-add-note = メモを追加
+reports-hide-notes = メモを隠す
+reports-show-notes = メモを表示
+reports-no-notes = メモはまだありません。
+settings-subtitle = アプリケーションの設定と環境設定
+settings-banner = 変更はサイト全体に適用されます。他のサーバーには 30 秒以内に反映されます。
+settings-application = アプリケーション設定
+settings-load-failed = 設定を読み込めませんでした: { $error }
+settings-help = お困りですか？
+settings-help-maintenance = メンテナンスモードは専用ページに移りました。読み取り専用の時間帯を事前に予約できます。
+settings-contact = サポートに連絡
+settings-recent = 最近のアクティビティ
+settings-no-changes = 変更はまだありません。
+settings-changed = { $fields } を変更
+settings-system = システム
+settings-saved = 設定を保存しました。
+settings-site-name = サイト名
+settings-site-name-placeholder = サイト名を入力
+settings-admin-email = 管理者のメール
+settings-registration = 登録
+settings-post-visibility = 新しい投稿の初期状態
+settings-ai-provider = AI プロバイダー
+settings-ai-model = モデル
+settings-ai-address = API アドレス
+settings-max-upload = 最大アップロードサイズ（MB）
+settings-user-quota = ユーザーごとの容量（MB）
+settings-upload-types = 許可するファイル形式
+settings-upload-types-placeholder = 1 行に 1 つの MIME タイプ（例: image/png）
+settings-save = 設定を保存
+users-title = ユーザー管理
+users-subtitle = ユーザーの管理、プロフィールの確認、管理作業
+users-active-count = アクティブなユーザー
+users-inactive-count = 非アクティブなユーザー
+users-unverified-count = 未確認のメール
+users-any-role = すべてのロール
+users-any-active = 有効・無効
+users-active = 有効
+users-inactive = 無効
+users-deactivated-filter = 無効化済み
+users-any-verified = 確認済み・未確認
+users-email-verified = メール確認済み
+users-email-unverified = メール未確認
+users-unverified = 未確認
+users-invited-by = { $name } の招待
+users-never = なし
+users-list = ユーザー一覧（{ $count }）
+users-selected = { $count } 件選択中
+users-bulk-actions = 一括操作
+users-apply = 適用
+users-confirm-bulk-delete = { $count } 人のユーザーを本当に削除しますか？
+users-bulk-done = { $action }: { $count } 人
+users-bulk-skipped = 、{ $count } 件をスキップ（{ $reasons }）
+users-col-status = 状態
+users-col-last-login = 最終サインイン
+users-col-actions = 操作
+users-reactivated = { $name } を再有効化しました
+users-deactivated = { $name } を無効化してサインアウトしました
+users-reset-sent = { $name } に新しいパスワードを設定するリンクを送信しました
+users-sessions-ended = { $name } のセッションを { $count } 件終了しました
+users-deleted = { $name } を削除しました
+users-deactivate = 無効化
+users-reactivate = 再有効化
+users-reset-password = パスワードをリセット
+users-sign-out = サインアウト
+users-impersonate = このユーザーとしてサインイン
+users-confirm-delete = 削除を確定
+users-keep = 残す
+users-edit = { $name } を編集
+users-locked = ロックされたアカウント
+users-locked-until = { $email } · { $time } までロック
+users-unlock = ロック解除
+db-health-title = データベースの状態
+db-health-critical = 危険
+db-health-warning = 警告
+db-health-normal = 正常
+db-health-no-data = データはまだありません
+db-health-hours = { $count } 時間
+db-health-days = { $count } 日
+db-health-check-now = 今すぐ確認
+db-health-connections = サーバー接続数
+db-health-pool = このサーバーのプール
+db-health-pool-usage = 使用中 { $used }、待機 { $idle }、最大 { $max }
+db-health-latency = レイテンシ
+db-health-latency-value = 待ち時間 { $wait } ms、ping { $ping } ms
+db-health-usage-legend = 接続の使用率（0–100%）
+db-health-wait-legend = プールの待ち時間（0–{ $max }）
+db-health-slow = 遅いクエリ
+db-health-slow-stats = { $count } 回実行、平均 { $mean } ms
+db-health-slow-empty = この期間の記録はありません。
@@ -0,0 +1,371 @@
+## Navigation
+
+nav-home = 首页
+nav-blog = 博客
+nav-editor = 编辑器
+nav-focus-mode = 专注模式
+nav-login = 登录
+nav-register = 注册
+nav-reset-password = 重置密码
+nav-admin-dashboard = 管理后台
+nav-admin-users = 用户管理
+nav-admin-reports = 举报管理
+nav-admin-settings = 站点设置
+nav-terms = 服务条款
+nav-privacy = 隐私政策
+nav-ai-chat = AI 聊天
+
+## Avatar menu
+
+menu-profile = 个人资料
+menu-toggle-theme = 切换主题
+menu-help = 帮助中心
+menu-sign-out = 退出登录
+
+common-loading = 加载中...
+
+## Appearance settings
+
+appearance-title = 外观
+appearance-theme-light = 浅色
+appearance-theme-dark = 深色
+appearance-theme-system = 跟随系统
+appearance-system-hint = 跟随系统的浅色或深色设置。
+appearance-language = 语言
+
+## Uploads
+
+upload-add-image = 添加图片
+upload-uploading = 上传中...
+upload-unreadable = 无法读取该文件
+media-title = 上传
+media-upload-image = 上传图片
+media-quota = 已用 { $used } / { $limit }
+media-empty = 你还没有上传任何内容。
+media-delete = 删除
+
+## Public profiles
+
+profile-not-found = 未找到该用户。
+profile-joined = @{ $username } · { $date }加入
+profile-followers = { $count } 位关注者
+profile-following = 关注了 { $count } 人
+profile-posts = 文章
+profile-no-posts = 还没有发布任何内容。
+
+## Search
+
+search-placeholder = 搜索文章、评论和用户...
+search-button = 搜索
+search-title = “{ $query }”的搜索结果
+search-count = { $count } 个结果
+search-no-results = 没有找到匹配的内容。
+search-kind-all = 全部
+search-kind-post = 文章
+search-kind-comment = 评论
+search-kind-user = 用户
+search-author = 作者
+search-from = 开始日期
+search-to = 结束日期
+search-comment-on = 对“{ $title }”的评论
+search-by = 作者 @{ $author }
+search-previous = 上一页
+search-next = 下一页
+search-page = 第 { $page } / { $pages } 页
+
+## Find and replace
+
+find-replace-title = 查找和替换
+find-replace-close = 关闭
+find-replace-find = 查找
+find-replace-replace = 替换为
+find-replace-match-case = 区分大小写
+find-replace-whole-word = 全字匹配
+find-replace-regex = 正则表达式
+find-replace-scope-document = 当前文档
+find-replace-scope-manuscript = 整部文稿
+find-replace-invalid-regex = 无效的正则表达式：{ $error }
+find-replace-no-matches = 没有匹配项。
+find-replace-count = { $documents } 个文档中有 { $count } 处匹配
+find-replace-include = 替换此处
+find-replace-line = 第 { $line } 行
+find-replace-apply = 替换 { $count } 处
+find-replace-undo = 撤销替换
+find-replace-undone = 已撤销替换。
+find-replace-summary = 已将“{ $query }”替换为“{ $replacement }”（{ $documents } 个文档中共 { $count } 处）
+
+## Revisions
+
+revision-title = 修订记录
+revision-empty = 还没有修订记录。
+revision-nothing-to-undo = 没有可撤销的替换。
+revision-edited-since = “{ $title }”在替换后已被编辑，因此无法撤销。
+revision-undo-summary = 已撤销：{ $summary }
+
+## Markdown
+
+markdown-contents = 目录
+markdown-preview = 预览
+markdown-preview-empty = 还没有可预览的内容。
+
+## Posts
+
+post-not-found = 未找到文章。
+post-published = 发布于 { $date }
+blog-previous = 上一篇
+blog-next = 下一篇
+
+## Sign in and registration
+
+auth-email = 电子邮件地址
+auth-password = 密码
+auth-sign-in = 登录
+auth-signing-in = 正在登录...
+auth-remember-password = 想起密码了？
+auth-fill-all-fields = 请填写所有字段
+auth-enter-email = 请输入您的电子邮件
+auth-invalid-email = 请输入有效的电子邮件
+auth-passwords-mismatch = 两次输入的密码不一致
+auth-password-too-short = 密码至少需要 8 个字符
+login-title = 登录您的账户
+login-no-account = 还没有账户？
+login-sign-up = 注册
+login-locked = 登录失败次数过多。请在 { $date } { $time } 之后重试。
+login-not-verified = 请先验证您的电子邮件地址再登录
+login-resend = 重新发送验证邮件
+login-resent = 如果该账户需要验证，新的链接已在路上。
+login-two-factor-hint = 输入身份验证器应用中的 6 位代码，或任意一个恢复代码。
+login-code = 验证码
+login-verify = 验证
+login-verifying = 正在验证...
+login-remember-me = 记住我
+login-forgot = 忘记密码？
+register-title = 创建新账户
+register-have-account = 已有账户？
+register-closed = 目前暂停注册。
+register-invited-by = { $name } 邀请您加入。
+register-invited = 您已受邀加入。
+register-created = 账户已创建。请查收邮件中的链接以验证您的电子邮件地址。
+register-username = 用户名
+register-confirm-password = 确认密码
+register-invite-code = 邀请码
+register-invite-code-optional = 邀请码（可选）
+register-submit = 注册
+register-submitting = 正在创建账户...
+reset-title = 重置密码
+reset-hint = 输入您的电子邮件，我们会发送重置密码的链接。
+reset-sent = 如果 { $email } 存在账户，我们已发送密码重置链接。请查收邮件。
+reset-failed = 出了点问题，请重试。
+reset-submit = 发送重置链接
+reset-submitting = 正在发送...
+new-password-title = 设置新密码
+new-password-label = 新密码
+new-password-confirm = 确认新密码
+new-password-invalid-link = 此重置链接无效或已过期
+new-password-changed = 密码已更改，您已在所有设备上退出登录。请使用新密码登录。
+new-password-submit = 更新密码
+new-password-submitting = 正在更新...
+verify-title = 验证您的电子邮件
+verify-ready = 准备好写作了吗？
+verify-checking = 正在检查链接...
+verify-done = 谢谢！{ $email } 已通过验证。
+verify-invalid-link = 此验证链接无效或已过期
+
+## Editor
+
+editor-save = 保存
+editor-words = { $count } 个词
+editor-selected = 已选择：{ $document }
+
+## Shared labels
+
+common-previous = 上一页
+common-next = 下一页
+common-page = 第 { $page } 页，共 { $pages } 页
+common-name = 名称
+common-description = 描述
+common-save = 保存
+common-cancel = 取消
+common-edit = 编辑
+common-delete = 删除
+
+## Administration
+
+admin-welcome = 欢迎使用管理面板！
+admin-welcome-hint = 您可以在这里管理用户、查看举报并执行管理任务。
+admin-total-users = 用户总数
+admin-reported-content = 被举报内容
+admin-active-sessions = 活跃会话
+admin-manage-users = 管理用户
+admin-view-reports = 查看举报
+admin-settings = 设置
+admin-user-filter = 用户名或邮箱
+admin-col-when = 时间
+admin-col-user = 用户
+admin-col-ip = IP
+audit-title = 审计日志
+audit-subtitle = 谁在何时更改了什么
+audit-all-entities = 所有对象
+audit-all-actions = 所有操作
+audit-export = 导出 CSV
+audit-download = 下载 { $file }
+audit-col-action = 操作
+audit-col-entity = 对象
+audit-col-changed = 更改内容
+audit-count = { $count } 条记录
+audit-system = 系统
+audit-before = 更改前
+audit-after = 更改后
+security-title = 安全日志
+security-subtitle = 全站的登录、密码更改及其他账户事件
+security-all-events = 所有事件
+security-ip-filter = IP 或 CIDR，例如 203.0.113.0/24
+security-col-event = 事件
+security-col-details = 详情
+security-count = { $count } 个事件
+flags-title = 功能开关
+flags-subtitle = 为所有人、部分用户或指定账户开启功能
+flags-new = 新建开关
+flags-bad-user-id = { $id } 不是用户 ID
+flags-name-placeholder = 例如 markdown_preview
+flags-rollout = 发布比例（逐步发布时为起始比例）
+flags-increment = 每日增加（0 表示保持比例不变）
+flags-targets = 对这些用户 ID 始终开启
+flags-enabled = 已启用
+flags-col-flag = 开关
+flags-col-status = 状态
+flags-col-reaching = 覆盖
+flags-col-targets = 指定用户
+flags-status-off = 关闭
+flags-status-on = 开启
+flags-status-gradual = 逐步：从 { $from }% 起每天 +{ $step }%
+invitations-title = 邀请
+invitations-subtitle = 站内所有邀请。在此创建的邀请可以指定角色，且不受限制。
+invitations-email = 仅限此邮箱（可选）
+invitations-default-role = 默认角色
+invitations-note = 备注（可选）
+invitations-uses = 使用次数（留空为不限）
+invitations-expires = 过期天数（留空为永不过期）
+invitations-create = 创建邀请
+invitations-empty = 暂无邀请。
+maintenance-title = 维护
+maintenance-subtitle = 维护期间，除管理员外所有人只能读取。时间为 UTC。
+maintenance-times-required = 请输入开始和结束时间
+maintenance-description = 维护内容，例如：数据库升级
+maintenance-schedule = 安排
+maintenance-empty = 暂无维护计划。
+maintenance-window = { $from } – { $to }（{ $minutes } 分钟）
+maintenance-running = 进行中
+maintenance-end-now = 立即结束
+reports-subtitle = 您可以在这里查看和处理被举报的内容。
+reports-total = 举报总数
+reports-pending = 待审核
+reports-unassigned-count = { $count } 个未分配
+reports-resolved = 已处理的举报
+reports-dismissed = 已驳回
+reports-any-status = 任意状态
+reports-any-target = 文章、评论和个人资料
+reports-any-reason = 任意原因
+reports-assigned-to-me = 分配给我的
+reports-empty = 没有需要审核的内容。
+reports-deleted-account = 已删除的账户
+reports-assigned-to = 已分配给 { $name }
+reports-unassigned = 未分配
+reports-resolved-with = 已处理：{ $action }
+reports-resolved-badge = 已处理
+reports-heading = { $reason } · { $author } 的{ $target }
+reports-reported-by = 由 { $reporter } 于 { $date } 举报
+reports-unassign = 取消分配
+reports-assign-to-me = 分配给我
+reports-resolve = 处理
+reports-dismiss = 驳回
+reports-note-placeholder = 审核备注（随举报保存；在处理或驳回时添加）
+reports-add-note = 添加备注
+reports-hide-notes = 隐藏备注
+reports-show-notes = 显示备注
+reports-no-notes = 暂无备注。
+settings-subtitle = 配置应用设置和偏好
+settings-banner = 更改将应用于整个站点，其他服务器会在 30 秒内生效。
+settings-application = 应用设置
+settings-load-failed = 无法加载设置：{ $error }
+settings-help = 需要帮助？
+settings-help-maintenance = 维护模式现在有独立页面，可以提前安排只读时段。
+settings-contact = 联系支持
+settings-recent = 最近活动
+settings-no-changes = 暂无更改。
+settings-changed = 更改了 { $fields }
+settings-system = 系统
+settings-saved = 设置已保存。
+settings-site-name = 站点名称
+settings-site-name-placeholder = 输入站点名称
+settings-admin-email = 管理员邮箱
+settings-registration = 注册
+settings-post-visibility = 新文章默认为
+settings-ai-provider = AI 提供商
+settings-ai-model = 模型
+settings-ai-address = API 地址
+settings-max-upload = 最大上传大小（MB）
+settings-user-quota = 每位用户的存储空间（MB）
+settings-upload-types = 允许的文件类型
+settings-upload-types-placeholder = 每行一个 MIME 类型，例如 image/png
+settings-save = 保存设置
+users-title = 用户管理
+users-subtitle = 管理用户、查看资料并执行管理任务
+users-active-count = 活跃用户
+users-inactive-count = 未激活用户
+users-unverified-count = 未验证邮箱
+users-any-role = 任意角色
+users-any-active = 是否启用
+users-active = 已启用
+users-inactive = 未启用
+users-deactivated-filter = 已停用
+users-any-verified = 是否已验证
+users-email-verified = 邮箱已验证
+users-email-unverified = 邮箱未验证
+users-unverified = 未验证
+users-invited-by = 由 { $name } 邀请
+users-never = 从未
+users-list = 用户列表（{ $count }）
+users-selected = 已选择 { $count } 个
+users-bulk-actions = 批量操作
+users-apply = 应用
+users-confirm-bulk-delete = 确定要删除 { $count } 个用户吗？
+users-bulk-done = { $action }：{ $count } 个用户
+users-bulk-skipped = ，跳过 { $count } 个（{ $reasons }）
+users-col-status = 状态
+users-col-last-login = 上次登录
+users-col-actions = 操作
+users-reactivated = 已重新启用 { $name }
+users-deactivated = 已停用 { $name } 并将其登出
+users-reset-sent = 已向 { $name } 发送设置新密码的链接
+users-sessions-ended = 已结束 { $name } 的 { $count } 个会话
+users-deleted = 已删除 { $name }
+users-deactivate = 停用
+users-reactivate = 重新启用
+users-reset-password = 重置密码
+users-sign-out = 登出
+users-impersonate = 以该用户登录
+users-confirm-delete = 确认删除
+users-keep = 保留
+users-edit = 编辑 { $name }
+users-locked = 已锁定账户
+users-locked-until = { $email } · 锁定至 { $time }
+users-unlock = 解锁
+db-health-title = 数据库健康状况
+db-health-critical = 严重
+db-health-warning = 警告
+db-health-normal = 正常
+db-health-no-data = 暂无数据
+db-health-hours = { $count } 小时
+db-health-days = { $count } 天
+db-health-check-now = 立即检查
+db-health-connections = 服务器连接数
+db-health-pool = 本服务器的连接池
+db-health-pool-usage = 使用中 { $used }，空闲 { $idle }，最大 { $max }
+db-health-latency = 延迟
+db-health-latency-value = 等待 { $wait } ms，ping { $ping } ms
+db-health-usage-legend = 连接使用率（0–100%）
+db-health-wait-legend = 连接池等待（0–{ $max }）
+db-health-slow = 慢查询
+db-health-slow-stats = 执行 { $count } 次，平均 { $mean } ms
+db-health-slow-empty = 此期间没有记录。
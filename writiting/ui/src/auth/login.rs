@@ -1,6 +1,10 @@
 // client/components/auth/login.rs
 use dioxus::prelude::*;
-use crate::auth::common::{AuthFormContainer, AuthInputField, AuthButton, AuthLink};
+use crate::auth::common::{AuthFormContainer, AuthInputField, AuthButton, AuthLink, AuthSuccessAlert};
+use crate::auth::session::session_changed;
+use crate::i18n::FluentDate;
+use crate::t;
+use api::authentication::{login, resend_verification_email, verify_two_factor_login, LoginOutcome, LoginRequest};
 
 #[derive(Default, Clone)]
 struct LoginForm {
@@ -14,6 +18,10 @@ pub fn Login() -> Element {
     let mut form = use_signal(LoginForm::default);
     let mut error = use_signal(|| None::<String>);
     let mut loading = use_signal(|| false);
+    let mut not_verified = use_signal(|| false);
+    let mut resent = use_signal(|| false);
+    let mut challenge = use_signal(|| None::<String>);
+    let mut code = use_signal(String::new);
     let navigator = use_navigator();
 
     let handle_submit = move |evt: FormEvent| {
@@ -21,93 +29,192 @@ pub fn Login() -> Element {
         loading.set(true);
 
         spawn(async move {
-            // Simulate API call
-            tokio::time::sleep(std::time::Duration::from_secs(1)).await;
-            
+            let current = form.read().clone();
+
             // Simple validation
-            if form.read().email.is_empty() || form.read().password.is_empty() {
-                error.set(Some("Please fill in all fields".to_string()));
-            } else if !form.read().email.contains('@') {
-                error.set(Some("Please enter a valid email".to_string()));
+            if current.email.is_empty() || current.password.is_empty() {
+                error.set(Some(t!("auth-fill-all-fields")));
+            } else if !current.email.contains('@') {
+                error.set(Some(t!("auth-invalid-email")));
             } else {
-                // Successful login
-                println!("Logged in with email: {}", form.read().email);
-                navigator.push("/");
+                let request = LoginRequest {
+                    email: current.email,
+                    password: current.password,
+                };
+
+                match login(request).await {
+                    Ok(LoginOutcome::Authenticated(_)) => {
+                        error.set(None);
+                        session_changed();
+                        navigator.push("/");
+                    }
+                    Ok(LoginOutcome::TwoFactorRequired { challenge: token }) => {
+                        error.set(None);
+                        challenge.set(Some(token));
+                    }
+                    Ok(LoginOutcome::Locked { locked_until }) => {
+                        let time = locked_until.with_timezone(&chrono::Local).format("%H:%M").to_string();
+                        error.set(Some(t!("login-locked", time = time, date = FluentDate::long(locked_until))));
+                    }
+                    Ok(LoginOutcome::EmailNotVerified) => {
+                        error.set(Some(t!("login-not-verified")));
+                        not_verified.set(true);
+                    }
+                    Err(err) => {
+                        log::error!("Login failed: {}", err);
+                        error.set(Some(err.to_string()));
+                    }
+                }
             }
 
             loading.set(false);
         });
     };
 
+    let handle_code = move |evt: FormEvent| {
+        evt.prevent_default();
+        let Some(token) = challenge() else { return };
+        loading.set(true);
+
+        spawn(async move {
+            match verify_two_factor_login(token, code()).await {
+                Ok(_) => {
+                    error.set(None);
+                    session_changed();
+                    navigator.push("/");
+                }
+                Err(err) => {
+                    log::error!("Two-factor verification failed: {}", err);
+                    error.set(Some(err.to_string()));
+                    code.set(String::new());
+                }
+            }
+
+            loading.set(false);
+        });
+    };
+
+    let handle_resend = move |_| {
+        spawn(async move {
+            let email = form.read().email.clone();
+            match resend_verification_email(email).await {
+                Ok(()) => resent.set(true),
+                Err(err) => error.set(Some(err.to_string())),
+            }
+        });
+    };
+
     rsx! {
         AuthFormContainer {
-            title: "Sign in to your account",
+            title: t!("login-title"),
             footer: rsx! {
                 AuthLink {
                     to: "/auth/register",
-                    text: "Don't have an account?",
-                    link_text: "Sign up",
+                    text: t!("login-no-account"),
+                    link_text: t!("login-sign-up"),
                 }
             },
 
-            form { class: "mt-8 space-y-6", onsubmit: handle_submit,
-                if let Some(err) = error.read().as_ref() {
-                    div { class: "p-4 text-sm text-red-600 bg-red-50 rounded-md",
-                        p { "{err}" }
+            if challenge.read().is_some() {
+                form { class: "mt-8 space-y-6", onsubmit: handle_code,
+                    if let Some(err) = error.read().as_ref() {
+                        div { class: "p-4 text-sm text-red-600 bg-red-50 rounded-md",
+                            p { "{err}" }
+                        }
                     }
-                }
 
-                div { class: "space-y-4",
-                    AuthInputField {
-                        id: "email",
-                        label: "Email address",
-                        r#type: "email",
-                        value: form.read().email.clone(),
-                        disabled: loading(),
-                        oninput: move |e: dioxus::events::FormEvent| form.with_mut(|f| f.email = e.value()),
-                        error: None,
+                    div { class: "text-sm text-gray-600",
+                        {t!("login-two-factor-hint")}
                     }
 
                     AuthInputField {
-                        id: "password",
-                        label: "Password",
-                        r#type: "password",
-                        value: form.read().password.clone(),
+                        id: "code",
+                        label: t!("login-code"),
+                        r#type: "text",
+                        value: code(),
                         disabled: loading(),
-                        oninput: move |e: dioxus::events::FormEvent| form.with_mut(|f| f.password = e.value()),
+                        oninput: move |e: dioxus::events::FormEvent| code.set(e.value()),
                         error: None,
                     }
+
+                    AuthButton {
+                        loading: loading(),
+                        label: t!("login-verify"),
+                        loading_label: t!("login-verifying"),
+                    }
                 }
+            } else {
+                form { class: "mt-8 space-y-6", onsubmit: handle_submit,
+                    if let Some(err) = error.read().as_ref() {
+                        div { class: "p-4 text-sm text-red-600 bg-red-50 rounded-md",
+                            p { "{err}" }
+                            if not_verified() && !resent() {
+                                button {
+                                    r#type: "button",
+                                    class: "mt-2 font-medium text-indigo-600 hover:text-indigo-500",
+                                    onclick: handle_resend,
+                                    {t!("login-resend")}
+                                }
+                            }
+                        }
+                    }
+
+                    if resent() {
+                        AuthSuccessAlert { message: t!("login-resent") }
+                    }
 
-                div { class: "flex items-center justify-between",
-                    div { class: "flex items-center",
-                        input {
-                            r#type: "checkbox",
-                            id: "remember-me",
-                            checked: form.read().remember,
-                            onchange: move |e| form.with_mut(|f| f.remember = e.value().parse().unwrap_or(false)),
-                            class: "h-4 w-4 text-indigo-600 focus:ring-indigo-500 border-gray-300 rounded",
+                    div { class: "space-y-4",
+                        AuthInputField {
+                            id: "email",
+                            label: t!("auth-email"),
+                            r#type: "email",
+                            value: form.read().email.clone(),
+                            disabled: loading(),
+                            oninput: move |e: dioxus::events::FormEvent| form.with_mut(|f| f.email = e.value()),
+                            error: None,
                         }
-                        label {
-                            r#for: "remember-me",
-                            class: "ml-2 block text-sm text-gray-900",
-                            "Remember me"
+
+                        AuthInputField {
+                            id: "password",
+                            label: t!("auth-password"),
+                            r#type: "password",
+                            value: form.read().password.clone(),
+                            disabled: loading(),
+                            oninput: move |e: dioxus::events::FormEvent| form.with_mut(|f| f.password = e.value()),
+                            error: None,
                         }
                     }
 
-                    div { class: "text-sm",
-                        Link {
-                            to: "/auth/reset-password", // Simple string path
-                            class: "font-medium text-indigo-600 hover:text-indigo-500",
-                            "Forgot password?"
+                    div { class: "flex items-center justify-between",
+                        div { class: "flex items-center",
+                            input {
+                                r#type: "checkbox",
+                                id: "remember-me",
+                                checked: form.read().remember,
+                                onchange: move |e| form.with_mut(|f| f.remember = e.value().parse().unwrap_or(false)),
+                                class: "h-4 w-4 text-indigo-600 focus:ring-indigo-500 border-gray-300 rounded",
+                            }
+                            label {
+                                r#for: "remember-me",
+                                class: "ml-2 block text-sm text-gray-900",
+                                {t!("login-remember-me")}
+                            }
+                        }
+
+                        div { class: "text-sm",
+                            Link {
+                                to: "/auth/reset-password", // Simple string path
+                                class: "font-medium text-indigo-600 hover:text-indigo-500",
+                                {t!("login-forgot")}
+                            }
                         }
                     }
-                }
 
-                AuthButton {
-                    loading: loading(),
-                    label: "Sign in",
-                    loading_label: "Signing in...",
+                    AuthButton {
+                        loading: loading(),
+                        label: t!("auth-sign-in"),
+                        loading_label: t!("auth-signing-in"),
+                    }
                 }
             }
         }
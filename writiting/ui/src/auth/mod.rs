@@ -4,9 +4,15 @@ pub mod register;
 pub mod reset_password;
 pub mod state;
 pub mod common;
+pub mod verify_email;
+pub mod new_password;
+pub mod session;
 pub use login::Login;
 pub use register::Register;
 pub use reset_password::ResetPassword;
+pub use verify_email::VerifyEmail;
+pub use new_password::NewPassword;
+pub use session::{init_session, session_changed, session_version, SessionVersion};
 pub use state::{Notification, NotificationType, LoginForm, FormErrors, LoginState, LoginResult, simulate_login_api};
 pub use common::{AuthFormContainer, AuthLink, AuthLinkProps, AuthFormContainerProps, AuthInputField, AuthInputFieldProps, AuthButton, AuthButtonProps};
 
@@ -0,0 +1,107 @@
+// client/components/auth/new_password.rs
+use dioxus::prelude::*;
+use uuid::Uuid;
+use crate::auth::common::{AuthFormContainer, AuthInputField, AuthButton, AuthLink, AuthSuccessAlert};
+use api::authentication::complete_password_reset;
+use crate::t;
+
+#[derive(Default, Clone)]
+struct NewPasswordForm {
+    password: String,
+    confirm_password: String,
+}
+
+/// Landing page for the link in password reset emails
+#[component]
+pub fn NewPassword(token: String) -> Element {
+    let mut form = use_signal(NewPasswordForm::default);
+    let mut error = use_signal(|| None::<String>);
+    let mut success = use_signal(|| false);
+    let mut loading = use_signal(|| false);
+
+    let handle_submit = move |evt: FormEvent| {
+        evt.prevent_default();
+        loading.set(true);
+        let token = token.clone();
+
+        spawn(async move {
+            let current = form.read().clone();
+
+            if current.password.len() < 8 {
+                error.set(Some(t!("auth-password-too-short")));
+            } else if current.password != current.confirm_password {
+                error.set(Some(t!("auth-passwords-mismatch")));
+            } else {
+                match Uuid::parse_str(token.trim()) {
+                    Ok(token) => match complete_password_reset(token, current.password).await {
+                        Ok(()) => success.set(true),
+                        Err(err) => {
+                            log::error!("Password reset failed: {}", err);
+                            error.set(Some(err.to_string()));
+                        }
+                    },
+                    Err(_) => error.set(Some(t!("new-password-invalid-link"))),
+                }
+            }
+
+            loading.set(false);
+        });
+    };
+
+    rsx! {
+        AuthFormContainer {
+            title: t!("new-password-title"),
+            footer: rsx! {
+                AuthLink {
+                    to: "/auth/login",
+                    text: t!("auth-remember-password"),
+                    link_text: t!("auth-sign-in"),
+                }
+            },
+
+            if success() {
+                AuthSuccessAlert {
+                    message: t!("new-password-changed"),
+                }
+            } else {
+                form { class: "mt-8 space-y-6", onsubmit: handle_submit,
+                    if let Some(err) = error.read().as_ref() {
+                        div { class: "p-4 text-sm text-red-600 bg-red-50 rounded-md",
+                            p { "{err}" }
+                        }
+                    }
+
+                    div { class: "space-y-4",
+                        AuthInputField {
+                            id: "password",
+                            label: t!("new-password-label"),
+                            r#type: "password",
+                            value: form.read().password.clone(),
+                            disabled: loading(),
+                            oninput: move |e: dioxus::events::FormEvent| form.with_mut(|f| f.password = e.value()),
+                            error: None,
+                        }
+
+                        AuthInputField {
+                            id: "confirm_password",
+                            label: t!("new-password-confirm"),
+                            r#type: "password",
+                            value: form.read().confirm_password.clone(),
+                            disabled: loading(),
+                            oninput: move |e: dioxus::events::FormEvent| {
+                                form.with_mut(|f| f.confirm_password = e.value())
+                            },
+                            error: None,
+                        }
+                    }
+
+                    AuthButton {
+                        loading: loading(),
+                        label: t!("new-password-submit"),
+                        loading_label: t!("new-password-submitting"),
+                    }
+                }
+            }
+        }
+    }
+}
@@ -1,46 +1,104 @@
 // client/components/auth/register.rs
 use dioxus::prelude::*;
-use crate::auth::common::{AuthFormContainer, AuthInputField, AuthButton, AuthLink};
+use crate::auth::common::{AuthFormContainer, AuthInputField, AuthButton, AuthLink, AuthSuccessAlert};
+use api::authentication::{check_invitation, register, RegisterRequest};
+use api::settings::{get_public_settings, RegistrationMode};
+use crate::t;
 
 #[derive(Default, Clone)]
 struct RegisterForm {
     email: String,
     password: String,
     confirm_password: String,
-    name: String,
+    username: String,
+    invite_code: String,
 }
 
+/// Sign-up form. `invite` comes from invitation links (`/auth/register?invite=<code>`).
 #[component]
-pub fn Register() -> Element {
-    let mut form = use_signal(RegisterForm::default);
+pub fn Register(invite: String) -> Element {
+    let mut form = use_signal(|| RegisterForm { invite_code: invite.clone(), ..Default::default() });
     let mut error = use_signal(|| None::<String>);
     let mut loading = use_signal(|| false);
-    let navigator = use_navigator();
+    let mut registered = use_signal(|| false);
+
+    let settings = use_resource(|| async move { get_public_settings().await });
+    // Only the code from the link is checked up front; a typed one is checked on submit
+    let preview = use_resource(use_reactive!(|invite| async move {
+        if invite.trim().is_empty() {
+            return None;
+        }
+        match check_invitation(invite).await {
+            Ok(preview) => {
+                if let Some(email) = &preview.email {
+                    form.with_mut(|f| f.email = email.clone());
+                }
+                Some(Ok(preview))
+            }
+            Err(err) => Some(Err(err.to_string())),
+        }
+    }));
+
+    let mode = match &*settings.read() {
+        Some(Ok(settings)) => settings.registration_mode,
+        _ => RegistrationMode::Open,
+    };
+    let invite_label = match mode {
+        RegistrationMode::InviteOnly => t!("register-invite-code"),
+        _ => t!("register-invite-code-optional"),
+    };
+    // (message, classes) about the invitation from the link
+    let invite_notice = match &*preview.read() {
+        Some(Some(Ok(preview))) => Some((
+            match &preview.invited_by {
+                Some(name) => t!("register-invited-by", name = name.clone()),
+                None => t!("register-invited"),
+            },
+            "text-green-700 bg-green-50",
+        )),
+        Some(Some(Err(err))) => Some((err.clone(), "text-red-600 bg-red-50")),
+        _ => None,
+    };
+    let email_pinned = matches!(&*preview.read(), Some(Some(Ok(p))) if p.email.is_some());
 
     let handle_submit = move |evt: FormEvent| {
         evt.prevent_default();
         loading.set(true);
 
         spawn(async move {
-            // Simulate API call
-            tokio::time::sleep(std::time::Duration::from_secs(1)).await;
-            
+            let current = form.read().clone();
+
             // Validation
-            if form.read().email.is_empty() 
-                || form.read().password.is_empty() 
-                || form.read().confirm_password.is_empty() 
-                || form.read().name.is_empty() {
-                error.set(Some("Please fill in all fields".to_string()));
-            } else if !form.read().email.contains('@') {
-                error.set(Some("Please enter a valid email".to_string()));
-            } else if form.read().password != form.read().confirm_password {
-                error.set(Some("Passwords do not match".to_string()));
-            } else if form.read().password.len() < 8 {
-                error.set(Some("Password must be at least 8 characters".to_string()));
+            if current.email.is_empty() 
+                || current.password.is_empty() 
+                || current.confirm_password.is_empty() 
+                || current.username.is_empty() {
+                error.set(Some(t!("auth-fill-all-fields")));
+            } else if !current.email.contains('@') {
+                error.set(Some(t!("auth-invalid-email")));
+            } else if current.password != current.confirm_password {
+                error.set(Some(t!("auth-passwords-mismatch")));
+            } else if current.password.len() < 8 {
+                error.set(Some(t!("auth-password-too-short")));
             } else {
-                // Successful registration
-                println!("Registered with email: {}", form.read().email);
-                navigator.push("/auth/login");
+                let request = RegisterRequest {
+                    email: current.email,
+                    username: current.username,
+                    password: current.password,
+                    password_confirmation: current.confirm_password,
+                    invite_code: Some(current.invite_code).filter(|code| !code.trim().is_empty()),
+                };
+
+                match register(request).await {
+                    Ok(_) => {
+                        error.set(None);
+                        registered.set(true);
+                    }
+                    Err(err) => {
+                        log::error!("Registration failed: {}", err);
+                        error.set(Some(err.to_string()));
+                    }
+                }
             }
             
             loading.set(false);
@@ -49,15 +107,33 @@ pub fn Register() -> Element {
 
     rsx! {
         AuthFormContainer {
-            title: "Create a new account",
+            title: t!("register-title"),
             footer: rsx! {
                 AuthLink {
                     to: "/auth/login",
-                    text: "Already have an account?",
-                    link_text: "Sign in",
+                    text: t!("register-have-account"),
+                    link_text: t!("auth-sign-in"),
                 }
             },
 
+            if mode == RegistrationMode::Closed {
+                div { class: "p-4 text-sm text-gray-700 bg-gray-50 rounded-md",
+                    p { {t!("register-closed")} }
+                }
+            }
+
+            if let Some((message, colors)) = invite_notice {
+                div { class: "p-4 text-sm rounded-md {colors}",
+                    p { "{message}" }
+                }
+            }
+
+            if registered() {
+                AuthSuccessAlert {
+                    message: t!("register-created"),
+                }
+            }
+
             form { class: "mt-8 space-y-6", onsubmit: handle_submit,
                 if let Some(err) = error.read().as_ref() {
                     div { class: "p-4 text-sm text-red-600 bg-red-50 rounded-md",
@@ -67,28 +143,28 @@ pub fn Register() -> Element {
 
                 div { class: "space-y-4",
                     AuthInputField {
-                        id: "name",
-                        label: "Full name",
+                        id: "username",
+                        label: t!("register-username"),
                         r#type: "text",
-                        value: form.read().name.clone(),
+                        value: form.read().username.clone(),
                         disabled: loading(),
-                        oninput: move |e: dioxus::events::FormEvent| form.with_mut(|f| f.name = e.value()),
+                        oninput: move |e: dioxus::events::FormEvent| form.with_mut(|f| f.username = e.value()),
                         error: None,
                     }
 
                     AuthInputField {
                         id: "email",
-                        label: "Email address",
+                        label: t!("auth-email"),
                         r#type: "email",
                         value: form.read().email.clone(),
-                        disabled: loading(),
+                        disabled: loading() || email_pinned,
                         oninput: move |e: dioxus::events::FormEvent| form.with_mut(|f| f.email = e.value()),
                         error: None,
                     }
 
                     AuthInputField {
                         id: "password",
-                        label: "Password",
+                        label: t!("auth-password"),
                         r#type: "password",
                         value: form.read().password.clone(),
                         disabled: loading(),
@@ -98,7 +174,7 @@ pub fn Register() -> Element {
 
                     AuthInputField {
                         id: "confirm_password",
-                        label: "Confirm Password",
+                        label: t!("register-confirm-password"),
                         r#type: "password",
                         value: form.read().confirm_password.clone(),
                         disabled: loading(),
@@ -107,12 +183,24 @@ pub fn Register() -> Element {
                         },
                         error: None,
                     }
+
+                    if mode != RegistrationMode::Closed {
+                        AuthInputField {
+                            id: "invite_code",
+                            label: invite_label.clone(),
+                            r#type: "text",
+                            value: form.read().invite_code.clone(),
+                            disabled: loading(),
+                            oninput: move |e: dioxus::events::FormEvent| form.with_mut(|f| f.invite_code = e.value()),
+                            error: None,
+                        }
+                    }
                 }
 
                 AuthButton {
                     loading: loading(),
-                    label: "Register",
-                    loading_label: "Creating account...",
+                    label: t!("register-submit"),
+                    loading_label: t!("register-submitting"),
                 }
             }
         }
@@ -1,6 +1,8 @@
 // client/components/auth/reset_password.rs
 use dioxus::prelude::*;
 use crate::auth::common::{AuthFormContainer, AuthInputField, AuthButton, AuthLink};
+use api::authentication::request_password_reset;
+use crate::t;
 
 #[derive(Default, Clone)]
 struct ResetPasswordForm {
@@ -19,17 +21,21 @@ pub fn ResetPassword() -> Element {
         loading.set(true);
 
         spawn(async move {
-            // Simulate API call
-            tokio::time::sleep(std::time::Duration::from_secs(1)).await;
-            
+            let email = form.read().email.clone();
+
             // Validation
-            if form.read().email.is_empty() {
-                error.set(Some("Please enter your email".to_string()));
-            } else if !form.read().email.contains('@') {
-                error.set(Some("Please enter a valid email".to_string()));
+            if email.is_empty() {
+                error.set(Some(t!("auth-enter-email")));
+            } else if !email.contains('@') {
+                error.set(Some(t!("auth-invalid-email")));
             } else {
-                // Successful submission
-                success.set(true);
+                match request_password_reset(email).await {
+                    Ok(()) => success.set(true),
+                    Err(err) => {
+                        log::error!("Password reset request failed: {}", err);
+                        error.set(Some(t!("reset-failed")));
+                    }
+                }
             }
             
             loading.set(false);
@@ -38,21 +44,18 @@ pub fn ResetPassword() -> Element {
 
     rsx! {
         AuthFormContainer {
-            title: "Reset your password",
+            title: t!("reset-title"),
             footer: rsx! {
                 AuthLink {
                     to: "/auth/login",
-                    text: "Remember your password?",
-                    link_text: "Sign in",
+                    text: t!("auth-remember-password"),
+                    link_text: t!("auth-sign-in"),
                 }
             },
 
             if *success.read() {
                 div { class: "p-4 text-sm text-green-600 bg-green-50 rounded-md",
-                    p {
-                        "We've sent a password reset link to {form.read().email}. "
-                        "Please check your email."
-                    }
+                    p { {t!("reset-sent", email = form.read().email.clone())} }
                 }
             } else {
                 form { class: "mt-8 space-y-6", onsubmit: handle_submit,
@@ -65,7 +68,7 @@ pub fn ResetPassword() -> Element {
                     div { class: "space-y-4",
                         AuthInputField {
                             id: "email",
-                            label: "Email address",
+                            label: t!("auth-email"),
                             r#type: "email",
                             value: form.read().email.clone(),
                             disabled: loading(),
@@ -75,13 +78,13 @@ pub fn ResetPassword() -> Element {
                     }
 
                     div { class: "text-sm text-gray-600",
-                        "Enter your email and we'll send you a link to reset your password."
+                        {t!("reset-hint")}
                     }
 
                     AuthButton {
                         loading: loading(),
-                        label: "Send reset link",
-                        loading_label: "Sending...",
+                        label: t!("reset-submit"),
+                        loading_label: t!("reset-submitting"),
                     }
                 }
             }
@@ -0,0 +1,28 @@
+use dioxus::prelude::*;
+
+/// Counts sign-ins, sign-outs and switches of account. Per-user state such as
+/// the theme and the UI language reads it, so it is fetched again for whoever
+/// is signed in now rather than only once when the app starts.
+#[derive(Clone, Copy, PartialEq)]
+pub struct SessionVersion(Signal<u32>);
+
+/// Provides the session version to the tree (call this in `main.rs`, before
+/// anything that reads it)
+pub fn init_session() -> SessionVersion {
+    let version = use_signal(|| 0);
+    use_context_provider(|| SessionVersion(version))
+}
+
+/// Reading this from a resource or effect re-runs it when the account changes
+pub fn session_version() -> u32 {
+    try_consume_context::<SessionVersion>()
+        .map(|SessionVersion(version)| version())
+        .unwrap_or_default()
+}
+
+/// Call once the server has signed someone in or out, or switched account
+pub fn session_changed() {
+    if let Some(SessionVersion(mut version)) = try_consume_context::<SessionVersion>() {
+        *version.write() += 1;
+    }
+}
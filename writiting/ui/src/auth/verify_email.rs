@@ -0,0 +1,45 @@
+// client/components/auth/verify_email.rs
+use dioxus::prelude::*;
+use uuid::Uuid;
+use crate::auth::common::{AuthFormContainer, AuthLink, AuthSuccessAlert, AuthErrorAlert, AuthInfoText};
+use api::authentication::verify_email;
+use crate::t;
+
+/// Landing page for the link in verification emails
+#[component]
+pub fn VerifyEmail(token: String) -> Element {
+    let result = use_resource(use_reactive!(|token| async move {
+        let token = Uuid::parse_str(token.trim())
+            .map_err(|_| t!("verify-invalid-link"))?;
+
+        verify_email(token).await.map_err(|err| {
+            log::error!("Email verification failed: {}", err);
+            err.to_string()
+        })
+    }));
+
+    rsx! {
+        AuthFormContainer {
+            title: t!("verify-title"),
+            footer: rsx! {
+                AuthLink {
+                    to: "/auth/login",
+                    text: t!("verify-ready"),
+                    link_text: t!("auth-sign-in"),
+                }
+            },
+
+            match &*result.read() {
+                None => rsx! {
+                    AuthInfoText { text: t!("verify-checking") }
+                },
+                Some(Ok(user)) => rsx! {
+                    AuthSuccessAlert { message: t!("verify-done", email = user.email.clone()) }
+                },
+                Some(Err(err)) => rsx! {
+                    AuthErrorAlert { message: err.clone() }
+                },
+            }
+        }
+    }
+}
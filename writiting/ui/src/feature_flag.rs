@@ -0,0 +1,22 @@
+use dioxus::prelude::*;
+
+/// Whether the feature flag `name` is on for the signed-in user.
+///
+/// Reads `false` until the server answers, and if it can't be reached, so
+/// flagged features stay hidden rather than flashing in and out.
+pub fn use_feature_flag(name: &str) -> bool {
+    let name = name.to_string();
+    let flag = use_resource(move || {
+        let name = name.clone();
+        async move { api::authentication::check_feature_flag(name).await }
+    });
+
+    // Logged once per failed request rather than on every render
+    use_effect(move || {
+        if let Some(Err(err)) = &*flag.read() {
+            log::warn!("Failed to check feature flag: {}", err);
+        }
+    });
+
+    matches!(&*flag.read(), Some(Ok(true)))
+}
@@ -0,0 +1,278 @@
+//! UI translations. Messages live in `ui/locales/<code>/main.ftl`, one Fluent
+//! file for each language `user_preferences.language` allows, and are looked
+//! up with [`t!`](crate::t).
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+use chrono::{DateTime, Locale, Utc};
+use dioxus::prelude::*;
+use fluent_bundle::concurrent::FluentBundle;
+use fluent_bundle::types::FluentType;
+use fluent_bundle::FluentResource;
+use intl_memoizer::concurrent::IntlLangMemoizer;
+use intl_memoizer::Memoizable;
+use unic_langid::LanguageIdentifier;
+
+pub use api::features::Language;
+pub use fluent_bundle::{FluentArgs, FluentValue};
+
+const SOURCES: [(Language, &str); 6] = [
+    (Language::English, include_str!("../locales/en/main.ftl")),
+    (Language::Spanish, include_str!("../locales/es/main.ftl")),
+    (Language::French, include_str!("../locales/fr/main.ftl")),
+    (Language::German, include_str!("../locales/de/main.ftl")),
+    (Language::Japanese, include_str!("../locales/ja/main.ftl")),
+    (Language::Chinese, include_str!("../locales/zh/main.ftl")),
+];
+
+/// Looks up a message in the current language, falling back to English.
+///
+/// ```ignore
+/// t!("nav-home")
+/// t!("profile-followers", count = profile.followers)
+/// t!("profile-joined", date = FluentDate::month_year(profile.joined_at))
+/// ```
+#[macro_export]
+macro_rules! t {
+    ($key:expr) => {
+        $crate::i18n::translate($key, None)
+    };
+    ($key:expr, $($name:ident = $value:expr),+ $(,)?) => {{
+        let mut args = $crate::i18n::FluentArgs::new();
+        $(args.set(stringify!($name), $value);)+
+        $crate::i18n::translate($key, Some(&args))
+    }};
+}
+
+/// The Fluent source for `language`
+pub fn source(language: Language) -> &'static str {
+    SOURCES
+        .iter()
+        .find(|(candidate, _)| *candidate == language)
+        .map(|(_, source)| *source)
+        .unwrap_or_default()
+}
+
+fn build_bundle(language: Language, source: &str) -> FluentBundle<FluentResource> {
+    let resource = FluentResource::try_new(source.to_string()).unwrap_or_else(|(resource, errors)| {
+        log::error!("Syntax errors in the {} messages: {:?}", language.code(), errors);
+        resource
+    });
+    let locale: LanguageIdentifier = language.code().parse().expect("language codes are valid locales");
+
+    let mut bundle = FluentBundle::new_concurrent(vec![locale]);
+    // Every supported language is left-to-right, so skip the bidi isolation marks
+    bundle.set_use_isolating(false);
+    bundle.set_formatter(Some(format_value));
+    if let Err(errors) = bundle.add_resource(resource) {
+        log::error!("Duplicate {} messages: {:?}", language.code(), errors);
+    }
+    bundle
+}
+
+fn bundles() -> &'static HashMap<Language, FluentBundle<FluentResource>> {
+    static BUNDLES: OnceLock<HashMap<Language, FluentBundle<FluentResource>>> = OnceLock::new();
+    BUNDLES.get_or_init(|| {
+        SOURCES
+            .iter()
+            .map(|(language, source)| (*language, build_bundle(*language, source)))
+            .collect()
+    })
+}
+
+/// `key` in `language`, or in English if that bundle lacks it. A key missing
+/// from both is logged and shown as-is.
+pub fn translate_in(language: Language, key: &str, args: Option<&FluentArgs>) -> String {
+    let bundles = bundles();
+    for candidate in [language, Language::English] {
+        let bundle = &bundles[&candidate];
+        let Some(pattern) = bundle.get_message(key).and_then(|message| message.value()) else {
+            continue;
+        };
+        let mut errors = Vec::new();
+        let text = bundle.format_pattern(pattern, args, &mut errors);
+        if !errors.is_empty() {
+            log::warn!("Problems formatting {} in {}: {:?}", key, candidate.code(), errors);
+        }
+        return text.into_owned();
+    }
+
+    log::warn!("Missing translation for {}", key);
+    key.to_string()
+}
+
+pub fn translate(key: &str, args: Option<&FluentArgs>) -> String {
+    translate_in(current_language(), key, args)
+}
+
+/// Provides the UI language to the tree (call this in `main.rs`). It starts as
+/// English and switches to the signed-in user's saved language, again whenever
+/// someone signs in or out.
+pub fn init_i18n() -> Signal<Language> {
+    let mut language = use_signal(Language::default);
+    use_context_provider(|| language);
+
+    use_resource(move || async move {
+        crate::auth::session_version();
+        match api::features::get_preferences().await {
+            Ok(preferences) => language.set(preferences.language),
+            Err(err) => {
+                log::debug!("Using the default language: {}", err);
+                language.set(Language::default());
+            }
+        }
+    });
+
+    use_effect(move || {
+        document::eval(&format!("document.documentElement.lang = '{}';", language().code()));
+    });
+
+    language
+}
+
+/// The UI language; set it to switch every `t!` below [`init_i18n`]
+pub fn use_language() -> Signal<Language> {
+    use_context::<Signal<Language>>()
+}
+
+/// Reading this from a component re-renders it when the language changes
+pub fn current_language() -> Language {
+    try_consume_context::<Signal<Language>>()
+        .map(|language| language())
+        .unwrap_or_default()
+}
+
+/// Thousands and decimal separators, from the bundle's locale
+struct NumberFormat {
+    group: &'static str,
+    decimal: &'static str,
+    /// Digits needed before grouping starts; Spanish leaves `1234` alone
+    min_grouped: usize,
+}
+
+impl Memoizable for NumberFormat {
+    type Args = ();
+    type Error = ();
+
+    fn construct(lang: LanguageIdentifier, _args: ()) -> Result<Self, ()> {
+        let (group, decimal, min_grouped) = match lang.language.as_str() {
+            "es" => (".", ",", 5),
+            "de" => (".", ",", 4),
+            "fr" => ("\u{202f}", ",", 4),
+            _ => (",", ".", 4),
+        };
+        Ok(Self { group, decimal, min_grouped })
+    }
+}
+
+impl NumberFormat {
+    /// Localizes Fluent's plain rendering, e.g. `-12345.5` -> `-12.345,5`
+    fn format(&self, plain: &str) -> String {
+        let (sign, unsigned) = plain.strip_prefix('-').map_or(("", plain), |rest| ("-", rest));
+        let (whole, fraction) = unsigned.split_once('.').map_or((unsigned, None), |(w, f)| (w, Some(f)));
+
+        let mut grouped = String::new();
+        for (i, digit) in whole.chars().enumerate() {
+            if i > 0 && whole.len() >= self.min_grouped && (whole.len() - i) % 3 == 0 {
+                grouped.push_str(self.group);
+            }
+            grouped.push(digit);
+        }
+
+        match fraction {
+            Some(fraction) => format!("{}{}{}{}", sign, grouped, self.decimal, fraction),
+            None => format!("{}{}", sign, grouped),
+        }
+    }
+}
+
+fn format_value(value: &FluentValue, intls: &IntlLangMemoizer) -> Option<String> {
+    match value {
+        FluentValue::Number(number) => intls
+            .with_try_get::<NumberFormat, _, _>((), |format| format.format(&number.as_string()))
+            .ok(),
+        _ => None,
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DateStyle {
+    /// e.g. "March 5, 2025"
+    Long,
+    /// e.g. "March 2025"
+    MonthYear,
+}
+
+/// A date to pass as a message argument; it is written in the bundle's language
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FluentDate {
+    pub at: DateTime<Utc>,
+    pub style: DateStyle,
+}
+
+impl FluentDate {
+    pub fn long(at: DateTime<Utc>) -> Self {
+        Self { at, style: DateStyle::Long }
+    }
+
+    pub fn month_year(at: DateTime<Utc>) -> Self {
+        Self { at, style: DateStyle::MonthYear }
+    }
+}
+
+impl From<FluentDate> for FluentValue<'_> {
+    fn from(date: FluentDate) -> Self {
+        FluentValue::Custom(Box::new(date))
+    }
+}
+
+struct DateFormat {
+    locale: Locale,
+    pattern: &'static str,
+}
+
+impl Memoizable for DateFormat {
+    type Args = DateStyle;
+    type Error = ();
+
+    fn construct(lang: LanguageIdentifier, style: DateStyle) -> Result<Self, ()> {
+        let (locale, long, month_year) = match lang.language.as_str() {
+            "es" => (Locale::es_ES, "%-d de %B de %Y", "%B de %Y"),
+            "fr" => (Locale::fr_FR, "%-d %B %Y", "%B %Y"),
+            "de" => (Locale::de_DE, "%-d. %B %Y", "%B %Y"),
+            "ja" => (Locale::ja_JP, "%Y年%-m月%-d日", "%Y年%-m月"),
+            "zh" => (Locale::zh_CN, "%Y年%-m月%-d日", "%Y年%-m月"),
+            _ => (Locale::en_US, "%B %-d, %Y", "%B %Y"),
+        };
+        let pattern = match style {
+            DateStyle::Long => long,
+            DateStyle::MonthYear => month_year,
+        };
+        Ok(Self { locale, pattern })
+    }
+}
+
+impl FluentType for FluentDate {
+    fn duplicate(&self) -> Box<dyn FluentType + Send> {
+        Box::new(*self)
+    }
+
+    fn as_string(&self, intls: &intl_memoizer::IntlLangMemoizer) -> Cow<'static, str> {
+        intls
+            .with_try_get::<DateFormat, _, _>(self.style, |format| {
+                self.at.format_localized(format.pattern, format.locale).to_string()
+            })
+            .unwrap_or_default()
+            .into()
+    }
+
+    fn as_string_threadsafe(&self, intls: &IntlLangMemoizer) -> Cow<'static, str> {
+        intls
+            .with_try_get::<DateFormat, _, _>(self.style, |format| {
+                self.at.format_localized(format.pattern, format.locale).to_string()
+            })
+            .unwrap_or_default()
+            .into()
+    }
+}
@@ -0,0 +1,64 @@
+use dioxus::prelude::*;
+use api::media::{upload_media, MediaItem, MediaUpload, MediaUsage};
+use crate::t;
+
+/// Reads the first file picked in a file input and uploads it.
+/// `None` if nothing was picked; `Err` holds a message for the user.
+pub async fn upload_picked_image(evt: FormEvent, usage: MediaUsage) -> Option<Result<MediaItem, String>> {
+    let files = evt.files()?;
+    let file_name = files.files().into_iter().next()?;
+    let Some(bytes) = files.read_file(&file_name).await else {
+        return Some(Err(t!("upload-unreadable")));
+    };
+
+    Some(
+        upload_media(MediaUpload { file_name, bytes, usage })
+            .await
+            .map_err(|err| {
+                log::error!("Failed to upload image: {}", err);
+                err.to_string()
+            }),
+    )
+}
+
+/// "Add image" button that uploads the picked image and hands back the stored item
+#[component]
+pub fn ImageUploadButton(
+    usage: MediaUsage,
+    on_uploaded: EventHandler<MediaItem>,
+    #[props(default = t!("upload-add-image"))] label: String,
+) -> Element {
+    let mut uploading = use_signal(|| false);
+    let mut error = use_signal(|| None::<String>);
+
+    rsx! {
+        div { class: "inline-flex flex-col",
+            label { class: "inline-flex items-center px-3 py-1 text-sm bg-gray-100 rounded cursor-pointer hover:bg-gray-200",
+                if uploading() { {t!("upload-uploading")} } else { "{label}" }
+                input {
+                    r#type: "file",
+                    class: "hidden",
+                    accept: "image/png,image/jpeg,image/gif,image/webp",
+                    disabled: uploading(),
+                    onchange: move |evt| {
+                        uploading.set(true);
+                        spawn(async move {
+                            match upload_picked_image(evt, usage).await {
+                                Some(Ok(item)) => {
+                                    error.set(None);
+                                    on_uploaded.call(item);
+                                }
+                                Some(Err(message)) => error.set(Some(message)),
+                                None => {}
+                            }
+                            uploading.set(false);
+                        });
+                    },
+                }
+            }
+            if let Some(err) = error() {
+                span { class: "mt-1 text-xs text-red-600", "{err}" }
+            }
+        }
+    }
+}
@@ -0,0 +1,37 @@
+use dioxus::prelude::*;
+
+/// Reminds an admin that they're signed in as someone else, with a way back
+#[component]
+pub fn ImpersonationBanner() -> Element {
+    let status = use_resource(|| async move { api::users::get_impersonation_status().await });
+    let navigator = use_navigator();
+
+    let Some(Ok(Some(current))) = status() else {
+        return rsx! {};
+    };
+
+    let until = current.expires_at.with_timezone(&chrono::Local).format("%H:%M").to_string();
+
+    rsx! {
+        div { class: "w-full bg-purple-700 text-white text-sm px-8 py-2 flex justify-between items-center",
+            span {
+                "You are signed in as {current.username} (you are {current.impersonator_username}) until {until}. Everything you do is logged."
+            }
+            button {
+                class: "px-3 py-1 bg-white text-purple-700 rounded font-medium hover:bg-purple-100",
+                onclick: move |_| {
+                    spawn(async move {
+                        match api::users::stop_impersonation().await {
+                            Ok(()) => {
+                                crate::auth::session_changed();
+                                navigator.push("/admin/users");
+                            }
+                            Err(err) => log::error!("Failed to stop impersonating: {}", err),
+                        }
+                    });
+                },
+                "Stop impersonating"
+            }
+        }
+    }
+}
@@ -6,12 +6,23 @@ pub mod button;
 pub mod cards;
 pub mod editor_sidebar;
 pub mod echo;
+pub mod feature_flag;
+pub mod i18n;
+pub mod image_upload;
+pub mod impersonation_banner;
 pub mod input;
 pub mod landing_page;
 pub mod loading_spinner;
+pub mod maintenance_banner;
+pub mod markdown;
+pub mod mention;
 pub mod modal;
 pub mod navbar_drop;
+pub mod post_article;
+pub mod public_profile;
+pub mod report_button;
 pub mod search;
+pub mod search_results;
 pub mod sidebar;
 pub mod table;
 
@@ -21,13 +32,24 @@ pub use avatar_drop::{AvatarDrop, MenuItem};
 pub use button::{Button, ButtonScheme, ButtonSize, ButtonType};
 pub use cards::{DocumentCard, StatCard};
 pub use echo::Echo;
+pub use feature_flag::use_feature_flag;
+pub use i18n::{init_i18n, use_language, FluentDate};
+pub use image_upload::{upload_picked_image, ImageUploadButton};
+pub use impersonation_banner::ImpersonationBanner;
 pub use editor_sidebar::{DocumentNode, NodeType, Sidebar, TreeNode, TreeView};
 pub use input::{DateInput, Input, InputProps, InputSize, InputType, NumberInput, PasswordInput, SelectInput, TextInput};
 pub use landing_page::LandingPage;
 pub use loading_spinner::{SpinnerColor, SpinnerProps, SpinnerSize};
+pub use maintenance_banner::MaintenanceBanner;
+pub use markdown::{MarkdownBody, MarkdownPreview, TableOfContents};
+pub use mention::{MentionAutocomplete, MentionText};
 pub use modal::Modal;
 pub use navbar_drop::{NavDrop, NavMenuItem};
-pub use search::Search;
+pub use post_article::PostArticle;
+pub use public_profile::PublicProfile;
+pub use report_button::ReportButton;
+pub use search::{search_href, Search};
+pub use search_results::SearchResults;
 pub use sidebar::{SidebarItem, TraditionalSidebar};
 pub use table::{
     Table, TableBody, TableBodyProps, TableCell, TableCellProps, TableFoot, 
@@ -0,0 +1,47 @@
+use std::time::Duration;
+use dioxus::prelude::*;
+
+// Upcoming windows are announced this far ahead
+const WARN_AHEAD_HOURS: i64 = 24;
+const REFRESH_SECONDS: u64 = 300;
+
+/// Warns of maintenance that is running or due within a day
+#[component]
+pub fn MaintenanceBanner() -> Element {
+    let mut status = use_resource(|| async move { api::log::get_system_status().await });
+
+    use_future(move || async move {
+        loop {
+            tokio::time::sleep(Duration::from_secs(REFRESH_SECONDS)).await;
+            status.restart();
+        }
+    });
+
+    let Some(Ok(current)) = status() else {
+        return rsx! {};
+    };
+
+    if let Some(window) = current.current_window {
+        let reason = window.description.trim_end_matches('.').to_string();
+        let until = window.ends_at.format("%H:%M UTC").to_string();
+        return rsx! {
+            div { class: "w-full bg-red-600 text-white text-sm px-8 py-2",
+                "Maintenance in progress: {reason}. The site is read-only until {until}."
+            }
+        };
+    }
+
+    match current.next_window {
+        Some(window) if window.starts_in().num_hours() < WARN_AHEAD_HOURS => {
+            let reason = window.description.trim_end_matches('.').to_string();
+            let from = window.starts_at.format("%a %d %b %H:%M").to_string();
+            let to = window.ends_at.format("%H:%M UTC").to_string();
+            rsx! {
+                div { class: "w-full bg-yellow-100 text-yellow-900 text-sm px-8 py-2",
+                    "Scheduled maintenance {from} – {to}: {reason}. Changes can't be saved during this time."
+                }
+            }
+        }
+        _ => rsx! {},
+    }
+}
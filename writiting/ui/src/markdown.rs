@@ -0,0 +1,140 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
+use dioxus::prelude::*;
+use api::posts::{preview_markdown, RenderedMarkdown, TocEntry};
+use crate::t;
+
+/// Fewer headings than this and the contents list is left out
+const TOC_MIN_HEADINGS: usize = 3;
+/// Wait for typing to pause this long before re-rendering the preview
+const PREVIEW_DELAY_MS: u64 = 300;
+
+static NEXT_BODY_ID: AtomicUsize = AtomicUsize::new(0);
+
+/// Sends the href of clicked app links (`/u/...`, `/blog/...`) inside the
+/// element back to Rust, so they go through the router. The listener is kept
+/// by body id so [`UNROUTE_APP_LINKS`] can remove it.
+const ROUTE_APP_LINKS: &str = r#"
+    const listener = (event) => {
+        const link = event.target.closest("#BODY_ID a[href^='/']");
+        if (link) {
+            event.preventDefault();
+            dioxus.send(link.getAttribute("href"));
+        }
+    };
+    window.markdownLinkListeners = window.markdownLinkListeners || {};
+    window.markdownLinkListeners["BODY_ID"] = listener;
+    document.addEventListener("click", listener);
+"#;
+
+/// Removes the listener [`ROUTE_APP_LINKS`] added, when the body unmounts
+const UNROUTE_APP_LINKS: &str = r#"
+    const listener = window.markdownLinkListeners && window.markdownLinkListeners["BODY_ID"];
+    if (listener) {
+        document.removeEventListener("click", listener);
+        delete window.markdownLinkListeners["BODY_ID"];
+    }
+"#;
+
+/// Links to each heading, indented by level
+#[component]
+pub fn TableOfContents(toc: Vec<TocEntry>) -> Element {
+    let top = toc.iter().map(|entry| entry.level).min().unwrap_or(1);
+    let entries: Vec<(TocEntry, String)> = toc
+        .into_iter()
+        .map(|entry| {
+            let indent = format!("padding-left: {}rem", entry.level - top);
+            (entry, indent)
+        })
+        .collect();
+
+    rsx! {
+        nav { class: "mb-6 p-4 bg-gray-50 rounded border border-gray-200 text-sm",
+            h2 { class: "font-semibold text-gray-700 mb-2", {t!("markdown-contents")} }
+            ul { class: "space-y-1",
+                for (entry, indent) in entries {
+                    li {
+                        key: "{entry.id}",
+                        style: indent,
+                        a { class: "text-blue-600 hover:underline", href: "#{entry.id}", "{entry.title}" }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// A post body rendered by the server. The HTML has already been sanitized
+/// there, which is what makes `dangerous_inner_html` safe here.
+#[component]
+pub fn MarkdownBody(rendered: RenderedMarkdown, #[props(default = true)] show_toc: bool) -> Element {
+    let body_id = use_hook(|| format!("markdown-{}", NEXT_BODY_ID.fetch_add(1, Ordering::Relaxed)));
+    let navigator = use_navigator();
+
+    let script_id = body_id.clone();
+    let drop_id = body_id.clone();
+    use_drop(move || {
+        document::eval(&UNROUTE_APP_LINKS.replace("BODY_ID", &drop_id));
+    });
+    use_future(move || {
+        let script = ROUTE_APP_LINKS.replace("BODY_ID", &script_id);
+        async move {
+            let mut clicks = document::eval(&script);
+            while let Ok(href) = clicks.recv::<String>().await {
+                navigator.push(href);
+            }
+        }
+    });
+
+    rsx! {
+        if show_toc && rendered.toc.len() >= TOC_MIN_HEADINGS {
+            TableOfContents { toc: rendered.toc.clone() }
+        }
+        div {
+            id: "{body_id}",
+            class: "markdown-body",
+            dangerous_inner_html: "{rendered.html}",
+        }
+    }
+}
+
+/// Live preview of Markdown being written, rendered by the server once typing pauses
+#[component]
+pub fn MarkdownPreview(source: String) -> Element {
+    // Restarting on every change cancels the pending sleep
+    let preview = use_resource(use_reactive!(|source| async move {
+        if source.trim().is_empty() {
+            return Ok(RenderedMarkdown::default());
+        }
+        tokio::time::sleep(Duration::from_millis(PREVIEW_DELAY_MS)).await;
+        preview_markdown(source).await
+    }));
+
+    // Keep showing the last preview while the next one renders
+    let mut last = use_signal(RenderedMarkdown::default);
+    let mut error = use_signal(|| None::<String>);
+    use_effect(move || match &*preview.read() {
+        Some(Ok(rendered)) => {
+            last.set(rendered.clone());
+            error.set(None);
+        }
+        Some(Err(err)) => {
+            log::error!("Failed to render preview: {}", err);
+            error.set(Some(err.to_string()));
+        }
+        None => {}
+    });
+
+    rsx! {
+        div { class: "h-full overflow-auto",
+            if let Some(err) = error() {
+                p { class: "mb-2 text-sm text-red-600", "{err}" }
+            }
+            if last.read().html.is_empty() {
+                p { class: "text-sm text-gray-400", {t!("markdown-preview-empty")} }
+            } else {
+                MarkdownBody { rendered: last(), show_toc: true }
+            }
+        }
+    }
+}
@@ -0,0 +1,73 @@
+use dioxus::prelude::*;
+use api::mentions::{active_mention_query, complete_mention, search_usernames, split_mentions, MentionSegment};
+
+/// Renders text with `@username` mentions turned into profile links.
+/// Only names listed in `mentions` (resolved by the server) become links.
+#[component]
+pub fn MentionText(text: String, mentions: Vec<String>) -> Element {
+    let segments = split_mentions(&text);
+
+    rsx! {
+        span { class: "whitespace-pre-wrap",
+            for segment in segments {
+                match segment {
+                    MentionSegment::Mention(name) if mentions.iter().any(|m| m.eq_ignore_ascii_case(&name)) => rsx! {
+                        Link {
+                            to: format!("/u/{}", name),
+                            class: "text-blue-600 hover:underline font-medium",
+                            "@{name}"
+                        }
+                    },
+                    MentionSegment::Mention(name) => rsx! { "@{name}" },
+                    MentionSegment::Text(text) => rsx! { "{text}" },
+                }
+            }
+        }
+    }
+}
+
+/// Username suggestions for the mention being typed at the end of `text`.
+/// Calls `on_complete` with the full text once a suggestion is picked.
+#[component]
+pub fn MentionAutocomplete(text: String, on_complete: EventHandler<String>) -> Element {
+    let mut query = use_signal(|| None::<String>);
+
+    use_effect(use_reactive!(|text| {
+        query.set(active_mention_query(&text).map(|(_, prefix)| prefix));
+    }));
+
+    let suggestions = use_resource(move || async move {
+        match query() {
+            Some(prefix) if !prefix.is_empty() => match search_usernames(prefix).await {
+                Ok(names) => names,
+                Err(err) => {
+                    log::error!("Failed to fetch username suggestions: {}", err);
+                    Vec::new()
+                }
+            },
+            _ => Vec::new(),
+        }
+    });
+
+    let names = suggestions.read().clone().unwrap_or_default();
+    if names.is_empty() {
+        return rsx! {};
+    }
+
+    rsx! {
+        ul { class: "absolute z-50 mt-1 w-56 rounded-md shadow-lg bg-white border border-gray-200 text-sm",
+            for name in names {
+                li {
+                    key: "{name}",
+                    class: "px-3 py-2 cursor-pointer hover:bg-blue-50",
+                    onclick: {
+                        let text = text.clone();
+                        let name = name.clone();
+                        move |_| on_complete.call(complete_mention(&text, &name))
+                    },
+                    "@{name}"
+                }
+            }
+        }
+    }
+}
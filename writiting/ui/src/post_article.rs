@@ -0,0 +1,47 @@
+use dioxus::prelude::*;
+use api::posts::{find_post, get_post_html};
+use crate::i18n::FluentDate;
+use crate::markdown::MarkdownBody;
+use crate::t;
+
+/// A post as readers see it: title, date and the rendered Markdown body
+#[component]
+pub fn PostArticle(id: i32) -> Element {
+    let post = use_resource(use_reactive!(|id| async move { find_post(id).await }));
+    let body = use_resource(use_reactive!(|id| async move {
+        get_post_html(id).await.map_err(|err| {
+            log::error!("Failed to render post {}: {}", id, err);
+            err
+        })
+    }));
+
+    let post = match &*post.read() {
+        Some(Ok(post)) => post.clone(),
+        Some(Err(err)) => {
+            log::error!("Failed to load post {}: {}", id, err);
+            return rsx! {
+                div { class: "py-12 text-center text-gray-600", {t!("post-not-found")} }
+            };
+        }
+        None => {
+            return rsx! {
+                div { class: "py-12 text-center text-gray-500", {t!("common-loading")} }
+            };
+        }
+    };
+
+    let published = t!("post-published", date = FluentDate::long(post.created_at));
+
+    rsx! {
+        article { class: "max-w-3xl mx-auto",
+            h1 { class: "text-3xl font-bold mb-2", "{post.title}" }
+            p { class: "text-sm text-gray-500 mb-6", "{published}" }
+            match &*body.read() {
+                Some(Ok(rendered)) => rsx! { MarkdownBody { rendered: rendered.clone() } },
+                // Fall back to the raw text rather than showing nothing
+                Some(Err(_)) => rsx! { p { class: "whitespace-pre-wrap", "{post.body}" } },
+                None => rsx! { p { class: "text-gray-500", {t!("common-loading")} } },
+            }
+        }
+    }
+}
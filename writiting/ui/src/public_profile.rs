@@ -0,0 +1,114 @@
+use dioxus::prelude::*;
+use api::moderation::ReportTarget;
+use api::users::get_public_profile;
+use crate::avatar::{Avatar, AvatarSize};
+use crate::i18n::FluentDate;
+use crate::t;
+use crate::report_button::ReportButton;
+
+/// The page behind `/u/:username`, shared by the web and desktop routers
+#[component]
+pub fn PublicProfile(username: String) -> Element {
+    let profile = use_resource(use_reactive!(|username| async move { get_public_profile(username).await }));
+
+    let profile = match &*profile.read() {
+        Some(Ok(profile)) => profile.clone(),
+        Some(Err(err)) => {
+            log::error!("Failed to load profile: {}", err);
+            return rsx! {
+                div { class: "max-w-3xl mx-auto py-12 px-4 text-center text-gray-600", {t!("profile-not-found")} }
+            };
+        }
+        None => {
+            return rsx! {
+                div { class: "max-w-3xl mx-auto py-12 px-4 text-center text-gray-500", {t!("common-loading")} }
+            };
+        }
+    };
+
+    let display_name = profile.display_name();
+    let initials = display_name.chars().next().map(|c| c.to_uppercase().to_string()).unwrap_or_default();
+    let joined = t!(
+        "profile-joined",
+        username = profile.username.clone(),
+        date = FluentDate::month_year(profile.joined_at),
+    );
+    let followers = t!("profile-followers", count = profile.followers);
+    let following = t!("profile-following", count = profile.following);
+    let posts: Vec<(String, String, String)> = profile
+        .posts
+        .iter()
+        .map(|post| (format!("/blog/{}", post.id), post.title.clone(), post.created_at.format("%Y-%m-%d").to_string()))
+        .collect();
+
+    rsx! {
+        div { class: "max-w-3xl mx-auto py-8 px-4",
+            div { class: "bg-white rounded-lg shadow p-6 mb-6",
+                div { class: "flex items-start gap-4",
+                    Avatar {
+                        src: profile.avatar_url.clone(),
+                        alt: display_name.clone(),
+                        initials: initials,
+                        size: AvatarSize::Large,
+                    }
+                    div { class: "flex-1",
+                        h1 { class: "text-2xl font-bold text-gray-900", "{display_name}" }
+                        p { class: "text-sm text-gray-500", "{joined}" }
+                        p { class: "mt-1 text-sm text-gray-700", "{followers} · {following}" }
+                    }
+                    ReportButton { target_type: ReportTarget::Profile, target_id: profile.user_id.to_string() }
+                }
+
+                if let Some(bio) = &profile.bio {
+                    p { class: "mt-4 text-gray-800 whitespace-pre-wrap", "{bio}" }
+                }
+
+                if let Some(website) = &profile.website_url {
+                    a {
+                        class: "mt-3 inline-block text-sm text-blue-600 hover:underline break-all",
+                        href: "{website}",
+                        rel: "nofollow noopener",
+                        target: "_blank",
+                        "{website}"
+                    }
+                }
+
+                if !profile.social_links.is_empty() {
+                    ul { class: "mt-4 flex flex-wrap gap-3",
+                        for link in profile.social_links.iter() {
+                            li { key: "{link.platform_id}",
+                                a {
+                                    class: "inline-flex items-center gap-1 px-3 py-1 text-sm bg-gray-100 rounded hover:bg-gray-200",
+                                    href: "{link.url}",
+                                    rel: "nofollow noopener",
+                                    target: "_blank",
+                                    title: "{link.platform_name}",
+                                    if let Some(icon) = &link.icon_class {
+                                        i { class: "fab {icon}" }
+                                    }
+                                    "{link.platform_name}"
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+
+            div { class: "bg-white rounded-lg shadow p-6",
+                h2 { class: "text-lg font-medium mb-3 text-gray-900", {t!("profile-posts")} }
+                if posts.is_empty() {
+                    p { class: "text-sm text-gray-500", {t!("profile-no-posts")} }
+                } else {
+                    ul { class: "divide-y",
+                        for (href, title, date) in posts {
+                            li { class: "py-2 flex justify-between items-center",
+                                Link { to: href, class: "text-blue-600 hover:underline", "{title}" }
+                                span { class: "text-xs text-gray-500", "{date}" }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,88 @@
+use dioxus::prelude::*;
+use api::moderation::{report_content, NewReport, ReportReason, ReportTarget};
+
+/// "Report" link that opens a small form for flagging content to the moderators
+#[component]
+pub fn ReportButton(target_type: ReportTarget, target_id: String) -> Element {
+    let mut open = use_signal(|| false);
+    let mut reason = use_signal(|| ReportReason::Spam);
+    let mut details = use_signal(String::new);
+    let mut sent = use_signal(|| false);
+    let mut error = use_signal(|| None::<String>);
+
+    let submit = move |evt: FormEvent| {
+        evt.prevent_default();
+        let report = NewReport {
+            target_type,
+            target_id: target_id.clone(),
+            reason: reason(),
+            details: Some(details()),
+        };
+
+        spawn(async move {
+            match report_content(report).await {
+                Ok(()) => {
+                    sent.set(true);
+                    open.set(false);
+                    error.set(None);
+                }
+                Err(err) => {
+                    log::error!("Failed to send report: {}", err);
+                    error.set(Some(err.to_string()));
+                }
+            }
+        });
+    };
+
+    if sent() {
+        return rsx! {
+            span { class: "text-sm text-gray-500", "Thanks, a moderator will take a look." }
+        };
+    }
+
+    if !open() {
+        return rsx! {
+            button {
+                class: "text-sm text-gray-500 hover:text-red-600",
+                onclick: move |_| open.set(true),
+                "Report"
+            }
+        };
+    }
+
+    rsx! {
+        form { class: "mt-2 p-3 border border-gray-200 rounded bg-gray-50 text-sm", onsubmit: submit,
+            if let Some(err) = error() {
+                p { class: "mb-2 text-red-600", "{err}" }
+            }
+            select {
+                class: "w-full mb-2 px-2 py-1 border border-gray-300 rounded",
+                onchange: move |e| {
+                    if let Some(r) = ReportReason::ALL.into_iter().find(|r| r.as_str() == e.value()) {
+                        reason.set(r);
+                    }
+                },
+                for r in ReportReason::ALL {
+                    option { value: r.as_str(), selected: reason() == r, "{r.label()}" }
+                }
+            }
+            textarea {
+                class: "w-full mb-2 px-2 py-1 border border-gray-300 rounded",
+                rows: "3",
+                maxlength: "1000",
+                placeholder: "Anything the moderators should know (optional)",
+                value: "{details}",
+                oninput: move |e| details.set(e.value()),
+            }
+            div { class: "flex gap-2",
+                button { r#type: "submit", class: "px-3 py-1 bg-red-600 text-white rounded hover:bg-red-700", "Send report" }
+                button {
+                    r#type: "button",
+                    class: "px-3 py-1 bg-gray-200 rounded hover:bg-gray-300",
+                    onclick: move |_| open.set(false),
+                    "Cancel"
+                }
+            }
+        }
+    }
+}
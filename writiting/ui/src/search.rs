@@ -1,31 +1,78 @@
+use std::time::Duration;
 use dioxus::prelude::*;
+use api::search::{search_suggestions, SearchKind};
+use crate::t;
+
+/// Wait for typing to pause this long before asking for suggestions
+const SUGGEST_DELAY_MS: u64 = 250;
+
+/// `/search?q=...` for a query
+pub fn search_href(query: &str) -> String {
+    let encoded: String = query
+        .bytes()
+        .map(|b| match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => (b as char).to_string(),
+            _ => format!("%{:02X}", b),
+        })
+        .collect();
+    format!("/search?q={}", encoded)
+}
 
 /// Modern search component with clean design and smooth interactions
 #[component]
 pub fn Search() -> Element {
     let mut search_query = use_signal(|| String::new());
+    let mut focused = use_signal(|| false);
+    let navigator = use_navigator();
+
+    // Restarting on every keystroke cancels the pending sleep, so this only
+    // hits the server once typing pauses
+    let suggestions = use_resource(move || async move {
+        let query = search_query();
+        if query.trim().chars().count() < 2 {
+            return Vec::new();
+        }
+        tokio::time::sleep(Duration::from_millis(SUGGEST_DELAY_MS)).await;
+        search_suggestions(query).await.unwrap_or_else(|err| {
+            log::error!("Failed to load search suggestions: {}", err);
+            Vec::new()
+        })
+    });
+
+    let suggestion_list = match &*suggestions.read() {
+        Some(list) if focused() => list.clone(),
+        _ => Vec::new(),
+    };
 
     rsx! {
-        div { class: "max-w-md mx-auto",
+        div { class: "max-w-md mx-auto relative",
             form {
                 class: "relative",
-                onsubmit: move |_| {
-                    log::info!("Searching for: {}", search_query());
+                onsubmit: move |evt| {
+                    evt.prevent_default();
+                    let query = search_query().trim().to_string();
+                    if !query.is_empty() {
+                        focused.set(false);
+                        navigator.push(search_href(&query));
+                    }
                 },
                 // Search input
                 input {
                     class: "w-full py-3 px-4 pr-12 rounded-xl border border-gray-200 dark:border-gray-700 focus:outline-none focus:ring-2 focus:ring-blue-500 focus:border-transparent transition-all duration-200 bg-white dark:bg-gray-800 text-gray-900 dark:text-gray-100 placeholder-gray-400 dark:placeholder-gray-500 shadow-sm",
                     id: "modern-search",
-                    placeholder: "Search...",
+                    placeholder: t!("search-placeholder"),
                     r#type: "text",
+                    autocomplete: "off",
                     value: "{search_query}",
                     oninput: move |e| search_query.set(e.value()),
+                    onfocus: move |_| focused.set(true),
+                    onblur: move |_| focused.set(false),
                 }
                 // Search icon button
                 button {
                     class: "absolute right-3 top-1/2 transform -translate-y-1/2 text-gray-400 hover:text-blue-500 dark:hover:text-blue-400 transition-colors duration-200",
                     r#type: "submit",
-                    aria_label: "Search",
+                    aria_label: t!("search-button"),
                     svg {
                         class: "w-5 h-5",
                         xmlns: "http://www.w3.org/2000/svg",
@@ -39,7 +86,27 @@ pub fn Search() -> Element {
                     }
                 }
             }
-                // Optional: Recent searches dropdown could be added here when focused
+            if !suggestion_list.is_empty() {
+                ul { class: "absolute z-20 mt-1 w-full bg-white dark:bg-gray-800 border border-gray-200 dark:border-gray-700 rounded-xl shadow-lg overflow-hidden",
+                    for suggestion in suggestion_list {
+                        li { key: "{suggestion.url}",
+                            // mousedown fires before the input's blur hides the list
+                            button {
+                                class: "w-full flex justify-between gap-3 px-4 py-2 text-left text-sm text-gray-900 dark:text-gray-100 hover:bg-gray-100 dark:hover:bg-gray-700",
+                                r#type: "button",
+                                onmousedown: move |_| {
+                                    focused.set(false);
+                                    navigator.push(suggestion.url.clone());
+                                },
+                                span { class: "truncate", "{suggestion.label}" }
+                                if suggestion.kind == SearchKind::User {
+                                    span { class: "text-xs text-gray-400", {t!("search-kind-user")} }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
         }
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,156 @@
+use dioxus::prelude::*;
+use chrono::NaiveDate;
+use api::search::{search, SearchFilters, SearchHit, SearchKind};
+use crate::t;
+
+fn kind_label(kind: Option<SearchKind>) -> String {
+    match kind {
+        None => t!("search-kind-all"),
+        Some(SearchKind::Post) => t!("search-kind-post"),
+        Some(SearchKind::Comment) => t!("search-kind-comment"),
+        Some(SearchKind::User) => t!("search-kind-user"),
+    }
+}
+
+/// The page behind `/search?q=...`, shared by the web and desktop routers
+#[component]
+pub fn SearchResults(q: String) -> Element {
+    let mut kind = use_signal(|| None::<SearchKind>);
+    let mut author = use_signal(String::new);
+    let mut from = use_signal(String::new);
+    let mut to = use_signal(String::new);
+    let mut page = use_signal(|| 1i64);
+
+    // A new query from the search box starts over on the first page
+    let mut last_query = use_signal(|| q.clone());
+    if *last_query.peek() != q {
+        last_query.set(q.clone());
+        page.set(1);
+    }
+
+    let results = use_resource(move || async move {
+        let filters = SearchFilters {
+            kind: kind(),
+            author: Some(author()),
+            from: NaiveDate::parse_from_str(&from(), "%Y-%m-%d").ok(),
+            to: NaiveDate::parse_from_str(&to(), "%Y-%m-%d").ok(),
+        };
+        search(last_query(), filters, page()).await
+    });
+
+    let (hits, total, per_page, error) = match &*results.read() {
+        Some(Ok(p)) => (p.hits.clone(), p.total, p.per_page, None),
+        Some(Err(err)) => {
+            log::error!("Search failed: {}", err);
+            (Vec::new(), 0, 20, Some(err.to_string()))
+        }
+        None => (Vec::new(), 0, 20, None),
+    };
+    let last_page = ((total + per_page - 1) / per_page).max(1);
+    let loading = results.read().is_none();
+
+    rsx! {
+        div { class: "max-w-3xl mx-auto py-8 px-4",
+            h1 { class: "text-2xl font-bold text-gray-900 mb-4", {t!("search-title", query = q.clone())} }
+
+            div { class: "bg-white rounded-lg shadow p-4 mb-6 grid grid-cols-1 md:grid-cols-4 gap-3",
+                select {
+                    class: "px-3 py-2 border border-gray-300 rounded",
+                    onchange: move |e| {
+                        kind.set(SearchKind::ALL.into_iter().find(|k| k.as_str() == e.value()));
+                        page.set(1);
+                    },
+                    option { value: "", selected: kind().is_none(), {kind_label(None)} }
+                    for k in SearchKind::ALL {
+                        option { value: k.as_str(), selected: kind() == Some(k), {kind_label(Some(k))} }
+                    }
+                }
+                input {
+                    class: "px-3 py-2 border border-gray-300 rounded",
+                    placeholder: t!("search-author"),
+                    value: "{author}",
+                    oninput: move |e| { author.set(e.value()); page.set(1); },
+                }
+                input {
+                    r#type: "date",
+                    class: "px-3 py-2 border border-gray-300 rounded",
+                    title: t!("search-from"),
+                    value: "{from}",
+                    oninput: move |e| { from.set(e.value()); page.set(1); },
+                }
+                input {
+                    r#type: "date",
+                    class: "px-3 py-2 border border-gray-300 rounded",
+                    title: t!("search-to"),
+                    value: "{to}",
+                    oninput: move |e| { to.set(e.value()); page.set(1); },
+                }
+            }
+
+            if let Some(err) = error {
+                p { class: "mb-4 text-sm text-red-600", "{err}" }
+            } else if loading {
+                p { class: "text-gray-500", {t!("common-loading")} }
+            } else if hits.is_empty() {
+                p { class: "text-gray-500", {t!("search-no-results")} }
+            } else {
+                p { class: "mb-3 text-sm text-gray-600", {t!("search-count", count = total)} }
+                ul { class: "space-y-3",
+                    for hit in hits {
+                        SearchHitRow { key: "{hit.kind.as_str()}-{hit.id}", hit }
+                    }
+                }
+
+                div { class: "flex justify-between items-center mt-6 text-sm text-gray-600",
+                    button {
+                        class: "px-3 py-1 bg-gray-200 rounded disabled:opacity-50",
+                        disabled: page() <= 1,
+                        onclick: move |_| page -= 1,
+                        {t!("search-previous")}
+                    }
+                    span { {t!("search-page", page = page(), pages = last_page)} }
+                    button {
+                        class: "px-3 py-1 bg-gray-200 rounded disabled:opacity-50",
+                        disabled: page() >= last_page,
+                        onclick: move |_| page += 1,
+                        {t!("search-next")}
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn SearchHitRow(hit: SearchHit) -> Element {
+    let heading = match hit.kind {
+        SearchKind::Comment => t!("search-comment-on", title = hit.title.clone()),
+        _ => hit.title.clone(),
+    };
+    let byline = match (&hit.kind, &hit.author) {
+        (SearchKind::User, _) | (_, None) => None,
+        (_, Some(author)) => Some(t!("search-by", author = author.clone())),
+    };
+    let date = hit.created_at.format("%Y-%m-%d").to_string();
+
+    rsx! {
+        li { class: "bg-white rounded-lg shadow p-4",
+            div { class: "flex justify-between items-baseline gap-3",
+                Link { to: hit.url(), class: "text-lg text-blue-600 hover:underline", "{heading}" }
+                span { class: "text-xs text-gray-500 whitespace-nowrap", "{date}" }
+            }
+            if let Some(byline) = byline {
+                p { class: "text-xs text-gray-500", "{byline}" }
+            }
+            p { class: "mt-1 text-sm text-gray-700",
+                for segment in hit.snippet_segments() {
+                    if segment.highlighted {
+                        mark { class: "bg-yellow-200 rounded px-0.5", "{segment.text}" }
+                    } else {
+                        span { "{segment.text}" }
+                    }
+                }
+            }
+        }
+    }
+}
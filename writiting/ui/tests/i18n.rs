@@ -0,0 +1,89 @@
+//! Every language must carry every message, and every `t!` key in the ui and
+//! desktop crates must have one.
+use std::collections::BTreeSet;
+use std::fs;
+use std::path::Path;
+
+use fluent_syntax::ast::Entry;
+use ui::i18n::{source, translate_in, FluentArgs, Language};
+
+fn message_ids(language: Language) -> BTreeSet<String> {
+    let resource = fluent_syntax::parser::parse(source(language))
+        .unwrap_or_else(|(_, errors)| panic!("{} messages don't parse: {:?}", language.code(), errors));
+
+    resource
+        .body
+        .iter()
+        .filter_map(|entry| match entry {
+            Entry::Message(message) => Some(message.id.name.to_string()),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Literal keys passed to `t!` in the `.rs` files under `dir`
+fn keys_used_in(dir: &Path, keys: &mut BTreeSet<String>) {
+    for entry in fs::read_dir(dir).expect("source directory is readable") {
+        let path = entry.expect("directory entry is readable").path();
+        if path.is_dir() {
+            keys_used_in(&path, keys);
+        } else if path.extension().is_some_and(|ext| ext == "rs") {
+            let code = fs::read_to_string(&path).expect("source file is readable");
+            for (at, call) in code.match_indices("t!(\"") {
+                // Skip `format!(` and friends
+                let macro_start = code[..at].chars().next_back();
+                if macro_start.is_some_and(|c| c.is_alphanumeric() || c == '_') {
+                    continue;
+                }
+                if let Some((key, _)) = code[at + call.len()..].split_once('"') {
+                    keys.insert(key.to_string());
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn every_language_has_every_message() {
+    let english = message_ids(Language::English);
+    let mut problems = Vec::new();
+
+    for language in Language::ALL {
+        let ids = message_ids(language);
+        for missing in english.difference(&ids) {
+            problems.push(format!("{} is missing {}", language.code(), missing));
+        }
+        for extra in ids.difference(&english) {
+            problems.push(format!("{} has {}, which English doesn't", language.code(), extra));
+        }
+    }
+
+    assert!(problems.is_empty(), "{}", problems.join("\n"));
+}
+
+#[test]
+fn every_key_in_the_code_has_a_message() {
+    let crates = Path::new(env!("CARGO_MANIFEST_DIR")).parent().expect("ui is inside the workspace");
+    let mut keys = BTreeSet::new();
+    keys_used_in(&crates.join("ui/src"), &mut keys);
+    keys_used_in(&crates.join("desktop/src"), &mut keys);
+    assert!(!keys.is_empty(), "no t! calls found");
+
+    let english = message_ids(Language::English);
+    let missing: Vec<_> = keys.difference(&english).cloned().collect();
+    assert!(missing.is_empty(), "no message for: {}", missing.join(", "));
+}
+
+#[test]
+fn counts_use_the_language_plural_and_number_rules() {
+    let count = |language, count: i64| {
+        let mut args = FluentArgs::new();
+        args.set("count", count);
+        translate_in(language, "profile-followers", Some(&args))
+    };
+
+    assert_eq!(count(Language::English, 1), "1 follower");
+    assert_eq!(count(Language::English, 12345), "12,345 followers");
+    assert_eq!(count(Language::Spanish, 1234), "1234 seguidores");
+    assert_eq!(count(Language::German, 12345), "12.345 Follower");
+}
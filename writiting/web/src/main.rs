@@ -1,5 +1,6 @@
 use dioxus::prelude::*;
 
+use ui::{PublicProfile, SearchResults};
 use views::{Blog, Home};
 
 mod views;
@@ -12,6 +13,10 @@ enum Route {
     Home {},
     #[route("/blog/:id")]
     Blog { id: i32 },
+    #[route("/u/:username")]
+    PublicProfile { username: String },
+    #[route("/search?:q")]
+    SearchResults { q: String },
 }
 
 const FAVICON: Asset = asset!("/assets/favicon.ico");
@@ -24,6 +29,8 @@ fn main() {
 #[component]
 fn App() -> Element {
     // Build cool things ✌️
+    ui::auth::init_session();
+    ui::init_i18n();
 
     rsx! {
         // Global app resources
@@ -1,5 +1,6 @@
 use crate::Route;
 use dioxus::prelude::*;
+use ui::{t, PostArticle};
 
 const BLOG_CSS: Asset = asset!("/assets/blog.css");
 
@@ -12,18 +13,17 @@ pub fn Blog(id: i32) -> Element {
             id: "blog",
 
             // Content
-            h1 { "This is blog #{id}!" }
-            p { "In blog #{id}, we show how the Dioxus router works and how URL parameters can be passed as props to our route components." }
+            PostArticle { id }
 
             // Navigation links
             Link {
                 to: Route::Blog { id: id - 1 },
-                "Previous"
+                {t!("blog-previous")}
             }
             span { " <---> " }
             Link {
                 to: Route::Blog { id: id + 1 },
-                "Next"
+                {t!("blog-next")}
             }
         }
     }